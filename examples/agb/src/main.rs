@@ -9,11 +9,6 @@ use agb::{
     interrupt::{Interrupt, VBlank, add_interrupt_handler},
 };
 
-// `agb` does not currently have any support for serial input/output, so for now we use pointers to
-// the mmio addresses.
-const RCNT: *mut u16 = 0x0400_0134 as *mut u16;
-const SIOCNT: *mut u16 = 0x0400_0128 as *mut u16;
-
 #[agb::entry]
 fn main(mut _gba: Gba) -> ! {
     let vblank = VBlank::get();
@@ -30,12 +25,9 @@ fn main(mut _gba: Gba) -> ! {
                 gba_rumble::game_boy_player_interrupt()
             })
         };
-        // Enable serial communication. `agb` doesn't currently natively support this, so we have
-        // to do it manually.
-        unsafe {
-            RCNT.write_volatile(0);
-            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
-        }
+        // Enable serial communication. `agb` doesn't currently natively support this, so we let
+        // `gba_rumble` configure it.
+        game_boy_player_rumble.enable_serial();
         loop {
             vblank.wait_for_vblank();
             // The Game Boy Player supports starting, stopping, and hard stopping the rumble motor
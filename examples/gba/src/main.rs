@@ -32,8 +32,7 @@ pub fn main() {
     // Detecting the Game Boy Player must be one of the first things done in your program.
     if let Some(game_boy_player_rumble) = gba_rumble::GameBoyPlayer::detect() {
         // Enable serial communication.
-        RCNT.write(0);
-        SIOCNT.write(0x4000 | 0x1000 | 8);
+        game_boy_player_rumble.enable_serial();
 
         loop {
             VBlankIntrWait();
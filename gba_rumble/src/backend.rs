@@ -0,0 +1,130 @@
+//! Common interface over the available rumble mechanisms.
+
+use crate::{Calibration, ResponseCurve};
+
+/// A command sent to a rumble [`Backend`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Command {
+    /// Activate rumble.
+    Start,
+    /// Deactivate rumble.
+    Stop,
+    /// Deactivate rumble with a "hard" stop, braking the motor.
+    ///
+    /// Backends without [`Capabilities::has_brake`] treat this the same as [`Command::Stop`].
+    HardStop,
+}
+
+/// Describes what a [`Backend`] is able to do.
+///
+/// Engine-level code can use this to adapt to the hardware in use, such as skipping intensity
+/// ramps on hardware that can only turn its motor on or off.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Capabilities {
+    /// Whether the motor can be driven at varying intensities, rather than only on or off.
+    pub has_intensity: bool,
+    /// Whether the motor can be braked using [`Command::HardStop`].
+    pub has_brake: bool,
+    /// The number of frames between sending a command and the motor responding to it.
+    pub latency_frames: u8,
+    /// Whether the backend communicates over the serial port, making the serial port unavailable
+    /// for other uses.
+    pub shared_with_sio: bool,
+}
+
+/// A mechanism through which rumble can be performed.
+///
+/// This is implemented by each of the crate's rumble mechanisms, such as [`Gpio`] and
+/// [`GameBoyPlayer`], allowing code to be written generically over them.
+///
+/// This is also the boundary at which this crate would be adapted to other libraries' rumble
+/// abstractions. `agb` does not currently provide one, so no adapter exists yet; if it gains one,
+/// its traits would be implemented for any `Backend` behind an `agb` feature, leaving [`Driver`],
+/// [`Mixer`], and the code built on them unchanged.
+///
+/// [`Driver`]: crate::Driver
+/// [`GameBoyPlayer`]: crate::GameBoyPlayer
+/// [`Gpio`]: crate::Gpio
+/// [`Mixer`]: crate::Mixer
+pub trait Backend {
+    /// Returns the capabilities of this backend.
+    fn capabilities(&self) -> Capabilities;
+
+    /// Returns the response curve suited to this backend's motor.
+    ///
+    /// This is applied by a [`Driver`] to convert intensities into duty cycles. The default is
+    /// [`ResponseCurve::LINEAR`], which suits backends that can only turn their motor on or off.
+    ///
+    /// [`Driver`]: crate::Driver
+    fn response_curve(&self) -> ResponseCurve {
+        ResponseCurve::LINEAR
+    }
+
+    /// Returns the number of frames this backend's motor takes to spin up enough to be felt.
+    ///
+    /// A motor started for fewer frames than this stops again before it is noticeable, so very
+    /// short pulses are lost. A [`Driver`] can compensate by keeping the motor on for at least
+    /// this many frames, using [`Driver::set_spin_up_frames()`]. The values for the crate's
+    /// backends are:
+    ///
+    /// | Backend           | Spin-up frames | Motor                                      |
+    /// |-------------------|----------------|--------------------------------------------|
+    /// | [`Gpio`]          | 3              | Cartridge motors, such as in *Drill Dozer* |
+    /// | [`GameBoyPlayer`] | 2              | The GameCube controller's motor            |
+    /// | `DsRumblePak`     | 0              | Moves as soon as it is pulsed              |
+    ///
+    /// The default is the [`calibration()`]'s spin-up frames.
+    ///
+    /// [`calibration()`]: Backend::calibration()
+    /// [`Driver`]: crate::Driver
+    /// [`Driver::set_spin_up_frames()`]: crate::Driver::set_spin_up_frames()
+    /// [`GameBoyPlayer`]: crate::GameBoyPlayer
+    /// [`Gpio`]: crate::Gpio
+    fn spin_up_frames(&self) -> u8 {
+        self.calibration().spin_up_frames
+    }
+
+    /// Returns the calibration profile of this backend's motor.
+    ///
+    /// This is selected automatically by a [`Driver`], unless overridden with
+    /// [`Driver::set_calibration()`]. The default is [`Calibration::UNCALIBRATED`], using this
+    /// backend's [`response_curve()`].
+    ///
+    /// [`Driver`]: crate::Driver
+    /// [`Driver::set_calibration()`]: crate::Driver::set_calibration()
+    /// [`response_curve()`]: Backend::response_curve()
+    fn calibration(&self) -> Calibration {
+        Calibration {
+            curve: self.response_curve(),
+            ..Calibration::UNCALIBRATED
+        }
+    }
+
+    /// Returns the fewest frames each command must be kept for this backend to deliver it.
+    ///
+    /// A [`Driver`] keeps the motor in each state for at least this many frames, so that a
+    /// command is never replaced before it is delivered. This is more than `1` only for a
+    /// [`GameBoyPlayer`] whose transfers are throttled with [`GbpConfig::transfer_interval()`].
+    /// The default is `1`.
+    ///
+    /// [`Driver`]: crate::Driver
+    /// [`GameBoyPlayer`]: crate::GameBoyPlayer
+    /// [`GbpConfig::transfer_interval()`]: crate::GbpConfig::transfer_interval()
+    fn min_command_frames(&self) -> u8 {
+        1
+    }
+
+    /// Sends a command to the rumble motor.
+    fn send(&mut self, command: Command);
+
+    /// Returns whether the hardware reports the motor as on, or `None` if this backend cannot
+    /// observe its motor.
+    ///
+    /// This is used by [`Driver::self_test()`] to confirm that commands take effect. The default
+    /// returns `None`.
+    ///
+    /// [`Driver::self_test()`]: crate::Driver::self_test()
+    fn read_back(&self) -> Option<bool> {
+        None
+    }
+}
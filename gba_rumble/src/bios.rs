@@ -0,0 +1,43 @@
+//! Wrappers around BIOS calls.
+//!
+//! With the `bios` feature enabled, these are the exact calls this crate makes, provided for
+//! programs that write glue around detection and need the same calls without repeating the inline
+//! assembly and its clobber lists. Both are issued from Thumb code, and declare every register the
+//! BIOS may overwrite.
+
+use core::arch::asm;
+
+/// Waits until a new v-blank interrupt occurs, using the BIOS `VBlankIntrWait` call.
+///
+/// This requires v-blank interrupts to be enabled, and the interrupt handler to acknowledge them
+/// in the BIOS interrupt flags, or waiting will hang forever.
+#[instruction_set(arm::t32)]
+pub fn wait_for_vblank() {
+    unsafe {
+        asm! {
+            "swi #0x05",
+            out("r0") _,
+            out("r1") _,
+            out("r3") _,
+            options(preserves_flags),
+        }
+    };
+}
+
+/// Resets VRAM and palette data, using the BIOS `RegisterRamReset` call.
+///
+/// This is called by the built-in splash screen after detecting the Game Boy Player, clearing the
+/// logo before the game draws its own graphics.
+#[inline]
+#[instruction_set(arm::t32)]
+pub fn reset_vram() {
+    unsafe {
+        asm! {
+            "swi #0x01",
+            // Palette and VRAM.
+            inlateout("r0") 0b1100 => _,
+            out("r1") _,
+            out("r3") _,
+        }
+    };
+}
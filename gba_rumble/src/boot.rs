@@ -0,0 +1,150 @@
+//! Distinguishing cold boots from soft resets.
+
+use core::ptr;
+
+/// The word in EWRAM marking that the program has booted before.
+///
+/// EWRAM is left untouched by a soft reset, but holds no meaningful value after the console is
+/// powered on.
+const SENTINEL: *mut u32 = ptr::with_exposed_provenance_mut(0x0203_FFFC);
+/// The upper bytes of the sentinel. The lowest byte holds the cached detection result.
+const MAGIC: u32 = 0x4750_4200;
+/// The lowest byte of the sentinel when no detection result is cached.
+const UNCACHED: u32 = 0;
+/// The lowest byte of the sentinel when the Game Boy Player was not detected.
+const NOT_DETECTED: u32 = 1;
+/// The lowest byte of the sentinel when the Game Boy Player was detected.
+const DETECTED: u32 = 2;
+
+/// The kind of boot determined by [`BootKind::current()`], cached until the next boot.
+///
+/// This is zeroed at each boot, unlike the sentinel.
+pub(crate) static mut CURRENT: Option<BootKind> = None;
+
+/// Whether the program was started by powering on the console or by a soft reset.
+///
+/// The Game Boy Player only needs to see its logo once after the console is powered on. After a
+/// soft reset, such as returning to the title screen with a button combination, the Game Boy
+/// Player is still active, and displaying the logo again only delays the player.
+/// [`DetectionPolicy::SkipOnWarmBoot`] uses this to skip the logo after a soft reset.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BootKind {
+    /// The console was powered on.
+    Cold,
+    /// The program was restarted without powering off, such as by a soft reset or by returning
+    /// from a flash cartridge's menu.
+    Warm,
+}
+
+impl BootKind {
+    /// Returns whether the current boot is a cold boot or a warm boot.
+    ///
+    /// A soft reset leaves EWRAM untouched, so the first call after each boot checks for a
+    /// sentinel in the last word of EWRAM, at `0x0203FFFC`, and then writes it for the next boot.
+    /// Programs using this must therefore not store anything in that word. Later calls return the
+    /// same result until the next boot.
+    ///
+    /// Soft resets are detected as long as the program's startup code does not clear EWRAM.
+    pub fn current() -> Self {
+        if let Some(boot) = unsafe { (&raw const CURRENT).read_volatile() } {
+            return boot;
+        }
+        let boot = if unsafe { SENTINEL.read_volatile() } & !0xFF == MAGIC {
+            Self::Warm
+        } else {
+            unsafe {
+                SENTINEL.write_volatile(MAGIC | UNCACHED);
+            }
+            Self::Cold
+        };
+        unsafe {
+            (&raw mut CURRENT).write_volatile(Some(boot));
+        }
+        boot
+    }
+}
+
+/// When to display the Game Boy Player logo, as set by [`GbpConfig::detection_policy()`].
+///
+/// [`GbpConfig::detection_policy()`]: crate::GbpConfig::detection_policy()
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum DetectionPolicy {
+    /// Always display the logo and listen for the Game Boy Player.
+    ///
+    /// This is the default.
+    #[default]
+    Always,
+    /// Reuse the result of the previous boot's detection on a [`BootKind::Warm`] boot, without
+    /// displaying the logo.
+    ///
+    /// Full detection is still performed on a cold boot, or if no previous result is available.
+    /// The result of each full detection is stored alongside the sentinel described in
+    /// [`BootKind::current()`].
+    SkipOnWarmBoot,
+}
+
+/// Returns the detection result stored by [`cache_detection()`] before the last soft reset.
+pub(crate) fn cached_detection() -> Option<bool> {
+    match unsafe { SENTINEL.read_volatile() } {
+        word if word == MAGIC | NOT_DETECTED => Some(false),
+        word if word == MAGIC | DETECTED => Some(true),
+        _ => None,
+    }
+}
+
+/// Stores whether the Game Boy Player was detected, to be reused after a soft reset.
+pub(crate) fn cache_detection(detected: bool) {
+    let result = if detected { DETECTED } else { NOT_DETECTED };
+    unsafe {
+        SENTINEL.write_volatile(MAGIC | result);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BootKind, CURRENT, SENTINEL, cache_detection, cached_detection};
+    use claims::{assert_none, assert_some_eq};
+    use gba_test::test;
+
+    #[test]
+    fn current_is_stable() {
+        let boot = BootKind::current();
+
+        assert_eq!(BootKind::current(), boot);
+    }
+
+    #[test]
+    fn cold_then_warm() {
+        unsafe {
+            SENTINEL.write_volatile(0);
+            CURRENT = None;
+        }
+        assert_eq!(BootKind::current(), BootKind::Cold);
+
+        unsafe {
+            CURRENT = None;
+        }
+        assert_eq!(BootKind::current(), BootKind::Warm);
+    }
+
+    #[test]
+    fn cold_boot_clears_cache() {
+        cache_detection(true);
+        unsafe {
+            SENTINEL.write_volatile(SENTINEL.read_volatile() ^ 0x0100);
+            CURRENT = None;
+        }
+
+        assert_eq!(BootKind::current(), BootKind::Cold);
+        assert_none!(cached_detection());
+    }
+
+    #[test]
+    fn cache_detection_round_trip() {
+        cache_detection(true);
+        assert_some_eq!(cached_detection(), true);
+
+        cache_detection(false);
+        assert_some_eq!(cached_detection(), false);
+    }
+}
@@ -0,0 +1,136 @@
+//! Measuring the boot phases owned by the crate, for the `boot-report` feature.
+//!
+//! With the feature enabled, timers 2 and 3 are cascaded into a 32-bit cycle counter the first
+//! time a phase is measured, and the length of each phase is written as an informational message
+//! once it ends. Otherwise, measuring does nothing and compiles away entirely.
+
+use core::fmt::{self, Display, Formatter};
+
+/// A part of booting performed by the crate.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Phase {
+    /// Uploading the Game Boy Player logo, as done by `SplashRenderer::prepare()`.
+    SplashUpload,
+    /// Displaying the logo and listening for the Game Boy Player, one frame at a time.
+    DetectionLoop,
+    /// Clearing the logo from VRAM, as done by `SplashRenderer::teardown()`.
+    VramReset,
+    /// Configuring the serial port and registering the serial interrupt handler.
+    SerialArming,
+}
+
+impl Display for Phase {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::SplashUpload => "splash upload",
+            Self::DetectionLoop => "detection loop",
+            Self::VramReset => "VRAM reset",
+            Self::SerialArming => "serial arming",
+        })
+    }
+}
+
+/// Measures a single phase, from its creation until [`report()`].
+///
+/// [`report()`]: Stopwatch::report()
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Stopwatch {
+    #[cfg(feature = "boot-report")]
+    start: u32,
+}
+
+impl Stopwatch {
+    /// Starts measuring.
+    #[inline(always)]
+    pub(crate) fn start() -> Self {
+        Self {
+            #[cfg(feature = "boot-report")]
+            start: counter::cycles(),
+        }
+    }
+
+    /// Stops measuring, writing how long `phase` took.
+    #[inline(always)]
+    pub(crate) fn report(self, phase: Phase) {
+        #[cfg(feature = "boot-report")]
+        {
+            let cycles = counter::cycles().wrapping_sub(self.start);
+            crate::logging::info!("{}", Report { phase, cycles });
+        }
+        #[cfg(not(feature = "boot-report"))]
+        let _ = phase;
+    }
+}
+
+/// A measured phase, formatted as a single line of the report.
+#[cfg_attr(not(any(feature = "boot-report", test)), expect(dead_code))]
+struct Report {
+    phase: Phase,
+    cycles: u32,
+}
+
+impl Display for Report {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        let frames = self.cycles / crate::clock::CYCLES_PER_FRAME;
+        let hundredths =
+            self.cycles % crate::clock::CYCLES_PER_FRAME * 100 / crate::clock::CYCLES_PER_FRAME;
+        write!(
+            formatter,
+            "boot: {} took {} cycles ({frames}.{hundredths:02} frames)",
+            self.phase, self.cycles
+        )
+    }
+}
+
+#[cfg(feature = "boot-report")]
+mod counter {
+    use crate::Timer;
+
+    const LOW: Timer = Timer::Timer2;
+    const HIGH: Timer = Timer::Timer3;
+
+    /// Returns the number of cycles counted so far, starting the counter if it is not running.
+    pub(super) fn cycles() -> u32 {
+        unsafe {
+            if HIGH.control().read_volatile() & 0x80 == 0 {
+                LOW.control().write_volatile(0);
+                HIGH.control().write_volatile(0);
+                LOW.counter().write_volatile(0);
+                HIGH.counter().write_volatile(0);
+                // Enabled, counting up whenever the lower timer overflows.
+                HIGH.control().write_volatile(0x80 | 0x04);
+                // Enabled, counting every cycle.
+                LOW.control().write_volatile(0x80);
+            }
+            // The lower half may overflow between reads, so read the upper half again until it is
+            // stable.
+            loop {
+                let high = HIGH.counter().read_volatile();
+                let low = LOW.counter().read_volatile();
+                if HIGH.counter().read_volatile() == high {
+                    return u32::from(high) << 16 | u32::from(low);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Phase, Report};
+    use alloc::format;
+    use gba_test::test;
+
+    #[test]
+    fn report_format() {
+        let report = Report {
+            phase: Phase::DetectionLoop,
+            cycles: 280_896 * 3 + 140_448,
+        };
+
+        assert_eq!(
+            format!("{report}"),
+            "boot: detection loop took 983136 cycles (3.50 frames)"
+        );
+    }
+}
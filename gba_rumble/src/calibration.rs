@@ -0,0 +1,220 @@
+//! Tuning intensities to the feel of specific motors.
+
+use crate::{Intensity, ResponseCurve};
+
+/// How a particular motor responds to being driven, used to make effects feel alike across
+/// hardware.
+///
+/// The same duty cycle feels very different on a cartridge's motor than on a GameCube
+/// controller's. A calibration profile describes a motor's perceived-strength [`ResponseCurve`],
+/// the weakest duty cycle at which it is felt at all, and how many frames it takes to spin up.
+///
+/// Each [`Backend`] provides a profile for its motor through [`Backend::calibration()`], which a
+/// [`Driver`] selects automatically. Profiles can be overridden with
+/// [`Driver::set_calibration()`], such as from a calibration screen in a game's options menu.
+///
+/// ``` rust
+/// use gba_rumble::{Calibration, Driver, Gpio, Intensity, ResponseCurve};
+///
+/// let mut driver = Driver::<_>::new(Gpio);
+/// // The player found weak effects hard to feel on their cartridge.
+/// driver.set_calibration(Some(Calibration {
+///     min_duty: Intensity::new(128),
+///     curve: ResponseCurve::SQUARE_ROOT,
+///     ..Calibration::CARTRIDGE
+/// }));
+/// ```
+///
+/// Games that know which motor they are meant for can instead declare it as a [`MotorType`], using
+/// [`Driver::set_motor_type()`].
+///
+/// [`Backend`]: crate::Backend
+/// [`Backend::calibration()`]: crate::Backend::calibration()
+/// [`Driver`]: crate::Driver
+/// [`Driver::set_calibration()`]: crate::Driver::set_calibration()
+/// [`Driver::set_motor_type()`]: crate::Driver::set_motor_type()
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Calibration {
+    /// The weakest duty cycle at which the motor is felt.
+    ///
+    /// Any nonzero duty cycle below this is raised to it, so that weak effects are still felt.
+    /// Backends that can only turn their motor on or off are unaffected by this.
+    pub min_duty: Intensity,
+    /// The number of frames the motor takes to spin up enough to be felt.
+    ///
+    /// This is only acted upon once spin-up compensation is turned on, using
+    /// [`Driver::set_spin_up_frames()`], since it lengthens short effects.
+    ///
+    /// [`Driver::set_spin_up_frames()`]: crate::Driver::set_spin_up_frames()
+    pub spin_up_frames: u8,
+    /// The curve converting intensities into duty cycles that feel as strong as intended.
+    pub curve: ResponseCurve,
+    /// Whether duty cycles are dithered across frames.
+    ///
+    /// Without dithering, the motor is kept on for every frame with a nonzero duty cycle. With
+    /// dithering, a [`Driver`] instead turns the motor on for the fraction of frames matching
+    /// the duty cycle, carrying the rounding error of each frame over to the next, so that weak
+    /// effects are felt as a steady, gentle buzz rather than in uneven pulses.
+    ///
+    /// [`Driver`]: crate::Driver
+    pub dither: bool,
+}
+
+impl Calibration {
+    /// A profile that leaves intensities unchanged and assumes the motor responds immediately.
+    pub const UNCALIBRATED: Self = Self {
+        min_duty: Intensity::OFF,
+        spin_up_frames: 0,
+        curve: ResponseCurve::LINEAR,
+        dither: false,
+    };
+
+    /// The profile of motors built into cartridges, such as in *Drill Dozer*, driven through
+    /// [`Gpio`].
+    ///
+    /// Cartridge motors are small and buzz sharply, but barely turn below roughly three eighths
+    /// duty.
+    ///
+    /// [`Gpio`]: crate::Gpio
+    pub const CARTRIDGE: Self = Self {
+        min_duty: Intensity::new(96),
+        spin_up_frames: 3,
+        curve: ResponseCurve::LINEAR,
+        dither: false,
+    };
+
+    /// The profile of the GameCube controller's motor, driven through the [`GameBoyPlayer`].
+    ///
+    /// The controller's larger motor is felt from roughly a quarter duty.
+    ///
+    /// [`GameBoyPlayer`]: crate::GameBoyPlayer
+    pub const GAMECUBE_CONTROLLER: Self = Self {
+        min_duty: Intensity::new(64),
+        spin_up_frames: 2,
+        curve: ResponseCurve::LINEAR,
+        dither: false,
+    };
+
+    /// A stronger profile for GameCube controllers with weak motors, such as some third-party
+    /// controllers.
+    ///
+    /// Weak intensities are boosted along a square root curve, and the motor is felt from roughly
+    /// three eighths duty. This is suited to a "strong" option in a game's settings, for players
+    /// who find effects hard to feel with [`GAMECUBE_CONTROLLER`].
+    ///
+    /// [`GAMECUBE_CONTROLLER`]: Calibration::GAMECUBE_CONTROLLER
+    pub const GAMECUBE_CONTROLLER_STRONG: Self = Self {
+        min_duty: Intensity::new(96),
+        spin_up_frames: 3,
+        curve: ResponseCurve::SQUARE_ROOT,
+        dither: false,
+    };
+
+    /// The profile of the DS Rumble Pak, which moves as soon as it is pulsed.
+    pub const DS_RUMBLE_PAK: Self = Self::UNCALIBRATED;
+
+    /// Converts `intensity` into the duty cycle to drive the motor at.
+    ///
+    /// The intensity is shaped by the [`curve`], then raised to at least [`min_duty`] unless it is
+    /// [`Intensity::OFF`].
+    ///
+    /// [`curve`]: Calibration::curve
+    /// [`min_duty`]: Calibration::min_duty
+    pub const fn apply(&self, intensity: Intensity) -> Intensity {
+        let duty = self.curve.apply(intensity);
+        if duty.is_off() || duty.get() >= self.min_duty.get() {
+            duty
+        } else {
+            self.min_duty
+        }
+    }
+}
+
+impl Default for Calibration {
+    fn default() -> Self {
+        Self::UNCALIBRATED
+    }
+}
+
+/// The kind of motor a game is tuned for, declared in place of a specific [`Calibration`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum MotorType {
+    /// A small eccentric-mass motor built into a cartridge, calibrated with
+    /// [`Calibration::CARTRIDGE`].
+    CartridgeEccentric,
+    /// A GameCube controller's motor, driven through the Game Boy Player and calibrated with
+    /// [`Calibration::GAMECUBE_CONTROLLER`].
+    GamecubeController,
+    /// No particular motor, leaving the profile to be selected from the backend.
+    #[default]
+    Unknown,
+}
+
+impl MotorType {
+    /// Returns the calibration profile for this motor, or `None` if it is [`Unknown`].
+    ///
+    /// [`Unknown`]: MotorType::Unknown
+    pub const fn calibration(self) -> Option<Calibration> {
+        match self {
+            Self::CartridgeEccentric => Some(Calibration::CARTRIDGE),
+            Self::GamecubeController => Some(Calibration::GAMECUBE_CONTROLLER),
+            Self::Unknown => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Calibration, MotorType};
+    use crate::{Intensity, ResponseCurve};
+    use claims::{assert_none, assert_some_eq};
+    use gba_test::test;
+
+    #[test]
+    fn uncalibrated() {
+        for value in [0, 1, 128, 255] {
+            assert_eq!(
+                Calibration::UNCALIBRATED.apply(Intensity::new(value)),
+                Intensity::new(value)
+            );
+        }
+    }
+
+    #[test]
+    fn min_duty() {
+        let calibration = Calibration {
+            min_duty: Intensity::new(100),
+            ..Calibration::UNCALIBRATED
+        };
+
+        assert_eq!(calibration.apply(Intensity::OFF), Intensity::OFF);
+        assert_eq!(calibration.apply(Intensity::new(1)), Intensity::new(100));
+        assert_eq!(calibration.apply(Intensity::new(150)), Intensity::new(150));
+    }
+
+    #[test]
+    fn curve_before_min_duty() {
+        let calibration = Calibration {
+            min_duty: Intensity::new(10),
+            curve: ResponseCurve::SQUARE,
+            spin_up_frames: 0,
+            dither: false,
+        };
+
+        assert_eq!(calibration.apply(Intensity::new(16)), Intensity::new(10));
+        assert_eq!(calibration.apply(Intensity::MAX), Intensity::MAX);
+    }
+
+    #[test]
+    fn motor_type() {
+        assert_some_eq!(
+            MotorType::CartridgeEccentric.calibration(),
+            Calibration::CARTRIDGE
+        );
+        assert_some_eq!(
+            MotorType::GamecubeController.calibration(),
+            Calibration::GAMECUBE_CONTROLLER
+        );
+        assert_none!(MotorType::Unknown.calibration());
+    }
+}
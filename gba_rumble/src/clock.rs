@@ -0,0 +1,284 @@
+//! Sources of elapsed time.
+
+use crate::Timer;
+
+/// Frames counted by [`VBlankClock::on_vblank()`].
+static mut VBLANKS: u32 = 0;
+
+/// The number of CPU cycles in a single frame.
+pub(crate) const CYCLES_PER_FRAME: u32 = 280_896;
+/// The number of CPU cycles in a single tick of a [`TimerClock`].
+const CYCLES_PER_TICK: u32 = 1024;
+/// The number of CPU cycles in a single frame, in units of `CYCLES_PER_TICK / 16`.
+///
+/// A frame is not a whole number of ticks, so ticks are counted in sixteenths to avoid drifting.
+const SIXTEENTHS_PER_FRAME: u32 = CYCLES_PER_FRAME * 16 / CYCLES_PER_TICK;
+
+/// A source of how many frames have elapsed.
+///
+/// Timed features, such as [`Haptics::tick_with()`], ask their clock how many frames have passed
+/// since they were last advanced, and advance playback by that many frames. This keeps effects
+/// the same length regardless of how often the game's logic runs: a game running its logic at
+/// 30 Hz advances by two frames at a time, rather than playing every effect at half speed.
+///
+/// Three clocks are provided:
+///
+/// - [`VBlankClock`] counts vblank interrupts.
+/// - [`TimerClock`] measures time with a free-running hardware [`Timer`].
+/// - [`FrameCounter`] counts frames reported by the caller.
+///
+/// Engines with their own notion of time can implement `Clock` themselves.
+///
+/// ``` rust
+/// use gba_rumble::{FrameCounter, Haptics, Pattern};
+///
+/// const EFFECTS: &[Pattern] = &[];
+///
+/// let mut haptics = Haptics::detect(EFFECTS);
+/// let mut clock = FrameCounter::new();
+///
+/// // Once per logic step, at 30 Hz:
+/// clock.count(2);
+/// haptics.tick_with(&mut clock);
+/// ```
+///
+/// [`Haptics::tick_with()`]: crate::Haptics::tick_with()
+pub trait Clock {
+    /// Returns the number of frames elapsed since the previous call.
+    ///
+    /// The first call returns the number of frames elapsed since the clock was created.
+    fn elapsed_frames(&mut self) -> u32;
+}
+
+impl<C> Clock for &mut C
+where
+    C: Clock + ?Sized,
+{
+    fn elapsed_frames(&mut self) -> u32 {
+        (**self).elapsed_frames()
+    }
+}
+
+/// A [`Clock`] counting frames reported by the caller.
+///
+/// Each call to [`count()`] adds to the frames elapsed, which are then consumed by the next call
+/// to [`elapsed_frames()`]. This suits engines that already track their own logic rate.
+///
+/// [`count()`]: FrameCounter::count()
+/// [`elapsed_frames()`]: Clock::elapsed_frames()
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FrameCounter {
+    frames: u32,
+}
+
+impl FrameCounter {
+    /// Creates a counter with no frames elapsed.
+    pub const fn new() -> Self {
+        Self { frames: 0 }
+    }
+
+    /// Records that `frames` more frames have elapsed.
+    pub const fn count(&mut self, frames: u32) {
+        self.frames = self.frames.saturating_add(frames);
+    }
+}
+
+impl Clock for FrameCounter {
+    fn elapsed_frames(&mut self) -> u32 {
+        core::mem::take(&mut self.frames)
+    }
+}
+
+/// A [`Clock`] counting vblank interrupts.
+///
+/// [`on_vblank()`] must be called on every vblank interrupt. It can be passed directly to
+/// [`irq::enable()`] when using this crate's interrupt dispatcher. Every `VBlankClock` counts the
+/// same interrupts, so any number of them can be used at once.
+///
+/// ``` rust
+/// use gba_rumble::{VBlankClock, irq, irq::Interrupt};
+///
+/// irq::install();
+/// irq::enable(Interrupt::VBlank, Some(VBlankClock::on_vblank));
+///
+/// let clock = VBlankClock::new();
+/// ```
+///
+/// [`irq::enable()`]: crate::irq::enable()
+/// [`on_vblank()`]: VBlankClock::on_vblank()
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct VBlankClock {
+    /// The count of vblanks as of the previous call to `elapsed_frames()`.
+    reported: u32,
+}
+
+impl VBlankClock {
+    /// Creates a clock counting vblanks from now.
+    pub fn new() -> Self {
+        Self {
+            reported: Self::vblanks(),
+        }
+    }
+
+    /// Counts a single vblank. Call this on every vblank interrupt.
+    pub fn on_vblank() {
+        unsafe {
+            (&raw mut VBLANKS).write_volatile(Self::vblanks().wrapping_add(1));
+        }
+    }
+
+    fn vblanks() -> u32 {
+        unsafe { (&raw const VBLANKS).read_volatile() }
+    }
+}
+
+impl Default for VBlankClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for VBlankClock {
+    fn elapsed_frames(&mut self) -> u32 {
+        let vblanks = Self::vblanks();
+        let elapsed = vblanks.wrapping_sub(self.reported);
+        self.reported = vblanks;
+        elapsed
+    }
+}
+
+/// A [`Clock`] measuring time with a free-running hardware [`Timer`].
+///
+/// The timer is started when the clock is created and counts every 1024 CPU cycles without
+/// requesting interrupts, so no interrupt handler is needed. Because the counter wraps roughly
+/// every four seconds, [`elapsed_frames()`] must be called more often than that. The timer must
+/// not be used for anything else while the clock is in use, including
+/// [`GameBoyPlayer::enable_timer_updates()`].
+///
+/// [`elapsed_frames()`]: Clock::elapsed_frames()
+/// [`GameBoyPlayer::enable_timer_updates()`]: crate::GameBoyPlayer::enable_timer_updates()
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TimerClock {
+    timer: Timer,
+    /// The counter as of the previous call to `elapsed_frames()`.
+    reported: u16,
+    /// Elapsed time not yet reported as a whole frame, in sixteenths of a tick.
+    remainder: u32,
+}
+
+impl TimerClock {
+    /// Starts `timer` and creates a clock measuring time from now.
+    pub fn new(timer: Timer) -> Self {
+        unsafe {
+            timer.control().write_volatile(0);
+            timer.counter().write_volatile(0);
+            // Enabled, counting every 1024 cycles.
+            timer.control().write_volatile(0x80 | 3);
+        }
+        Self {
+            timer,
+            reported: 0,
+            remainder: 0,
+        }
+    }
+
+    /// Stops the timer, returning it.
+    pub fn into_inner(self) -> Timer {
+        unsafe {
+            self.timer.control().write_volatile(0);
+        }
+        self.timer
+    }
+}
+
+impl Clock for TimerClock {
+    fn elapsed_frames(&mut self) -> u32 {
+        let counter = unsafe { self.timer.counter().read_volatile() };
+        let ticks = counter.wrapping_sub(self.reported) as u32;
+        self.reported = counter;
+        self.remainder += ticks * 16;
+        let frames = self.remainder / SIXTEENTHS_PER_FRAME;
+        self.remainder %= SIXTEENTHS_PER_FRAME;
+        frames
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Clock, FrameCounter, SIXTEENTHS_PER_FRAME, TimerClock, VBlankClock};
+    use crate::{Timer, VcountWaiter, Waiter};
+    use gba_test::test;
+
+    #[test]
+    fn frame_counter() {
+        let mut clock = FrameCounter::new();
+        assert_eq!(clock.elapsed_frames(), 0);
+
+        clock.count(2);
+        clock.count(1);
+        assert_eq!(clock.elapsed_frames(), 3);
+        assert_eq!(clock.elapsed_frames(), 0);
+    }
+
+    #[test]
+    fn frame_counter_saturates() {
+        let mut clock = FrameCounter::new();
+
+        clock.count(u32::MAX);
+        clock.count(1);
+
+        assert_eq!(clock.elapsed_frames(), u32::MAX);
+    }
+
+    #[test]
+    fn vblank_clock() {
+        let mut clock = VBlankClock::new();
+        let mut other = VBlankClock::new();
+
+        VBlankClock::on_vblank();
+        VBlankClock::on_vblank();
+
+        assert_eq!(clock.elapsed_frames(), 2);
+        assert_eq!(clock.elapsed_frames(), 0);
+        VBlankClock::on_vblank();
+        assert_eq!(clock.elapsed_frames(), 1);
+        assert_eq!(other.elapsed_frames(), 3);
+    }
+
+    #[test]
+    fn vblank_clock_through_reference() {
+        fn elapsed_frames<C>(mut clock: C) -> u32
+        where
+            C: Clock,
+        {
+            clock.elapsed_frames()
+        }
+        let mut clock = VBlankClock::new();
+
+        VBlankClock::on_vblank();
+
+        assert_eq!(elapsed_frames(&mut clock), 1);
+        assert_eq!(elapsed_frames(&mut clock), 0);
+    }
+
+    #[test]
+    fn sixteenths_per_frame() {
+        // 280896 cycles per frame is 274.3125 ticks of 1024 cycles.
+        assert_eq!(SIXTEENTHS_PER_FRAME, 4389);
+    }
+
+    #[test]
+    fn timer_clock() {
+        let mut clock = TimerClock::new(Timer::Timer3);
+        let mut waiter = VcountWaiter;
+
+        // Between two and three frames pass, depending on where in the frame the clock started.
+        waiter.wait_for_vblank();
+        waiter.wait_for_vblank();
+        waiter.wait_for_vblank();
+        let frames = clock.elapsed_frames();
+        clock.into_inner();
+
+        assert!((2..=3).contains(&frames), "{frames}");
+    }
+}
@@ -0,0 +1,315 @@
+//! Driving the cartridge's motor and the controller's motor together.
+
+use crate::{Backend, Calibration, Capabilities, Command, GameBoyPlayer, Gpio};
+
+/// Which motors of a [`CompositeRumble`] are driven.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Route {
+    /// Only the cartridge's motor.
+    Cart,
+    /// Only the controller's motor.
+    Controller,
+    /// Both motors at once.
+    #[default]
+    Both,
+}
+
+impl Route {
+    const fn includes_cart(self) -> bool {
+        matches!(self, Self::Cart | Self::Both)
+    }
+
+    const fn includes_controller(self) -> bool {
+        matches!(self, Self::Controller | Self::Both)
+    }
+}
+
+/// A [`Backend`] driving both a cartridge's motor and a Game Boy Player controller's motor.
+///
+/// A game with rumble in its cartridge that is played on a Game Boy Player has two motors
+/// available, with very different character: the cartridge's motor buzzes sharply, while the
+/// controller's motor rumbles heavily. Commands are sent to the motors selected by the current
+/// [`Route`], which can be changed with [`set_route()`] before playing each effect to use the
+/// motors intentionally.
+///
+/// ``` rust
+/// use gba_rumble::{CompositeRumble, Driver, GameBoyPlayer, Gpio, Intensity, Route};
+///
+/// if let Some(game_boy_player) = GameBoyPlayer::detect() {
+///     game_boy_player.enable_serial();
+///     let mut driver = Driver::<_>::new(CompositeRumble::new(Gpio, game_boy_player));
+///
+///     // A sharp buzz for footsteps, felt only through the cartridge.
+///     driver.backend_mut().set_route(Route::Cart);
+///     driver.tick(Intensity::MAX);
+/// }
+/// ```
+///
+/// [`set_route()`]: CompositeRumble::set_route()
+#[derive(Clone, Debug)]
+pub struct CompositeRumble<C = Gpio, G = GameBoyPlayer> {
+    cart: C,
+    controller: G,
+    route: Route,
+    /// The most recent command sent, resent to motors added to the route.
+    command: Command,
+}
+
+impl<C, G> CompositeRumble<C, G>
+where
+    C: Backend,
+    G: Backend,
+{
+    /// Creates a backend driving both `cart` and `controller`, routed to [`Route::Both`].
+    pub const fn new(cart: C, controller: G) -> Self {
+        Self {
+            cart,
+            controller,
+            route: Route::Both,
+            command: Command::Stop,
+        }
+    }
+
+    /// Returns which motors are currently driven.
+    pub const fn route(&self) -> Route {
+        self.route
+    }
+
+    /// Changes which motors are driven.
+    ///
+    /// Motors removed from the route are stopped, and motors added to it are sent the most recent
+    /// command, so that a change of route takes effect immediately even while rumbling.
+    pub fn set_route(&mut self, route: Route) {
+        let stop = match self.command {
+            Command::Start => Command::Stop,
+            command => command,
+        };
+        if self.route.includes_cart() != route.includes_cart() {
+            self.cart.send(if route.includes_cart() {
+                self.command
+            } else {
+                stop
+            });
+        }
+        if self.route.includes_controller() != route.includes_controller() {
+            self.controller.send(if route.includes_controller() {
+                self.command
+            } else {
+                stop
+            });
+        }
+        self.route = route;
+    }
+
+    /// Returns the backend driving the cartridge's motor.
+    pub const fn cart(&self) -> &C {
+        &self.cart
+    }
+
+    /// Returns the backend driving the controller's motor.
+    pub const fn controller(&self) -> &G {
+        &self.controller
+    }
+
+    /// Consumes this backend, returning the cartridge and controller backends.
+    pub fn into_inner(self) -> (C, G) {
+        (self.cart, self.controller)
+    }
+}
+
+impl<C, G> Backend for CompositeRumble<C, G>
+where
+    C: Backend,
+    G: Backend,
+{
+    /// Returns the capabilities shared by every routed motor.
+    ///
+    /// The serial port is reported as shared whenever either backend uses it, regardless of
+    /// route.
+    fn capabilities(&self) -> Capabilities {
+        let cart = self.cart.capabilities();
+        let controller = self.controller.capabilities();
+        let shared_with_sio = cart.shared_with_sio || controller.shared_with_sio;
+        match self.route {
+            Route::Cart => Capabilities {
+                shared_with_sio,
+                ..cart
+            },
+            Route::Controller => Capabilities {
+                shared_with_sio,
+                ..controller
+            },
+            Route::Both => Capabilities {
+                has_intensity: cart.has_intensity && controller.has_intensity,
+                has_brake: cart.has_brake && controller.has_brake,
+                latency_frames: cart.latency_frames.max(controller.latency_frames),
+                shared_with_sio,
+            },
+        }
+    }
+
+    /// Returns the calibration of the routed motor.
+    ///
+    /// When routed to both motors, the cartridge's response curve and dithering are used, along
+    /// with the larger
+    /// minimum duty cycle and spin-up time of the two, so that both motors are felt.
+    fn calibration(&self) -> Calibration {
+        let cart = self.cart.calibration();
+        let controller = self.controller.calibration();
+        match self.route {
+            Route::Cart => cart,
+            Route::Controller => controller,
+            Route::Both => Calibration {
+                min_duty: cart.min_duty.max(controller.min_duty),
+                spin_up_frames: cart.spin_up_frames.max(controller.spin_up_frames),
+                curve: cart.curve,
+                dither: cart.dither,
+            },
+        }
+    }
+
+    /// Returns the larger of the routed motors' minimum command frames.
+    fn min_command_frames(&self) -> u8 {
+        let cart = self.cart.min_command_frames();
+        let controller = self.controller.min_command_frames();
+        match self.route {
+            Route::Cart => cart,
+            Route::Controller => controller,
+            Route::Both => cart.max(controller),
+        }
+    }
+
+    fn send(&mut self, command: Command) {
+        if self.route.includes_cart() {
+            self.cart.send(command);
+        }
+        if self.route.includes_controller() {
+            self.controller.send(command);
+        }
+        self.command = command;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CompositeRumble, Route};
+    use crate::{Backend, Calibration, Capabilities, Command};
+    use claims::{assert_none, assert_some_eq};
+    use gba_test::test;
+
+    #[derive(Debug, Default)]
+    struct Motor {
+        sent: Option<Command>,
+        has_brake: bool,
+    }
+
+    impl Backend for Motor {
+        fn capabilities(&self) -> Capabilities {
+            Capabilities {
+                has_intensity: false,
+                has_brake: self.has_brake,
+                latency_frames: u8::from(self.has_brake),
+                shared_with_sio: self.has_brake,
+            }
+        }
+
+        fn calibration(&self) -> Calibration {
+            if self.has_brake {
+                Calibration::GAMECUBE_CONTROLLER
+            } else {
+                Calibration::CARTRIDGE
+            }
+        }
+
+        fn send(&mut self, command: Command) {
+            self.sent = Some(command);
+        }
+    }
+
+    fn composite() -> CompositeRumble<Motor, Motor> {
+        CompositeRumble::new(
+            Motor::default(),
+            Motor {
+                sent: None,
+                has_brake: true,
+            },
+        )
+    }
+
+    #[test]
+    fn send_both() {
+        let mut composite = composite();
+
+        composite.send(Command::Start);
+
+        assert_some_eq!(composite.cart().sent, Command::Start);
+        assert_some_eq!(composite.controller().sent, Command::Start);
+    }
+
+    #[test]
+    fn send_cart() {
+        let mut composite = composite();
+        composite.set_route(Route::Cart);
+
+        composite.send(Command::Start);
+
+        assert_some_eq!(composite.cart().sent, Command::Start);
+        assert_some_eq!(composite.controller().sent, Command::Stop);
+    }
+
+    #[test]
+    fn send_controller() {
+        let mut composite = composite();
+        composite.set_route(Route::Controller);
+
+        composite.send(Command::Start);
+
+        assert_some_eq!(composite.cart().sent, Command::Stop);
+        assert_some_eq!(composite.controller().sent, Command::Start);
+    }
+
+    #[test]
+    fn set_route_while_rumbling() {
+        let mut composite = composite();
+        composite.set_route(Route::Cart);
+        composite.send(Command::Start);
+
+        composite.set_route(Route::Controller);
+
+        assert_some_eq!(composite.cart().sent, Command::Stop);
+        assert_some_eq!(composite.controller().sent, Command::Start);
+    }
+
+    #[test]
+    fn set_route_unchanged_motor() {
+        let mut composite = composite();
+
+        composite.set_route(Route::Cart);
+
+        assert_none!(composite.cart().sent);
+    }
+
+    #[test]
+    fn capabilities() {
+        let mut composite = composite();
+        assert!(!composite.capabilities().has_brake);
+        assert_eq!(composite.capabilities().latency_frames, 1);
+        assert!(composite.capabilities().shared_with_sio);
+
+        composite.set_route(Route::Controller);
+        assert!(composite.capabilities().has_brake);
+
+        composite.set_route(Route::Cart);
+        assert_eq!(composite.capabilities().latency_frames, 0);
+        assert!(composite.capabilities().shared_with_sio);
+    }
+
+    #[test]
+    fn calibration() {
+        let mut composite = composite();
+        assert_eq!(composite.calibration(), Calibration::CARTRIDGE);
+
+        composite.set_route(Route::Controller);
+        assert_eq!(composite.calibration(), Calibration::GAMECUBE_CONTROLLER);
+    }
+}
@@ -0,0 +1,511 @@
+//! Configuration of Game Boy Player behavior.
+
+use crate::{
+    BiosWaiter, BuiltinSplash, DetectOptions, DetectionPolicy, ResetReason, SplashRenderer, Waiter,
+};
+use core::ptr;
+
+/// How rumble commands issued in quick succession are sent to the Game Boy Player.
+///
+/// The Game Boy Player only receives a rumble state once per transfer, which usually happens once
+/// a frame. When commands are issued faster than that, such as a [`hard_stop()`] immediately
+/// followed by a [`start()`], some of them may never be sent. This decides how such commands are
+/// coalesced:
+///
+/// | Delivery        | [`hard_stop()`] then [`start()`] within a frame                      |
+/// |-----------------|-----------------------------------------------------------------------|
+/// | [`LatestWins`]  | Only the start is sent                                                |
+/// | [`AtLeastOnce`] | The brake is sent on one transfer, and the start on the next          |
+/// | [`OnePerFrame`] | The brake is sent for the next frame, and the start for the one after |
+///
+/// [`AtLeastOnce`]: CommandDelivery::AtLeastOnce
+/// [`LatestWins`]: CommandDelivery::LatestWins
+/// [`OnePerFrame`]: CommandDelivery::OnePerFrame
+/// [`hard_stop()`]: crate::GameBoyPlayer::hard_stop()
+/// [`start()`]: crate::GameBoyPlayer::start()
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum CommandDelivery {
+    /// Only the most recent command is sent, and any commands it replaced before being sent are
+    /// dropped.
+    ///
+    /// This is the default.
+    #[default]
+    LatestWins,
+    /// Every command that changes the rumble state is sent at least once, in the order they were
+    /// issued.
+    ///
+    /// Commands are queued until they are sent, one per transfer. Up to
+    /// [`MAX_PENDING`] commands can wait at once; beyond that, the most recently queued command is
+    /// replaced.
+    ///
+    /// [`MAX_PENDING`]: CommandDelivery::MAX_PENDING
+    AtLeastOnce,
+    /// Every command that changes the rumble state is sent for at least a whole frame, in the
+    /// order they were issued.
+    ///
+    /// Commands are queued as with [`AtLeastOnce`], but only the first transfer of each frame
+    /// moves on to the next one, and any further transfers in that frame repeat it. A brake is
+    /// therefore held for a full frame before a following start is sent, rather than lasting for
+    /// a single transfer. Frames are counted by calls to [`GameBoyPlayer::update()`].
+    ///
+    /// [`AtLeastOnce`]: CommandDelivery::AtLeastOnce
+    /// [`GameBoyPlayer::update()`]: crate::GameBoyPlayer::update()
+    OnePerFrame,
+}
+
+impl CommandDelivery {
+    /// The most commands that can wait to be sent when delivering [`AtLeastOnce`] or
+    /// [`OnePerFrame`].
+    ///
+    /// [`AtLeastOnce`]: CommandDelivery::AtLeastOnce
+    /// [`OnePerFrame`]: CommandDelivery::OnePerFrame
+    pub const MAX_PENDING: usize = 4;
+}
+
+/// What is sent to the Game Boy Player on transfers where the rumble state has not changed.
+///
+/// Once the handshake completes, the Game Boy Player requests the rumble state on every transfer,
+/// usually once a frame. Most transfers carry no change, and what is sent in reply is otherwise
+/// unimportant, but emulators do not all treat these replies the way the Game Boy Player does.
+/// This allows comparing the two when tracking down differences between them.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum KeepAlive {
+    /// The last rumble state is sent again.
+    ///
+    /// The Game Boy Player is told the motor's state on every transfer, so a transfer it misses
+    /// or misreads is corrected by the next one. This is the default.
+    #[default]
+    Repeat,
+    /// The given word is sent in place of the rumble state.
+    ///
+    /// A rumble state is then only sent on the transfer after it changes, as in the exchanges
+    /// listed in [`consts`]. Choosing a word that is none of the rumble states, such as
+    /// `0x4000_0000`, allows checking whether a receiver keeps the motor in the state last sent,
+    /// or stops it when no state is received. Sending one of the rumble states instead forces the
+    /// motor into that state on every unchanged transfer, which is rarely wanted.
+    ///
+    /// [`consts`]: crate::protocol::consts
+    Word(u32),
+}
+
+/// Settings used by the Game Boy Player after detection.
+///
+/// These are copied into a static when detecting, so that they are available to the serial
+/// interrupt handler.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct GbpSettings {
+    pub(crate) watchdog_frames: u8,
+    pub(crate) auto_update: bool,
+    pub(crate) hard_stop_revert_frames: u8,
+    pub(crate) transfers_per_frame: u8,
+    pub(crate) transfer_interval: u8,
+    pub(crate) reset_tolerance: u8,
+    pub(crate) link_failure_resets: u8,
+    pub(crate) handshake_attempts: u8,
+    pub(crate) handshake_timeout_frames: u16,
+    pub(crate) command_delivery: CommandDelivery,
+    pub(crate) keep_alive: KeepAlive,
+    pub(crate) on_reset: Option<fn(ResetReason)>,
+    pub(crate) defer_serial: bool,
+}
+
+impl GbpSettings {
+    pub(crate) const DEFAULT: Self = Self {
+        watchdog_frames: 0,
+        auto_update: false,
+        hard_stop_revert_frames: 0,
+        transfers_per_frame: 0,
+        transfer_interval: 0,
+        reset_tolerance: 0,
+        link_failure_resets: 0,
+        handshake_attempts: 0,
+        handshake_timeout_frames: 0,
+        command_delivery: CommandDelivery::LatestWins,
+        keep_alive: KeepAlive::Repeat,
+        on_reset: None,
+        defer_serial: false,
+    };
+}
+
+impl PartialEq for GbpSettings {
+    fn eq(&self, other: &Self) -> bool {
+        self.watchdog_frames == other.watchdog_frames
+            && self.auto_update == other.auto_update
+            && self.hard_stop_revert_frames == other.hard_stop_revert_frames
+            && self.transfers_per_frame == other.transfers_per_frame
+            && self.transfer_interval == other.transfer_interval
+            && self.reset_tolerance == other.reset_tolerance
+            && self.link_failure_resets == other.link_failure_resets
+            && self.handshake_attempts == other.handshake_attempts
+            && self.handshake_timeout_frames == other.handshake_timeout_frames
+            && self.command_delivery == other.command_delivery
+            && self.keep_alive == other.keep_alive
+            && self.defer_serial == other.defer_serial
+            && match (self.on_reset, other.on_reset) {
+                (Some(a), Some(b)) => ptr::fn_addr_eq(a, b),
+                (a, b) => a.is_none() && b.is_none(),
+            }
+    }
+}
+
+impl Eq for GbpSettings {}
+
+/// Configuration of how the Game Boy Player is detected and communicated with.
+///
+/// This is passed to [`GameBoyPlayer::detect_with()`]. The default configuration matches the
+/// behavior of [`GameBoyPlayer::detect()`].
+///
+/// ``` rust
+/// use gba_rumble::{DetectOptions, GameBoyPlayer, GbpConfig};
+///
+/// let game_boy_player = GameBoyPlayer::detect_with(
+///     GbpConfig::new()
+///         .detection(DetectOptions::new().early_exit(true))
+///         // Restart the handshake if the Game Boy Player goes quiet for half a second.
+///         .watchdog_frames(30)
+///         // Release the brake shortly after a hard stop.
+///         .hard_stop_revert(10),
+/// );
+/// ```
+///
+/// [`GameBoyPlayer::detect()`]: crate::GameBoyPlayer::detect()
+/// [`GameBoyPlayer::detect_with()`]: crate::GameBoyPlayer::detect_with()
+#[derive(Debug)]
+pub struct GbpConfig<R = BuiltinSplash, W = BiosWaiter> {
+    pub(crate) detection: DetectOptions<R, W>,
+    pub(crate) settings: GbpSettings,
+    pub(crate) policy: DetectionPolicy,
+}
+
+impl GbpConfig {
+    /// Creates the default configuration.
+    pub const fn new() -> Self {
+        Self {
+            detection: DetectOptions::new(),
+            settings: GbpSettings::DEFAULT,
+            policy: DetectionPolicy::Always,
+        }
+    }
+}
+
+impl<R, W> GbpConfig<R, W>
+where
+    R: SplashRenderer,
+    W: Waiter,
+{
+    /// Detect the Game Boy Player using `options`.
+    pub fn detection<S, V>(self, options: DetectOptions<S, V>) -> GbpConfig<S, V>
+    where
+        S: SplashRenderer,
+        V: Waiter,
+    {
+        GbpConfig {
+            detection: options,
+            settings: self.settings,
+            policy: self.policy,
+        }
+    }
+
+    /// Decide whether to display the logo according to `policy`.
+    ///
+    /// By default, the logo is displayed on every boot. With
+    /// [`DetectionPolicy::SkipOnWarmBoot`], the logo is only displayed after the console is
+    /// powered on, and the previous result is reused after a soft reset. This only affects
+    /// [`GameBoyPlayer::detect_with()`] and [`GameBoyPlayer::boot_with()`], not
+    /// [`GameBoyPlayer::detection_frames()`].
+    ///
+    /// [`GameBoyPlayer::boot_with()`]: crate::GameBoyPlayer::boot_with()
+    /// [`GameBoyPlayer::detect_with()`]: crate::GameBoyPlayer::detect_with()
+    /// [`GameBoyPlayer::detection_frames()`]: crate::GameBoyPlayer::detection_frames()
+    pub const fn detection_policy(mut self, policy: DetectionPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Restart communication if no serial transfer is received for `frames` frames.
+    ///
+    /// Frames are counted by calls to [`GameBoyPlayer::update()`]. If the Game Boy Player stops
+    /// responding partway through the handshake, communication would otherwise stay stuck waiting
+    /// for it. A value of `0` disables the watchdog, which is the default.
+    ///
+    /// [`GameBoyPlayer::update()`]: crate::GameBoyPlayer::update()
+    pub const fn watchdog_frames(mut self, frames: u8) -> Self {
+        self.settings.watchdog_frames = frames;
+        self
+    }
+
+    /// Re-arm the serial port from the interrupt handler after every transfer.
+    ///
+    /// By default, the serial port is only re-armed after a successful transfer, and otherwise
+    /// waits for the next call to [`GameBoyPlayer::update()`]. Enabling this lets communication
+    /// recover from unexpected values immediately.
+    ///
+    /// [`GameBoyPlayer::update()`]: crate::GameBoyPlayer::update()
+    pub const fn auto_update(mut self, auto_update: bool) -> Self {
+        self.settings.auto_update = auto_update;
+        self
+    }
+
+    /// Revert to a normal stop `frames` frames after a [`hard_stop()`].
+    ///
+    /// Frames are counted by calls to [`GameBoyPlayer::update()`]. A value of `0` disables the
+    /// revert, leaving the motor braked until the next command, which is the default.
+    ///
+    /// [`GameBoyPlayer::update()`]: crate::GameBoyPlayer::update()
+    /// [`hard_stop()`]: crate::GameBoyPlayer::hard_stop()
+    pub const fn hard_stop_revert(mut self, frames: u8) -> Self {
+        self.settings.hard_stop_revert_frames = frames;
+        self
+    }
+
+    /// Respond to at most `count` rumble transfers each frame.
+    ///
+    /// Once the limit is reached, further transfers wait for the next call to
+    /// [`GameBoyPlayer::update()`], reducing the time spent in the serial interrupt handler. A
+    /// value of `0` places no limit on transfers, which is the default.
+    ///
+    /// [`GameBoyPlayer::update()`]: crate::GameBoyPlayer::update()
+    pub const fn transfers_per_frame(mut self, count: u8) -> Self {
+        self.settings.transfers_per_frame = count;
+        self
+    }
+
+    /// Respond to rumble transfers only once every `frames` frames.
+    ///
+    /// Some links, such as a GC-Loader paired with a third-party cable, corrupt transfers made at
+    /// the full cadence of every frame. Throttling transfers gives these links time to
+    /// settle, at the cost of rumble commands taking up to `frames` frames to arrive. A [`Driver`]
+    /// stretches every command over this many frames so that none are lost in between. Frames are
+    /// counted by calls to [`GameBoyPlayer::update()`], and the handshake is never throttled. This
+    /// can also be changed later using [`GameBoyPlayer::set_transfer_interval()`], such as from a
+    /// compatibility option in a settings menu. A value of `0` or `1` transfers every frame, which
+    /// is the default.
+    ///
+    /// [`Driver`]: crate::Driver
+    /// [`GameBoyPlayer::set_transfer_interval()`]: crate::GameBoyPlayer::set_transfer_interval()
+    /// [`GameBoyPlayer::update()`]: crate::GameBoyPlayer::update()
+    pub const fn transfer_interval(mut self, frames: u8) -> Self {
+        self.settings.transfer_interval = frames;
+        self
+    }
+
+    /// Ignore up to `count` consecutive unexpected values before restarting the handshake.
+    ///
+    /// Some Game Boy Player revisions and modded consoles respond with a slightly different
+    /// cadence, occasionally sending a stray word that would otherwise reset communication. While
+    /// within the tolerance, an unexpected value is ignored and communication continues from where
+    /// it was; only once more than `count` unexpected values are received in a row is the
+    /// handshake restarted. A value of `0` resets on the first unexpected value, which is the
+    /// default.
+    pub const fn reset_tolerance(mut self, count: u8) -> Self {
+        self.settings.reset_tolerance = count;
+        self
+    }
+
+    /// Give up on the link after `count` consecutive resets caused by unexpected values.
+    ///
+    /// Normally, communication restarts the handshake after every unexpected value, forever. If
+    /// the program ends up running without a Game Boy Player on the other end, such as after a save
+    /// state is moved to a plain GBA, this spends time in the serial interrupt handler on a link
+    /// that will never come back. Once `count` resets happen without a single expected value in
+    /// between, transfers are no longer started and [`GameBoyPlayer::link_status()`] reports
+    /// [`LinkStatus::LinkFailed`], until [`GameBoyPlayer::retry_link()`] is called. A value of `0`
+    /// never gives up, which is the default.
+    ///
+    /// [`GameBoyPlayer::link_status()`]: crate::GameBoyPlayer::link_status()
+    /// [`GameBoyPlayer::retry_link()`]: crate::GameBoyPlayer::retry_link()
+    /// [`LinkStatus::LinkFailed`]: crate::LinkStatus::LinkFailed
+    pub const fn link_failure_after(mut self, count: u8) -> Self {
+        self.settings.link_failure_resets = count;
+        self
+    }
+
+    /// Give up on the link after `count` handshake attempts fail without completing.
+    ///
+    /// An attempt fails when an unexpected value resets communication partway through the
+    /// handshake. Unlike [`link_failure_after()`], attempts are counted even if some expected
+    /// values were received along the way, so a link that keeps getting partway through the
+    /// handshake before failing is also given up on. This bounds the time spent in the serial
+    /// interrupt handler when something on the link is broken. Once `count` attempts fail,
+    /// transfers are no longer started and [`GameBoyPlayer::link_status()`] reports
+    /// [`LinkStatus::LinkFailed`], until [`GameBoyPlayer::retry_link()`] or
+    /// [`GameBoyPlayer::restart_session()`] is called. The count starts over whenever the
+    /// handshake completes. A value of `0` never gives up, which is the default.
+    ///
+    /// [`GameBoyPlayer::link_status()`]: crate::GameBoyPlayer::link_status()
+    /// [`GameBoyPlayer::restart_session()`]: crate::GameBoyPlayer::restart_session()
+    /// [`GameBoyPlayer::retry_link()`]: crate::GameBoyPlayer::retry_link()
+    /// [`LinkStatus::LinkFailed`]: crate::LinkStatus::LinkFailed
+    /// [`link_failure_after()`]: GbpConfig::link_failure_after()
+    pub const fn handshake_attempts(mut self, count: u8) -> Self {
+        self.settings.handshake_attempts = count;
+        self
+    }
+
+    /// Give up on the Game Boy Player if it has not sent anything within `frames` calls to
+    /// [`GameBoyPlayer::update()`] after detection.
+    ///
+    /// Some clone consoles have hardware faults that leave `KEYINPUT` holding the value the Game
+    /// Boy Player is detected by, so it is detected when none is present. A real Game Boy Player
+    /// begins the handshake right away, so if nothing at all is received within `frames` frames,
+    /// transfers are no longer started and [`GameBoyPlayer::link_status()`] reports
+    /// [`LinkStatus::Unresponsive`]. [`Device`] and [`Haptics`] fall back to cartridge rumble when
+    /// this happens. A value of `0` waits forever, which is the default.
+    ///
+    /// [`Device`]: crate::Device
+    /// [`GameBoyPlayer::link_status()`]: crate::GameBoyPlayer::link_status()
+    /// [`GameBoyPlayer::update()`]: crate::GameBoyPlayer::update()
+    /// [`Haptics`]: crate::Haptics
+    /// [`LinkStatus::Unresponsive`]: crate::LinkStatus::Unresponsive
+    pub const fn handshake_timeout(mut self, frames: u16) -> Self {
+        self.settings.handshake_timeout_frames = frames;
+        self
+    }
+
+    /// Send rumble commands to the Game Boy Player according to `delivery`.
+    ///
+    /// By default, only the most recent command is sent, so a [`hard_stop()`] followed by a
+    /// [`start()`] on the next frame may never brake the motor. Using
+    /// [`CommandDelivery::AtLeastOnce`] guarantees that each of them is sent, and
+    /// [`CommandDelivery::OnePerFrame`] also holds each of them for a whole frame.
+    ///
+    /// [`hard_stop()`]: crate::GameBoyPlayer::hard_stop()
+    /// [`start()`]: crate::GameBoyPlayer::start()
+    pub const fn command_delivery(mut self, delivery: CommandDelivery) -> Self {
+        self.settings.command_delivery = delivery;
+        self
+    }
+
+    /// Reply to rumble requests that carry no change in state according to `keep_alive`.
+    ///
+    /// By default, the last rumble state is sent again on every transfer. See [`KeepAlive`] for
+    /// the alternative.
+    pub const fn keep_alive(mut self, keep_alive: KeepAlive) -> Self {
+        self.settings.keep_alive = keep_alive;
+        self
+    }
+
+    /// Call `callback` whenever communication is reset, with the reason it was reset.
+    ///
+    /// Communication is reset when the Game Boy Player sends an unexpected value, or when the
+    /// [`watchdog_frames()`] watchdog expires. This allows games to track how often the link is
+    /// unstable, such as through telemetry in release builds. Resets while communication is
+    /// already at the start of the handshake are not reported.
+    ///
+    /// The callback is only ever called from [`GameBoyPlayer::update()`], never from the serial
+    /// interrupt handler, so it may take as long as it needs. Resets caused by unexpected values
+    /// are reported on the next call to [`GameBoyPlayer::update()`] after they happen.
+    ///
+    /// [`GameBoyPlayer::update()`]: crate::GameBoyPlayer::update()
+    /// [`watchdog_frames()`]: GbpConfig::watchdog_frames()
+    pub const fn on_reset(mut self, callback: fn(ResetReason)) -> Self {
+        self.settings.on_reset = Some(callback);
+        self
+    }
+
+    /// Leave the serial port alone after detection, until [`GameBoyPlayer::enable_serial()`] is
+    /// called.
+    ///
+    /// Some games need the serial port for something else early on, such as a link cable menu on
+    /// the title screen, and only begin communicating with the Game Boy Player afterwards. With
+    /// this enabled, [`GameBoyPlayer::boot_with()`] registers the interrupt handler but does not
+    /// configure the serial port, and [`GameBoyPlayer::link_status()`] reports
+    /// [`LinkStatus::AwaitingSerial`] until the serial port is enabled. Until then,
+    /// [`GameBoyPlayer::update()`] does nothing, the handshake timeout does not start, and rumble
+    /// commands are held, to be sent once the handshake completes. This is off by default.
+    ///
+    /// This has no effect if the serial port is armed during detection using
+    /// [`DetectOptions::arm_serial()`].
+    ///
+    /// [`GameBoyPlayer::boot_with()`]: crate::GameBoyPlayer::boot_with()
+    /// [`GameBoyPlayer::enable_serial()`]: crate::GameBoyPlayer::enable_serial()
+    /// [`GameBoyPlayer::link_status()`]: crate::GameBoyPlayer::link_status()
+    /// [`GameBoyPlayer::update()`]: crate::GameBoyPlayer::update()
+    /// [`LinkStatus::AwaitingSerial`]: crate::LinkStatus::AwaitingSerial
+    pub const fn defer_serial(mut self, defer: bool) -> Self {
+        self.settings.defer_serial = defer;
+        self
+    }
+}
+
+impl Default for GbpConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommandDelivery, GbpConfig, GbpSettings, KeepAlive};
+    use crate::{DetectOptions, DetectionPolicy, ResetReason};
+    use gba_test::test;
+
+    #[test]
+    fn default_settings() {
+        assert_eq!(GbpConfig::new().settings, GbpSettings::DEFAULT);
+    }
+
+    #[test]
+    fn settings() {
+        let config = GbpConfig::new()
+            .watchdog_frames(30)
+            .auto_update(true)
+            .hard_stop_revert(10)
+            .transfers_per_frame(2)
+            .transfer_interval(3)
+            .reset_tolerance(3)
+            .link_failure_after(20)
+            .handshake_attempts(5)
+            .handshake_timeout(300)
+            .command_delivery(CommandDelivery::AtLeastOnce)
+            .keep_alive(KeepAlive::Word(0x4000_0000))
+            .defer_serial(true);
+
+        assert_eq!(
+            config.settings,
+            GbpSettings {
+                watchdog_frames: 30,
+                auto_update: true,
+                hard_stop_revert_frames: 10,
+                transfers_per_frame: 2,
+                transfer_interval: 3,
+                reset_tolerance: 3,
+                link_failure_resets: 20,
+                handshake_attempts: 5,
+                handshake_timeout_frames: 300,
+                command_delivery: CommandDelivery::AtLeastOnce,
+                keep_alive: KeepAlive::Word(0x4000_0000),
+                on_reset: None,
+                defer_serial: true,
+            }
+        );
+    }
+
+    #[test]
+    fn on_reset() {
+        fn callback(_: ResetReason) {}
+
+        let config = GbpConfig::new().on_reset(callback);
+
+        assert!(config.settings.on_reset.is_some());
+    }
+
+    #[test]
+    fn detection_keeps_settings() {
+        let config = GbpConfig::new()
+            .watchdog_frames(30)
+            .detection(DetectOptions::new().early_exit(true));
+
+        assert_eq!(config.settings.watchdog_frames, 30);
+        assert!(config.detection.early_exit);
+    }
+
+    #[test]
+    fn detection_keeps_policy() {
+        let config = GbpConfig::new()
+            .detection_policy(DetectionPolicy::SkipOnWarmBoot)
+            .detection(DetectOptions::new());
+
+        assert_eq!(config.policy, DetectionPolicy::SkipOnWarmBoot);
+    }
+}
@@ -0,0 +1,348 @@
+//! A conformance suite for emulators implementing Game Boy Player rumble.
+//!
+//! The suite checks that an emulator's serial port and Game Boy Player emulation behave the way
+//! this crate relies on. It first drives the serial interrupt handler through complete sessions
+//! offline, feeding it the exact values from [`protocol::consts`] and checking each response read
+//! back from the serial registers. It then detects the emulated Game Boy Player and checks that a
+//! live session connects and stays connected while rumble is toggled.
+//!
+//! Each check is logged as it completes, through [`mgba_log`](https://docs.rs/mgba_log) with the
+//! `mgba-log` feature or through any other logger with the `log` feature, and the results are
+//! returned in a [`Report`].
+//!
+//! This module is only available with the `conformance` feature enabled.
+//!
+//! ``` rust,no_run
+//! // Set up interrupts as you would for `GameBoyPlayer`, then run the suite.
+//! let report = gba_rumble::conformance::run();
+//! if !report.passed() {
+//!     for check in report.checks() {
+//!         // Display the failed checks.
+//!     }
+//! }
+//! ```
+//!
+//! [`protocol::consts`]: crate::protocol::consts
+
+use crate::{
+    GameBoyPlayer, bios, game_boy_player,
+    game_boy_player::SIOCNT_CONFIG,
+    game_boy_player_interrupt,
+    logging::{self, error, info},
+    protocol::consts::{
+        HANDSHAKE_ECHOES, HANDSHAKE_KEYS, HANDSHAKE_RECEIVED, HANDSHAKE_SENT, MAGIC_RECEIVED,
+        MAGIC_SENT, RUMBLE_HARD_STOP, RUMBLE_REQUEST, RUMBLE_START, RUMBLE_STOP,
+    },
+    regs,
+};
+use core::{
+    fmt,
+    fmt::{Display, Formatter},
+    ptr,
+};
+
+const SIODATA: *mut u32 = ptr::with_exposed_provenance_mut(regs::SIODATA32);
+const SIOCNT: *mut u16 = ptr::with_exposed_provenance_mut(regs::SIOCNT);
+
+/// The bits of `SIOCNT` configured by this crate.
+const SIOCNT_MASK: u16 = 0x7000 | 8 | 1;
+
+/// How many frames a live session is given to complete the handshake.
+const CONNECT_FRAMES: u16 = 300;
+/// How many frames a live session must stay connected while rumble is toggled.
+const RUMBLE_FRAMES: u16 = 60;
+
+/// The number of checks in the suite.
+const CHECKS: usize = 6;
+
+/// The result of a single [`Check`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Outcome {
+    /// The emulator behaved as expected.
+    Passed,
+    /// A value read back from the serial registers was `actual` rather than `expected`.
+    Mismatch {
+        /// The value this crate expected.
+        expected: u32,
+        /// The value that was read.
+        actual: u32,
+    },
+    /// The live session did not complete the handshake within this many frames.
+    TimedOut {
+        /// The number of frames waited.
+        frames: u16,
+    },
+    /// The live session was reset this many frames after rumble was first started.
+    Disconnected {
+        /// The number of frames the session stayed connected.
+        frames: u16,
+    },
+    /// The check was not run, because no Game Boy Player was detected.
+    Skipped,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Passed => formatter.write_str("passed"),
+            Self::Mismatch { expected, actual } => {
+                write!(formatter, "expected {expected:#010X}, read {actual:#010X}")
+            }
+            Self::TimedOut { frames } => {
+                write!(formatter, "handshake not completed after {frames} frames")
+            }
+            Self::Disconnected { frames } => {
+                write!(formatter, "reset after {frames} frames of rumble")
+            }
+            Self::Skipped => formatter.write_str("skipped, no Game Boy Player detected"),
+        }
+    }
+}
+
+/// A single named check of the conformance suite, along with its [`Outcome`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Check {
+    /// A short description of what was checked.
+    pub name: &'static str,
+    /// The result of the check.
+    pub outcome: Outcome,
+}
+
+/// The results of every check run by [`run()`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Report {
+    checks: [Check; CHECKS],
+}
+
+impl Report {
+    /// Returns the checks that were run, in the order they were run.
+    pub fn checks(&self) -> &[Check] {
+        &self.checks
+    }
+
+    /// Returns whether no check failed.
+    ///
+    /// Skipped checks are not counted as failures.
+    pub fn passed(&self) -> bool {
+        self.checks
+            .iter()
+            .all(|check| matches!(check.outcome, Outcome::Passed | Outcome::Skipped))
+    }
+}
+
+/// Runs every check of the suite, logging and returning the results.
+///
+/// The offline checks run first, before the Game Boy Player is detected, so that the emulated Game
+/// Boy Player is not yet communicating over the serial port. Detection then displays the Game Boy
+/// Player logo: as with [`GameBoyPlayer::detect()`], vblank interrupts must be enabled, and
+/// [`game_boy_player_interrupt()`] must be called on serial interrupts. The serial port itself is
+/// configured by the suite.
+///
+/// [`game_boy_player_interrupt()`]: crate::game_boy_player_interrupt()
+pub fn run() -> Report {
+    logging::init();
+
+    let checks = [
+        check("serial registers", serial_registers),
+        check("handshake", handshake),
+        check("repeated handshake keys", repeated_keys),
+        check("rumble states", rumble_states),
+        check("unexpected value resets", unexpected_value),
+        check("live session", live_session),
+    ];
+    let report = Report { checks };
+    if report.passed() {
+        info!("Conformance: all checks passed");
+    } else {
+        error!("Conformance: some checks failed");
+    }
+    report
+}
+
+/// Runs a single check, logging its outcome.
+fn check(name: &'static str, run: fn() -> Outcome) -> Check {
+    let outcome = run();
+    if matches!(outcome, Outcome::Passed | Outcome::Skipped) {
+        info!("{name}: {outcome}");
+    } else {
+        error!("{name}: {outcome}");
+    }
+    Check { name, outcome }
+}
+
+/// Compares a value read back against the value expected.
+fn expect(expected: u32, actual: u32) -> Result<(), Outcome> {
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(Outcome::Mismatch { expected, actual })
+    }
+}
+
+/// Passes `input` to the serial interrupt handler as if it were received from the Game Boy Player,
+/// returning the response the handler prepared.
+fn exchange(input: u32) -> u32 {
+    unsafe {
+        SIODATA.write_volatile(input);
+    }
+    game_boy_player_interrupt();
+    unsafe {
+        // Keep the transfer armed by the handler from starting.
+        SIOCNT.write_volatile(SIOCNT.read_volatile() & !(1 << 7));
+        SIODATA.read_volatile()
+    }
+}
+
+/// Completes an offline handshake, checking every response along the way.
+fn connect() -> Result<(), Outcome> {
+    for (input, response) in HANDSHAKE_RECEIVED.into_iter().zip(HANDSHAKE_SENT) {
+        expect(response, exchange(input))?;
+    }
+    for (input, response) in MAGIC_RECEIVED.into_iter().zip(MAGIC_SENT) {
+        expect(response, exchange(input))?;
+    }
+    Ok(())
+}
+
+/// Runs an offline check from the start of a fresh session, cleaning up afterward.
+fn offline(check: impl FnOnce() -> Result<(), Outcome>) -> Outcome {
+    game_boy_player::reset_session();
+    unsafe {
+        SIOCNT.write_volatile(SIOCNT_CONFIG);
+    }
+    let result = check();
+    game_boy_player::reset_session();
+    unsafe {
+        SIOCNT.write_volatile(0);
+    }
+    result.err().unwrap_or(Outcome::Passed)
+}
+
+fn serial_registers() -> Outcome {
+    offline(|| {
+        expect(
+            (SIOCNT_CONFIG & SIOCNT_MASK) as u32,
+            (unsafe { SIOCNT.read_volatile() } & SIOCNT_MASK) as u32,
+        )?;
+        unsafe {
+            SIODATA.write_volatile(0x1234_5678);
+        }
+        expect(0x1234_5678, unsafe { SIODATA.read_volatile() })
+    })
+}
+
+fn handshake() -> Outcome {
+    offline(|| {
+        connect()?;
+        expect(RUMBLE_STOP, exchange(RUMBLE_REQUEST))
+    })
+}
+
+fn repeated_keys() -> Outcome {
+    offline(|| {
+        for ((key, echo), (input, response)) in HANDSHAKE_KEYS
+            .into_iter()
+            .zip(HANDSHAKE_ECHOES)
+            .zip(HANDSHAKE_RECEIVED.into_iter().zip(HANDSHAKE_SENT))
+        {
+            expect(echo, exchange(key as u32))?;
+            expect(echo, exchange(key as u32))?;
+            expect(response, exchange(input))?;
+        }
+        Ok(())
+    })
+}
+
+fn rumble_states() -> Outcome {
+    offline(|| {
+        let game_boy_player = GameBoyPlayer::new();
+        connect()?;
+        game_boy_player.start();
+        expect(RUMBLE_START, exchange(RUMBLE_REQUEST))?;
+        expect(RUMBLE_START, exchange(RUMBLE_REQUEST))?;
+        game_boy_player.hard_stop();
+        expect(RUMBLE_HARD_STOP, exchange(RUMBLE_REQUEST))?;
+        game_boy_player.stop();
+        expect(RUMBLE_STOP, exchange(RUMBLE_REQUEST))
+    })
+}
+
+fn unexpected_value() -> Outcome {
+    offline(|| {
+        connect()?;
+        exchange(0x1234_5678);
+        // The session must be able to start over from the first key.
+        connect()?;
+        expect(RUMBLE_STOP, exchange(RUMBLE_REQUEST))
+    })
+}
+
+fn live_session() -> Outcome {
+    let Some(game_boy_player) = GameBoyPlayer::detect() else {
+        return Outcome::Skipped;
+    };
+    game_boy_player.enable_serial();
+
+    let mut frames = 0;
+    while !game_boy_player::is_connected() {
+        if frames == CONNECT_FRAMES {
+            return Outcome::TimedOut { frames };
+        }
+        game_boy_player.update();
+        bios::wait_for_vblank();
+        frames += 1;
+    }
+
+    let mut outcome = Outcome::Passed;
+    for frame in 0..RUMBLE_FRAMES {
+        if frame % 10 == 0 {
+            if frame % 20 == 0 {
+                game_boy_player.start();
+            } else {
+                game_boy_player.stop();
+            }
+        }
+        game_boy_player.update();
+        bios::wait_for_vblank();
+        if !game_boy_player::is_connected() {
+            outcome = Outcome::Disconnected { frames: frame };
+            break;
+        }
+    }
+    game_boy_player.stop();
+    outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Check, Outcome, Report, handshake, repeated_keys, rumble_states, serial_registers,
+        unexpected_value,
+    };
+    use gba_test::test;
+
+    #[test]
+    fn offline_checks_pass() {
+        assert_eq!(serial_registers(), Outcome::Passed);
+        assert_eq!(handshake(), Outcome::Passed);
+        assert_eq!(repeated_keys(), Outcome::Passed);
+        assert_eq!(rumble_states(), Outcome::Passed);
+        assert_eq!(unexpected_value(), Outcome::Passed);
+    }
+
+    #[test]
+    fn report_passed_ignores_skipped() {
+        let check = |outcome| Check {
+            name: "check",
+            outcome,
+        };
+        let mut report = Report {
+            checks: [check(Outcome::Passed); 6],
+        };
+        report.checks[5] = check(Outcome::Skipped);
+        assert!(report.passed());
+
+        report.checks[2] = check(Outcome::TimedOut { frames: 300 });
+        assert!(!report.passed());
+    }
+}
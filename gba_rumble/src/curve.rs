@@ -0,0 +1,219 @@
+//! Shaping intensities into motor duty cycles.
+
+use crate::Intensity;
+
+/// Maps a logical [`Intensity`] to the duty cycle a motor is driven at.
+///
+/// Vibration strength is not perceived linearly: a motor driven at half its duty cycle feels much
+/// weaker than half as strong, and may barely spin at all. A response curve corrects for this,
+/// so that effects can be authored in terms of how strong they should feel.
+///
+/// A curve is a lookup table of [`POINTS`] output values, one for every 16th intensity, with
+/// linear interpolation between them. Interpolation rounds up, so as long as the first point is
+/// zero and the second point is nonzero, only [`Intensity::OFF`] maps to [`Intensity::OFF`].
+///
+/// Each [`Backend`] provides a default curve suited to its motor through
+/// [`Backend::response_curve()`], which a [`Driver`] applies to every intensity. It can be
+/// replaced using [`Driver::set_response_curve()`].
+///
+/// ``` rust
+/// use gba_rumble::{Intensity, ResponseCurve};
+///
+/// // Boost weak intensities, which would otherwise barely be felt.
+/// let curve = ResponseCurve::SQUARE_ROOT;
+///
+/// assert_eq!(curve.apply(Intensity::OFF), Intensity::OFF);
+/// assert!(curve.apply(Intensity::new(64)) > Intensity::new(64));
+/// assert_eq!(curve.apply(Intensity::MAX), Intensity::MAX);
+/// ```
+///
+/// [`Backend`]: crate::Backend
+/// [`Backend::response_curve()`]: crate::Backend::response_curve()
+/// [`Driver`]: crate::Driver
+/// [`Driver::set_response_curve()`]: crate::Driver::set_response_curve()
+/// [`POINTS`]: ResponseCurve::POINTS
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ResponseCurve {
+    points: [u8; Self::POINTS],
+}
+
+impl ResponseCurve {
+    /// The number of points in a curve's lookup table.
+    pub const POINTS: usize = 17;
+
+    /// Outputs every intensity unchanged.
+    ///
+    /// This is the default for backends that can only turn their motor on or off.
+    pub const LINEAR: Self = Self::sample(Shape::Linear);
+
+    /// Squares each intensity, weakening low intensities.
+    pub const SQUARE: Self = Self::sample(Shape::Square);
+
+    /// Takes the square root of each intensity, boosting low intensities.
+    ///
+    /// This suits motors that need a large duty cycle before they are felt at all.
+    pub const SQUARE_ROOT: Self = Self::sample(Shape::SquareRoot);
+
+    /// Creates a curve from its lookup table.
+    ///
+    /// `points[i]` is the output for an intensity of `16 * i`, except for the last point, which is
+    /// the output for [`Intensity::MAX`].
+    pub const fn from_points(points: [u8; Self::POINTS]) -> Self {
+        Self { points }
+    }
+
+    /// Returns the curve's lookup table.
+    pub const fn points(self) -> [u8; Self::POINTS] {
+        self.points
+    }
+
+    /// Creates a curve by sampling `shape` at each point.
+    const fn sample(shape: Shape) -> Self {
+        let mut points = [0; Self::POINTS];
+        let mut index = 0;
+        while index < Self::POINTS {
+            let input = if index == Self::POINTS - 1 {
+                255
+            } else {
+                index as u32 * 16
+            };
+            points[index] = shape.output(input) as u8;
+            index += 1;
+        }
+        Self { points }
+    }
+
+    /// Returns the duty cycle for `intensity`.
+    pub const fn apply(self, intensity: Intensity) -> Intensity {
+        let value = intensity.get();
+        if value == u8::MAX {
+            return Intensity::new(self.points[Self::POINTS - 1]);
+        }
+        let index = (value / 16) as usize;
+        let offset = (value % 16) as u16;
+        let start = self.points[index] as u16;
+        let end = self.points[index + 1] as u16;
+        let output = if end >= start {
+            start + ((end - start) * offset).div_ceil(16)
+        } else {
+            start - (start - end) * offset / 16
+        };
+        Intensity::new(output as u8)
+    }
+}
+
+/// A function from which the built-in curves are sampled.
+#[derive(Clone, Copy)]
+enum Shape {
+    Linear,
+    Square,
+    SquareRoot,
+}
+
+impl Shape {
+    /// Returns the output for `input`, both ranging from `0` to `255`.
+    const fn output(self, input: u32) -> u32 {
+        match self {
+            Self::Linear => input,
+            Self::Square => (input * input).div_ceil(255),
+            Self::SquareRoot => (input * 255).isqrt(),
+        }
+    }
+}
+
+impl Default for ResponseCurve {
+    fn default() -> Self {
+        Self::LINEAR
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResponseCurve;
+    use crate::Intensity;
+    use gba_test::test;
+
+    #[test]
+    fn linear_unchanged() {
+        for value in 0..=u8::MAX {
+            assert_eq!(
+                ResponseCurve::LINEAR.apply(Intensity::new(value)),
+                Intensity::new(value)
+            );
+        }
+    }
+
+    #[test]
+    fn endpoints() {
+        for curve in [
+            ResponseCurve::LINEAR,
+            ResponseCurve::SQUARE,
+            ResponseCurve::SQUARE_ROOT,
+        ] {
+            assert_eq!(curve.apply(Intensity::OFF), Intensity::OFF);
+            assert_eq!(curve.apply(Intensity::MAX), Intensity::MAX);
+        }
+    }
+
+    #[test]
+    fn monotonic() {
+        for curve in [ResponseCurve::SQUARE, ResponseCurve::SQUARE_ROOT] {
+            for value in 1..=u8::MAX {
+                assert!(
+                    curve.apply(Intensity::new(value)) >= curve.apply(Intensity::new(value - 1))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn nonzero_stays_nonzero() {
+        for curve in [ResponseCurve::SQUARE, ResponseCurve::SQUARE_ROOT] {
+            assert!(!curve.apply(Intensity::new(1)).is_off());
+        }
+    }
+
+    #[test]
+    fn square() {
+        assert_eq!(
+            ResponseCurve::SQUARE.apply(Intensity::new(128)),
+            Intensity::new(65)
+        );
+    }
+
+    #[test]
+    fn square_root() {
+        assert_eq!(
+            ResponseCurve::SQUARE_ROOT.apply(Intensity::new(64)),
+            Intensity::new(127)
+        );
+    }
+
+    #[test]
+    fn from_points() {
+        let mut points = [u8::MAX; ResponseCurve::POINTS];
+        points[0] = 0;
+        let curve = ResponseCurve::from_points(points);
+
+        assert_eq!(curve.points(), points);
+        assert_eq!(curve.apply(Intensity::OFF), Intensity::OFF);
+        assert_eq!(curve.apply(Intensity::new(16)), Intensity::MAX);
+        assert_eq!(curve.apply(Intensity::new(1)), Intensity::new(16));
+    }
+
+    #[test]
+    fn decreasing() {
+        let mut points = [0; ResponseCurve::POINTS];
+        points[0] = u8::MAX;
+        let curve = ResponseCurve::from_points(points);
+
+        assert_eq!(curve.apply(Intensity::OFF), Intensity::MAX);
+        assert_eq!(curve.apply(Intensity::new(16)), Intensity::OFF);
+        assert_eq!(curve.apply(Intensity::MAX), Intensity::OFF);
+    }
+
+    #[test]
+    fn default_linear() {
+        assert_eq!(ResponseCurve::default(), ResponseCurve::LINEAR);
+    }
+}
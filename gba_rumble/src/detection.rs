@@ -0,0 +1,513 @@
+//! Options controlling Game Boy Player detection.
+
+use crate::{
+    BiosWaiter, BuiltinSplash, GameBoyPlayer, SplashRenderer, Waiter,
+    boot_report::{Phase, Stopwatch},
+    game_boy_player,
+    protocol::consts,
+    regs,
+};
+use core::{iter::FusedIterator, ptr};
+
+const KEYINPUT: *mut u16 = ptr::with_exposed_provenance_mut(regs::KEYINPUT);
+const DISPCNT: *mut u16 = ptr::with_exposed_provenance_mut(regs::DISPCNT);
+
+/// The `DISPCNT` bit that blanks the screen.
+const FORCED_BLANK: u16 = 1 << 7;
+
+/// Applies the `disabled`, `force-gbp`, and `force-gpio` features to whether the Game Boy Player
+/// was detected.
+pub(crate) const fn forced(detected: bool) -> bool {
+    if cfg!(feature = "disabled") {
+        false
+    } else if cfg!(feature = "force-gbp") {
+        true
+    } else if cfg!(feature = "force-gpio") {
+        false
+    } else {
+        detected
+    }
+}
+
+/// Options controlling how the Game Boy Player is detected.
+///
+/// These are passed to [`GameBoyPlayer::detect_with()`] through [`GbpConfig::detection()`].
+///
+/// By default, the Game Boy Player logo is drawn using [`BuiltinSplash`] and displayed for the
+/// full detection window of [`DETECTION_FRAMES`] frames, waiting for each frame using
+/// [`BiosWaiter`]. This matches [`GameBoyPlayer::detect()`].
+///
+/// ``` rust
+/// use gba_rumble::{DetectOptions, GameBoyPlayer, GbpConfig};
+///
+/// // Finish detecting as soon as the Game Boy Player is found, but still display the logo for at
+/// // least one second.
+/// let game_boy_player = GameBoyPlayer::detect_with(
+///     GbpConfig::new().detection(DetectOptions::new().early_exit(true).min_frames(60)),
+/// );
+/// ```
+///
+/// [`DETECTION_FRAMES`]: crate::protocol::consts::DETECTION_FRAMES
+/// [`GameBoyPlayer::detect()`]: crate::GameBoyPlayer::detect()
+/// [`GameBoyPlayer::detect_with()`]: crate::GameBoyPlayer::detect_with()
+/// [`GbpConfig::detection()`]: crate::GbpConfig::detection()
+#[derive(Debug)]
+pub struct DetectOptions<R = BuiltinSplash, W = BiosWaiter> {
+    pub(crate) splash: R,
+    pub(crate) waiter: W,
+    pub(crate) early_exit: bool,
+    pub(crate) min_frames: u8,
+    pub(crate) on_frame: Option<fn()>,
+    pub(crate) arm_serial: bool,
+}
+
+impl DetectOptions {
+    /// Creates the default detection options.
+    pub const fn new() -> Self {
+        Self {
+            splash: BuiltinSplash::new(),
+            waiter: BiosWaiter,
+            early_exit: false,
+            min_frames: 0,
+            on_frame: None,
+            arm_serial: false,
+        }
+    }
+}
+
+impl<R, W> DetectOptions<R, W>
+where
+    R: SplashRenderer,
+    W: Waiter,
+{
+    /// Display the Game Boy Player logo using `splash`.
+    pub fn splash<S>(self, splash: S) -> DetectOptions<S, W>
+    where
+        S: SplashRenderer,
+    {
+        DetectOptions {
+            splash,
+            waiter: self.waiter,
+            early_exit: self.early_exit,
+            min_frames: self.min_frames,
+            on_frame: self.on_frame,
+            arm_serial: self.arm_serial,
+        }
+    }
+
+    /// Wait for each frame of detection using `waiter`.
+    ///
+    /// This allows detection to compose with runtimes that manage interrupt acknowledgement
+    /// themselves, rather than detection issuing its own BIOS calls.
+    pub fn waiter<V>(self, waiter: V) -> DetectOptions<R, V>
+    where
+        V: Waiter,
+    {
+        DetectOptions {
+            splash: self.splash,
+            waiter,
+            early_exit: self.early_exit,
+            min_frames: self.min_frames,
+            on_frame: self.on_frame,
+            arm_serial: self.arm_serial,
+        }
+    }
+
+    /// Finish detection as soon as the Game Boy Player is detected.
+    ///
+    /// Without this, detection always waits for the full detection window, even if the Game Boy
+    /// Player was detected early on.
+    pub const fn early_exit(mut self, early_exit: bool) -> Self {
+        self.early_exit = early_exit;
+        self
+    }
+
+    /// Display the logo for at least `frames` frames.
+    ///
+    /// This is useful in combination with [`early_exit()`] to keep the classic Game Boy Player
+    /// boot presentation, even when detection finishes early. Values larger than the 125 frame
+    /// detection window extend the window, continuing to listen for the Game Boy Player.
+    ///
+    /// [`early_exit()`]: DetectOptions::early_exit()
+    pub const fn min_frames(mut self, frames: u8) -> Self {
+        self.min_frames = frames;
+        self
+    }
+
+    /// Call `callback` once every frame during detection.
+    ///
+    /// The callback is called immediately before waiting for v-blank, which makes it the place to
+    /// service anything that must keep running during the roughly two seconds detection takes,
+    /// such as refilling streaming audio or DMA double-buffers so music doesn't crackle or stop.
+    pub const fn on_frame(mut self, callback: fn()) -> Self {
+        self.on_frame = Some(callback);
+        self
+    }
+
+    /// Configure the serial port when detection begins, rather than once it has finished.
+    ///
+    /// The Game Boy Player may begin its handshake while its logo is still displayed. With this
+    /// set, the handshake is answered straight away, giving the session a head start, and a
+    /// handshake seen during the window also counts as detecting the Game Boy Player. Whether one
+    /// was seen is reported by [`FrameResult::handshake`]. If the Game Boy Player is not detected,
+    /// the serial port is disabled again once detection finishes.
+    ///
+    /// The serial interrupt handler must be installed, and serial interrupts enabled, before
+    /// detection begins. [`GameBoyPlayer::boot_with()`] does this itself when this is set.
+    ///
+    /// This is off by default.
+    ///
+    /// [`GameBoyPlayer::boot_with()`]: crate::GameBoyPlayer::boot_with()
+    pub const fn arm_serial(mut self, arm_serial: bool) -> Self {
+        self.arm_serial = arm_serial;
+        self
+    }
+
+    /// Returns whether detection should finish before displaying `frame`.
+    pub(crate) fn is_finished(&self, frame: u8, detected: bool) -> bool {
+        if detected && self.early_exit {
+            frame >= self.min_frames
+        } else {
+            frame >= consts::DETECTION_FRAMES.max(self.min_frames)
+        }
+    }
+}
+
+impl Default for DetectOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The outcome of a single frame of detection, yielded by [`DetectionFrames`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct FrameResult {
+    /// The frame that was just displayed, counting from `0`.
+    pub frame: u8,
+    /// Whether the Game Boy Player has been detected so far.
+    pub detected: bool,
+    /// Whether the Game Boy Player has begun its handshake so far.
+    ///
+    /// This is only ever `true` when the serial port is armed using
+    /// [`DetectOptions::arm_serial()`].
+    pub handshake: bool,
+}
+
+/// The outcome of Game Boy Player detection, along with how long it took.
+///
+/// This is returned by [`GameBoyPlayer::detect_bounded()`] and [`DetectionFrames::detection()`].
+///
+/// [`GameBoyPlayer::detect_bounded()`]: crate::GameBoyPlayer::detect_bounded()
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Detection {
+    /// The Game Boy Player was detected.
+    Detected {
+        /// The number of frames displayed up to and including the one on which the Game Boy
+        /// Player was first detected.
+        after_frames: u8,
+        /// The number of frames displayed before detection finished.
+        ///
+        /// This is larger than `after_frames` unless detection exits early.
+        frames_waited: u8,
+    },
+    /// The Game Boy Player was not detected.
+    NotDetected {
+        /// The number of frames displayed before detection finished.
+        frames_waited: u8,
+    },
+}
+
+impl Detection {
+    /// Returns the detected Game Boy Player, if any.
+    pub fn game_boy_player(self) -> Option<GameBoyPlayer> {
+        match self {
+            Self::Detected { .. } => Some(GameBoyPlayer::new()),
+            Self::NotDetected { .. } => None,
+        }
+    }
+
+    /// Returns the number of frames displayed before detection finished.
+    pub const fn frames_waited(self) -> u8 {
+        match self {
+            Self::Detected { frames_waited, .. } | Self::NotDetected { frames_waited } => {
+                frames_waited
+            }
+        }
+    }
+}
+
+/// An iterator over the frames of Game Boy Player detection.
+///
+/// This is created by [`GameBoyPlayer::detection_frames()`], and is a lower-level alternative to
+/// [`GameBoyPlayer::detect_with()`]. Each call to [`next()`] performs one frame of detection,
+/// waiting for v-blank using the configured [`Waiter`] and rendering the logo, which allows
+/// detection to be driven from the game's own loop with full control over what else happens each
+/// frame. Once detection finishes, the splash screen is torn down and iteration ends.
+///
+/// The iterator should be run to completion, as the splash screen is only torn down at the end.
+///
+/// The Game Boy Player can only see its logo if the screen is not blanked, so if forced blank is
+/// set in `DISPCNT` when detection begins, such as by an engine that boots with the screen blanked
+/// while loading, it is cleared once the splash screen has been prepared. Forced blank is set
+/// again after the splash screen is torn down.
+///
+/// ``` rust
+/// use gba_rumble::{GameBoyPlayer, GbpConfig};
+///
+/// let mut frames = GameBoyPlayer::detection_frames(GbpConfig::new());
+/// for result in &mut frames {
+///     // Anything else that needs to happen every frame goes here.
+///     if result.detected {
+///         // The Game Boy Player has been found, although the logo is still being displayed.
+///     }
+/// }
+/// let game_boy_player = frames.game_boy_player();
+/// ```
+///
+/// [`GameBoyPlayer::detect_with()`]: crate::GameBoyPlayer::detect_with()
+/// [`GameBoyPlayer::detection_frames()`]: crate::GameBoyPlayer::detection_frames()
+/// [`next()`]: DetectionFrames::next()
+#[derive(Debug)]
+pub struct DetectionFrames<R = BuiltinSplash, W = BiosWaiter> {
+    options: DetectOptions<R, W>,
+    frame: u8,
+    /// The number of frames displayed when the Game Boy Player was first detected.
+    detected_after: Option<u8>,
+    finished: bool,
+    /// Whether forced blank was set when detection began, and should be restored afterwards.
+    forced_blank: bool,
+    /// Measures the frames of detection, for the `boot-report` feature.
+    stopwatch: Stopwatch,
+}
+
+impl<R, W> DetectionFrames<R, W>
+where
+    R: SplashRenderer,
+    W: Waiter,
+{
+    /// Begins detection, preparing the splash screen.
+    pub(crate) fn new(mut options: DetectOptions<R, W>) -> Self {
+        if cfg!(feature = "disabled") {
+            // Detection finishes immediately, without touching the display or the serial port.
+            return Self {
+                options,
+                frame: 0,
+                detected_after: None,
+                finished: true,
+                forced_blank: false,
+                stopwatch: Stopwatch::start(),
+            };
+        }
+        let forced_blank = unsafe { DISPCNT.read_volatile() } & FORCED_BLANK != 0;
+        if options.arm_serial {
+            let stopwatch = Stopwatch::start();
+            GameBoyPlayer::new().restart_session();
+            stopwatch.report(Phase::SerialArming);
+        }
+        let stopwatch = Stopwatch::start();
+        options.splash.prepare();
+        stopwatch.report(Phase::SplashUpload);
+        // The logo must be visible for the Game Boy Player to detect it.
+        unsafe {
+            DISPCNT.write_volatile(DISPCNT.read_volatile() & !FORCED_BLANK);
+        }
+        Self {
+            options,
+            frame: 0,
+            detected_after: None,
+            finished: false,
+            forced_blank,
+            stopwatch: Stopwatch::start(),
+        }
+    }
+
+    /// Returns the detected Game Boy Player, if it has been detected so far.
+    ///
+    /// This is typically called once iteration has finished.
+    pub fn game_boy_player(&self) -> Option<GameBoyPlayer> {
+        self.detection().game_boy_player()
+    }
+
+    /// Returns whether the Game Boy Player has begun its handshake so far.
+    ///
+    /// This is only ever `true` when the serial port is armed using
+    /// [`DetectOptions::arm_serial()`].
+    pub fn is_handshake_started(&self) -> bool {
+        self.options.arm_serial && game_boy_player::is_handshake_started()
+    }
+
+    /// Returns the outcome of detection so far, along with the number of frames it has taken.
+    ///
+    /// This is typically called once iteration has finished. With the `force-gbp` feature, the
+    /// Game Boy Player is always reported as detected, counting it as detected after the frames
+    /// waited so far if it was not actually seen. With the `force-gpio` feature, it is never
+    /// reported as detected, unless `force-gbp` is also enabled, which takes precedence.
+    pub fn detection(&self) -> Detection {
+        match self.detected_after {
+            Some(after_frames) if forced(true) => Detection::Detected {
+                after_frames,
+                frames_waited: self.frame,
+            },
+            None if forced(false) => Detection::Detected {
+                after_frames: self.frame,
+                frames_waited: self.frame,
+            },
+            _ => Detection::NotDetected {
+                frames_waited: self.frame,
+            },
+        }
+    }
+}
+
+impl<R, W> Iterator for DetectionFrames<R, W>
+where
+    R: SplashRenderer,
+    W: Waiter,
+{
+    type Item = FrameResult;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        if self
+            .options
+            .is_finished(self.frame, self.detected_after.is_some())
+        {
+            self.stopwatch.report(Phase::DetectionLoop);
+            let stopwatch = Stopwatch::start();
+            self.options.splash.teardown(&mut self.options.waiter);
+            stopwatch.report(Phase::VramReset);
+            if self.options.arm_serial && self.game_boy_player().is_none() {
+                game_boy_player::disable_serial();
+            }
+            if self.forced_blank {
+                unsafe {
+                    DISPCNT.write_volatile(DISPCNT.read_volatile() | FORCED_BLANK);
+                }
+            }
+            self.finished = true;
+            return None;
+        }
+
+        if let Some(on_frame) = self.options.on_frame {
+            on_frame();
+        }
+        self.options.waiter.wait_for_vblank();
+        // All 4 directions pressed at once is not possible on a normal console, so the game boy
+        // player uses this value to indicate that its extra functionality has been unlocked. See
+        // GBATEK for more information. Only the Game Boy Player sends its handshake, so that is
+        // accepted as well.
+        let handshake = self.is_handshake_started();
+        if self.detected_after.is_none()
+            && (handshake || unsafe { KEYINPUT.read_volatile() } == consts::DETECTION_KEYINPUT)
+        {
+            self.detected_after = Some(self.frame + 1);
+        }
+        self.options.splash.render_frame(self.frame);
+
+        let result = FrameResult {
+            frame: self.frame,
+            detected: self.detected_after.is_some(),
+            handshake,
+        };
+        self.frame += 1;
+        Some(result)
+    }
+}
+
+impl<R, W> FusedIterator for DetectionFrames<R, W>
+where
+    R: SplashRenderer,
+    W: Waiter,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DetectOptions, Detection, forced};
+    use gba_test::test;
+
+    #[test]
+    fn detection_detected() {
+        let detection = Detection::Detected {
+            after_frames: 3,
+            frames_waited: 125,
+        };
+
+        assert!(detection.game_boy_player().is_some());
+        assert_eq!(detection.frames_waited(), 125);
+    }
+
+    #[test]
+    fn detection_not_detected() {
+        let detection = Detection::NotDetected { frames_waited: 125 };
+
+        assert!(detection.game_boy_player().is_none());
+        assert_eq!(detection.frames_waited(), 125);
+    }
+
+    #[test]
+    #[cfg(not(any(feature = "disabled", feature = "force-gbp", feature = "force-gpio")))]
+    fn forced_unchanged() {
+        assert!(forced(true));
+        assert!(!forced(false));
+    }
+
+    #[test]
+    #[cfg(all(feature = "force-gbp", not(feature = "disabled")))]
+    fn forced_gbp() {
+        assert!(forced(false));
+    }
+
+    #[test]
+    #[cfg(any(
+        feature = "disabled",
+        all(feature = "force-gpio", not(feature = "force-gbp"))
+    ))]
+    fn forced_gpio() {
+        assert!(!forced(true));
+    }
+
+    #[test]
+    fn default_finishes_after_window() {
+        let options = DetectOptions::new();
+
+        assert!(!options.is_finished(124, false));
+        assert!(options.is_finished(125, false));
+    }
+
+    #[test]
+    fn default_ignores_detection() {
+        let options = DetectOptions::new();
+
+        assert!(!options.is_finished(10, true));
+        assert!(options.is_finished(125, true));
+    }
+
+    #[test]
+    fn early_exit() {
+        let options = DetectOptions::new().early_exit(true);
+
+        assert!(!options.is_finished(10, false));
+        assert!(options.is_finished(10, true));
+    }
+
+    #[test]
+    fn early_exit_min_frames() {
+        let options = DetectOptions::new().early_exit(true).min_frames(120);
+
+        assert!(!options.is_finished(10, true));
+        assert!(!options.is_finished(119, true));
+        assert!(options.is_finished(120, true));
+    }
+
+    #[test]
+    fn min_frames_extends_window() {
+        let options = DetectOptions::new().min_frames(200);
+
+        assert!(!options.is_finished(125, false));
+        assert!(!options.is_finished(199, true));
+        assert!(options.is_finished(200, false));
+    }
+}
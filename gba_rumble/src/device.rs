@@ -0,0 +1,863 @@
+//! A single rumble device backed by the best available mechanism.
+
+use crate::{
+    Backend, Calibration, Capabilities, Command, Effect, GameBoyPlayer, GbpConfig, Gpio, Intensity,
+    LinkStatus, Pattern, ResponseCurve, Sequencer, SerialStatus, game_boy_player, power,
+};
+use core::{
+    fmt,
+    fmt::{Debug, Display, Formatter},
+    iter::FusedIterator,
+    slice,
+};
+
+/// What the last call to [`detect_all()`] found, read by [`backends()`].
+static mut DETECTED: Detected = Detected {
+    game_boy_player: Presence::Unknown,
+    #[cfg(feature = "ds-rumble-pak")]
+    ds_rumble_pak: Presence::Unknown,
+};
+
+/// Detect every available rumble mechanism, returning a [`Device`] using the best of them.
+///
+/// This detects the Game Boy Player, displaying its logo for a few seconds, as well as the DS
+/// Rumble Pak when the `ds-rumble-pak` feature is enabled. Cartridge rumble through [`Gpio`] is
+/// assumed to be available whenever the program is running from a cartridge.
+///
+/// Among the available mechanisms, the one with the most capable [`Capabilities`] is chosen:
+/// intensity control is preferred, then braking, then lower latency. If a Game Boy Player is
+/// selected, its serial port is configured using [`GameBoyPlayer::enable_serial()`], but
+/// [`game_boy_player_interrupt()`] must still be called on serial interrupts.
+///
+/// Some clone consoles are mistaken for a Game Boy Player. To recover from this, if a selected Game
+/// Boy Player sends nothing within [`Device::HANDSHAKE_TIMEOUT_FRAMES`] frames, as with
+/// [`GbpConfig::handshake_timeout()`], the device falls back to cartridge rumble.
+///
+/// With the `force-gbp` feature, the Game Boy Player is always selected, although it still falls
+/// back to cartridge rumble if nothing answers. With the `force-gpio` feature, cartridge rumble is
+/// always selected, unless `force-gbp` is also enabled, which takes precedence.
+///
+/// As with [`GameBoyPlayer::detect()`], vblank interrupts must be enabled whenever `IME` is, or
+/// this function will hang forever.
+///
+/// [`game_boy_player_interrupt()`]: crate::game_boy_player_interrupt()
+pub fn detect_all<'a>() -> Device<'a> {
+    Device::new(Mechanism::detect())
+}
+
+/// Returns the status of every rumble mechanism known to the crate, as found by the last call to
+/// [`detect_all()`].
+///
+/// This is intended for hardware information and debug screens. Each [`BackendStatus`] can be
+/// displayed directly, such as `Game Boy Player: detected`:
+///
+/// ``` rust
+/// let device = gba_rumble::detect_all();
+/// for status in gba_rumble::backends() {
+///     // log::info!("{status}");
+/// }
+/// ```
+///
+/// Mechanisms are listed whether or not they were found, in the order [`BackendKind`] declares
+/// them. Before detection, or for mechanisms that cannot be detected, the [`Presence`] is
+/// [`Presence::Unknown`].
+pub fn backends() -> Backends {
+    Backends {
+        kinds: BackendKind::ALL.iter(),
+        detected: unsafe { (&raw const DETECTED).read_volatile() },
+    }
+}
+
+/// The outcome of detecting each detectable mechanism.
+#[derive(Clone, Copy, Debug)]
+struct Detected {
+    game_boy_player: Presence,
+    #[cfg(feature = "ds-rumble-pak")]
+    ds_rumble_pak: Presence,
+}
+
+/// A rumble mechanism supported by the crate.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BackendKind {
+    /// Cartridge rumble, through [`Gpio`].
+    Gpio,
+    /// GameCube controller rumble, through the [`GameBoyPlayer`].
+    GameBoyPlayer,
+    /// The DS Rumble Pak, through [`DsRumblePak`].
+    ///
+    /// [`DsRumblePak`]: crate::DsRumblePak
+    #[cfg(feature = "ds-rumble-pak")]
+    DsRumblePak,
+}
+
+impl BackendKind {
+    #[cfg(not(feature = "ds-rumble-pak"))]
+    const ALL: &[Self] = &[Self::Gpio, Self::GameBoyPlayer];
+    #[cfg(feature = "ds-rumble-pak")]
+    const ALL: &[Self] = &[Self::Gpio, Self::GameBoyPlayer, Self::DsRumblePak];
+
+    /// Returns a human-readable name for the mechanism.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Gpio => "GPIO",
+            Self::GameBoyPlayer => "Game Boy Player",
+            #[cfg(feature = "ds-rumble-pak")]
+            Self::DsRumblePak => "DS Rumble Pak",
+        }
+    }
+
+    /// Returns what the mechanism is able to do, whether or not it is present.
+    pub fn capabilities(self) -> Capabilities {
+        match self {
+            Self::Gpio => Gpio.capabilities(),
+            Self::GameBoyPlayer => GameBoyPlayer::new().capabilities(),
+            #[cfg(feature = "ds-rumble-pak")]
+            Self::DsRumblePak => crate::ds_rumble_pak::CAPABILITIES,
+        }
+    }
+}
+
+impl Display for BackendKind {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.name())
+    }
+}
+
+/// Whether a rumble mechanism was found by detection.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Presence {
+    /// The mechanism was detected.
+    Detected,
+    /// The mechanism was looked for, but not found.
+    NotPresent,
+    /// The mechanism has not been looked for, or cannot be detected.
+    ///
+    /// Cartridge rumble is always unknown, since a cartridge's rumble motor cannot be detected.
+    Unknown,
+}
+
+impl Display for Presence {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::Detected => "detected",
+            Self::NotPresent => "not present",
+            Self::Unknown => "unknown",
+        })
+    }
+}
+
+impl From<bool> for Presence {
+    fn from(detected: bool) -> Self {
+        if detected {
+            Self::Detected
+        } else {
+            Self::NotPresent
+        }
+    }
+}
+
+/// A rumble mechanism along with its detection outcome and capabilities, returned by
+/// [`backends()`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct BackendStatus {
+    /// The mechanism.
+    pub kind: BackendKind,
+    /// Whether the mechanism was found by detection.
+    pub presence: Presence,
+    /// What the mechanism is able to do.
+    pub capabilities: Capabilities,
+}
+
+impl Display for BackendStatus {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}: {}", self.kind, self.presence)
+    }
+}
+
+/// An iterator over the status of every rumble mechanism, returned by [`backends()`].
+#[derive(Clone, Debug)]
+pub struct Backends {
+    kinds: slice::Iter<'static, BackendKind>,
+    detected: Detected,
+}
+
+impl Iterator for Backends {
+    type Item = BackendStatus;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let kind = *self.kinds.next()?;
+        Some(BackendStatus {
+            kind,
+            presence: match kind {
+                BackendKind::Gpio => Presence::Unknown,
+                BackendKind::GameBoyPlayer => self.detected.game_boy_player,
+                #[cfg(feature = "ds-rumble-pak")]
+                BackendKind::DsRumblePak => self.detected.ds_rumble_pak,
+            },
+            capabilities: kind.capabilities(),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.kinds.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Backends {}
+
+impl FusedIterator for Backends {}
+
+/// Orders capabilities from least to most preferred.
+fn rank(capabilities: Capabilities) -> (bool, bool, u8) {
+    (
+        capabilities.has_intensity,
+        capabilities.has_brake,
+        u8::MAX - capabilities.latency_frames,
+    )
+}
+
+/// Which mechanism a [`Device`] or [`Haptics`] uses when more than one is available.
+///
+/// Games that run both on rumble cartridges and on the Game Boy Player can offer players the
+/// choice, such as with a "prefer cartridge rumble" option. Changing the preference at runtime
+/// hands the motor over between mechanisms: the motor is stopped on the old one, and whatever is
+/// playing continues on the new one from the same point.
+///
+/// [`Haptics`]: crate::Haptics
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Preference {
+    /// Use the most capable mechanism, as chosen by [`detect_all()`].
+    ///
+    /// This is the default.
+    #[default]
+    Best,
+    /// Use cartridge rumble through [`Gpio`], even if a Game Boy Player was detected.
+    ///
+    /// The Game Boy Player's session is kept alive in the background, so that switching back to
+    /// [`Preference::Best`] is immediate. This has no effect when the cartridge slot is occupied
+    /// by a DS Rumble Pak.
+    Cartridge,
+}
+
+/// Tracks the [`Preference`] between mechanisms, along with any Game Boy Player not in use.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Handoff {
+    preference: Preference,
+    /// The detected Game Boy Player, while cartridge rumble is used instead.
+    standby: Option<GameBoyPlayer>,
+}
+
+impl Handoff {
+    pub(crate) const fn preference(&self) -> Preference {
+        self.preference
+    }
+
+    /// Switches `mechanism` to the one matching `preference`, returning whether it changed.
+    ///
+    /// If `rumbling`, the motor is stopped on the old mechanism and started on the new one.
+    pub(crate) fn set_preference(
+        &mut self,
+        preference: Preference,
+        mechanism: &mut Mechanism,
+        rumbling: bool,
+    ) -> bool {
+        self.preference = preference;
+        let next = match (preference, *mechanism) {
+            (Preference::Cartridge, Mechanism::GameBoyPlayer(game_boy_player)) => {
+                self.standby = Some(game_boy_player);
+                Mechanism::Gpio(Gpio)
+            }
+            (Preference::Best, Mechanism::Gpio(_)) => match self.standby.take() {
+                Some(game_boy_player) => Mechanism::GameBoyPlayer(game_boy_player),
+                None => return false,
+            },
+            _ => return false,
+        };
+        if rumbling {
+            mechanism.send(Command::Stop);
+        }
+        *mechanism = next;
+        if rumbling {
+            mechanism.send(Command::Start);
+        }
+        true
+    }
+
+    /// Services the Game Boy Player not in use, so that it delivers the stop sent on handoff and
+    /// its session stays alive. This should be called once a frame.
+    ///
+    /// A Game Boy Player that becomes unresponsive is forgotten, so that cartridge rumble remains
+    /// in use.
+    pub(crate) fn update(&mut self) {
+        if let Some(game_boy_player) = self.standby {
+            game_boy_player.update();
+            if game_boy_player.link_status() == LinkStatus::Unresponsive {
+                self.standby = None;
+            }
+        }
+    }
+}
+
+/// A rumble mechanism that a [`Device`] can be backed by.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Mechanism {
+    Gpio(Gpio),
+    GameBoyPlayer(GameBoyPlayer),
+    #[cfg(feature = "ds-rumble-pak")]
+    DsRumblePak(crate::DsRumblePak),
+}
+
+impl Mechanism {
+    /// Detects every available mechanism, returning the best of them.
+    ///
+    /// See [`detect_all()`] for details.
+    pub(crate) fn detect() -> Self {
+        let mut mechanism = Self::Gpio(Gpio);
+        #[cfg(feature = "ds-rumble-pak")]
+        let ds_rumble_pak = if cfg!(feature = "force-gpio") {
+            None
+        } else {
+            crate::DsRumblePak::detect()
+        };
+        #[cfg(feature = "ds-rumble-pak")]
+        if let Some(ds_rumble_pak) = ds_rumble_pak {
+            // The Rumble Pak occupies the cartridge slot, so there is no cartridge rumble.
+            mechanism = Self::DsRumblePak(ds_rumble_pak);
+        }
+        let game_boy_player = GameBoyPlayer::detect_with(
+            GbpConfig::new().handshake_timeout(Device::HANDSHAKE_TIMEOUT_FRAMES),
+        );
+        unsafe {
+            (&raw mut DETECTED).write_volatile(Detected {
+                game_boy_player: game_boy_player.is_some().into(),
+                #[cfg(feature = "ds-rumble-pak")]
+                ds_rumble_pak: ds_rumble_pak.is_some().into(),
+            });
+        }
+        if let Some(game_boy_player) = game_boy_player {
+            let candidate = Self::GameBoyPlayer(game_boy_player);
+            if cfg!(feature = "force-gbp")
+                || rank(candidate.capabilities()) > rank(mechanism.capabilities())
+            {
+                game_boy_player.enable_serial();
+                mechanism = candidate;
+            }
+        }
+        mechanism
+    }
+
+    /// Services the mechanism. This should be called once a frame.
+    ///
+    /// A Game Boy Player that never responds is replaced by cartridge rumble, continuing whatever
+    /// rumble was last requested.
+    pub(crate) fn update(&mut self) {
+        match self {
+            Self::Gpio(_) => {}
+            Self::GameBoyPlayer(game_boy_player) => {
+                game_boy_player.update();
+                if game_boy_player.link_status() == LinkStatus::Unresponsive {
+                    let rumbling = game_boy_player::is_rumble_requested();
+                    game_boy_player.stop();
+                    *self = Self::Gpio(Gpio);
+                    if rumbling {
+                        Gpio.start();
+                    }
+                }
+            }
+            #[cfg(feature = "ds-rumble-pak")]
+            Self::DsRumblePak(ds_rumble_pak) => ds_rumble_pak.update(),
+        }
+    }
+
+    /// Returns which kind of mechanism this is.
+    pub(crate) fn kind(&self) -> BackendKind {
+        match self {
+            Self::Gpio(_) => BackendKind::Gpio,
+            Self::GameBoyPlayer(_) => BackendKind::GameBoyPlayer,
+            #[cfg(feature = "ds-rumble-pak")]
+            Self::DsRumblePak(_) => BackendKind::DsRumblePak,
+        }
+    }
+
+    /// Returns a human-readable name for the mechanism.
+    pub(crate) fn name(&self) -> &'static str {
+        self.kind().name()
+    }
+
+    /// Returns the state of the serial session, if the mechanism uses one.
+    pub(crate) fn serial_status(&self) -> Option<SerialStatus> {
+        match self {
+            Self::GameBoyPlayer(game_boy_player) => {
+                Some(game_boy_player::serial_status(*game_boy_player))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Backend for Mechanism {
+    fn capabilities(&self) -> Capabilities {
+        match self {
+            Self::Gpio(gpio) => gpio.capabilities(),
+            Self::GameBoyPlayer(game_boy_player) => game_boy_player.capabilities(),
+            #[cfg(feature = "ds-rumble-pak")]
+            Self::DsRumblePak(ds_rumble_pak) => ds_rumble_pak.capabilities(),
+        }
+    }
+
+    fn response_curve(&self) -> ResponseCurve {
+        match self {
+            Self::Gpio(gpio) => gpio.response_curve(),
+            Self::GameBoyPlayer(game_boy_player) => game_boy_player.response_curve(),
+            #[cfg(feature = "ds-rumble-pak")]
+            Self::DsRumblePak(ds_rumble_pak) => ds_rumble_pak.response_curve(),
+        }
+    }
+
+    fn calibration(&self) -> Calibration {
+        match self {
+            Self::Gpio(gpio) => gpio.calibration(),
+            Self::GameBoyPlayer(game_boy_player) => game_boy_player.calibration(),
+            #[cfg(feature = "ds-rumble-pak")]
+            Self::DsRumblePak(ds_rumble_pak) => ds_rumble_pak.calibration(),
+        }
+    }
+
+    fn min_command_frames(&self) -> u8 {
+        match self {
+            Self::Gpio(gpio) => gpio.min_command_frames(),
+            Self::GameBoyPlayer(game_boy_player) => game_boy_player.min_command_frames(),
+            #[cfg(feature = "ds-rumble-pak")]
+            Self::DsRumblePak(ds_rumble_pak) => ds_rumble_pak.min_command_frames(),
+        }
+    }
+
+    fn send(&mut self, command: Command) {
+        match self {
+            Self::Gpio(gpio) => gpio.send(command),
+            Self::GameBoyPlayer(game_boy_player) => game_boy_player.send(command),
+            #[cfg(feature = "ds-rumble-pak")]
+            Self::DsRumblePak(ds_rumble_pak) => ds_rumble_pak.send(command),
+        }
+    }
+
+    fn read_back(&self) -> Option<bool> {
+        match self {
+            Self::Gpio(gpio) => gpio.read_back(),
+            Self::GameBoyPlayer(game_boy_player) => game_boy_player.read_back(),
+            #[cfg(feature = "ds-rumble-pak")]
+            Self::DsRumblePak(ds_rumble_pak) => ds_rumble_pak.read_back(),
+        }
+    }
+}
+
+/// What a [`Device`] is currently playing.
+enum Source<'a> {
+    Idle,
+    Constant(Intensity),
+    Timed {
+        intensity: Intensity,
+        remaining: u16,
+    },
+    Pattern(Sequencer<'a>),
+    Effect {
+        effect: &'a mut dyn Effect,
+        frame: u32,
+    },
+}
+
+impl Source<'_> {
+    /// Returns the intensity for the current frame, then advances to the next frame.
+    fn tick(&mut self) -> Intensity {
+        match self {
+            Self::Idle => Intensity::OFF,
+            Self::Constant(intensity) => *intensity,
+            Self::Timed {
+                intensity,
+                remaining,
+            } => {
+                if *remaining == 0 {
+                    *self = Self::Idle;
+                    Intensity::OFF
+                } else {
+                    *remaining -= 1;
+                    *intensity
+                }
+            }
+            Self::Pattern(sequencer) => sequencer.tick(),
+            Self::Effect { effect, frame } => {
+                let intensity = effect.sample(*frame);
+                *frame = frame.wrapping_add(1);
+                intensity
+            }
+        }
+    }
+}
+
+impl Debug for Source<'_> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Idle => formatter.write_str("Idle"),
+            Self::Constant(intensity) => {
+                formatter.debug_tuple("Constant").field(intensity).finish()
+            }
+            Self::Timed {
+                intensity,
+                remaining,
+            } => formatter
+                .debug_struct("Timed")
+                .field("intensity", intensity)
+                .field("remaining", remaining)
+                .finish(),
+            Self::Pattern(sequencer) => formatter.debug_tuple("Pattern").field(sequencer).finish(),
+            Self::Effect { frame, .. } => formatter
+                .debug_struct("Effect")
+                .field("frame", frame)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+/// A rumble device, routing high-level requests to whichever mechanism is available.
+///
+/// A `Device` is obtained from [`detect_all()`]. Game code can then request rumble without knowing
+/// whether it is performed by the cartridge, the Game Boy Player, or the DS Rumble Pak. Only one
+/// request plays at a time; each new request replaces the previous one.
+///
+/// [`update()`] must be called once a frame, which advances playback and services the underlying
+/// mechanism. Intensities are converted to duty cycles using the mechanism's
+/// [`Backend::calibration()`], then limited by the [`PowerSaving`] policy, if one is set. The
+/// mechanism can be switched at runtime with [`set_preference()`].
+///
+/// [`PowerSaving`]: crate::PowerSaving
+/// [`set_preference()`]: Device::set_preference()
+///
+/// ``` rust
+/// use gba_rumble::{Intensity, Pattern, Step, pattern};
+///
+/// const HIT: Pattern = pattern![Step::Hold { intensity: Intensity::MAX, frames: 10 }];
+///
+/// let mut device = gba_rumble::detect_all();
+///
+/// device.play(HIT);
+/// // Once a frame:
+/// device.update();
+///
+/// // Rumble gently for half a second.
+/// device.rumble_for(Intensity::new(64), 30);
+/// ```
+///
+/// [`update()`]: Device::update()
+#[derive(Debug)]
+pub struct Device<'a> {
+    mechanism: Mechanism,
+    source: Source<'a>,
+    active: bool,
+    /// Consecutive frames the motor has been on, counted for the power saving policy.
+    on_frames: u16,
+    handoff: Handoff,
+}
+
+impl<'a> Device<'a> {
+    /// The frames a Game Boy Player selected by [`detect_all()`] has to begin the handshake,
+    /// before cartridge rumble is used instead.
+    ///
+    /// This is five seconds, far longer than a real Game Boy Player takes.
+    pub const HANDSHAKE_TIMEOUT_FRAMES: u16 = 300;
+
+    fn new(mechanism: Mechanism) -> Self {
+        Self {
+            mechanism,
+            source: Source::Idle,
+            active: false,
+            on_frames: 0,
+            handoff: Handoff::default(),
+        }
+    }
+
+    /// Returns the capabilities of the mechanism in use.
+    pub fn capabilities(&self) -> Capabilities {
+        self.mechanism.capabilities()
+    }
+
+    /// Rumble at `intensity` until another request is made.
+    pub fn set_intensity(&mut self, intensity: Intensity) {
+        self.source = Source::Constant(intensity);
+    }
+
+    /// Rumble at `intensity` for `frames` frames.
+    pub fn rumble_for(&mut self, intensity: Intensity, frames: u16) {
+        self.source = Source::Timed {
+            intensity,
+            remaining: frames,
+        };
+    }
+
+    /// Play `pattern` from the beginning.
+    pub fn play(&mut self, pattern: Pattern<'a>) {
+        self.source = Source::Pattern(Sequencer::new(pattern));
+    }
+
+    /// Play `effect`, starting from frame `0`, until another request is made.
+    pub fn play_effect(&mut self, effect: &'a mut dyn Effect) {
+        self.source = Source::Effect { effect, frame: 0 };
+    }
+
+    /// Stop rumbling immediately.
+    pub fn stop(&mut self) {
+        self.source = Source::Idle;
+        self.mechanism.send(Command::Stop);
+        self.active = false;
+    }
+
+    /// Stop rumbling immediately, braking the motor if the mechanism supports it.
+    pub fn hard_stop(&mut self) {
+        self.source = Source::Idle;
+        self.mechanism.send(Command::HardStop);
+        self.active = false;
+    }
+
+    /// Returns whether the motor is currently on.
+    pub fn is_rumbling(&self) -> bool {
+        self.active
+    }
+
+    /// Returns which mechanism is preferred.
+    pub fn preference(&self) -> Preference {
+        self.handoff.preference()
+    }
+
+    /// Switches to the mechanism matching `preference`, if it differs from the one in use.
+    ///
+    /// Playback is not interrupted: the motor is stopped on the old mechanism, and whatever is
+    /// playing continues on the new one. Returns whether the mechanism changed.
+    ///
+    /// ``` rust
+    /// use gba_rumble::Preference;
+    ///
+    /// let mut device = gba_rumble::detect_all();
+    /// // The player chose "prefer cartridge rumble" in the options menu.
+    /// device.set_preference(Preference::Cartridge);
+    /// ```
+    pub fn set_preference(&mut self, preference: Preference) -> bool {
+        self.handoff
+            .set_preference(preference, &mut self.mechanism, self.active)
+    }
+
+    /// Advance playback by one frame, and service the underlying mechanism.
+    ///
+    /// This should be called once a frame.
+    pub fn update(&mut self) {
+        if cfg!(feature = "disabled") {
+            return;
+        }
+        let duty = power::limit(
+            self.mechanism.calibration().apply(self.source.tick()),
+            &mut self.on_frames,
+        );
+        let active = !duty.is_off();
+        if active != self.active {
+            self.mechanism.send(if active {
+                Command::Start
+            } else {
+                Command::Stop
+            });
+            self.active = active;
+        }
+        self.mechanism.update();
+        self.handoff.update();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        BackendKind, DETECTED, Detected, Device, Mechanism, Preference, Presence, backends, rank,
+    };
+    use crate::{
+        Backend, Capabilities, EffectFn, GameBoyPlayer, Gpio, Intensity, Pattern, Step,
+        game_boy_player, pattern,
+    };
+    use alloc::format;
+    use claims::{assert_none, assert_some};
+    use gba_test::test;
+
+    const PULSE: Pattern = pattern![
+        Step::Hold {
+            intensity: Intensity::MAX,
+            frames: 2
+        },
+        Step::Hold {
+            intensity: Intensity::OFF,
+            frames: 1
+        },
+    ];
+
+    #[test]
+    fn backends_list() {
+        unsafe {
+            DETECTED = Detected {
+                game_boy_player: Presence::Detected,
+                #[cfg(feature = "ds-rumble-pak")]
+                ds_rumble_pak: Presence::NotPresent,
+            };
+        }
+
+        let mut backends = backends();
+
+        let gpio = assert_some!(backends.next());
+        assert_eq!(gpio.kind, BackendKind::Gpio);
+        assert_eq!(gpio.presence, Presence::Unknown);
+        assert_eq!(gpio.capabilities, Gpio.capabilities());
+        assert_eq!(format!("{gpio}"), "GPIO: unknown");
+        let game_boy_player = assert_some!(backends.next());
+        assert_eq!(format!("{game_boy_player}"), "Game Boy Player: detected");
+        #[cfg(feature = "ds-rumble-pak")]
+        assert_eq!(
+            format!("{}", assert_some!(backends.next())),
+            "DS Rumble Pak: not present"
+        );
+        assert_none!(backends.next());
+    }
+
+    #[test]
+    fn rank_prefers_brake() {
+        let brake = Capabilities {
+            has_intensity: false,
+            has_brake: true,
+            latency_frames: 1,
+            shared_with_sio: true,
+        };
+
+        assert!(rank(brake) > rank(Mechanism::Gpio(Gpio).capabilities()));
+    }
+
+    #[test]
+    fn rank_prefers_latency() {
+        let slow = Capabilities {
+            has_intensity: false,
+            has_brake: false,
+            latency_frames: 1,
+            shared_with_sio: false,
+        };
+
+        assert!(rank(Mechanism::Gpio(Gpio).capabilities()) > rank(slow));
+    }
+
+    #[test]
+    fn timed() {
+        let mut device = Device::new(Mechanism::Gpio(Gpio));
+
+        device.rumble_for(Intensity::MAX, 2);
+        device.update();
+        assert!(device.is_rumbling());
+        device.update();
+        assert!(device.is_rumbling());
+        device.update();
+        assert!(!device.is_rumbling());
+    }
+
+    #[test]
+    fn timed_off() {
+        let mut device = Device::new(Mechanism::Gpio(Gpio));
+
+        device.rumble_for(Intensity::OFF, 10);
+        device.update();
+
+        assert!(!device.is_rumbling());
+    }
+
+    #[test]
+    fn constant() {
+        let mut device = Device::new(Mechanism::Gpio(Gpio));
+
+        device.set_intensity(Intensity::new(1));
+        for _ in 0..10 {
+            device.update();
+        }
+        assert!(device.is_rumbling());
+
+        device.set_intensity(Intensity::OFF);
+        device.update();
+        assert!(!device.is_rumbling());
+    }
+
+    #[test]
+    fn pattern() {
+        let mut device = Device::new(Mechanism::Gpio(Gpio));
+
+        device.play(PULSE);
+        device.update();
+        device.update();
+        assert!(device.is_rumbling());
+        device.update();
+        assert!(!device.is_rumbling());
+    }
+
+    #[test]
+    fn effect() {
+        let mut effect = EffectFn::new(|frame| Intensity::new((frame % 2) as u8));
+        let mut device = Device::new(Mechanism::Gpio(Gpio));
+
+        device.play_effect(&mut effect);
+        device.update();
+        assert!(!device.is_rumbling());
+        device.update();
+        assert!(device.is_rumbling());
+    }
+
+    #[test]
+    fn stop() {
+        let mut device = Device::new(Mechanism::Gpio(Gpio));
+        device.set_intensity(Intensity::MAX);
+        device.update();
+
+        device.stop();
+        assert!(!device.is_rumbling());
+        device.update();
+        assert!(!device.is_rumbling());
+    }
+
+    #[test]
+    fn set_preference_hands_off() {
+        let game_boy_player = assert_some!(unsafe { GameBoyPlayer::from_raw(0x4700_0000) });
+        game_boy_player.enable_serial();
+        let mut device = Device::new(Mechanism::GameBoyPlayer(game_boy_player));
+        device.set_intensity(Intensity::MAX);
+        device.update();
+        assert!(game_boy_player::is_rumble_requested());
+
+        assert!(device.set_preference(Preference::Cartridge));
+        assert_eq!(device.preference(), Preference::Cartridge);
+        assert_eq!(device.capabilities(), Gpio.capabilities());
+        assert!(!game_boy_player::is_rumble_requested());
+        assert!(device.is_rumbling());
+
+        assert!(device.set_preference(Preference::Best));
+        assert_eq!(device.capabilities(), game_boy_player.capabilities());
+        assert!(game_boy_player::is_rumble_requested());
+        device.stop();
+    }
+
+    #[test]
+    fn set_preference_without_game_boy_player() {
+        let mut device = Device::new(Mechanism::Gpio(Gpio));
+
+        assert!(!device.set_preference(Preference::Cartridge));
+        assert_eq!(device.preference(), Preference::Cartridge);
+        assert!(!device.set_preference(Preference::Best));
+        assert_eq!(device.capabilities(), Gpio.capabilities());
+    }
+
+    #[test]
+    fn hard_stop() {
+        let mut device = Device::new(Mechanism::Gpio(Gpio));
+        device.play(PULSE);
+        device.update();
+
+        device.hard_stop();
+        device.update();
+
+        assert!(!device.is_rumbling());
+    }
+}
@@ -0,0 +1,349 @@
+//! An interactive on-device diagnostics menu.
+//!
+//! The menu shows which rumble backends were detected, allows each test effect to be fired on any
+//! of them, and displays live statistics about serial communication with the Game Boy Player.
+//! Everything is also logged, through [`mgba_log`](https://docs.rs/mgba_log) with the `mgba-log`
+//! feature or through any other logger with the `log` feature, making it useful for verifying
+//! hardware reports from players.
+//!
+//! This module is only available with the `diagnostics` feature enabled.
+//!
+//! ``` rust,no_run
+//! // Set up interrupts as you would for `GameBoyPlayer`, then hand control to the menu.
+//! gba_rumble::diagnostics::run();
+//! ```
+
+mod font;
+
+use crate::logging::{self, info};
+use crate::{
+    Backend, Command, GameBoyPlayer, Gpio, Intensity, Pattern, Sequencer, Step, bios,
+    game_boy_player, pattern, regs,
+};
+use core::{fmt, fmt::Write, ptr};
+
+const DISPCNT: *mut u16 = ptr::with_exposed_provenance_mut(regs::DISPCNT);
+const BG0CNT: *mut u16 = ptr::with_exposed_provenance_mut(regs::BG0CNT);
+const KEYINPUT: *const u16 = ptr::with_exposed_provenance(regs::KEYINPUT);
+const PALETTE: *mut u16 = ptr::with_exposed_provenance_mut(regs::BG_PALETTE);
+const TILES: *mut u32 = ptr::with_exposed_provenance_mut(regs::VRAM);
+const MAP: *mut u16 = ptr::with_exposed_provenance_mut(regs::VRAM + 0xF800);
+
+/// The width of the screen, in tiles.
+const COLUMNS: usize = 30;
+
+const KEY_A: u16 = 1 << 0;
+const KEY_B: u16 = 1 << 1;
+const KEY_RIGHT: u16 = 1 << 4;
+const KEY_LEFT: u16 = 1 << 5;
+const KEY_UP: u16 = 1 << 6;
+const KEY_DOWN: u16 = 1 << 7;
+
+/// Effects that can be fired from the menu.
+const EFFECTS: [(&str, Pattern); 4] = [
+    (
+        "SHORT PULSE",
+        pattern![Step::Hold {
+            intensity: Intensity::MAX,
+            frames: 10
+        }],
+    ),
+    (
+        "LONG PULSE",
+        pattern![Step::Hold {
+            intensity: Intensity::MAX,
+            frames: 60
+        }],
+    ),
+    (
+        "HEARTBEAT",
+        pattern![
+            Step::Hold {
+                intensity: Intensity::MAX,
+                frames: 6
+            },
+            Step::Hold {
+                intensity: Intensity::OFF,
+                frames: 6
+            },
+            Step::Hold {
+                intensity: Intensity::MAX,
+                frames: 6
+            },
+            Step::Hold {
+                intensity: Intensity::OFF,
+                frames: 40
+            },
+            Step::Loop { step: 0, count: 3 },
+        ],
+    ),
+    (
+        "CONTINUOUS",
+        pattern![
+            Step::Hold {
+                intensity: Intensity::MAX,
+                frames: 60
+            },
+            Step::Loop { step: 0, count: 0 },
+        ],
+    ),
+];
+
+/// A backend that can be selected in the menu.
+enum Selection {
+    Gpio(Gpio),
+    GameBoyPlayer(GameBoyPlayer),
+    #[cfg(feature = "ds-rumble-pak")]
+    DsRumblePak(crate::DsRumblePak),
+}
+
+impl Selection {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Gpio(_) => "GPIO",
+            Self::GameBoyPlayer(_) => "GAME BOY PLAYER",
+            #[cfg(feature = "ds-rumble-pak")]
+            Self::DsRumblePak(_) => "DS RUMBLE PAK",
+        }
+    }
+
+    fn backend(&mut self) -> &mut dyn Backend {
+        match self {
+            Self::Gpio(gpio) => gpio,
+            Self::GameBoyPlayer(game_boy_player) => game_boy_player,
+            #[cfg(feature = "ds-rumble-pak")]
+            Self::DsRumblePak(ds_rumble_pak) => ds_rumble_pak,
+        }
+    }
+}
+
+/// Writes text to the screen, starting at a given tile.
+struct Cursor {
+    index: usize,
+}
+
+impl Cursor {
+    fn at(column: usize, row: usize) -> Self {
+        Self {
+            index: row * 32 + column,
+        }
+    }
+}
+
+impl Write for Cursor {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        for byte in string.bytes() {
+            let glyph = font::CHARACTERS
+                .iter()
+                .position(|&character| character == byte.to_ascii_uppercase())
+                .unwrap_or(0);
+            unsafe {
+                MAP.add(self.index).write_volatile(glyph as u16);
+            }
+            self.index += 1;
+        }
+        Ok(())
+    }
+}
+
+/// Writes a full line of text, clearing whatever was previously displayed on it.
+fn line(row: usize, arguments: fmt::Arguments) {
+    for column in 0..COLUMNS {
+        unsafe {
+            MAP.add(row * 32 + column).write_volatile(0);
+        }
+    }
+    let _ = Cursor::at(0, row).write_fmt(arguments);
+}
+
+/// Switches the display to text mode and loads the font.
+fn prepare_display() {
+    unsafe {
+        DISPCNT.write_volatile(0);
+        // 4bpp tiles from character base block 0, with the map in screen base block 31.
+        BG0CNT.write_volatile(31 << 8);
+        PALETTE.write_volatile(0x0000);
+        PALETTE.add(1).write_volatile(0x7FFF);
+        for (index, glyph) in font::GLYPHS.iter().enumerate() {
+            for (row, bits) in glyph.iter().enumerate() {
+                // Expand each bit into a 4bpp pixel using palette entry 1.
+                let mut pixels = 0;
+                for column in 0..8 {
+                    if bits & (1 << column) != 0 {
+                        pixels |= 1 << (column * 4);
+                    }
+                }
+                TILES.add(index * 8 + row).write_volatile(pixels);
+            }
+        }
+        for index in 0..32 * 32 {
+            MAP.add(index).write_volatile(0);
+        }
+        DISPCNT.write_volatile(1 << 8);
+    }
+}
+
+fn detection_result(detected: bool) -> &'static str {
+    if detected { "DETECTED" } else { "NOT FOUND" }
+}
+
+/// Runs the diagnostics menu forever.
+///
+/// Backends are detected first, which displays the Game Boy Player logo. As with
+/// [`GameBoyPlayer::detect()`], vblank interrupts must be enabled, and
+/// [`game_boy_player_interrupt()`] must be called on serial interrupts for the Game Boy Player to
+/// be usable. The serial port itself is configured by the menu.
+///
+/// The menu is controlled with the following buttons:
+///
+/// | Button     | Action                  |
+/// |------------|-------------------------|
+/// | Left/Right | Select a backend        |
+/// | Up/Down    | Select an effect        |
+/// | A          | Fire the effect         |
+/// | B          | Stop with a hard stop   |
+///
+/// [`game_boy_player_interrupt()`]: crate::game_boy_player_interrupt()
+pub fn run() -> ! {
+    logging::init();
+
+    let game_boy_player = GameBoyPlayer::detect();
+    if let Some(game_boy_player) = game_boy_player {
+        game_boy_player.enable_serial();
+    }
+    #[cfg(feature = "ds-rumble-pak")]
+    let ds_rumble_pak = crate::DsRumblePak::detect();
+
+    let mut backends: [Option<Selection>; 3] = [Some(Selection::Gpio(Gpio)), None, None];
+    backends[1] = game_boy_player.map(Selection::GameBoyPlayer);
+    #[cfg(feature = "ds-rumble-pak")]
+    {
+        backends[2] = ds_rumble_pak.map(Selection::DsRumblePak);
+    }
+
+    info!("GPIO: assumed available");
+    info!(
+        "Game Boy Player: {}",
+        detection_result(game_boy_player.is_some())
+    );
+    #[cfg(feature = "ds-rumble-pak")]
+    info!(
+        "DS Rumble Pak: {}",
+        detection_result(ds_rumble_pak.is_some())
+    );
+
+    prepare_display();
+    line(0, format_args!("GBA RUMBLE DIAGNOSTICS"));
+    line(2, format_args!("GPIO: ASSUMED"));
+    line(
+        3,
+        format_args!(
+            "GAME BOY PLAYER: {}",
+            detection_result(game_boy_player.is_some())
+        ),
+    );
+    #[cfg(feature = "ds-rumble-pak")]
+    line(
+        4,
+        format_args!(
+            "DS RUMBLE PAK: {}",
+            detection_result(ds_rumble_pak.is_some())
+        ),
+    );
+    #[cfg(not(feature = "ds-rumble-pak"))]
+    line(4, format_args!("DS RUMBLE PAK: DISABLED"));
+    line(9, format_args!("A: FIRE  B: STOP"));
+
+    let mut selected = 0;
+    let mut effect = 0;
+    let mut sequencer: Option<Sequencer> = None;
+    let mut active = false;
+    let mut previous_keys = 0;
+
+    loop {
+        bios::wait_for_vblank();
+
+        let keys = !unsafe { KEYINPUT.read_volatile() } & 0x03FF;
+        let pressed = keys & !previous_keys;
+        previous_keys = keys;
+
+        if pressed & (KEY_LEFT | KEY_RIGHT) != 0 {
+            if let Some(backend) = &mut backends[selected] {
+                backend.backend().send(Command::Stop);
+            }
+            active = false;
+            sequencer = None;
+            let step = if pressed & KEY_RIGHT != 0 { 1 } else { 2 };
+            loop {
+                selected = (selected + step) % backends.len();
+                if backends[selected].is_some() {
+                    break;
+                }
+            }
+        }
+        if pressed & KEY_DOWN != 0 {
+            effect = (effect + 1) % EFFECTS.len();
+        }
+        if pressed & KEY_UP != 0 {
+            effect = (effect + EFFECTS.len() - 1) % EFFECTS.len();
+        }
+
+        if let Some(backend) = &mut backends[selected] {
+            if pressed & KEY_A != 0 {
+                info!("Firing {} on {}", EFFECTS[effect].0, backend.name());
+                sequencer = Some(Sequencer::new(EFFECTS[effect].1));
+            }
+            if pressed & KEY_B != 0 {
+                info!("Hard stop on {}", backend.name());
+                sequencer = None;
+                active = false;
+                backend.backend().send(Command::HardStop);
+            }
+
+            let intensity = sequencer
+                .as_mut()
+                .map_or(Intensity::OFF, |sequencer| sequencer.tick());
+            if active != !intensity.is_off() {
+                active = !active;
+                let command = if active {
+                    Command::Start
+                } else {
+                    Command::Stop
+                };
+                info!("{:?} sent to {}", command, backend.name());
+                backend.backend().send(command);
+            }
+
+            line(6, format_args!("BACKEND: < {} >", backend.name()));
+        }
+
+        if let Some(game_boy_player) = game_boy_player {
+            game_boy_player.update();
+        }
+        #[cfg(feature = "ds-rumble-pak")]
+        if let Some(ds_rumble_pak) = ds_rumble_pak {
+            ds_rumble_pak.update();
+        }
+
+        line(7, format_args!("EFFECT: < {} >", EFFECTS[effect].0));
+        line(
+            8,
+            format_args!("MOTOR: {}", if active { "ON" } else { "OFF" }),
+        );
+
+        let stats = game_boy_player::sio_stats();
+        line(11, format_args!("SIO"));
+        line(12, format_args!(" INTERRUPTS: {}", stats.interrupts));
+        line(13, format_args!(" CONNECTIONS: {}", stats.connections));
+        line(14, format_args!(" TRANSFERS: {}", stats.transfers));
+        line(15, format_args!(" RESETS: {}", stats.resets));
+        line(16, format_args!(" SKIPPED: {}", stats.skipped));
+        line(
+            17,
+            format_args!(
+                " NESTED: {}",
+                game_boy_player::game_boy_player_nested_interrupts()
+            ),
+        );
+    }
+}
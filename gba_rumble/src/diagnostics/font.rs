@@ -0,0 +1,52 @@
+//! Font used to display diagnostics.
+
+/// The characters available in [`GLYPHS`], in order.
+pub(crate) const CHARACTERS: &[u8] = b" 0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ:-<>/.";
+
+/// 8x8 glyphs for each of [`CHARACTERS`], one byte per row with the least significant bit as the
+/// leftmost pixel.
+pub(crate) const GLYPHS: [[u8; 8]; 43] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // space
+    [0x1C, 0x22, 0x32, 0x2A, 0x26, 0x22, 0x1C, 0x00], // 0
+    [0x08, 0x0C, 0x08, 0x08, 0x08, 0x08, 0x1C, 0x00], // 1
+    [0x1C, 0x22, 0x20, 0x10, 0x08, 0x04, 0x3E, 0x00], // 2
+    [0x3E, 0x10, 0x08, 0x10, 0x20, 0x22, 0x1C, 0x00], // 3
+    [0x10, 0x18, 0x14, 0x12, 0x3E, 0x10, 0x10, 0x00], // 4
+    [0x3E, 0x02, 0x1E, 0x20, 0x20, 0x22, 0x1C, 0x00], // 5
+    [0x18, 0x04, 0x02, 0x1E, 0x22, 0x22, 0x1C, 0x00], // 6
+    [0x3E, 0x20, 0x10, 0x08, 0x04, 0x04, 0x04, 0x00], // 7
+    [0x1C, 0x22, 0x22, 0x1C, 0x22, 0x22, 0x1C, 0x00], // 8
+    [0x1C, 0x22, 0x22, 0x3C, 0x20, 0x10, 0x0C, 0x00], // 9
+    [0x08, 0x14, 0x22, 0x22, 0x3E, 0x22, 0x22, 0x00], // A
+    [0x1E, 0x22, 0x22, 0x1E, 0x22, 0x22, 0x1E, 0x00], // B
+    [0x1C, 0x22, 0x02, 0x02, 0x02, 0x22, 0x1C, 0x00], // C
+    [0x0E, 0x12, 0x22, 0x22, 0x22, 0x12, 0x0E, 0x00], // D
+    [0x3E, 0x02, 0x02, 0x1E, 0x02, 0x02, 0x3E, 0x00], // E
+    [0x3E, 0x02, 0x02, 0x1E, 0x02, 0x02, 0x02, 0x00], // F
+    [0x1C, 0x22, 0x02, 0x3A, 0x22, 0x22, 0x3C, 0x00], // G
+    [0x22, 0x22, 0x22, 0x3E, 0x22, 0x22, 0x22, 0x00], // H
+    [0x1C, 0x08, 0x08, 0x08, 0x08, 0x08, 0x1C, 0x00], // I
+    [0x38, 0x10, 0x10, 0x10, 0x10, 0x12, 0x0C, 0x00], // J
+    [0x22, 0x12, 0x0A, 0x06, 0x0A, 0x12, 0x22, 0x00], // K
+    [0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x3E, 0x00], // L
+    [0x22, 0x36, 0x2A, 0x2A, 0x22, 0x22, 0x22, 0x00], // M
+    [0x22, 0x22, 0x26, 0x2A, 0x32, 0x22, 0x22, 0x00], // N
+    [0x1C, 0x22, 0x22, 0x22, 0x22, 0x22, 0x1C, 0x00], // O
+    [0x1E, 0x22, 0x22, 0x1E, 0x02, 0x02, 0x02, 0x00], // P
+    [0x1C, 0x22, 0x22, 0x22, 0x2A, 0x12, 0x2C, 0x00], // Q
+    [0x1E, 0x22, 0x22, 0x1E, 0x0A, 0x12, 0x22, 0x00], // R
+    [0x3C, 0x02, 0x02, 0x1C, 0x20, 0x20, 0x1E, 0x00], // S
+    [0x3E, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x00], // T
+    [0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x1C, 0x00], // U
+    [0x22, 0x22, 0x22, 0x22, 0x22, 0x14, 0x08, 0x00], // V
+    [0x22, 0x22, 0x22, 0x2A, 0x2A, 0x2A, 0x14, 0x00], // W
+    [0x22, 0x22, 0x14, 0x08, 0x14, 0x22, 0x22, 0x00], // X
+    [0x22, 0x22, 0x22, 0x14, 0x08, 0x08, 0x08, 0x00], // Y
+    [0x3E, 0x20, 0x10, 0x08, 0x04, 0x02, 0x3E, 0x00], // Z
+    [0x00, 0x0C, 0x0C, 0x00, 0x0C, 0x0C, 0x00, 0x00], // :
+    [0x00, 0x00, 0x00, 0x3E, 0x00, 0x00, 0x00, 0x00], // -
+    [0x10, 0x08, 0x04, 0x02, 0x04, 0x08, 0x10, 0x00], // <
+    [0x04, 0x08, 0x10, 0x20, 0x10, 0x08, 0x04, 0x00], // >
+    [0x00, 0x20, 0x10, 0x08, 0x04, 0x02, 0x00, 0x00], // /
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C, 0x00], // .
+];
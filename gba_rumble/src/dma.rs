@@ -0,0 +1,96 @@
+//! Direct memory access channels.
+
+use crate::regs;
+use core::ptr;
+
+/// One of the GBA's DMA channels able to read from ROM.
+///
+/// This is used to select which channel [`BuiltinSplash::dma()`] uploads the logo with. DMA 0 can
+/// only read from internal memory, so it is not offered.
+///
+/// [`BuiltinSplash::dma()`]: crate::BuiltinSplash::dma()
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Dma {
+    /// DMA 1, typically used for streaming audio to sound FIFO A.
+    Dma1 = 1,
+    /// DMA 2, typically used for streaming audio to sound FIFO B.
+    Dma2 = 2,
+    /// DMA 3, the general purpose channel.
+    Dma3 = 3,
+}
+
+impl Dma {
+    /// Returns the address of this channel's register at `offset` from `DMA0SAD`.
+    fn register(self, offset: usize) -> usize {
+        regs::DMA0SAD + self as usize * 12 + offset
+    }
+
+    /// Returns the channel's `DMAxSAD` source address register.
+    fn source(self) -> *mut u32 {
+        ptr::with_exposed_provenance_mut(self.register(0))
+    }
+
+    /// Returns the channel's `DMAxDAD` destination address register.
+    fn destination(self) -> *mut u32 {
+        ptr::with_exposed_provenance_mut(self.register(regs::DMA0DAD - regs::DMA0SAD))
+    }
+
+    /// Returns the channel's `DMAxCNT_L` transfer count register.
+    fn count(self) -> *mut u16 {
+        ptr::with_exposed_provenance_mut(self.register(regs::DMA0CNT_L - regs::DMA0SAD))
+    }
+
+    /// Returns the channel's `DMAxCNT_H` control register.
+    fn control(self) -> *mut u16 {
+        ptr::with_exposed_provenance_mut(self.register(regs::DMA0CNT_H - regs::DMA0SAD))
+    }
+
+    /// Copies `words` 32-bit words from `source` to `destination`, returning once the transfer is
+    /// complete.
+    ///
+    /// # Safety
+    /// `source` and `destination` must be word aligned and valid for `words` words, and `words`
+    /// must be nonzero.
+    pub(crate) unsafe fn copy_words(self, source: *const u32, destination: *mut u32, words: u16) {
+        unsafe {
+            self.control().write_volatile(0);
+            // The channel reads and writes through these addresses, outside of Rust's knowledge.
+            self.source()
+                .write_volatile(source.expose_provenance() as u32);
+            self.destination()
+                .write_volatile(destination.expose_provenance() as u32);
+            self.count().write_volatile(words);
+            // Enabled, starting immediately, transferring words with both addresses incrementing.
+            self.control().write_volatile(1 << 15 | 1 << 10);
+            // The CPU is halted during the transfer, but it only starts two cycles later.
+            while self.control().read_volatile() & (1 << 15) != 0 {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Dma;
+    use gba_test::test;
+
+    #[test]
+    fn registers() {
+        assert_eq!(Dma::Dma1.source().addr(), 0x0400_00BC);
+        assert_eq!(Dma::Dma3.source().addr(), 0x0400_00D4);
+        assert_eq!(Dma::Dma3.destination().addr(), 0x0400_00D8);
+        assert_eq!(Dma::Dma3.count().addr(), 0x0400_00DC);
+        assert_eq!(Dma::Dma3.control().addr(), 0x0400_00DE);
+    }
+
+    #[test]
+    fn copy_words() {
+        let source = [1, 2, 3, 4];
+        let mut destination = [0u32; 4];
+
+        unsafe {
+            Dma::Dma3.copy_words(source.as_ptr(), destination.as_mut_ptr(), 4);
+        }
+
+        assert_eq!(destination, source);
+    }
+}
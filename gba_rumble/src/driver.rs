@@ -0,0 +1,1832 @@
+//! Driving a backend from per-frame intensities.
+
+use crate::{
+    Backend, Calibration, Command, DutyBudget, Fixed, Intensity, MotorType, ResponseCurve,
+    SaveState, SelfTest, Settings, SettingsStore, StateError,
+    duty::DutyMeter,
+    metrics, power,
+    self_test::SelfTestRun,
+    state::{StateReader, StateWriter},
+};
+use core::ops::ControlFlow;
+
+/// A [`Command`] stamped with the frame it was sent on.
+///
+/// Commands to be sent later can also be given to [`Driver::queue()`], stamped with the frame to
+/// send them on.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct TimedCommand {
+    /// The frame the command was sent on, or is to be sent on.
+    pub frame: u32,
+    /// The command that was sent, or is to be sent.
+    pub command: Command,
+}
+
+/// The most recent `N` commands sent by a [`Driver`].
+///
+/// Once full, each new command overwrites the oldest one.
+#[derive(Clone, Debug)]
+pub struct CommandLog<const N: usize> {
+    entries: [Option<TimedCommand>; N],
+    /// Index of the next entry to be written.
+    next: usize,
+    len: usize,
+}
+
+impl<const N: usize> CommandLog<N> {
+    /// Creates an empty log.
+    pub const fn new() -> Self {
+        Self {
+            entries: [None; N],
+            next: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of commands in the log.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the log contains no commands.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes all commands from the log.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Returns an iterator over the logged commands, from oldest to newest.
+    pub fn iter(&self) -> impl Iterator<Item = TimedCommand> + '_ {
+        let start = (self.next + N - self.len) % N.max(1);
+        (0..self.len).filter_map(move |offset| self.entries[(start + offset) % N])
+    }
+
+    fn push(&mut self, command: TimedCommand) {
+        if N == 0 {
+            return;
+        }
+        self.entries[self.next] = Some(command);
+        self.next = (self.next + 1) % N;
+        self.len = (self.len + 1).min(N);
+    }
+}
+
+impl<const N: usize> Default for CommandLog<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, const M: usize> PartialEq<CommandLog<M>> for CommandLog<N> {
+    fn eq(&self, other: &CommandLog<M>) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<const N: usize> Eq for CommandLog<N> {}
+
+/// The frame counter shared by every [`Driver`], as returned by [`frame()`].
+static mut FRAME: u32 = 0;
+
+/// Returns the number of frames ticked since boot, shared by every [`Driver`].
+///
+/// Each call to [`Driver::tick()`] advances this counter, unless another driver has already
+/// advanced it since that driver's previous tick. A program ticking several drivers once a frame
+/// therefore still sees the counter advance once a frame, whichever drivers are created, dropped,
+/// or restored along the way. Unlike [`Driver::frame()`], this is never reset, so it can serve as
+/// the one time base shared by the game, its effects, and anything it logs or records. It wraps
+/// around to `0` after [`u32::MAX`] frames, which takes over two years.
+pub fn frame() -> u32 {
+    unsafe { (&raw const FRAME).read_volatile() }
+}
+
+/// The motor-on frames counted by every [`Driver`], as returned by [`motor_on_frames()`].
+static mut MOTOR_ON_FRAMES: u32 = 0;
+
+/// Returns the number of frames the motor has been on since boot, summed over every [`Driver`].
+///
+/// Each call to [`Driver::tick()`] that leaves the motor on counts one frame, whatever caused it to
+/// be on. Comparing this with [`frame()`] gives the fraction of play time spent rumbling, which
+/// games can use for battery usage hints, and which can be correlated with measured power draw.
+/// Programs driving several motors count a frame for each motor that is on. Like [`frame()`], this
+/// is never reset, and wraps around to `0` after [`u32::MAX`] frames.
+pub fn motor_on_frames() -> u32 {
+    unsafe { (&raw const MOTOR_ON_FRAMES).read_volatile() }
+}
+
+/// Commands queued by [`Driver::queue()`], sent verbatim as their frames arrive.
+#[derive(Clone, Copy, Debug)]
+struct Script {
+    commands: &'static [TimedCommand],
+    /// Index of the next command to send.
+    next: usize,
+    /// The frame the commands were queued on, which their frames are relative to.
+    start: u32,
+}
+
+/// A linear change in intensity, started by [`Driver::ramp_to()`].
+///
+/// The level is stepped in 8.8 fixed point, so that ramps longer than the distance they cover
+/// still move smoothly.
+#[derive(Clone, Copy, Debug)]
+struct Ramp {
+    level: Fixed,
+    /// The change in level each frame.
+    step: Fixed,
+    rising: bool,
+    target: Intensity,
+    /// Frames remaining until `target` is reached.
+    remaining: u16,
+}
+
+impl Ramp {
+    fn new(from: Intensity, target: Intensity, frames: u16) -> Self {
+        let rising = target > from;
+        let distance = from.get().abs_diff(target.get());
+        Self {
+            level: Fixed::from_int(from.get()),
+            step: Fixed::from_ratio(distance as u16, frames),
+            rising,
+            target,
+            remaining: frames,
+        }
+    }
+
+    /// Advances the ramp by one frame, returning its new level.
+    #[cfg_attr(
+        feature = "iwram-tick",
+        unsafe(link_section = ".iwram.gba_rumble.tick.driver_ramp")
+    )]
+    #[cfg_attr(
+        all(feature = "ewram-tick", not(feature = "iwram-tick")),
+        unsafe(link_section = ".ewram.gba_rumble.tick.driver_ramp")
+    )]
+    fn tick(&mut self) -> Intensity {
+        if self.remaining <= 1 {
+            // Land exactly on the target, regardless of rounding along the way.
+            self.remaining = 0;
+            self.level = Fixed::from_int(self.target.get());
+        } else {
+            self.remaining -= 1;
+            self.level = if self.rising {
+                self.level.saturating_add(self.step)
+            } else {
+                self.level.saturating_sub(self.step)
+            };
+        }
+        Intensity::new(self.level.to_int())
+    }
+
+    fn is_finished(&self) -> bool {
+        self.remaining == 0
+    }
+}
+
+/// Sends commands to a [`Backend`] from the intensity output each frame.
+///
+/// Each call to [`tick()`] counts as one frame. A [`Command::Start`] is sent when the intensity
+/// becomes nonzero, and a [`Command::Stop`] when it returns to [`Intensity::OFF`]. Commands are
+/// only sent when the motor state changes, and the most recent `L` of them are kept in a
+/// [`CommandLog`], stamped with their frame. Engine systems that mirror the motor, such as screen
+/// shake, can also be notified of each command as it is sent using [`set_subscriber()`].
+///
+/// Every intensity passes through the driver's [`Settings`] first, so disabling rumble or turning
+/// down its strength in an options menu applies to every effect. The result is then shaped into a
+/// duty cycle by a [`Calibration`] profile, which defaults to the one suited to the backend.
+/// Finally, the global [`PowerSaving`] policy, if one is set, limits the duty cycle.
+///
+/// # Determinism
+/// [`Sequencer`], [`Mixer`], and [`Driver`] depend only on the calls made to them; they never
+/// read the time, hardware state, or any other hidden input. Given the same calls on the same
+/// frames, they always send the same commands on the same frames. This allows replay systems and
+/// TAS tooling to verify that the rumble of a run matches a recording by comparing command logs.
+///
+/// ``` rust
+/// use gba_rumble::{Driver, Gpio, Intensity, Mixer, Pattern, Preemption, Step, pattern};
+///
+/// const HIT: Pattern = pattern![Step::Hold { intensity: Intensity::MAX, frames: 10 }];
+///
+/// let mut mixer = Mixer::<1>::new();
+/// let channel = mixer.add_channel(0, Preemption::Replace).unwrap();
+/// let mut driver = Driver::<_, 16>::new(Gpio);
+///
+/// mixer.play(channel, HIT);
+/// // Once a frame:
+/// driver.tick(mixer.tick());
+///
+/// for command in driver.log().iter() {
+///     // Compare against a recorded run.
+/// }
+/// ```
+///
+/// [`Mixer`]: crate::Mixer
+/// [`PowerSaving`]: crate::PowerSaving
+/// [`Sequencer`]: crate::Sequencer
+/// [`set_subscriber()`]: Driver::set_subscriber()
+/// [`tick()`]: Driver::tick()
+#[derive(Debug)]
+pub struct Driver<B, const L: usize = 0> {
+    backend: B,
+    frame: u32,
+    /// The value of the shared [`frame()`] counter after this driver's previous tick.
+    shared_frame: Option<u32>,
+    active: bool,
+    /// Consecutive frames the motor has been on, counted for the power saving policy.
+    on_frames: u16,
+    duty: DutyMeter,
+    duty_budget: Option<DutyBudget>,
+    throttled: bool,
+    settings: Settings,
+    /// Replaces the backend's calibration, if set.
+    calibration: Option<Calibration>,
+    /// The fewest frames the motor is kept on once started.
+    spin_up_frames: u8,
+    /// Consecutive frames the motor has been on, counted for spin-up compensation.
+    spun_up_frames: u8,
+    /// Frames since a command was last sent, for keeping each command long enough to be delivered.
+    command_frames: u8,
+    /// The duty carried over from previous frames when dithering.
+    dither_error: i16,
+    ramp: Option<Ramp>,
+    /// The intensity output on the previous frame, before applying settings.
+    level: Intensity,
+    script: Option<Script>,
+    self_test: Option<SelfTestRun>,
+    /// The result of the last self-test, until taken.
+    self_test_result: Option<SelfTest>,
+    log: CommandLog<L>,
+    /// Called with every command sent to the backend.
+    subscriber: Option<fn(TimedCommand)>,
+}
+
+impl<B, const L: usize> Driver<B, L>
+where
+    B: Backend,
+{
+    /// Creates a driver for `backend`, starting at frame `0` with the motor stopped.
+    ///
+    /// The driver starts with the default [`Settings`].
+    pub const fn new(backend: B) -> Self {
+        Self {
+            backend,
+            frame: 0,
+            shared_frame: None,
+            active: false,
+            on_frames: 0,
+            duty: DutyMeter::new(),
+            duty_budget: None,
+            throttled: false,
+            settings: Settings::new(),
+            calibration: None,
+            spin_up_frames: 0,
+            spun_up_frames: 0,
+            command_frames: u8::MAX,
+            dither_error: 0,
+            ramp: None,
+            level: Intensity::OFF,
+            script: None,
+            self_test: None,
+            self_test_result: None,
+            log: CommandLog::new(),
+            subscriber: None,
+        }
+    }
+
+    /// Outputs `intensity` for the current frame, then advances to the next frame.
+    ///
+    /// `intensity` is adjusted by the driver's [`Settings`], then converted to a duty cycle using
+    /// its [`Calibration`] and limited by the [`PowerSaving`] policy, if any, before being
+    /// output. If the calibration [dithers](Calibration::dither), the motor is only on for the
+    /// fraction of frames matching the duty cycle. While the [`DutyBudget`] is exceeded, the motor
+    /// is kept off. A motor that has not yet been on for [`spin_up_frames()`] is kept on, unless
+    /// rumble is disabled. Each command is kept for at least the backend's
+    /// [`Backend::min_command_frames()`]. Returns the command sent to the backend, if any.
+    ///
+    /// While a timer is set with [`set_tick_profiling()`], the cost of each call is recorded in
+    /// [`metrics()`].
+    ///
+    /// While a ramp started by [`ramp_to()`] is in progress or holding its target, the stronger of
+    /// `intensity` and the ramp's level is output. While commands queued by [`queue()`] remain,
+    /// `intensity` is ignored, and the queued commands are sent instead. The same is true while a
+    /// test started by [`self_test()`] is running.
+    ///
+    /// [`PowerSaving`]: crate::PowerSaving
+    /// [`metrics()`]: crate::metrics()
+    /// [`queue()`]: Driver::queue()
+    /// [`ramp_to()`]: Driver::ramp_to()
+    /// [`self_test()`]: Driver::self_test()
+    /// [`set_tick_profiling()`]: crate::set_tick_profiling()
+    /// [`spin_up_frames()`]: Driver::spin_up_frames()
+    #[cfg_attr(any(feature = "iwram-tick", feature = "ewram-tick"), inline(never))]
+    #[cfg_attr(
+        feature = "iwram-tick",
+        unsafe(link_section = ".iwram.gba_rumble.tick.driver")
+    )]
+    #[cfg_attr(
+        all(feature = "ewram-tick", not(feature = "iwram-tick")),
+        unsafe(link_section = ".ewram.gba_rumble.tick.driver")
+    )]
+    pub fn tick(&mut self, intensity: Intensity) -> Option<Command> {
+        if cfg!(feature = "disabled") {
+            return None;
+        }
+        let start = metrics::tick_started();
+        let command = self.tick_unmeasured(intensity);
+        metrics::tick_finished(start);
+        command
+    }
+
+    /// Performs a [`tick()`], without recording its cost.
+    ///
+    /// [`tick()`]: Driver::tick()
+    #[inline(always)]
+    fn tick_unmeasured(&mut self, intensity: Intensity) -> Option<Command> {
+        if let Some(run) = &mut self.self_test {
+            match run.tick(&self.backend) {
+                ControlFlow::Continue(command) => return self.tick_self_test(command),
+                ControlFlow::Break(result) => {
+                    self.self_test = None;
+                    self.self_test_result = Some(result);
+                }
+            }
+        }
+        if self.script.is_some() {
+            return self.tick_script();
+        }
+        let intensity = match &mut self.ramp {
+            Some(ramp) => {
+                let level = ramp.tick();
+                if ramp.is_finished() && level.is_off() {
+                    self.ramp = None;
+                }
+                intensity.max(level)
+            }
+            None => intensity,
+        };
+        self.level = intensity;
+        let calibration = self.calibration();
+        let duty = power::limit(
+            calibration.apply(self.settings.apply(intensity)),
+            &mut self.on_frames,
+        );
+        let pulsed = if calibration.dither {
+            self.dither(duty)
+        } else {
+            !duty.is_off()
+        };
+        self.throttled = self
+            .duty_budget
+            .is_some_and(|budget| self.duty.duty(budget.window_seconds) > budget.max_duty);
+        // Keep a motor that is still spinning up on, so that short pulses are felt.
+        let spinning_up = self.active && self.spun_up_frames < self.spin_up_frames;
+        let mut active = (pulsed || (spinning_up && self.settings.enabled)) && !self.throttled;
+        // Keep the previous command until the backend has had time to deliver it.
+        if active != self.active && self.command_frames < self.backend.min_command_frames() {
+            active = self.active;
+        }
+        self.spun_up_frames = if active {
+            self.spun_up_frames.saturating_add(1)
+        } else {
+            0
+        };
+        let command = (active != self.active).then_some(if active {
+            Command::Start
+        } else {
+            Command::Stop
+        });
+        if let Some(command) = command {
+            self.send(command);
+        }
+        self.active = active;
+        self.duty.record(active);
+        self.advance_frame();
+        command
+    }
+
+    /// Returns whether the motor is on this frame when dithering `duty` across frames.
+    ///
+    /// This diffuses the error of each frame into the next: the motor is turned on whenever the
+    /// duty accumulated since it was last on rounds up to a full frame.
+    fn dither(&mut self, duty: Intensity) -> bool {
+        if duty.is_off() {
+            self.dither_error = 0;
+            return false;
+        }
+        let level = self.dither_error + i16::from(duty.get());
+        let on = level >= 128;
+        self.dither_error = if on { level - 255 } else { level };
+        on
+    }
+
+    /// Sends `commands` to the backend verbatim, each on its frame, in place of the intensities
+    /// given to [`tick()`].
+    ///
+    /// This bypasses effects entirely, for tools that generate exact command streams, such as
+    /// exports from external haptic authoring software. Each command's frame is counted from the
+    /// next call to [`tick()`], which is frame `0`, and commands are expected in order of their
+    /// frames. Several commands may share a frame, in which case they are all sent on that frame,
+    /// and the last one is returned by [`tick()`]. Once every command has been sent, [`tick()`]
+    /// goes back to following its `intensity`.
+    ///
+    /// Commands are not adjusted by the driver's [`Settings`], [`Calibration`], or any power
+    /// saving policy, and the [`DutyBudget`] is not enforced. The only exception is that
+    /// [`Command::Start`] is skipped while rumble is disabled in the [`Settings`]. Queueing
+    /// commands replaces any queued before, and any ramp in progress is paused until they finish.
+    ///
+    /// ``` rust
+    /// use gba_rumble::{Command, Driver, Gpio, TimedCommand};
+    ///
+    /// // Exported from an authoring tool.
+    /// static TAPS: [TimedCommand; 4] = [
+    ///     TimedCommand { frame: 0, command: Command::Start },
+    ///     TimedCommand { frame: 3, command: Command::Stop },
+    ///     TimedCommand { frame: 6, command: Command::Start },
+    ///     TimedCommand { frame: 9, command: Command::HardStop },
+    /// ];
+    ///
+    /// let mut driver = Driver::<_>::new(Gpio);
+    /// driver.queue(&TAPS);
+    /// ```
+    ///
+    /// [`tick()`]: Driver::tick()
+    pub fn queue(&mut self, commands: &'static [TimedCommand]) {
+        self.script = (!commands.is_empty()).then_some(Script {
+            commands,
+            next: 0,
+            start: self.frame,
+        });
+    }
+
+    /// Returns the number of commands queued by [`queue()`] that have yet to be sent.
+    ///
+    /// [`queue()`]: Driver::queue()
+    pub fn queued(&self) -> usize {
+        self.script
+            .map_or(0, |script| script.commands.len() - script.next)
+    }
+
+    /// Discards every command queued by [`queue()`] that has yet to be sent.
+    ///
+    /// The motor is left as the last sent command left it, until the next [`tick()`].
+    ///
+    /// [`queue()`]: Driver::queue()
+    /// [`tick()`]: Driver::tick()
+    pub fn clear_queue(&mut self) {
+        self.script = None;
+    }
+
+    /// Sends any queued commands that are due this frame, then advances to the next frame.
+    fn tick_script(&mut self) -> Option<Command> {
+        let mut sent = None;
+        if let Some(mut script) = self.script {
+            let elapsed = self.frame.wrapping_sub(script.start);
+            while let Some(timed) = script.commands.get(script.next)
+                && timed.frame <= elapsed
+            {
+                script.next += 1;
+                if timed.command == Command::Start && !self.settings.enabled {
+                    continue;
+                }
+                self.send(timed.command);
+                self.active = timed.command == Command::Start;
+                sent = Some(timed.command);
+            }
+            self.script = (script.next < script.commands.len()).then_some(script);
+        }
+        self.level = Intensity::OFF;
+        self.spun_up_frames = 0;
+        self.duty.record(self.active);
+        self.advance_frame();
+        sent
+    }
+
+    /// Pulses the motor to check that rumble works, such as for a "test vibration" option in a
+    /// settings menu.
+    ///
+    /// Over the next [`SelfTest::PULSE_FRAMES`] calls to [`tick()`], the motor is turned on at
+    /// full strength, and over as many again it is left off, regardless of the intensities given
+    /// and of the driver's [`Settings`]. The backend is asked to confirm each half through
+    /// [`Backend::read_back()`]. The result can then be taken with [`take_self_test()`], and
+    /// [`tick()`] goes back to following its `intensity`.
+    ///
+    /// Starting a self-test discards any commands queued by [`queue()`], and restarts a self-test
+    /// already running.
+    ///
+    /// ``` rust
+    /// use gba_rumble::{Driver, Gpio, Intensity};
+    ///
+    /// let mut driver = Driver::<_>::new(Gpio);
+    /// # let test_pressed = true;
+    ///
+    /// // Once a frame:
+    /// if test_pressed {
+    ///     driver.self_test();
+    /// }
+    /// driver.tick(Intensity::OFF);
+    /// if let Some(result) = driver.take_self_test() {
+    ///     // Show `result` next to the option.
+    /// }
+    /// ```
+    ///
+    /// [`queue()`]: Driver::queue()
+    /// [`take_self_test()`]: Driver::take_self_test()
+    /// [`tick()`]: Driver::tick()
+    pub fn self_test(&mut self) {
+        self.script = None;
+        self.self_test = Some(SelfTestRun::new());
+        self.self_test_result = None;
+    }
+
+    /// Returns whether a test started by [`self_test()`] is running.
+    ///
+    /// [`self_test()`]: Driver::self_test()
+    pub const fn is_self_testing(&self) -> bool {
+        self.self_test.is_some()
+    }
+
+    /// Returns the result of the last test started by [`self_test()`], if it has finished and its
+    /// result has not already been taken.
+    ///
+    /// [`self_test()`]: Driver::self_test()
+    pub const fn take_self_test(&mut self) -> Option<SelfTest> {
+        self.self_test_result.take()
+    }
+
+    /// Sends the self-test's command for this frame, if any, then advances to the next frame.
+    fn tick_self_test(&mut self, command: Option<Command>) -> Option<Command> {
+        if let Some(command) = command {
+            self.send(command);
+            self.active = command == Command::Start;
+        }
+        self.level = Intensity::OFF;
+        self.spun_up_frames = 0;
+        self.duty.record(self.active);
+        self.advance_frame();
+        command
+    }
+
+    /// Advances this driver's frame, and the shared [`frame()`] counter if no other driver has
+    /// advanced it since this driver's previous tick. The frame is added to [`motor_on_frames()`]
+    /// if the motor was left on.
+    fn advance_frame(&mut self) {
+        if self.active {
+            unsafe {
+                (&raw mut MOTOR_ON_FRAMES).write_volatile(motor_on_frames().wrapping_add(1));
+            }
+        }
+        self.frame = self.frame.wrapping_add(1);
+        self.command_frames = self.command_frames.saturating_add(1);
+        let mut shared = frame();
+        if self.shared_frame.is_none_or(|previous| previous == shared) {
+            shared = shared.wrapping_add(1);
+            unsafe {
+                (&raw mut FRAME).write_volatile(shared);
+            }
+        }
+        self.shared_frame = Some(shared);
+    }
+
+    /// Ramps linearly from the current level to `target` over the next `frames` frames.
+    ///
+    /// The current level is the intensity output on the previous frame. Once the ramp reaches
+    /// `target`, it holds there until another ramp is started, so ramping to [`Intensity::OFF`]
+    /// ends it. A new ramp replaces any ramp already in progress, starting from wherever it had
+    /// reached, and a `frames` value of `0` jumps straight to `target`.
+    ///
+    /// Each frame, [`tick()`] outputs the stronger of its `intensity` and the ramp's level, so a
+    /// ramp can rise underneath effects played on a [`Mixer`].
+    ///
+    /// ``` rust
+    /// use gba_rumble::{Driver, Gpio, Intensity};
+    ///
+    /// let mut driver = Driver::<_>::new(Gpio);
+    ///
+    /// // Spool up over half a second, then spool back down over a second.
+    /// driver.ramp_to(Intensity::MAX, 30);
+    /// for _ in 0..30 {
+    ///     driver.tick(Intensity::OFF);
+    /// }
+    /// driver.ramp_to(Intensity::OFF, 60);
+    /// ```
+    ///
+    /// [`Mixer`]: crate::Mixer
+    /// [`tick()`]: Driver::tick()
+    pub fn ramp_to(&mut self, target: Intensity, frames: u16) {
+        self.ramp = Some(Ramp::new(self.level, target, frames));
+    }
+
+    /// Returns whether a ramp started by [`ramp_to()`] has yet to reach its target.
+    ///
+    /// [`ramp_to()`]: Driver::ramp_to()
+    pub fn is_ramping(&self) -> bool {
+        self.ramp.is_some_and(|ramp| !ramp.is_finished())
+    }
+
+    /// Returns the current frame, which is the number of times [`tick()`] has been called.
+    ///
+    /// This counts this driver's frames only. See [`frame()`] for the counter shared by every
+    /// driver.
+    ///
+    /// [`frame()`]: crate::frame()
+    /// [`tick()`]: Driver::tick()
+    pub const fn frame(&self) -> u32 {
+        self.frame
+    }
+
+    /// Returns the fraction of time the motor has been on over the last `seconds` seconds, where
+    /// [`Fixed::ONE`] means it was always on.
+    ///
+    /// Time is measured in frames, as counted by [`tick()`]. The window consists of the frames of
+    /// the current second so far, plus up to `seconds` whole seconds before it, limited to
+    /// [`DutyBudget::MAX_WINDOW_SECONDS`]. Returns [`Fixed::ZERO`] before the first frame.
+    ///
+    /// [`tick()`]: Driver::tick()
+    pub fn duty_in_last_seconds(&self, seconds: u8) -> Fixed {
+        self.duty.duty(seconds)
+    }
+
+    /// Returns the budget limiting the motor's duty, if any.
+    pub const fn duty_budget(&self) -> Option<DutyBudget> {
+        self.duty_budget
+    }
+
+    /// Replaces the budget limiting the motor's duty.
+    ///
+    /// Passing `None` removes the budget, which is the default. See [`DutyBudget`] for details.
+    pub const fn set_duty_budget(&mut self, budget: Option<DutyBudget>) {
+        self.duty_budget = budget;
+    }
+
+    /// Returns whether the motor was kept off on the last frame because the [`DutyBudget`] was
+    /// exceeded.
+    pub const fn is_throttled(&self) -> bool {
+        self.throttled
+    }
+
+    /// Returns the settings applied to each intensity.
+    pub const fn settings(&self) -> Settings {
+        self.settings
+    }
+
+    /// Replaces the settings applied to each intensity.
+    ///
+    /// The new settings take effect on the next call to [`tick()`]. If rumble is disabled while
+    /// the motor is on, it is stopped then.
+    ///
+    /// [`tick()`]: Driver::tick()
+    pub const fn set_settings(&mut self, settings: Settings) {
+        self.settings = settings;
+    }
+
+    /// Returns the response curve converting intensities into duty cycles.
+    ///
+    /// This is the curve of the driver's [`calibration()`].
+    ///
+    /// [`calibration()`]: Driver::calibration()
+    pub fn response_curve(&self) -> ResponseCurve {
+        self.calibration().curve
+    }
+
+    /// Replaces the response curve converting intensities into duty cycles.
+    ///
+    /// This is useful for tuning how effects feel on a specific motor.
+    /// The rest of the driver's [`calibration()`] is kept.
+    ///
+    /// [`calibration()`]: Driver::calibration()
+    pub fn set_response_curve(&mut self, curve: ResponseCurve) {
+        self.calibration = Some(Calibration {
+            curve,
+            ..self.calibration()
+        });
+    }
+
+    /// Returns the calibration profile tuning intensities to the backend's motor.
+    ///
+    /// This is the backend's [`Backend::calibration()`], unless overridden using
+    /// [`set_calibration()`].
+    ///
+    /// [`set_calibration()`]: Driver::set_calibration()
+    pub fn calibration(&self) -> Calibration {
+        self.calibration
+            .unwrap_or_else(|| self.backend.calibration())
+    }
+
+    /// Overrides the calibration profile, such as with one chosen by the player.
+    ///
+    /// Passing `None` returns to the backend's own profile. The profile's spin-up frames are only
+    /// acted upon once set using [`set_spin_up_frames()`].
+    ///
+    /// [`set_spin_up_frames()`]: Driver::set_spin_up_frames()
+    pub const fn set_calibration(&mut self, calibration: Option<Calibration>) {
+        self.calibration = calibration;
+    }
+
+    /// Overrides the calibration profile with the one suited to `motor`.
+    ///
+    /// [`MotorType::Unknown`] returns to the backend's own profile, as when passing `None` to
+    /// [`set_calibration()`].
+    ///
+    /// [`set_calibration()`]: Driver::set_calibration()
+    pub const fn set_motor_type(&mut self, motor: MotorType) {
+        self.calibration = motor.calibration();
+    }
+
+    /// Returns the fewest frames the motor is kept on once started.
+    ///
+    /// This is `0` unless set using [`set_spin_up_frames()`].
+    ///
+    /// [`set_spin_up_frames()`]: Driver::set_spin_up_frames()
+    pub const fn spin_up_frames(&self) -> u8 {
+        self.spin_up_frames
+    }
+
+    /// Keeps the motor on for at least `frames` frames once started.
+    ///
+    /// Motors take several frames to spin up, so pulses shorter than that are not felt. Extending
+    /// them to the motor's [`Backend::spin_up_frames()`] makes every pulse perceptible, at the
+    /// cost of short pulses lasting longer than authored. Disabling rumble through the driver's
+    /// [`Settings`], or exceeding the [`DutyBudget`], still stops the motor immediately. Passing
+    /// `0` turns compensation off, which is the default.
+    ///
+    /// ``` rust
+    /// use gba_rumble::{Backend, Driver, Gpio};
+    ///
+    /// let mut driver = Driver::<_>::new(Gpio);
+    /// driver.set_spin_up_frames(driver.backend().spin_up_frames());
+    /// ```
+    pub const fn set_spin_up_frames(&mut self, frames: u8) {
+        self.spin_up_frames = frames;
+    }
+
+    /// Replaces the settings with those loaded from `store`.
+    ///
+    /// If `store` contains no settings, or fails to load them, the current settings are kept.
+    pub fn load_settings<S>(&mut self, store: &mut S) -> Result<(), S::Error>
+    where
+        S: SettingsStore + ?Sized,
+    {
+        if let Some(settings) = store.load()? {
+            self.settings = settings;
+        }
+        Ok(())
+    }
+
+    /// Sends `command` to the backend on the current frame, logging it and notifying the
+    /// subscriber.
+    #[inline(always)]
+    fn send(&mut self, command: Command) {
+        self.backend.send(command);
+        self.command_frames = 0;
+        let timed = TimedCommand {
+            frame: self.frame,
+            command,
+        };
+        self.log.push(timed);
+        if let Some(subscriber) = self.subscriber {
+            subscriber(timed);
+        }
+    }
+
+    /// Calls `subscriber` with every command sent to the backend, stamped with its frame, or stops
+    /// calling it if `None`.
+    ///
+    /// This is a single point at which engine systems can mirror what the motor is actually doing,
+    /// such as shaking the screen or animating a controller icon while it rumbles, without
+    /// shadowing the game logic that triggers each effect. The subscriber sees the commands
+    /// returned by [`tick()`], after every setting, limit, and queued command has been applied, as
+    /// well as the command sent when restoring a saved state. Each driver has its own subscriber,
+    /// so the commands of each backend can be told apart.
+    ///
+    /// The subscriber is called from within [`tick()`], so it should return quickly.
+    ///
+    /// ``` rust
+    /// use gba_rumble::{Command, Driver, Gpio, TimedCommand};
+    ///
+    /// fn mirror(timed: TimedCommand) {
+    ///     if timed.command == Command::Start {
+    ///         // Start shaking the screen.
+    ///     }
+    /// }
+    ///
+    /// let mut driver = Driver::<_>::new(Gpio);
+    /// driver.set_subscriber(Some(mirror));
+    /// ```
+    ///
+    /// [`tick()`]: Driver::tick()
+    pub const fn set_subscriber(&mut self, subscriber: Option<fn(TimedCommand)>) {
+        self.subscriber = subscriber;
+    }
+
+    /// Returns the log of the most recently sent commands.
+    pub const fn log(&self) -> &CommandLog<L> {
+        &self.log
+    }
+
+    /// Returns the backend being driven.
+    pub const fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Returns the backend being driven, mutably.
+    pub const fn backend_mut(&mut self) -> &mut B {
+        &mut self.backend
+    }
+}
+
+/// Saves the current frame, whether the motor is on, and the driver's [`Settings`].
+///
+/// The calibration, spin-up frames, command log, and backend are not saved, and the motor's
+/// continuous on-time counted for the [`PowerSaving`] policy and spin-up compensation restarts.
+/// Any ramp in progress, self-test, and commands queued by [`Driver::queue()`] are cancelled. The
+/// motor's duty history is not saved either, since it describes the physical motor, which is not
+/// rewound along with the game. Restoring state sends the backend the command matching the
+/// restored motor state, so that the motor is in sync with the driver.
+///
+/// [`PowerSaving`]: crate::PowerSaving
+impl<B, const L: usize> SaveState for Driver<B, L>
+where
+    B: Backend,
+{
+    const STATE_LEN: usize = 7;
+
+    fn serialize_state(&self, buffer: &mut [u8]) -> Result<usize, StateError> {
+        let mut writer = StateWriter::new(buffer, Self::STATE_LEN)?;
+        writer.u32(self.frame);
+        writer.u8(self.active as u8);
+        for byte in self.settings.to_bytes() {
+            writer.u8(byte);
+        }
+        Ok(writer.finish())
+    }
+
+    fn restore_state(&mut self, buffer: &[u8]) -> Result<usize, StateError> {
+        let mut reader = StateReader::new(buffer, Self::STATE_LEN)?;
+        let frame = reader.u32()?;
+        let active = reader.bool()?;
+        let settings =
+            Settings::from_bytes([reader.u8()?, reader.u8()?]).ok_or(StateError::Invalid)?;
+
+        self.frame = frame;
+        self.active = active;
+        self.on_frames = 0;
+        self.spun_up_frames = 0;
+        self.dither_error = 0;
+        self.ramp = None;
+        self.script = None;
+        self.self_test = None;
+        self.level = Intensity::OFF;
+        self.settings = settings;
+        let command = if active {
+            Command::Start
+        } else {
+            Command::Stop
+        };
+        self.backend.send(command);
+        if let Some(subscriber) = self.subscriber {
+            subscriber(TimedCommand { frame, command });
+        }
+        Ok(reader.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommandLog, Driver, TimedCommand, frame, motor_on_frames};
+    use crate::{
+        Backend, Calibration, Capabilities, Command, DutyBudget, EffectFn, Fixed, Gpio, Intensity,
+        Marker, Mixer, MotorType, Pattern, PowerSaving, Preemption, ResponseCurve, RumbleSpy,
+        SaveState, SelfTest, Settings, SettingsStore, StateError, Step, pattern,
+    };
+    use claims::{
+        assert_err, assert_err_eq, assert_none, assert_ok, assert_ok_eq, assert_some,
+        assert_some_eq,
+    };
+    use core::cell::Cell;
+    use gba_test::test;
+
+    #[derive(Default)]
+    struct Counting {
+        sent: usize,
+    }
+
+    impl Backend for Counting {
+        fn capabilities(&self) -> Capabilities {
+            Capabilities {
+                has_intensity: false,
+                has_brake: false,
+                latency_frames: 0,
+                shared_with_sio: false,
+            }
+        }
+
+        fn send(&mut self, _command: Command) {
+            self.sent += 1;
+        }
+    }
+
+    /// Turns off every intensity up to 16.
+    const CUTOFF: ResponseCurve = {
+        let mut points = [u8::MAX; ResponseCurve::POINTS];
+        points[0] = 0;
+        points[1] = 0;
+        ResponseCurve::from_points(points)
+    };
+
+    struct Curved;
+
+    impl Backend for Curved {
+        fn capabilities(&self) -> Capabilities {
+            Capabilities {
+                has_intensity: true,
+                has_brake: false,
+                latency_frames: 0,
+                shared_with_sio: false,
+            }
+        }
+
+        fn response_curve(&self) -> ResponseCurve {
+            CUTOFF
+        }
+
+        fn send(&mut self, _command: Command) {}
+    }
+
+    /// A backend whose motor reports the state last sent, unless stuck off.
+    #[derive(Default)]
+    struct Observed {
+        on: bool,
+        stuck: bool,
+    }
+
+    impl Backend for Observed {
+        fn capabilities(&self) -> Capabilities {
+            Counting::default().capabilities()
+        }
+
+        fn send(&mut self, command: Command) {
+            self.on = command == Command::Start && !self.stuck;
+        }
+
+        fn read_back(&self) -> Option<bool> {
+            Some(self.on)
+        }
+    }
+
+    /// A backend that takes three frames to deliver each command.
+    struct Throttled;
+
+    impl Backend for Throttled {
+        fn capabilities(&self) -> Capabilities {
+            Counting::default().capabilities()
+        }
+
+        fn min_command_frames(&self) -> u8 {
+            3
+        }
+
+        fn send(&mut self, _command: Command) {}
+    }
+
+    struct Store(Result<Option<Settings>, ()>);
+
+    impl SettingsStore for Store {
+        type Error = ();
+
+        fn load(&mut self) -> Result<Option<Settings>, Self::Error> {
+            self.0
+        }
+
+        fn save(&mut self, settings: Settings) -> Result<(), Self::Error> {
+            self.0 = Ok(Some(settings));
+            Ok(())
+        }
+    }
+
+    const DISABLED: Settings = Settings {
+        enabled: false,
+        intensity_scale: Intensity::MAX,
+    };
+
+    const RELEASE: Marker = Marker::new(0);
+    const PULSE: Pattern = pattern![
+        Step::Hold {
+            intensity: Intensity::MAX,
+            frames: 2
+        },
+        Step::Hold {
+            intensity: Intensity::OFF,
+            frames: 1
+        },
+        Step::Loop { step: 0, count: 2 },
+        Step::Marker(RELEASE),
+        Step::Hold {
+            intensity: Intensity::new(1),
+            frames: 3
+        },
+    ];
+
+    #[derive(Clone, Copy)]
+    enum Input {
+        Release,
+        Hit,
+        Heal,
+    }
+
+    /// Plays a scenario driven by frame-indexed inputs, returning the resulting command log.
+    fn run(inputs: &[(u32, Input)]) -> CommandLog<32> {
+        let damage = Cell::new(0);
+        let mut effects = [EffectFn::new(|_| Intensity::new(damage.get())); 8];
+        let mut effects = effects.iter_mut();
+        let mut mixer = Mixer::<2>::new();
+        let pattern = assert_some!(mixer.add_channel(0, Preemption::Queue));
+        let effect = assert_some!(mixer.add_channel(1, Preemption::Replace));
+        let mut driver = Driver::<_, 32>::new(Counting::default());
+
+        mixer.play(pattern, PULSE);
+        for frame in 0..40 {
+            for &(_, input) in inputs.iter().filter(|(at, _)| *at == frame) {
+                match input {
+                    Input::Release => {
+                        mixer.advance(pattern, RELEASE);
+                    }
+                    Input::Hit => {
+                        damage.set(damage.get() + 100);
+                        mixer.play_effect(effect, assert_some!(effects.next()));
+                    }
+                    Input::Heal => mixer.stop(effect),
+                }
+            }
+            driver.tick(mixer.tick());
+        }
+        driver.log().clone()
+    }
+
+    #[test]
+    fn tick_sends_on_change() {
+        let mut driver = Driver::<_, 4>::new(Counting::default());
+
+        assert_some_eq!(driver.tick(Intensity::MAX), Command::Start);
+        assert_none!(driver.tick(Intensity::new(1)));
+        assert_some_eq!(driver.tick(Intensity::OFF), Command::Stop);
+        assert_none!(driver.tick(Intensity::OFF));
+
+        assert_eq!(driver.frame(), 4);
+        assert_eq!(driver.backend().sent, 2);
+    }
+
+    #[test]
+    fn log_frames() {
+        let mut driver = Driver::<_, 4>::new(Counting::default());
+
+        driver.tick(Intensity::OFF);
+        driver.tick(Intensity::MAX);
+        driver.tick(Intensity::MAX);
+        driver.tick(Intensity::OFF);
+
+        assert!(driver.log().iter().eq([
+            TimedCommand {
+                frame: 1,
+                command: Command::Start
+            },
+            TimedCommand {
+                frame: 3,
+                command: Command::Stop
+            },
+        ]));
+    }
+
+    #[test]
+    fn log_keeps_most_recent() {
+        let mut driver = Driver::<_, 2>::new(Counting::default());
+
+        for _ in 0..3 {
+            driver.tick(Intensity::MAX);
+            driver.tick(Intensity::OFF);
+        }
+
+        assert!(driver.log().iter().eq([
+            TimedCommand {
+                frame: 4,
+                command: Command::Start
+            },
+            TimedCommand {
+                frame: 5,
+                command: Command::Stop
+            },
+        ]));
+    }
+
+    #[test]
+    fn log_disabled() {
+        let mut driver = Driver::<_>::new(Counting::default());
+
+        driver.tick(Intensity::MAX);
+
+        assert!(driver.log().is_empty());
+    }
+
+    #[test]
+    fn log_clear() {
+        let mut driver = Driver::<_, 2>::new(Counting::default());
+        driver.tick(Intensity::MAX);
+
+        let mut log = driver.log().clone();
+        log.clear();
+
+        assert!(log.is_empty());
+        assert_eq!(log, CommandLog::<0>::new());
+    }
+
+    #[test]
+    fn queue() {
+        static COMMANDS: [TimedCommand; 3] = [
+            TimedCommand {
+                frame: 1,
+                command: Command::Start,
+            },
+            TimedCommand {
+                frame: 3,
+                command: Command::Stop,
+            },
+            TimedCommand {
+                frame: 3,
+                command: Command::HardStop,
+            },
+        ];
+        let mut driver = Driver::<_, 4>::new(Counting::default());
+        driver.tick(Intensity::OFF);
+
+        driver.queue(&COMMANDS);
+        assert_eq!(driver.queued(), 3);
+
+        // Intensities are ignored while commands are queued.
+        assert_none!(driver.tick(Intensity::MAX));
+        assert_some_eq!(driver.tick(Intensity::OFF), Command::Start);
+        assert_none!(driver.tick(Intensity::OFF));
+        assert_some_eq!(driver.tick(Intensity::MAX), Command::HardStop);
+        assert_eq!(driver.queued(), 0);
+        assert_eq!(driver.backend().sent, 3);
+        assert!(driver.log().iter().eq([
+            TimedCommand {
+                frame: 2,
+                command: Command::Start
+            },
+            TimedCommand {
+                frame: 4,
+                command: Command::Stop
+            },
+            TimedCommand {
+                frame: 4,
+                command: Command::HardStop
+            },
+        ]));
+
+        // Once finished, intensities are followed again.
+        assert_some_eq!(driver.tick(Intensity::MAX), Command::Start);
+    }
+
+    #[test]
+    fn queue_bypasses_settings() {
+        static COMMANDS: [TimedCommand; 1] = [TimedCommand {
+            frame: 0,
+            command: Command::Start,
+        }];
+        let mut driver = Driver::<_, 0>::new(Counting::default());
+        driver.set_settings(Settings {
+            enabled: true,
+            intensity_scale: Intensity::OFF,
+        });
+
+        driver.queue(&COMMANDS);
+
+        assert_some_eq!(driver.tick(Intensity::OFF), Command::Start);
+    }
+
+    #[test]
+    fn queue_start_skipped_while_disabled() {
+        static COMMANDS: [TimedCommand; 2] = [
+            TimedCommand {
+                frame: 0,
+                command: Command::Start,
+            },
+            TimedCommand {
+                frame: 1,
+                command: Command::Stop,
+            },
+        ];
+        let mut driver = Driver::<_, 0>::new(Counting::default());
+        driver.set_settings(DISABLED);
+
+        driver.queue(&COMMANDS);
+
+        assert_none!(driver.tick(Intensity::OFF));
+        assert_some_eq!(driver.tick(Intensity::OFF), Command::Stop);
+        assert_eq!(driver.backend().sent, 1);
+    }
+
+    #[test]
+    fn clear_queue() {
+        static COMMANDS: [TimedCommand; 1] = [TimedCommand {
+            frame: 1,
+            command: Command::Start,
+        }];
+        let mut driver = Driver::<_, 0>::new(Counting::default());
+        driver.queue(&COMMANDS);
+
+        driver.clear_queue();
+
+        assert_eq!(driver.queued(), 0);
+        assert_none!(driver.tick(Intensity::OFF));
+        assert_none!(driver.tick(Intensity::OFF));
+    }
+
+    #[test]
+    fn shared_frame_advances_once_per_frame() {
+        let mut first = Driver::<_>::new(Counting::default());
+        let mut second = Driver::<_>::new(Counting::default());
+        first.tick(Intensity::OFF);
+        second.tick(Intensity::OFF);
+        let start = frame();
+
+        for _ in 0..3 {
+            first.tick(Intensity::OFF);
+            second.tick(Intensity::OFF);
+        }
+
+        assert_eq!(frame(), start.wrapping_add(3));
+    }
+
+    #[test]
+    fn shared_frame_continues_after_driver_stops() {
+        let mut first = Driver::<_>::new(Counting::default());
+        let mut second = Driver::<_>::new(Counting::default());
+        first.tick(Intensity::OFF);
+        second.tick(Intensity::OFF);
+        let start = frame();
+
+        second.tick(Intensity::OFF);
+        second.tick(Intensity::OFF);
+
+        assert_eq!(frame(), start.wrapping_add(2));
+        assert_eq!(second.frame(), 3);
+    }
+
+    #[test]
+    fn motor_on_frames_counted() {
+        let mut driver = Driver::<_>::new(Counting::default());
+        let start = motor_on_frames();
+
+        driver.tick(Intensity::MAX);
+        driver.tick(Intensity::MAX);
+        driver.tick(Intensity::OFF);
+
+        assert_eq!(motor_on_frames(), start.wrapping_add(2));
+    }
+
+    #[test]
+    fn self_test_confirmed() {
+        let mut driver = Driver::<_>::new(Observed::default());
+        driver.set_settings(DISABLED);
+
+        driver.self_test();
+        assert!(driver.is_self_testing());
+        assert_some_eq!(driver.tick(Intensity::OFF), Command::Start);
+        for _ in 1..SelfTest::PULSE_FRAMES {
+            assert_none!(driver.tick(Intensity::OFF));
+        }
+        assert_some_eq!(driver.tick(Intensity::MAX), Command::Stop);
+        for _ in 1..SelfTest::PULSE_FRAMES {
+            assert_none!(driver.tick(Intensity::MAX));
+        }
+        assert_none!(driver.take_self_test());
+
+        assert_none!(driver.tick(Intensity::MAX));
+        assert!(!driver.is_self_testing());
+        let result = assert_some!(driver.take_self_test());
+        assert!(result.is_confirmed());
+        assert!(result.passed());
+        assert_none!(driver.take_self_test());
+    }
+
+    #[test]
+    fn self_test_stuck() {
+        let mut driver = Driver::<_>::new(Observed {
+            on: false,
+            stuck: true,
+        });
+
+        driver.self_test();
+        for _ in 0..=SelfTest::PULSE_FRAMES * 2 {
+            driver.tick(Intensity::OFF);
+        }
+
+        let result = assert_some!(driver.take_self_test());
+        assert_some_eq!(result.started, false);
+        assert!(!result.passed());
+    }
+
+    #[test]
+    fn self_test_unobservable() {
+        let mut driver = Driver::<_>::new(Counting::default());
+
+        driver.self_test();
+        for _ in 0..=SelfTest::PULSE_FRAMES * 2 {
+            driver.tick(Intensity::OFF);
+        }
+
+        let result = assert_some!(driver.take_self_test());
+        assert_none!(result.started);
+        assert!(result.passed());
+        assert_eq!(driver.backend().sent, 2);
+    }
+
+    #[test]
+    fn settings_disabled() {
+        let mut driver = Driver::<_>::new(Counting::default());
+        driver.set_settings(DISABLED);
+
+        assert_none!(driver.tick(Intensity::MAX));
+        assert_eq!(driver.backend().sent, 0);
+    }
+
+    #[test]
+    fn settings_disabled_while_on() {
+        let mut driver = Driver::<_>::new(Counting::default());
+
+        assert_some_eq!(driver.tick(Intensity::MAX), Command::Start);
+        driver.set_settings(DISABLED);
+        assert_some_eq!(driver.tick(Intensity::MAX), Command::Stop);
+    }
+
+    #[test]
+    fn settings_scale_to_off() {
+        let mut driver = Driver::<_>::new(Counting::default());
+        driver.set_settings(Settings {
+            enabled: true,
+            intensity_scale: Intensity::OFF,
+        });
+
+        assert_none!(driver.tick(Intensity::MAX));
+    }
+
+    #[test]
+    fn response_curve_default() {
+        let driver = Driver::<_>::new(Curved);
+
+        assert_eq!(driver.response_curve(), CUTOFF);
+    }
+
+    #[test]
+    fn response_curve_applied() {
+        let mut driver = Driver::<_>::new(Curved);
+
+        assert_none!(driver.tick(Intensity::new(16)));
+        assert_some_eq!(driver.tick(Intensity::new(17)), Command::Start);
+    }
+
+    #[test]
+    fn set_response_curve() {
+        let mut driver = Driver::<_>::new(Counting::default());
+        driver.set_response_curve(CUTOFF);
+
+        assert_eq!(driver.response_curve(), CUTOFF);
+        assert_none!(driver.tick(Intensity::new(1)));
+    }
+
+    #[test]
+    fn calibration_default() {
+        let driver = Driver::<_>::new(Curved);
+
+        assert_eq!(
+            driver.calibration(),
+            Calibration {
+                curve: CUTOFF,
+                ..Calibration::UNCALIBRATED
+            }
+        );
+    }
+
+    #[test]
+    fn calibration_from_backend() {
+        let driver = Driver::<_>::new(Gpio);
+
+        assert_eq!(driver.calibration(), Calibration::CARTRIDGE);
+    }
+
+    #[test]
+    fn set_calibration() {
+        let mut driver = Driver::<_>::new(Gpio);
+        let calibration = Calibration {
+            curve: CUTOFF,
+            ..Calibration::GAMECUBE_CONTROLLER
+        };
+
+        driver.set_calibration(Some(calibration));
+        assert_eq!(driver.calibration(), calibration);
+        assert_none!(driver.tick(Intensity::new(16)));
+
+        driver.set_calibration(None);
+        assert_eq!(driver.calibration(), Calibration::CARTRIDGE);
+        assert_some_eq!(driver.tick(Intensity::new(16)), Command::Start);
+    }
+
+    #[test]
+    fn set_motor_type() {
+        let mut driver = Driver::<_>::new(Gpio);
+
+        driver.set_motor_type(MotorType::GamecubeController);
+        assert_eq!(driver.calibration(), Calibration::GAMECUBE_CONTROLLER);
+
+        driver.set_motor_type(MotorType::Unknown);
+        assert_eq!(driver.calibration(), Calibration::CARTRIDGE);
+    }
+
+    const DITHERED: Calibration = Calibration {
+        dither: true,
+        ..Calibration::UNCALIBRATED
+    };
+
+    #[test]
+    fn dither_quarter_duty() {
+        let mut driver = Driver::<_>::new(Counting::default());
+        driver.set_calibration(Some(DITHERED));
+
+        let commands: [_; 8] = core::array::from_fn(|_| driver.tick(Intensity::new(64)));
+
+        assert_eq!(
+            commands,
+            [
+                None,
+                Some(Command::Start),
+                Some(Command::Stop),
+                None,
+                None,
+                Some(Command::Start),
+                Some(Command::Stop),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn dither_full_duty() {
+        let mut driver = Driver::<_>::new(Counting::default());
+        driver.set_calibration(Some(DITHERED));
+
+        assert_some_eq!(driver.tick(Intensity::MAX), Command::Start);
+        for _ in 0..8 {
+            assert_none!(driver.tick(Intensity::MAX));
+        }
+    }
+
+    #[test]
+    fn dither_restarts_after_off() {
+        let mut driver = Driver::<_>::new(Counting::default());
+        driver.set_calibration(Some(DITHERED));
+
+        assert_none!(driver.tick(Intensity::new(64)));
+        assert_none!(driver.tick(Intensity::OFF));
+        assert_none!(driver.tick(Intensity::new(64)));
+        assert_some_eq!(driver.tick(Intensity::new(64)), Command::Start);
+    }
+
+    #[test]
+    fn set_response_curve_keeps_calibration() {
+        let mut driver = Driver::<_>::new(Gpio);
+
+        driver.set_response_curve(CUTOFF);
+
+        assert_eq!(
+            driver.calibration(),
+            Calibration {
+                curve: CUTOFF,
+                ..Calibration::CARTRIDGE
+            }
+        );
+    }
+
+    #[test]
+    fn ramp_up() {
+        let mut driver = Driver::<_>::new(Counting::default());
+
+        driver.ramp_to(Intensity::new(100), 4);
+
+        assert!(driver.is_ramping());
+        let levels: [u8; 5] = core::array::from_fn(|_| {
+            driver.tick(Intensity::OFF);
+            driver.level.get()
+        });
+        assert_eq!(levels, [25, 50, 75, 100, 100]);
+        assert!(!driver.is_ramping());
+    }
+
+    #[test]
+    fn ramp_down_ends() {
+        let mut driver = Driver::<_>::new(Counting::default());
+        driver.ramp_to(Intensity::MAX, 0);
+        assert_some_eq!(driver.tick(Intensity::OFF), Command::Start);
+
+        driver.ramp_to(Intensity::OFF, 3);
+
+        assert_none!(driver.tick(Intensity::OFF));
+        assert_eq!(driver.level, Intensity::new(170));
+        assert_none!(driver.tick(Intensity::OFF));
+        assert_eq!(driver.level, Intensity::new(85));
+        assert_some_eq!(driver.tick(Intensity::OFF), Command::Stop);
+        assert_none!(driver.ramp);
+    }
+
+    #[test]
+    fn ramp_uneven_steps() {
+        let mut driver = Driver::<_>::new(Counting::default());
+
+        driver.ramp_to(Intensity::new(3), 8);
+
+        let levels: [u8; 8] = core::array::from_fn(|_| {
+            driver.tick(Intensity::OFF);
+            driver.level.get()
+        });
+        assert_eq!(levels, [0, 0, 1, 1, 1, 2, 2, 3]);
+    }
+
+    #[test]
+    fn ramp_from_current_level() {
+        let mut driver = Driver::<_>::new(Counting::default());
+        driver.tick(Intensity::new(200));
+
+        driver.ramp_to(Intensity::new(100), 2);
+        driver.tick(Intensity::OFF);
+
+        assert_eq!(driver.level, Intensity::new(150));
+    }
+
+    #[test]
+    fn ramp_under_intensity() {
+        let mut driver = Driver::<_>::new(Counting::default());
+        driver.ramp_to(Intensity::new(50), 0);
+
+        driver.tick(Intensity::new(80));
+        assert_eq!(driver.level, Intensity::new(80));
+        driver.tick(Intensity::new(20));
+        assert_eq!(driver.level, Intensity::new(50));
+    }
+
+    #[test]
+    fn spin_up_default() {
+        let mut driver = Driver::<_>::new(Counting::default());
+
+        assert_eq!(driver.spin_up_frames(), 0);
+        assert_some_eq!(driver.tick(Intensity::MAX), Command::Start);
+        assert_some_eq!(driver.tick(Intensity::OFF), Command::Stop);
+    }
+
+    #[test]
+    fn min_command_frames() {
+        let mut driver = Driver::<_>::new(Throttled);
+
+        assert_some_eq!(driver.tick(Intensity::MAX), Command::Start);
+        assert_none!(driver.tick(Intensity::OFF));
+        assert_none!(driver.tick(Intensity::OFF));
+        assert_some_eq!(driver.tick(Intensity::OFF), Command::Stop);
+        assert_none!(driver.tick(Intensity::MAX));
+        assert_none!(driver.tick(Intensity::MAX));
+        assert_some_eq!(driver.tick(Intensity::MAX), Command::Start);
+    }
+
+    #[test]
+    fn spin_up_extends_short_pulse() {
+        let mut driver = Driver::<_>::new(Counting::default());
+        driver.set_spin_up_frames(3);
+
+        assert_some_eq!(driver.tick(Intensity::MAX), Command::Start);
+        assert_none!(driver.tick(Intensity::OFF));
+        assert_none!(driver.tick(Intensity::OFF));
+        assert_some_eq!(driver.tick(Intensity::OFF), Command::Stop);
+        assert_eq!(driver.spin_up_frames(), 3);
+    }
+
+    #[test]
+    fn spin_up_long_pulse_unchanged() {
+        let mut driver = Driver::<_>::new(Counting::default());
+        driver.set_spin_up_frames(2);
+
+        assert_some_eq!(driver.tick(Intensity::MAX), Command::Start);
+        assert_none!(driver.tick(Intensity::MAX));
+        assert_none!(driver.tick(Intensity::MAX));
+        assert_some_eq!(driver.tick(Intensity::OFF), Command::Stop);
+    }
+
+    #[test]
+    fn spin_up_restarts_with_each_pulse() {
+        let mut driver = Driver::<_>::new(Counting::default());
+        driver.set_spin_up_frames(2);
+
+        driver.tick(Intensity::MAX);
+        driver.tick(Intensity::OFF);
+        assert_some_eq!(driver.tick(Intensity::OFF), Command::Stop);
+
+        assert_some_eq!(driver.tick(Intensity::MAX), Command::Start);
+        assert_none!(driver.tick(Intensity::OFF));
+        assert_some_eq!(driver.tick(Intensity::OFF), Command::Stop);
+    }
+
+    #[test]
+    fn spin_up_disabled_stops_immediately() {
+        let mut driver = Driver::<_>::new(Counting::default());
+        driver.set_spin_up_frames(3);
+
+        driver.tick(Intensity::MAX);
+        driver.set_settings(DISABLED);
+
+        assert_some_eq!(driver.tick(Intensity::MAX), Command::Stop);
+    }
+
+    #[test]
+    fn backend_spin_up_frames() {
+        assert_eq!(Counting::default().spin_up_frames(), 0);
+        assert_eq!(Gpio.spin_up_frames(), 3);
+    }
+
+    #[test]
+    fn load_settings() {
+        let mut driver = Driver::<_>::new(Counting::default());
+        let mut store = Store(Ok(None));
+        assert_ok!(store.save(DISABLED));
+
+        assert_ok!(driver.load_settings(&mut store));
+
+        assert_eq!(driver.settings(), DISABLED);
+    }
+
+    #[test]
+    fn load_settings_empty() {
+        let mut driver = Driver::<_>::new(Counting::default());
+
+        assert_ok!(driver.load_settings(&mut Store(Ok(None))));
+
+        assert_eq!(driver.settings(), Settings::new());
+    }
+
+    #[test]
+    fn load_settings_error() {
+        let mut driver = Driver::<_>::new(Counting::default());
+
+        assert_err!(driver.load_settings(&mut Store(Err(()))));
+
+        assert_eq!(driver.settings(), Settings::new());
+    }
+
+    #[test]
+    fn deterministic() {
+        let inputs = [
+            (3, Input::Hit),
+            (8, Input::Release),
+            (12, Input::Heal),
+            (20, Input::Hit),
+            (25, Input::Release),
+        ];
+
+        let first = run(&inputs);
+        let second = run(&inputs);
+
+        assert!(!first.is_empty());
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn different_inputs_differ() {
+        assert_ne!(run(&[(12, Input::Release)]), run(&[(13, Input::Release)]));
+    }
+
+    #[test]
+    fn power_saving() {
+        let mut driver = Driver::<_>::new(Counting::default());
+        crate::set_power_saving(Some(PowerSaving {
+            max_duty: Intensity::MAX,
+            max_frames: 2,
+        }));
+
+        assert_some_eq!(driver.tick(Intensity::MAX), Command::Start);
+        assert_none!(driver.tick(Intensity::MAX));
+        assert_some_eq!(driver.tick(Intensity::MAX), Command::Stop);
+        assert_none!(driver.tick(Intensity::MAX));
+        assert_none!(driver.tick(Intensity::OFF));
+        assert_some_eq!(driver.tick(Intensity::MAX), Command::Start);
+
+        crate::set_power_saving(None);
+    }
+
+    #[test]
+    fn duty_in_last_seconds() {
+        let mut driver = Driver::<_>::new(Counting::default());
+
+        assert_eq!(driver.duty_in_last_seconds(1), Fixed::ZERO);
+        for _ in 0..60 {
+            driver.tick(Intensity::MAX);
+        }
+        for _ in 0..60 {
+            driver.tick(Intensity::OFF);
+        }
+
+        assert_eq!(driver.duty_in_last_seconds(1), Fixed::ZERO);
+        assert_eq!(driver.duty_in_last_seconds(2), Fixed::HALF);
+    }
+
+    #[test]
+    fn duty_budget() {
+        let mut driver = Driver::<_>::new(Counting::default());
+        let budget = DutyBudget {
+            max_duty: Fixed::HALF,
+            window_seconds: 1,
+        };
+        driver.set_duty_budget(Some(budget));
+
+        assert_some_eq!(driver.duty_budget(), budget);
+        assert_some_eq!(driver.tick(Intensity::MAX), Command::Start);
+        assert!(!driver.is_throttled());
+        assert_some_eq!(driver.tick(Intensity::MAX), Command::Stop);
+        assert!(driver.is_throttled());
+        // Once the duty falls back within budget, the motor is allowed on again.
+        assert_some_eq!(driver.tick(Intensity::MAX), Command::Start);
+        assert_eq!(driver.duty_in_last_seconds(1), Fixed::from_ratio(2, 3));
+
+        driver.set_duty_budget(None);
+        assert_none!(driver.tick(Intensity::MAX));
+        assert!(!driver.is_throttled());
+    }
+
+    #[test]
+    fn subscriber() {
+        static mut SEEN: [Option<TimedCommand>; 2] = [None; 2];
+        fn record(timed: TimedCommand) {
+            unsafe {
+                SEEN = [SEEN[1], Some(timed)];
+            }
+        }
+        let mut driver = Driver::<_>::new(RumbleSpy::<4>::new());
+        driver.set_subscriber(Some(record));
+
+        driver.tick(Intensity::MAX);
+        driver.tick(Intensity::MAX);
+        driver.tick(Intensity::OFF);
+        driver.set_subscriber(None);
+        driver.tick(Intensity::MAX);
+
+        assert_eq!(
+            unsafe { SEEN },
+            [
+                Some(TimedCommand {
+                    frame: 0,
+                    command: Command::Start
+                }),
+                Some(TimedCommand {
+                    frame: 2,
+                    command: Command::Stop
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn save_state_round_trip() {
+        let mut driver = Driver::<_>::new(RumbleSpy::<4>::new());
+        driver.set_settings(Settings {
+            enabled: true,
+            intensity_scale: Intensity::new(128),
+        });
+        driver.tick(Intensity::OFF);
+        driver.tick(Intensity::MAX);
+        let mut buffer = [0; Driver::<RumbleSpy>::STATE_LEN];
+
+        assert_ok_eq!(driver.serialize_state(&mut buffer), 7);
+
+        let mut restored = Driver::<_>::new(RumbleSpy::<4>::new());
+
+        assert_ok_eq!(restored.restore_state(&buffer), 7);
+        assert_eq!(restored.frame(), 2);
+        assert_eq!(restored.settings(), driver.settings());
+        // The backend is brought in sync with the restored motor state.
+        assert!(restored.backend().is_on());
+        assert_none!(restored.tick(Intensity::MAX));
+        assert_some_eq!(restored.tick(Intensity::OFF), Command::Stop);
+    }
+
+    #[test]
+    fn restore_state_invalid() {
+        let mut driver = Driver::<_>::new(Counting::default());
+
+        assert_err_eq!(
+            driver.restore_state(&[0, 0, 0, 0, 2, 1, 255]),
+            StateError::Invalid
+        );
+        assert_err_eq!(
+            driver.restore_state(&[0, 0, 0, 0, 0, 2, 255]),
+            StateError::Invalid
+        );
+        assert_err_eq!(driver.restore_state(&[0; 6]), StateError::BufferTooSmall);
+        assert_eq!(driver.backend().sent, 0);
+    }
+}
@@ -0,0 +1,240 @@
+//! Rumble through the DS Rumble Pak.
+
+use crate::{Backend, Calibration, Capabilities, Command};
+use core::ptr;
+
+/// Any write to the cartridge bus drives the Rumble Pak, but this is the conventional address.
+const RUMBLE: *mut u16 = ptr::with_exposed_provenance_mut(0x0800_0000);
+const BUS: *const u16 = ptr::with_exposed_provenance(0x0800_0000);
+/// The fixed value `0x96` found in the header of every GBA cartridge.
+const HEADER_FIXED_VALUE: *const u8 = ptr::with_exposed_provenance(0x0800_00B2);
+
+/// The number of halfwords checked for the Rumble Pak's bus signature during detection.
+const DETECTION_LENGTH: usize = 0x1000;
+
+/// The capabilities of the DS Rumble Pak, whether or not one is inserted.
+pub(crate) const CAPABILITIES: Capabilities = Capabilities {
+    has_intensity: false,
+    has_brake: false,
+    // The motor only moves when pulsed by `update()`.
+    latency_frames: 1,
+    shared_with_sio: false,
+};
+
+static mut DS_RUMBLE_PAK_ACTIVE: bool = false;
+static mut DS_RUMBLE_PAK_POSITION: bool = false;
+
+/// DS Rumble Pak rumble functionality.
+///
+/// The DS Rumble Pak (and compatible cartridges) sits in the GBA cartridge slot. Its motor moves
+/// each time bit 1 of a write to the cartridge bus changes, so rumble is produced by pulsing that
+/// bit every frame through [`update()`].
+///
+/// Since the Rumble Pak occupies the cartridge slot, it can only be used by programs that are not
+/// running from a cartridge, such as multiboot programs.
+///
+/// # Setup
+/// The Rumble Pak must be detected using [`detect()`] before it can be used.
+///
+/// # Usage
+/// Once a frame, [`update()`] must be called to pulse the motor. Rumble is controlled through the
+/// [`start()`] and [`stop()`] methods.
+///
+/// ``` rust
+/// if let Some(ds_rumble_pak) = gba_rumble::DsRumblePak::detect() {
+///     // Activate rumble. This will continue until `stop()` is called.
+///     ds_rumble_pak.start();
+///
+///     // Pulse the motor once a frame.
+///     ds_rumble_pak.update();
+///
+///     // Deactivate rumble.
+///     ds_rumble_pak.stop();
+/// }
+/// ```
+///
+/// [`detect()`]: DsRumblePak::detect()
+/// [`start()`]: DsRumblePak::start()
+/// [`stop()`]: DsRumblePak::stop()
+/// [`update()`]: DsRumblePak::update()
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DsRumblePak {
+    private: (),
+}
+
+impl DsRumblePak {
+    /// Detect whether a DS Rumble Pak is inserted in the cartridge slot.
+    ///
+    /// With nothing inserted, reads from the cartridge bus return the halfword index of the
+    /// address being read. The Rumble Pak pulls data line 1 low, so the same reads return the
+    /// index with bit 1 cleared.
+    pub fn detect() -> Option<Self> {
+        if cfg!(feature = "disabled") {
+            return None;
+        }
+        // A GBA cartridge is inserted, so the slot cannot contain a Rumble Pak.
+        if unsafe { HEADER_FIXED_VALUE.read_volatile() } == 0x96 {
+            return None;
+        }
+        for index in 0..DETECTION_LENGTH {
+            if unsafe { BUS.add(index).read_volatile() } != index as u16 & 0xFFFD {
+                return None;
+            }
+        }
+        Some(Self { private: () })
+    }
+
+    /// Activate rumble.
+    ///
+    /// This does nothing while rumble is disabled by [`set_enabled()`].
+    ///
+    /// [`set_enabled()`]: crate::set_enabled()
+    pub fn start(&self) {
+        if !crate::is_enabled() {
+            return;
+        }
+        unsafe {
+            DS_RUMBLE_PAK_ACTIVE = true;
+        }
+    }
+
+    /// Deactivate rumble.
+    pub fn stop(&self) {
+        unsafe {
+            DS_RUMBLE_PAK_ACTIVE = false;
+        }
+    }
+
+    /// Pulse the motor if rumble is active.
+    ///
+    /// This should be called once a frame.
+    pub fn update(&self) {
+        unsafe {
+            if DS_RUMBLE_PAK_ACTIVE {
+                DS_RUMBLE_PAK_POSITION = !DS_RUMBLE_PAK_POSITION;
+                RUMBLE.write_volatile(if DS_RUMBLE_PAK_POSITION { 2 } else { 0 });
+            }
+        }
+    }
+}
+
+/// Deactivate rumble, without requiring a detected [`DsRumblePak`].
+pub(crate) fn force_stop() {
+    unsafe {
+        DS_RUMBLE_PAK_ACTIVE = false;
+    }
+}
+
+/// Deactivate rumble and forget the motor's position, as if the program had just started.
+pub(crate) fn reset() {
+    unsafe {
+        DS_RUMBLE_PAK_ACTIVE = false;
+        DS_RUMBLE_PAK_POSITION = false;
+    }
+}
+
+impl Backend for DsRumblePak {
+    fn capabilities(&self) -> Capabilities {
+        CAPABILITIES
+    }
+
+    fn calibration(&self) -> Calibration {
+        Calibration::DS_RUMBLE_PAK
+    }
+
+    fn send(&mut self, command: Command) {
+        match command {
+            Command::Start => self.start(),
+            Command::Stop | Command::HardStop => self.stop(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DS_RUMBLE_PAK_ACTIVE, DsRumblePak};
+    use crate::{Backend, Capabilities, Command};
+    use claims::assert_none;
+    use gba_test::test;
+
+    #[test]
+    #[cfg_attr(
+        multiboot,
+        ignore = "This test should be run from a cartridge. Omit `--cfg multiboot` to enable."
+    )]
+    fn detect_cartridge_inserted() {
+        // Tests are run from a cartridge, which occupies the slot.
+        assert_none!(DsRumblePak::detect());
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(multiboot),
+        ignore = "This test should be run as a multiboot program with nothing in the cartridge slot. Pass `--cfg multiboot` to enable."
+    )]
+    fn detect_empty_slot() {
+        // With nothing inserted, the bus signature of the Rumble Pak is not present.
+        assert_none!(DsRumblePak::detect());
+    }
+
+    #[test]
+    fn start() {
+        DsRumblePak { private: () }.start();
+
+        assert!(unsafe { DS_RUMBLE_PAK_ACTIVE });
+    }
+
+    #[test]
+    fn stop() {
+        DsRumblePak { private: () }.stop();
+
+        assert!(!unsafe { DS_RUMBLE_PAK_ACTIVE });
+    }
+
+    #[test]
+    fn start_disabled() {
+        let ds_rumble_pak = DsRumblePak { private: () };
+        ds_rumble_pak.stop();
+        crate::set_enabled(false);
+
+        ds_rumble_pak.start();
+        let active = unsafe { DS_RUMBLE_PAK_ACTIVE };
+        crate::set_enabled(true);
+
+        assert!(!active);
+    }
+
+    #[test]
+    fn disable_stops() {
+        DsRumblePak { private: () }.start();
+
+        crate::set_enabled(false);
+        let active = unsafe { DS_RUMBLE_PAK_ACTIVE };
+        crate::set_enabled(true);
+
+        assert!(!active);
+    }
+
+    #[test]
+    fn send_hard_stop() {
+        let mut ds_rumble_pak = DsRumblePak { private: () };
+        ds_rumble_pak.start();
+
+        ds_rumble_pak.send(Command::HardStop);
+
+        assert!(!unsafe { DS_RUMBLE_PAK_ACTIVE });
+    }
+
+    #[test]
+    fn capabilities() {
+        assert_eq!(
+            DsRumblePak { private: () }.capabilities(),
+            Capabilities {
+                has_intensity: false,
+                has_brake: false,
+                latency_frames: 1,
+                shared_with_sio: false,
+            }
+        );
+    }
+}
@@ -0,0 +1,166 @@
+//! Tracking how much of the time a motor is on.
+
+use crate::Fixed;
+
+/// The number of frames in a second.
+const FRAMES_PER_SECOND: u8 = 60;
+
+/// A limit on the fraction of time a motor may be on, enforced by a [`Driver`].
+///
+/// Motors kept on nearly continuously, such as during long racing-game sessions, can run hot and
+/// drain the battery. Once the motor has been on for more than `max_duty` of the last
+/// `window_seconds` seconds, the driver throttles it, keeping it off until its duty falls back
+/// under the budget.
+///
+/// ``` rust
+/// use gba_rumble::{Driver, DutyBudget, Fixed, Gpio};
+///
+/// let mut driver = Driver::<_>::new(Gpio);
+/// // Keep the motor on for at most three quarters of any 30 second period.
+/// driver.set_duty_budget(Some(DutyBudget {
+///     max_duty: Fixed::from_ratio(3, 4),
+///     window_seconds: 30,
+/// }));
+/// ```
+///
+/// [`Driver`]: crate::Driver
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DutyBudget {
+    /// The largest fraction of the window the motor may be on, where [`Fixed::ONE`] is always on.
+    pub max_duty: Fixed,
+    /// The length of the window, in seconds, up to [`MAX_WINDOW_SECONDS`].
+    ///
+    /// [`MAX_WINDOW_SECONDS`]: DutyBudget::MAX_WINDOW_SECONDS
+    pub window_seconds: u8,
+}
+
+impl DutyBudget {
+    /// The longest window over which duty is tracked, in seconds.
+    pub const MAX_WINDOW_SECONDS: u8 = 60;
+}
+
+/// Records whether a motor was on each frame, over a sliding window of whole seconds.
+///
+/// Each completed second is stored as the number of frames the motor was on during it, so the
+/// history takes a single byte per second.
+#[derive(Clone, Debug)]
+pub(crate) struct DutyMeter {
+    /// Frames the motor was on during each completed second, as a ring buffer.
+    seconds: [u8; Self::WINDOW_SECONDS as usize],
+    /// Index of the next completed second to be written.
+    next: usize,
+    /// The number of completed seconds recorded, up to `WINDOW_SECONDS`.
+    len: u8,
+    /// Frames recorded during the current second.
+    frames: u8,
+    /// Frames the motor was on during the current second.
+    on_frames: u8,
+}
+
+impl DutyMeter {
+    /// The number of completed seconds remembered.
+    pub(crate) const WINDOW_SECONDS: u8 = DutyBudget::MAX_WINDOW_SECONDS;
+
+    pub(crate) const fn new() -> Self {
+        Self {
+            seconds: [0; Self::WINDOW_SECONDS as usize],
+            next: 0,
+            len: 0,
+            frames: 0,
+            on_frames: 0,
+        }
+    }
+
+    /// Records a single frame.
+    pub(crate) fn record(&mut self, on: bool) {
+        self.frames += 1;
+        self.on_frames += on as u8;
+        if self.frames == FRAMES_PER_SECOND {
+            self.seconds[self.next] = self.on_frames;
+            self.next = (self.next + 1) % Self::WINDOW_SECONDS as usize;
+            self.len = (self.len + 1).min(Self::WINDOW_SECONDS);
+            self.frames = 0;
+            self.on_frames = 0;
+        }
+    }
+
+    /// Returns the fraction of frames the motor was on during the current second and up to
+    /// `seconds` completed seconds before it.
+    pub(crate) fn duty(&self, seconds: u8) -> Fixed {
+        let seconds = seconds.min(self.len);
+        let mut on_frames = self.on_frames as u16;
+        for offset in 1..=seconds as usize {
+            let index = (self.next + Self::WINDOW_SECONDS as usize - offset)
+                % Self::WINDOW_SECONDS as usize;
+            on_frames += self.seconds[index] as u16;
+        }
+        let frames = self.frames as u16 + seconds as u16 * FRAMES_PER_SECOND as u16;
+        if frames == 0 {
+            Fixed::ZERO
+        } else {
+            Fixed::from_ratio(on_frames, frames)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DutyMeter;
+    use crate::Fixed;
+    use gba_test::test;
+
+    #[test]
+    fn empty() {
+        assert_eq!(DutyMeter::new().duty(10), Fixed::ZERO);
+    }
+
+    #[test]
+    fn current_second() {
+        let mut meter = DutyMeter::new();
+
+        meter.record(true);
+        meter.record(false);
+
+        assert_eq!(meter.duty(0), Fixed::HALF);
+        assert_eq!(meter.duty(10), Fixed::HALF);
+    }
+
+    #[test]
+    fn completed_seconds() {
+        let mut meter = DutyMeter::new();
+        for _ in 0..60 {
+            meter.record(true);
+        }
+        for _ in 0..60 {
+            meter.record(false);
+        }
+        meter.record(false);
+
+        assert_eq!(meter.duty(0), Fixed::ZERO);
+        assert_eq!(meter.duty(1), Fixed::ZERO);
+        assert_eq!(meter.duty(2), Fixed::from_ratio(60, 121));
+    }
+
+    #[test]
+    fn window_slides() {
+        let mut meter = DutyMeter::new();
+        for _ in 0..60 {
+            meter.record(true);
+        }
+        for _ in 0..DutyMeter::WINDOW_SECONDS as usize * 60 {
+            meter.record(false);
+        }
+
+        assert_eq!(meter.duty(DutyMeter::WINDOW_SECONDS), Fixed::ZERO);
+    }
+
+    #[test]
+    fn always_on() {
+        let mut meter = DutyMeter::new();
+        for _ in 0..1000 {
+            meter.record(true);
+        }
+
+        assert_eq!(meter.duty(u8::MAX), Fixed::ONE);
+    }
+}
@@ -0,0 +1,109 @@
+//! Procedurally generated rumble.
+
+use crate::Intensity;
+use core::{
+    fmt,
+    fmt::{Debug, Formatter},
+};
+
+/// A source of rumble computed each frame, rather than authored ahead of time as a [`Pattern`].
+///
+/// Effects allow rumble to be driven by live game state, such as an engine's RPM or a character's
+/// damage level. They are played on a [`Mixer`] channel using [`Mixer::play_effect()`], and play
+/// until they are stopped or replaced.
+///
+/// Closures can be used as effects through [`EffectFn`].
+///
+/// [`Mixer`]: crate::Mixer
+/// [`Mixer::play_effect()`]: crate::Mixer::play_effect()
+/// [`Pattern`]: crate::Pattern
+pub trait Effect {
+    /// Returns the intensity for the given frame.
+    ///
+    /// `frame` counts up from `0`, starting when the effect begins playing.
+    fn sample(&mut self, frame: u32) -> Intensity;
+}
+
+impl<E> Effect for &mut E
+where
+    E: Effect + ?Sized,
+{
+    fn sample(&mut self, frame: u32) -> Intensity {
+        (**self).sample(frame)
+    }
+}
+
+/// An [`Effect`] that samples a closure.
+///
+/// ``` rust
+/// use gba_rumble::{EffectFn, Intensity};
+///
+/// let rpm: u16 = 3000;
+/// // Rumble harder as the engine revs up.
+/// let engine = EffectFn::new(|_frame| Intensity::new((rpm / 40) as u8));
+/// ```
+#[derive(Clone, Copy)]
+pub struct EffectFn<F> {
+    f: F,
+}
+
+impl<F> EffectFn<F>
+where
+    F: FnMut(u32) -> Intensity,
+{
+    /// Creates an effect that calls `f` with each frame to sample it.
+    pub const fn new(f: F) -> Self {
+        Self { f }
+    }
+}
+
+impl<F> Effect for EffectFn<F>
+where
+    F: FnMut(u32) -> Intensity,
+{
+    fn sample(&mut self, frame: u32) -> Intensity {
+        (self.f)(frame)
+    }
+}
+
+impl<F> Debug for EffectFn<F> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.debug_struct("EffectFn").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Effect, EffectFn};
+    use crate::Intensity;
+    use alloc::format;
+    use gba_test::test;
+
+    #[test]
+    fn effect_fn_sample() {
+        let mut effect = EffectFn::new(|frame| Intensity::new(frame as u8 * 2));
+
+        assert_eq!(effect.sample(0), Intensity::OFF);
+        assert_eq!(effect.sample(3), Intensity::new(6));
+    }
+
+    #[test]
+    fn effect_fn_captures_state() {
+        let mut damage = 10;
+        let mut effect = EffectFn::new(|_| {
+            damage += 1;
+            Intensity::new(damage)
+        });
+
+        assert_eq!(effect.sample(0), Intensity::new(11));
+        assert_eq!(effect.sample(1), Intensity::new(12));
+    }
+
+    #[test]
+    fn effect_fn_debug() {
+        assert_eq!(
+            format!("{:?}", EffectFn::new(|_| Intensity::OFF)),
+            "EffectFn { .. }"
+        );
+    }
+}
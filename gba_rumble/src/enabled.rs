@@ -0,0 +1,65 @@
+//! Global control over whether rumble is enabled.
+
+use crate::{Gpio, game_boy_player};
+
+static mut ENABLED: bool = true;
+
+/// Enables or disables rumble on every backend.
+///
+/// While disabled, starting rumble through [`Gpio`], [`GameBoyPlayer`], or any other hardware
+/// backend does nothing, regardless of which code path holds the backend. This makes it suitable
+/// for implementing an options-menu toggle that cannot be bypassed.
+///
+/// Disabling rumble also stops the motor immediately. Re-enabling it does not restart the motor;
+/// rumble resumes the next time it is started.
+///
+/// ``` rust
+/// gba_rumble::set_enabled(false);
+///
+/// // This does nothing while rumble is disabled.
+/// gba_rumble::Gpio.start();
+/// ```
+///
+/// [`GameBoyPlayer`]: crate::GameBoyPlayer
+pub fn set_enabled(enabled: bool) {
+    if cfg!(feature = "disabled") {
+        return;
+    }
+    unsafe {
+        ENABLED = enabled;
+    }
+    if !enabled {
+        Gpio.stop();
+        game_boy_player::force_stop();
+        #[cfg(feature = "ds-rumble-pak")]
+        crate::ds_rumble_pak::force_stop();
+    }
+}
+
+/// Returns whether rumble is enabled.
+///
+/// Rumble is enabled by default, and can be disabled using [`set_enabled()`]. With the `disabled`
+/// feature, this always returns `false`.
+pub fn is_enabled() -> bool {
+    !cfg!(feature = "disabled") && unsafe { ENABLED }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_enabled, set_enabled};
+    use gba_test::test;
+
+    #[test]
+    fn enabled_by_default() {
+        assert!(is_enabled());
+    }
+
+    #[test]
+    fn set_enabled_round_trip() {
+        set_enabled(false);
+        assert!(!is_enabled());
+
+        set_enabled(true);
+        assert!(is_enabled());
+    }
+}
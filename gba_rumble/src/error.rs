@@ -0,0 +1,123 @@
+//! A single error type covering every fallible operation.
+
+use crate::{GpioConflict, PatternError, RegistryError, SerialConfigError, StateError};
+use core::{
+    fmt,
+    fmt::{Display, Formatter},
+};
+
+/// Any error returned by this crate.
+///
+/// Each fallible operation returns its own specific error type, such as [`PatternError`] from
+/// [`Pattern::from_bytes()`]. Every one of them converts into this type through [`From`], so
+/// games can propagate rumble errors with `?` alongside the rest of their error handling, with
+/// the specific error available through [`source()`].
+///
+/// ``` rust
+/// use gba_rumble::{EffectId, Error, Pattern, Registry};
+///
+/// fn load(bytes: &'static [u8], registry: &mut Registry<4>) -> Result<(), Error> {
+///     registry.register(EffectId::new(0), Pattern::from_bytes(bytes)?)?;
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Pattern::from_bytes()`]: crate::Pattern::from_bytes()
+/// [`source()`]: core::error::Error::source()
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// A pattern could not be decoded.
+    Pattern(PatternError),
+    /// Playback state could not be serialized or restored.
+    State(StateError),
+    /// An effect could not be registered.
+    Registry(RegistryError),
+    /// The serial port is not configured for communicating with the Game Boy Player.
+    SerialConfig(SerialConfigError),
+    /// Other code changed the GPIO port while rumble was active.
+    Gpio(GpioConflict),
+}
+
+impl Display for Error {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::Pattern(_) => "invalid pattern",
+            Self::State(_) => "unable to save or restore state",
+            Self::Registry(_) => "unable to register effect",
+            Self::SerialConfig(_) => "invalid serial configuration",
+            Self::Gpio(_) => "GPIO port conflict",
+        })
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(match self {
+            Self::Pattern(error) => error,
+            Self::State(error) => error,
+            Self::Registry(error) => error,
+            Self::SerialConfig(error) => error,
+            Self::Gpio(error) => error,
+        })
+    }
+}
+
+impl From<PatternError> for Error {
+    fn from(error: PatternError) -> Self {
+        Self::Pattern(error)
+    }
+}
+
+impl From<StateError> for Error {
+    fn from(error: StateError) -> Self {
+        Self::State(error)
+    }
+}
+
+impl From<RegistryError> for Error {
+    fn from(error: RegistryError) -> Self {
+        Self::Registry(error)
+    }
+}
+
+impl From<SerialConfigError> for Error {
+    fn from(error: SerialConfigError) -> Self {
+        Self::SerialConfig(error)
+    }
+}
+
+impl From<GpioConflict> for Error {
+    fn from(error: GpioConflict) -> Self {
+        Self::Gpio(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+    use crate::{PatternError, StateError};
+    use alloc::{format, string::ToString};
+    use claims::assert_some;
+    use core::error::Error as _;
+    use gba_test::test;
+
+    #[test]
+    fn from() {
+        assert_eq!(
+            Error::from(StateError::Invalid),
+            Error::State(StateError::Invalid)
+        );
+    }
+
+    #[test]
+    fn source() {
+        let error = Error::from(PatternError::ZeroFrames { offset: 3 });
+
+        assert_eq!(format!("{error}"), "invalid pattern");
+        assert_eq!(
+            assert_some!(error.source()).to_string(),
+            "hold step at offset 3 has zero frames"
+        );
+    }
+}
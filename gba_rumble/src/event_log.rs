@@ -0,0 +1,212 @@
+//! Logging Game Boy Player session events.
+
+use crate::{Command, ResetReason};
+
+/// Something that happened during a session with the Game Boy Player.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SessionEvent {
+    /// The handshake completed, and rumble states are being sent.
+    Connected,
+    /// Communication was reset, for the given reason.
+    Reset(ResetReason),
+    /// Communication was given up on after too many consecutive resets, as configured with
+    /// [`GbpConfig::link_failure_after()`].
+    ///
+    /// [`GbpConfig::link_failure_after()`]: crate::GbpConfig::link_failure_after()
+    LinkFailed,
+    /// The rumble state sent to the Game Boy Player changed.
+    Command(Command),
+}
+
+/// A [`SessionEvent`] stamped with the frame it was logged on.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct TimedEvent {
+    /// The frame the event was logged on, counted by calls to [`GameBoyPlayer::update()`].
+    ///
+    /// [`GameBoyPlayer::update()`]: crate::GameBoyPlayer::update()
+    pub frame: u32,
+    /// The event that happened.
+    pub event: SessionEvent,
+}
+
+/// The most recent events of a Game Boy Player session, stored in a caller-provided buffer.
+///
+/// Once the buffer is full, each new event overwrites the oldest one. A log is installed using
+/// [`GameBoyPlayer::set_event_log()`], after which the Game Boy Player records every connection,
+/// reset, and change of rumble state into it. Since the log is kept in release builds, it can be
+/// shown on a game's own crash or telemetry screens to help triage reports of rumble no longer
+/// working.
+///
+/// ``` rust
+/// use gba_rumble::{EventLog, GameBoyPlayer, TimedEvent};
+///
+/// static mut EVENTS: [Option<TimedEvent>; 32] = [None; 32];
+///
+/// if let Some(game_boy_player) = GameBoyPlayer::detect() {
+///     let buffer = unsafe { (&raw mut EVENTS).as_mut() }.unwrap();
+///     game_boy_player.set_event_log(Some(EventLog::new(buffer)));
+///
+///     // Later, such as on a crash screen:
+///     if let Some(log) = game_boy_player.set_event_log(None) {
+///         for event in log.iter() {
+///             // Display the event.
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`GameBoyPlayer::set_event_log()`]: crate::GameBoyPlayer::set_event_log()
+#[derive(Debug)]
+pub struct EventLog<'a> {
+    entries: &'a mut [Option<TimedEvent>],
+    /// Index of the next entry to be written.
+    next: usize,
+    len: usize,
+}
+
+impl<'a> EventLog<'a> {
+    /// Creates an empty log storing its events in `buffer`.
+    ///
+    /// The log holds as many events as `buffer` has entries. Any events already in `buffer` are
+    /// discarded.
+    pub fn new(buffer: &'a mut [Option<TimedEvent>]) -> Self {
+        buffer.fill(None);
+        Self {
+            entries: buffer,
+            next: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of events in the log.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the log contains no events.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of events the log can hold.
+    pub const fn capacity(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Removes all events from the log.
+    pub fn clear(&mut self) {
+        self.entries.fill(None);
+        self.next = 0;
+        self.len = 0;
+    }
+
+    /// Returns an iterator over the logged events, from oldest to newest.
+    pub fn iter(&self) -> impl Iterator<Item = TimedEvent> + '_ {
+        let capacity = self.capacity();
+        let start = (self.next + capacity - self.len) % capacity.max(1);
+        (0..self.len).filter_map(move |offset| self.entries[(start + offset) % capacity])
+    }
+
+    /// Returns the buffer the events are stored in.
+    pub fn into_inner(self) -> &'a mut [Option<TimedEvent>] {
+        self.entries
+    }
+
+    pub(crate) fn push(&mut self, event: TimedEvent) {
+        let capacity = self.capacity();
+        if capacity == 0 {
+            return;
+        }
+        self.entries[self.next] = Some(event);
+        self.next = (self.next + 1) % capacity;
+        self.len = (self.len + 1).min(capacity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EventLog, SessionEvent, TimedEvent};
+    use crate::{Command, ResetReason};
+    use claims::{assert_none, assert_some_eq};
+    use gba_test::test;
+
+    const fn event(frame: u32, event: SessionEvent) -> TimedEvent {
+        TimedEvent { frame, event }
+    }
+
+    #[test]
+    fn empty() {
+        let mut buffer = [None; 4];
+        let log = EventLog::new(&mut buffer);
+
+        assert!(log.is_empty());
+        assert_eq!(log.capacity(), 4);
+        assert_none!(log.iter().next());
+    }
+
+    #[test]
+    fn push() {
+        let mut buffer = [None; 4];
+        let mut log = EventLog::new(&mut buffer);
+
+        log.push(event(0, SessionEvent::Connected));
+        log.push(event(5, SessionEvent::Command(Command::Start)));
+
+        assert_eq!(log.len(), 2);
+        let mut iter = log.iter();
+        assert_some_eq!(iter.next(), event(0, SessionEvent::Connected));
+        assert_some_eq!(iter.next(), event(5, SessionEvent::Command(Command::Start)));
+        assert_none!(iter.next());
+    }
+
+    #[test]
+    fn overwrites_oldest() {
+        let mut buffer = [None; 2];
+        let mut log = EventLog::new(&mut buffer);
+
+        log.push(event(0, SessionEvent::Connected));
+        log.push(event(1, SessionEvent::Command(Command::Start)));
+        log.push(event(2, SessionEvent::Reset(ResetReason::Timeout)));
+
+        assert_eq!(log.len(), 2);
+        let mut iter = log.iter();
+        assert_some_eq!(iter.next(), event(1, SessionEvent::Command(Command::Start)));
+        assert_some_eq!(
+            iter.next(),
+            event(2, SessionEvent::Reset(ResetReason::Timeout))
+        );
+        assert_none!(iter.next());
+    }
+
+    #[test]
+    fn empty_buffer() {
+        let mut log = EventLog::new(&mut []);
+
+        log.push(event(0, SessionEvent::Connected));
+
+        assert!(log.is_empty());
+        assert_none!(log.iter().next());
+    }
+
+    #[test]
+    fn clear() {
+        let mut buffer = [None; 2];
+        let mut log = EventLog::new(&mut buffer);
+        log.push(event(0, SessionEvent::Connected));
+
+        log.clear();
+
+        assert!(log.is_empty());
+        assert_none!(log.iter().next());
+    }
+
+    #[test]
+    fn new_discards_buffer_contents() {
+        let mut buffer = [Some(event(0, SessionEvent::Connected)); 2];
+
+        let log = EventLog::new(&mut buffer);
+
+        assert!(log.is_empty());
+        assert_eq!(log.into_inner(), [None; 2]);
+    }
+}
@@ -0,0 +1,147 @@
+//! Snapshots explaining why rumble is or is not being felt.
+
+use crate::{Command, Intensity, LinkStatus, PowerSaving};
+use core::{
+    fmt,
+    fmt::{Display, Formatter},
+};
+
+/// A snapshot of everything deciding whether rumble is felt, returned by
+/// [`Haptics::debug_explain()`].
+///
+/// When rumble is reported missing at some point in a game, the cause could be in any of several
+/// places: rumble may be disabled globally or in the player's [`Settings`], scaled down to nothing,
+/// cut off by the [`PowerSaving`] policy or the [`DutyBudget`], or never delivered over a serial
+/// link that has stalled. This gathers all of them in one place.
+///
+/// The [`Display`] implementation writes the snapshot on a single line, suitable for logging.
+///
+/// [`DutyBudget`]: crate::DutyBudget
+/// [`Haptics::debug_explain()`]: crate::Haptics::debug_explain()
+/// [`Settings`]: crate::Settings
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Explanation {
+    /// The name of the mechanism rumble is performed by.
+    pub backend: &'static str,
+    /// Whether rumble is enabled globally, as set by [`set_enabled()`].
+    ///
+    /// [`set_enabled()`]: crate::set_enabled()
+    pub enabled: bool,
+    /// Whether rumble is enabled in the player's settings.
+    pub settings_enabled: bool,
+    /// The scale applied to every intensity by the player's settings.
+    pub intensity_scale: Intensity,
+    /// The global power saving policy, if any.
+    pub power_saving: Option<PowerSaving>,
+    /// Whether playback is paused.
+    pub paused: bool,
+    /// Whether the motor is being kept off because its duty budget is exceeded.
+    pub throttled: bool,
+    /// The most recent command sent to the mechanism.
+    pub command: Command,
+    /// The state of the serial session, if the mechanism communicates over the serial port.
+    pub serial: Option<SerialStatus>,
+}
+
+impl Display for Explanation {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "backend: {}, enabled: {}, settings enabled: {}, scale: {}, paused: {}, throttled: {}, command: {:?}",
+            self.backend,
+            self.enabled,
+            self.settings_enabled,
+            self.intensity_scale.get(),
+            self.paused,
+            self.throttled,
+            self.command,
+        )?;
+        if let Some(power_saving) = self.power_saving {
+            write!(
+                formatter,
+                ", power saving: max duty {} for {} frames",
+                power_saving.max_duty.get(),
+                power_saving.max_frames,
+            )?;
+        }
+        if let Some(serial) = self.serial {
+            write!(
+                formatter,
+                ", link: {:?}, sent: {:?}",
+                serial.link, serial.sent
+            )?;
+            match serial.frames_since_transfer {
+                Some(frames) => write!(formatter, ", last transfer: {frames} frames ago"),
+                None => formatter.write_str(", last transfer: never"),
+            }?;
+        }
+        Ok(())
+    }
+}
+
+/// The state of a serial session with the Game Boy Player, as part of an [`Explanation`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SerialStatus {
+    /// The state of the link.
+    pub link: LinkStatus,
+    /// The most recent command actually sent to the Game Boy Player.
+    ///
+    /// This lags behind [`Explanation::command`] until the next transfer.
+    pub sent: Command,
+    /// The number of frames since a transfer last occurred, or `None` if none has occurred since
+    /// the session began.
+    ///
+    /// A value that keeps growing while the link is connected means the Game Boy Player has
+    /// stopped responding.
+    pub frames_since_transfer: Option<u16>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Explanation, SerialStatus};
+    use crate::{Command, Intensity, LinkStatus, PowerSaving};
+    use alloc::format;
+    use gba_test::test;
+
+    const EXPLANATION: Explanation = Explanation {
+        backend: "GPIO",
+        enabled: true,
+        settings_enabled: true,
+        intensity_scale: Intensity::MAX,
+        power_saving: None,
+        paused: false,
+        throttled: false,
+        command: Command::Start,
+        serial: None,
+    };
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            format!("{EXPLANATION}"),
+            "backend: GPIO, enabled: true, settings enabled: true, scale: 255, paused: false, throttled: false, command: Start"
+        );
+    }
+
+    #[test]
+    fn display_serial() {
+        let explanation = Explanation {
+            backend: "Game Boy Player",
+            power_saving: Some(PowerSaving {
+                max_duty: Intensity::new(128),
+                max_frames: 120,
+            }),
+            serial: Some(SerialStatus {
+                link: LinkStatus::Connected,
+                sent: Command::Stop,
+                frames_since_transfer: Some(3),
+            }),
+            ..EXPLANATION
+        };
+
+        assert_eq!(
+            format!("{explanation}"),
+            "backend: Game Boy Player, enabled: true, settings enabled: true, scale: 255, paused: false, throttled: false, command: Start, power saving: max duty 128 for 120 frames, link: Connected, sent: Stop, last transfer: 3 frames ago"
+        );
+    }
+}
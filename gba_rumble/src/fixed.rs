@@ -0,0 +1,151 @@
+//! Fixed-point arithmetic.
+
+/// An unsigned fixed-point number with 8 integer bits and 8 fractional bits.
+///
+/// The GBA has no floating-point unit, so floating-point math is emulated in software and can
+/// take hundreds of cycles per operation. This crate never uses floating-point numbers. Instead,
+/// every fractional quantity, such as an envelope level, a playback speed, or a scaling factor, is
+/// represented as a `Fixed` 8.8 number: the raw `u16` value divided by 256. This gives a range of
+/// `0` to just under `256` in steps of `1/256`.
+///
+/// Arithmetic saturates rather than overflowing, and multiplication truncates towards zero.
+///
+/// ``` rust
+/// use gba_rumble::{Fixed, Intensity};
+///
+/// let three_quarters = Fixed::from_ratio(3, 4);
+///
+/// assert_eq!(three_quarters.to_bits(), 192);
+/// assert_eq!(Intensity::new(200).scale(three_quarters), Intensity::new(150));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Fixed(u16);
+
+impl Fixed {
+    /// The number of fractional bits.
+    pub const FRACTIONAL_BITS: u32 = 8;
+
+    /// Zero.
+    pub const ZERO: Self = Self(0);
+    /// One half.
+    pub const HALF: Self = Self(1 << (Self::FRACTIONAL_BITS - 1));
+    /// One.
+    pub const ONE: Self = Self(1 << Self::FRACTIONAL_BITS);
+    /// The largest representable value, just under `256`.
+    pub const MAX: Self = Self(u16::MAX);
+
+    /// Creates a number from its raw 8.8 representation.
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the raw 8.8 representation of this number.
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Creates a number from an integer.
+    pub const fn from_int(value: u8) -> Self {
+        Self((value as u16) << Self::FRACTIONAL_BITS)
+    }
+
+    /// Returns the integer part of this number, discarding the fraction.
+    pub const fn to_int(self) -> u8 {
+        (self.0 >> Self::FRACTIONAL_BITS) as u8
+    }
+
+    /// Creates a number equal to `numerator / denominator`, truncated towards zero.
+    ///
+    /// Saturates to [`Fixed::MAX`] if the result is too large, including when `denominator` is
+    /// `0`.
+    pub const fn from_ratio(numerator: u16, denominator: u16) -> Self {
+        if denominator == 0 {
+            return Self::MAX;
+        }
+        Self::saturate(((numerator as u32) << Self::FRACTIONAL_BITS) / denominator as u32)
+    }
+
+    /// Adds two numbers, saturating at [`Fixed::MAX`].
+    pub const fn saturating_add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+
+    /// Subtracts `other` from this number, saturating at [`Fixed::ZERO`].
+    pub const fn saturating_sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+
+    /// Multiplies two numbers, saturating at [`Fixed::MAX`].
+    pub const fn saturating_mul(self, other: Self) -> Self {
+        Self::saturate((self.0 as u32 * other.0 as u32) >> Self::FRACTIONAL_BITS)
+    }
+
+    const fn saturate(bits: u32) -> Self {
+        if bits > u16::MAX as u32 {
+            Self::MAX
+        } else {
+            Self(bits as u16)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Fixed;
+    use gba_test::test;
+
+    #[test]
+    fn constants() {
+        assert_eq!(Fixed::ZERO.to_bits(), 0);
+        assert_eq!(Fixed::HALF.to_bits(), 0x80);
+        assert_eq!(Fixed::ONE.to_bits(), 0x100);
+        assert_eq!(Fixed::MAX.to_bits(), 0xFFFF);
+    }
+
+    #[test]
+    fn int_round_trip() {
+        assert_eq!(Fixed::from_int(3).to_bits(), 0x300);
+        assert_eq!(Fixed::from_int(3).to_int(), 3);
+        assert_eq!(Fixed::from_bits(0x3FF).to_int(), 3);
+    }
+
+    #[test]
+    fn from_ratio() {
+        assert_eq!(Fixed::from_ratio(1, 2), Fixed::HALF);
+        assert_eq!(Fixed::from_ratio(3, 3), Fixed::ONE);
+        assert_eq!(Fixed::from_ratio(1, 3).to_bits(), 85);
+        assert_eq!(Fixed::from_ratio(600, 2), Fixed::MAX);
+        assert_eq!(Fixed::from_ratio(1, 0), Fixed::MAX);
+    }
+
+    #[test]
+    fn saturating_add() {
+        assert_eq!(Fixed::HALF.saturating_add(Fixed::HALF), Fixed::ONE);
+        assert_eq!(Fixed::MAX.saturating_add(Fixed::ONE), Fixed::MAX);
+    }
+
+    #[test]
+    fn saturating_sub() {
+        assert_eq!(Fixed::ONE.saturating_sub(Fixed::HALF), Fixed::HALF);
+        assert_eq!(Fixed::ZERO.saturating_sub(Fixed::ONE), Fixed::ZERO);
+    }
+
+    #[test]
+    fn saturating_mul() {
+        assert_eq!(Fixed::HALF.saturating_mul(Fixed::HALF).to_bits(), 0x40);
+        assert_eq!(
+            Fixed::from_int(3).saturating_mul(Fixed::HALF),
+            Fixed::from_bits(0x180)
+        );
+        assert_eq!(
+            Fixed::from_int(16).saturating_mul(Fixed::from_int(16)),
+            Fixed::MAX
+        );
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(Fixed::HALF < Fixed::ONE);
+        assert_eq!(Fixed::default(), Fixed::ZERO);
+    }
+}
@@ -0,0 +1,4957 @@
+//! Game Boy Player rumble.
+
+use crate::ranged::RangedUsize;
+use crate::{
+    Backend, BootKind, Calibration, Capabilities, Command, CommandDelivery, DetectOptions,
+    Detection, DetectionFrames, DetectionPolicy, EventLog, GbpConfig, Gpio, IrqRegistrar,
+    KeepAlive, ManagedGameBoyPlayer, SaveState, SerialStatus, SessionEvent, SplashRenderer,
+    StateError, TimedEvent, Timer, VcountWaiter, Waiter, boot,
+    boot_report::{Phase, Stopwatch},
+    config::GbpSettings,
+    detection, metrics,
+    protocol::{
+        Stage, StateMachine,
+        consts::{
+            DETECTION_FRAMES, HANDSHAKE_ECHOES, HANDSHAKE_KEYS, HANDSHAKE_RECEIVED, HANDSHAKE_SENT,
+            MAGIC_RECEIVED, MAGIC_SENT, RUMBLE_HARD_STOP, RUMBLE_REQUEST, RUMBLE_START,
+            RUMBLE_STOP,
+        },
+    },
+    regs,
+    sections::Region,
+    session,
+    state::{StateReader, StateWriter},
+};
+use core::{
+    arch::asm,
+    fmt,
+    fmt::{Debug, Display, Formatter},
+    ptr,
+};
+
+const SIODATA: *mut u32 = ptr::with_exposed_provenance_mut(regs::SIODATA32);
+const SIOCNT: *mut u16 = ptr::with_exposed_provenance_mut(regs::SIOCNT);
+const RCNT: *mut u16 = ptr::with_exposed_provenance_mut(regs::RCNT);
+const IE: *const u16 = ptr::with_exposed_provenance(regs::IE);
+
+/// The serial configuration used to communicate with the Game Boy Player: 32-bit normal mode
+/// using the external clock, with interrupts enabled.
+pub(crate) const SIOCNT_CONFIG: u16 = 0x4000 | 0x1000 | 8;
+
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_rumble")
+)]
+static mut GAME_BOY_PLAYER_RUMBLE: GameBoyPlayerRumble = GameBoyPlayerRumble::Stop;
+/// The rumble state most recently sent to the Game Boy Player, written by the interrupt handler.
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_sent_rumble")
+)]
+static mut GAME_BOY_PLAYER_SENT_RUMBLE: GameBoyPlayerRumble = GameBoyPlayerRumble::Stop;
+/// Rumble states waiting to be sent when delivering every command at least once, as a ring buffer.
+///
+/// Entries are added by the game and removed by the interrupt handler, each advancing only its own
+/// wrapping index, so that neither can overwrite a change made by the other.
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_pending")
+)]
+static mut GAME_BOY_PLAYER_PENDING: [GameBoyPlayerRumble; CommandDelivery::MAX_PENDING] =
+    [GameBoyPlayerRumble::Stop; CommandDelivery::MAX_PENDING];
+/// Entries removed from `GAME_BOY_PLAYER_PENDING`, counted by the interrupt handler with wrapping.
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_pending_read")
+)]
+static mut GAME_BOY_PLAYER_PENDING_READ: u8 = 0;
+/// Entries added to `GAME_BOY_PLAYER_PENDING`, counted with wrapping.
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_pending_write")
+)]
+static mut GAME_BOY_PLAYER_PENDING_WRITE: u8 = 0;
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_sio_state")
+)]
+static mut GAME_BOY_PLAYER_SIO_STATE: GameBoyPlayerSioState = GameBoyPlayerSioState::Handshake {
+    index: RangedUsize::new_static::<0>(),
+};
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_settings")
+)]
+static mut GAME_BOY_PLAYER_SETTINGS: GbpSettings = GbpSettings::DEFAULT;
+/// Frames since the last serial interrupt, counted for the watchdog.
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_idle_frames")
+)]
+static mut GAME_BOY_PLAYER_IDLE_FRAMES: u8 = 0;
+/// Whether `game_boy_player_interrupt()` is currently running.
+///
+/// ARMv4T has no atomic types, but single byte loads and stores cannot be interrupted, so this is
+/// only ever accessed with volatile operations.
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_interrupt_active")
+)]
+static mut GAME_BOY_PLAYER_INTERRUPT_ACTIVE: bool = false;
+/// Calls to `game_boy_player_interrupt()` that bailed out because they interrupted another call.
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_nested_interrupts")
+)]
+static mut GAME_BOY_PLAYER_NESTED_INTERRUPTS: u32 = 0;
+/// Rumble transfers responded to during the current frame.
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_transfers")
+)]
+static mut GAME_BOY_PLAYER_TRANSFERS: u8 = 0;
+/// Whether `update()` has been called since the last call to `game_boy_player_keep_alive()`.
+///
+/// This is set by `update()` and cleared by `game_boy_player_keep_alive()`, and is only ever
+/// accessed with volatile operations.
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_updated")
+)]
+static mut GAME_BOY_PLAYER_UPDATED: bool = false;
+/// Whether the serial port was deliberately left unconfigured after detection, as configured with
+/// `GbpConfig::defer_serial()`, and has not yet been enabled.
+///
+/// This is set when detecting, and cleared by `enable_serial()`. It is only ever accessed with
+/// volatile operations.
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_awaiting_serial")
+)]
+static mut GAME_BOY_PLAYER_AWAITING_SERIAL: bool = false;
+/// Consecutive unexpected values ignored, counted against the configured reset tolerance.
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_ignored_values")
+)]
+static mut GAME_BOY_PLAYER_IGNORED_VALUES: u8 = 0;
+/// Consecutive resets caused by unexpected values, counted against the configured link failure
+/// threshold. Any expected value clears this.
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_consecutive_resets")
+)]
+static mut GAME_BOY_PLAYER_CONSECUTIVE_RESETS: u8 = 0;
+/// Handshake attempts that failed partway through, counted against the configured limit on
+/// handshake attempts. Completing the handshake clears this.
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_failed_handshakes")
+)]
+static mut GAME_BOY_PLAYER_FAILED_HANDSHAKES: u8 = 0;
+/// Whether the link was given up on after too many consecutive resets or failed handshakes.
+///
+/// This is set by the interrupt handler, and cleared by `retry_link()`. It is only ever accessed
+/// with volatile operations outside of the interrupt handler.
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_link_failed")
+)]
+static mut GAME_BOY_PLAYER_LINK_FAILED: bool = false;
+/// Whether the link was given up on because nothing was received within the handshake timeout.
+static mut GAME_BOY_PLAYER_UNRESPONSIVE: bool = false;
+/// Calls to `update()` this session without receiving anything, or `None` once something has been
+/// received.
+static mut GAME_BOY_PLAYER_SILENT_FRAMES: Option<u16> = Some(0);
+/// The value of `GAME_BOY_PLAYER_INBOUND_COUNT` when the session began.
+static mut GAME_BOY_PLAYER_SESSION_INBOUND: u32 = 0;
+/// Whether the current link failure has been logged.
+static mut GAME_BOY_PLAYER_LOGGED_FAILURE: bool = false;
+/// Frames remaining until a hard stop reverts to a normal stop.
+static mut GAME_BOY_PLAYER_HARD_STOP_FRAMES: u8 = 0;
+/// Resets caused by unexpected values, counted by the interrupt handler with wrapping.
+///
+/// This is only ever written by the interrupt handler. `update()` reports the difference between
+/// this and `GAME_BOY_PLAYER_REPORTED_RESETS`, which it alone writes, so that neither can
+/// overwrite a change made by the other.
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_unexpected_resets")
+)]
+static mut GAME_BOY_PLAYER_UNEXPECTED_RESETS: u8 = 0;
+/// The value of `GAME_BOY_PLAYER_UNEXPECTED_RESETS` as of the last report.
+static mut GAME_BOY_PLAYER_REPORTED_RESETS: u8 = 0;
+/// Handshakes completed, counted by the interrupt handler with wrapping.
+///
+/// As with `GAME_BOY_PLAYER_UNEXPECTED_RESETS`, this is only ever written by the interrupt handler,
+/// and `update()` logs the difference between this and `GAME_BOY_PLAYER_LOGGED_CONNECTIONS`.
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_connections")
+)]
+static mut GAME_BOY_PLAYER_CONNECTIONS: u8 = 0;
+/// The value of `GAME_BOY_PLAYER_CONNECTIONS` as of the last log.
+static mut GAME_BOY_PLAYER_LOGGED_CONNECTIONS: u8 = 0;
+/// Calls to `update()` so far, used to timestamp logged events and changes of protocol stage.
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_frame")
+)]
+static mut GAME_BOY_PLAYER_FRAME: u32 = 0;
+/// The value of `GAME_BOY_PLAYER_FRAME` when the protocol stage last changed.
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_stage_changed")
+)]
+static mut GAME_BOY_PLAYER_STAGE_CHANGED: u32 = 0;
+/// The misconfiguration of the serial port found by the last call to `update()`, if any.
+static mut GAME_BOY_PLAYER_SERIAL_ERROR: Option<SerialConfigError> = None;
+/// Whether the detected Game Boy Player was passed over using `decline()`, until it is detected
+/// again.
+static mut GAME_BOY_PLAYER_DECLINED: bool = false;
+/// Calls to `update()` since the last one following a rumble transfer, or `None` if no rumble
+/// transfer has occurred this session.
+static mut GAME_BOY_PLAYER_FRAMES_SINCE_TRANSFER: Option<u16> = None;
+/// The most recent word received from the Game Boy Player.
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_inbound")
+)]
+pub(crate) static mut GAME_BOY_PLAYER_INBOUND: u32 = 0;
+/// Words received from the Game Boy Player, counted by the interrupt handler with wrapping.
+///
+/// The interrupt handler writes this after `GAME_BOY_PLAYER_INBOUND`, so that readers can detect
+/// a word received while they were reading by checking that this is unchanged.
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_inbound_count")
+)]
+pub(crate) static mut GAME_BOY_PLAYER_INBOUND_COUNT: u32 = 0;
+/// The running CRC-32 of every word written to `SIODATA`, before its final inversion.
+#[cfg(feature = "sio-crc")]
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_outbound_crc")
+)]
+static mut GAME_BOY_PLAYER_OUTBOUND_CRC: u32 = u32::MAX;
+/// Writes to `SIOCNT` or `SIODATA` that did not read back as written.
+#[cfg(feature = "paranoid")]
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_write_mismatches")
+)]
+static mut GAME_BOY_PLAYER_WRITE_MISMATCHES: u32 = 0;
+/// The log session events are recorded into, if any.
+static mut GAME_BOY_PLAYER_EVENT_LOG: Option<EventLog<'static>> = None;
+#[cfg(feature = "diagnostics")]
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_sio_stats")
+)]
+static mut GAME_BOY_PLAYER_SIO_STATS: SioStats = SioStats {
+    interrupts: 0,
+    connections: 0,
+    transfers: 0,
+    resets: 0,
+    skipped: 0,
+};
+
+/// Counts of serial communication events, displayed by the diagnostics menu.
+#[cfg(feature = "diagnostics")]
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct SioStats {
+    /// Serial interrupts handled.
+    pub(crate) interrupts: u32,
+    /// Handshakes completed.
+    pub(crate) connections: u32,
+    /// Rumble states sent.
+    pub(crate) transfers: u32,
+    /// Times communication was reset due to an unexpected value.
+    pub(crate) resets: u32,
+    /// Frames where a transfer could not be started because one was already in progress.
+    pub(crate) skipped: u32,
+}
+
+/// Returns the serial communication statistics gathered so far.
+#[cfg(feature = "diagnostics")]
+pub(crate) fn sio_stats() -> SioStats {
+    unsafe { GAME_BOY_PLAYER_SIO_STATS }
+}
+
+/// Why communication with the Game Boy Player was reset.
+///
+/// This is passed to the callback registered with [`GbpConfig::on_reset()`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ResetReason {
+    /// No serial transfer was received for the number of frames given to
+    /// [`GbpConfig::watchdog_frames()`].
+    Timeout,
+    /// The watchdog expired while a transfer was still in progress, meaning the Game Boy Player
+    /// stopped clocking the serial port partway through a transfer.
+    Busy,
+    /// The Game Boy Player sent a value that does not belong at the current point in the protocol.
+    UnexpectedValue,
+}
+
+impl Display for ResetReason {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::Timeout => "no serial transfer was received before the watchdog expired",
+            Self::Busy => "a serial transfer was still in progress when the watchdog expired",
+            Self::UnexpectedValue => "an unexpected value was received",
+        })
+    }
+}
+
+/// A snapshot of the session's position in the protocol, along with when it last moved.
+///
+/// This is returned by [`GameBoyPlayer::sio_state()`]. Frames are counted by calls to
+/// [`GameBoyPlayer::update()`], the same frames that timestamp [`SessionEvent`]s, so a watchdog in
+/// game code can act on a session that has stayed in one [`Stage`] for too long:
+///
+/// ``` rust
+/// use gba_rumble::{GameBoyPlayer, protocol::Stage};
+///
+/// if let Some(game_boy_player) = GameBoyPlayer::detect() {
+///     // Once a frame:
+///     game_boy_player.update();
+///     let state = game_boy_player.sio_state();
+///     if state.stage != Stage::Rumble && state.frames_since_change() > 120 {
+///         game_boy_player.restart_session();
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SioState {
+    /// The stage the session has reached in the protocol.
+    pub stage: Stage,
+    /// The frame at which the stage last changed.
+    pub changed_frame: u32,
+    /// The current frame.
+    pub frame: u32,
+}
+
+impl SioState {
+    /// Returns the number of frames the session has spent in its current stage.
+    pub const fn frames_since_change(&self) -> u32 {
+        self.frame.wrapping_sub(self.changed_frame)
+    }
+}
+
+/// The state of the link with the Game Boy Player.
+///
+/// This is returned by [`GameBoyPlayer::link_status()`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LinkStatus {
+    /// The Game Boy Player was detected, but the serial port has deliberately not been enabled
+    /// yet, as configured with [`GbpConfig::defer_serial()`]. Communication begins once
+    /// [`GameBoyPlayer::enable_serial()`] is called.
+    AwaitingSerial,
+    /// The handshake is in progress, and rumble states are not yet being sent.
+    Handshaking,
+    /// The handshake has completed, and rumble states are being sent.
+    Connected,
+    /// Communication was given up on after the number of consecutive resets configured with
+    /// [`GbpConfig::link_failure_after()`], or the number of failed handshake attempts configured
+    /// with [`GbpConfig::handshake_attempts()`]. No further transfers are started until
+    /// [`GameBoyPlayer::retry_link()`] is called.
+    LinkFailed,
+    /// Nothing was received within the timeout configured with
+    /// [`GbpConfig::handshake_timeout()`], so the Game Boy Player was most likely detected by
+    /// mistake. No further transfers are started until [`GameBoyPlayer::retry_link()`] is called.
+    Unresponsive,
+}
+
+/// A problem with the serial configuration, preventing communication with the Game Boy Player.
+///
+/// This is returned by [`GameBoyPlayer::check_serial()`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SerialConfigError {
+    /// `RCNT` is set to general purpose or JOY Bus mode, rather than serial communication.
+    NotSerialMode,
+    /// `SIOCNT` is not set to 32-bit normal mode.
+    Not32BitNormalMode,
+    /// `SIOCNT` is set to use the internal clock, but the Game Boy Player provides the clock.
+    InternalClock,
+    /// Serial interrupts are not requested in `SIOCNT`.
+    InterruptRequestDisabled,
+    /// Serial interrupts are not enabled in `IE`.
+    InterruptDisabled,
+}
+
+impl Display for SerialConfigError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::NotSerialMode => {
+                "RCNT is not set to serial communication mode (bit 15 must be 0)"
+            }
+            Self::Not32BitNormalMode => {
+                "SIOCNT is not set to 32-bit normal mode (bits 12-13 must be 0b01)"
+            }
+            Self::InternalClock => "SIOCNT is set to use the internal clock (bit 0 must be 0)",
+            Self::InterruptRequestDisabled => {
+                "SIOCNT does not request serial interrupts (bit 14 must be 1)"
+            }
+            Self::InterruptDisabled => "IE does not enable serial interrupts (bit 7 must be 1)",
+        })
+    }
+}
+
+impl core::error::Error for SerialConfigError {}
+
+#[derive(Clone, Copy, Debug, Eq)]
+#[repr(u32)]
+enum GameBoyPlayerRumble {
+    Stop = RUMBLE_STOP,
+    HardStop = RUMBLE_HARD_STOP,
+    Start = RUMBLE_START,
+}
+
+// Implemented by hand, including `ne()`, so that comparisons are always inlined into the ARM build
+// of the interrupt handler.
+impl PartialEq for GameBoyPlayerRumble {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        *self as u32 == *other as u32
+    }
+
+    #[expect(clippy::partialeq_ne_impl)]
+    #[inline(always)]
+    fn ne(&self, other: &Self) -> bool {
+        *self as u32 != *other as u32
+    }
+}
+
+impl GameBoyPlayerRumble {
+    /// Returns the code identifying this rumble state in [`GameBoyPlayer::into_raw()`].
+    const fn to_raw(self) -> u32 {
+        match self {
+            Self::Stop => 0,
+            Self::Start => 1,
+            Self::HardStop => 2,
+        }
+    }
+
+    /// Returns the command that produces this rumble state.
+    const fn to_command(self) -> Command {
+        match self {
+            Self::Stop => Command::Stop,
+            Self::Start => Command::Start,
+            Self::HardStop => Command::HardStop,
+        }
+    }
+
+    const fn from_raw(raw: u32) -> Option<Self> {
+        match raw {
+            0 => Some(Self::Stop),
+            1 => Some(Self::Start),
+            2 => Some(Self::HardStop),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq)]
+pub(crate) enum GameBoyPlayerSioState {
+    Handshake { index: RangedUsize<0, 3> },
+    Magic { index: RangedUsize<1, 3> },
+    SendData,
+}
+
+impl GameBoyPlayerSioState {
+    #[inline(always)]
+    pub(crate) fn new() -> Self {
+        Self::handshake::<0>()
+    }
+
+    /// Returns the public form of this state.
+    pub(crate) fn stage(self) -> Stage {
+        match self {
+            Self::Handshake { index } => Stage::Handshake {
+                key: index.get() as u8,
+            },
+            Self::Magic { index } => Stage::Magic {
+                value: index.get() as u8,
+            },
+            Self::SendData => Stage::Rumble,
+        }
+    }
+
+    #[inline(always)]
+    const fn handshake<const INDEX: usize>() -> Self {
+        Self::Handshake {
+            index: RangedUsize::new_static::<INDEX>(),
+        }
+    }
+
+    #[inline(always)]
+    const fn magic<const INDEX: usize>() -> Self {
+        Self::Magic {
+            index: RangedUsize::new_static::<INDEX>(),
+        }
+    }
+
+    /// Returns the code identifying this state in [`GameBoyPlayer::into_raw()`].
+    ///
+    /// Handshake keys are numbered `0` to `3`, followed by the magic values as `4` to `6`, and
+    /// finally `7` for sending data.
+    #[inline(always)]
+    fn to_raw(self) -> u32 {
+        match self {
+            Self::Handshake { index } => index.get() as u32,
+            Self::Magic { index } => (index.get() as u32).wrapping_add(3),
+            Self::SendData => 7,
+        }
+    }
+
+    fn from_raw(raw: u32) -> Option<Self> {
+        match raw {
+            0..=3 => Some(Self::Handshake {
+                index: RangedUsize::new(raw as usize)?,
+            }),
+            4..=6 => Some(Self::Magic {
+                index: RangedUsize::new(raw as usize - 3)?,
+            }),
+            7 => Some(Self::SendData),
+            _ => None,
+        }
+    }
+
+    /// Returns the transition in `transitions` taken when `input` is received in this state, if
+    /// any.
+    #[inline(always)]
+    fn transition(
+        self,
+        transitions: &'static [Transition],
+        input: u32,
+    ) -> Option<&'static Transition> {
+        // Iterator adapters are not inlined into the ARM build of the interrupt handler, so a
+        // plain loop is used to keep it from calling into ROM. Matching the slice, rather than
+        // indexing it, also avoids a bounds check that would panic from ROM.
+        let mut remaining = transitions;
+        while let [transition, rest @ ..] = remaining {
+            if transition.state == self && input & transition.mask == transition.input {
+                return Some(transition);
+            }
+            remaining = rest;
+        }
+        None
+    }
+}
+
+// Implemented by hand, including `ne()`, so that comparisons are always inlined into the ARM build
+// of the interrupt handler.
+impl PartialEq for GameBoyPlayerSioState {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.to_raw() == other.to_raw()
+    }
+
+    #[expect(clippy::partialeq_ne_impl)]
+    #[inline(always)]
+    fn ne(&self, other: &Self) -> bool {
+        self.to_raw() != other.to_raw()
+    }
+}
+
+/// The [`TRANSITIONS`] read by [`game_boy_player_interrupt()`].
+///
+/// This is read by the serial interrupt handler, so it is placed in RAM alongside it rather than
+/// in ROM.
+#[cfg_attr(
+    not(feature = "ewram-serial"),
+    unsafe(link_section = ".iwram.gba_rumble.game_boy_player_transitions")
+)]
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_transitions")
+)]
+pub(crate) static GAME_BOY_PLAYER_TRANSITIONS: [Transition; 11] = TRANSITIONS;
+/// A copy of [`TRANSITIONS`] in ROM, read by `game_boy_player_interrupt_rom()`.
+static GAME_BOY_PLAYER_ROM_TRANSITIONS: [Transition; 11] = TRANSITIONS;
+
+/// Every transition of the protocol before rumble data is sent.
+///
+/// Transitions are checked in order, so for each state the full match is listed before any
+/// partial match.
+#[rustfmt::skip]
+const TRANSITIONS: [Transition; 11] = [
+    // During the handshake, the Game Boy Player sends each key in the low half-word and its
+    // complement in the high half-word. The complement is echoed back alongside the next key.
+    //              state                                    mask      input                       response               next
+    Transition::new(GameBoyPlayerSioState::handshake::<0>(), u32::MAX, HANDSHAKE_RECEIVED[0],      HANDSHAKE_SENT[0],     GameBoyPlayerSioState::handshake::<1>()),
+    Transition::new(GameBoyPlayerSioState::handshake::<0>(), 0xFFFF,   HANDSHAKE_KEYS[0] as u32,   HANDSHAKE_ECHOES[0],   GameBoyPlayerSioState::handshake::<0>()),
+    Transition::new(GameBoyPlayerSioState::handshake::<1>(), u32::MAX, HANDSHAKE_RECEIVED[1],      HANDSHAKE_SENT[1],     GameBoyPlayerSioState::handshake::<2>()),
+    Transition::new(GameBoyPlayerSioState::handshake::<1>(), 0xFFFF,   HANDSHAKE_KEYS[1] as u32,   HANDSHAKE_ECHOES[1],   GameBoyPlayerSioState::handshake::<1>()),
+    Transition::new(GameBoyPlayerSioState::handshake::<2>(), u32::MAX, HANDSHAKE_RECEIVED[2],      HANDSHAKE_SENT[2],     GameBoyPlayerSioState::handshake::<3>()),
+    Transition::new(GameBoyPlayerSioState::handshake::<2>(), 0xFFFF,   HANDSHAKE_KEYS[2] as u32,   HANDSHAKE_ECHOES[2],   GameBoyPlayerSioState::handshake::<2>()),
+    Transition::new(GameBoyPlayerSioState::handshake::<3>(), u32::MAX, HANDSHAKE_RECEIVED[3],      HANDSHAKE_SENT[3],     GameBoyPlayerSioState::magic::<1>()),
+    Transition::new(GameBoyPlayerSioState::handshake::<3>(), 0xFFFF,   HANDSHAKE_KEYS[3] as u32,   HANDSHAKE_ECHOES[3],   GameBoyPlayerSioState::handshake::<3>()),
+    // Each magic value is answered with the next one.
+    Transition::new(GameBoyPlayerSioState::magic::<1>(),     u32::MAX, MAGIC_RECEIVED[0],          MAGIC_SENT[0],         GameBoyPlayerSioState::magic::<2>()),
+    Transition::new(GameBoyPlayerSioState::magic::<2>(),     u32::MAX, MAGIC_RECEIVED[1],          MAGIC_SENT[1],         GameBoyPlayerSioState::magic::<3>()),
+    Transition::new(GameBoyPlayerSioState::magic::<3>(),     u32::MAX, MAGIC_RECEIVED[2],          MAGIC_SENT[2],         GameBoyPlayerSioState::SendData),
+];
+
+/// A single step of the serial protocol.
+///
+/// When in `state`, an input matching `input` in the bits selected by `mask` is answered with
+/// `response`, moving communication to the `next` state.
+#[derive(Debug)]
+pub(crate) struct Transition {
+    pub(crate) state: GameBoyPlayerSioState,
+    pub(crate) mask: u32,
+    pub(crate) input: u32,
+    pub(crate) response: u32,
+    pub(crate) next: GameBoyPlayerSioState,
+}
+
+impl Transition {
+    const fn new(
+        state: GameBoyPlayerSioState,
+        mask: u32,
+        input: u32,
+        response: u32,
+        next: GameBoyPlayerSioState,
+    ) -> Self {
+        Self {
+            state,
+            mask,
+            input,
+            response,
+            next,
+        }
+    }
+}
+
+/// Handles SIO interrupts for every stage of the Game Boy Player communication process.
+///
+/// This function should be called within an interrupt handler when the SIO interrupt is triggered.
+/// See [`/examples`](https://github.com/Anders429/gba_rumble/tree/master/examples) for examples of
+/// using this function in both the [`gba`](https://crates.io/crates/gba) or
+/// [`agb`](https://crates.io/crates/agba) crates.
+///
+/// If nested interrupts are enabled and this function interrupts another call to itself, the
+/// nested call returns without doing anything, as continuing could corrupt the communication
+/// state. The number of such calls is returned by [`game_boy_player_nested_interrupts()`].
+///
+/// This function is placed in IWRAM, along with everything it reads other than the serial
+/// registers, so that it never accesses the cartridge bus. This keeps it fast, and unaffected by
+/// the game's use of ROM, such as DMA transfers from ROM. In IWRAM, it is compiled as ARM code,
+/// which runs faster than THUMB code there. If the program's linker script does not copy it into
+/// RAM, [`game_boy_player_interrupt_handler()`] returns a build that runs from ROM instead.
+///
+/// This guarantee only holds when debug assertions are disabled, as they are in release builds.
+/// With debug assertions enabled, the standard library's checks on volatile accesses and bounded
+/// integers are not inlined, so the function calls into ROM to run them.
+#[cfg_attr(not(feature = "ewram-serial"), instruction_set(arm::a32))]
+#[cfg_attr(
+    not(feature = "ewram-serial"),
+    unsafe(link_section = ".iwram.gba_rumble.game_boy_player_interrupt")
+)]
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_interrupt")
+)]
+pub fn game_boy_player_interrupt() {
+    interrupt(&GAME_BOY_PLAYER_TRANSITIONS);
+}
+
+/// The serial interrupt handler, for calling from interrupt dispatchers written in assembly.
+///
+/// This behaves exactly as [`game_boy_player_interrupt()`], and is placed alongside it, but uses
+/// the C calling convention and is exported under the stable symbol name
+/// `gba_rumble_on_serial_irq`, so that a dispatcher can branch to it directly. It is compiled as
+/// ARM code in IWRAM and as THUMB code in EWRAM, so it should be called with an interworking
+/// branch such as `bx`.
+///
+/// See [`ACKNOWLEDGES_IF`] for what the dispatcher remains responsible for.
+///
+/// ``` asm
+/// @ Within a dispatcher that found the serial bit set in `IE & IF`:
+/// ldr r0, =gba_rumble_on_serial_irq
+/// mov lr, pc
+/// bx r0
+/// ```
+///
+/// [`ACKNOWLEDGES_IF`]: crate::irq::ACKNOWLEDGES_IF
+#[cfg_attr(not(feature = "ewram-serial"), instruction_set(arm::a32))]
+#[cfg_attr(
+    not(feature = "ewram-serial"),
+    unsafe(link_section = ".iwram.gba_rumble.on_serial_irq")
+)]
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.on_serial_irq")
+)]
+#[unsafe(export_name = "gba_rumble_on_serial_irq")]
+pub extern "C" fn on_serial_irq() {
+    interrupt(&GAME_BOY_PLAYER_TRANSITIONS);
+}
+
+/// A THUMB build of [`game_boy_player_interrupt()`] that runs from ROM.
+///
+/// This is used in place of [`game_boy_player_interrupt()`] when the program's linker script did
+/// not copy it into RAM.
+fn game_boy_player_interrupt_rom() {
+    interrupt(&GAME_BOY_PLAYER_ROM_TRANSITIONS);
+}
+
+/// A known value placed alongside [`game_boy_player_interrupt()`], read to check that its section
+/// was copied into RAM at startup.
+#[cfg_attr(
+    not(feature = "ewram-serial"),
+    unsafe(link_section = ".iwram.gba_rumble.game_boy_player_sentinel")
+)]
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_sentinel")
+)]
+static GAME_BOY_PLAYER_SENTINEL: u32 = SENTINEL;
+/// The value of `GAME_BOY_PLAYER_SENTINEL`, spelling out `"GBPR"`.
+const SENTINEL: u32 = 0x5250_4247;
+
+/// Returns the build of the serial interrupt handler suited to how the program was linked.
+///
+/// This is [`game_boy_player_interrupt()`] if the program's linker script copied it into RAM at
+/// startup, as the linker scripts of the `gba` and `agb` crates do. Otherwise, such as with a
+/// linker script that has no IWRAM section, [`game_boy_player_interrupt()`] cannot be run, and a
+/// THUMB build that runs from ROM is returned instead. It behaves the same, only more slowly, and
+/// is affected by the game's use of ROM.
+///
+/// [`GameBoyPlayer::boot()`] registers the handler returned by this function, so it only needs
+/// to be called when registering the handler by hand.
+///
+/// ``` rust
+/// use gba_rumble::irq::{self, Interrupt};
+///
+/// irq::enable(
+///     Interrupt::Serial,
+///     Some(gba_rumble::game_boy_player_interrupt_handler()),
+/// );
+/// ```
+pub fn game_boy_player_interrupt_handler() -> fn() {
+    let address = (game_boy_player_interrupt as *const ()).addr();
+    let sentinel = &raw const GAME_BOY_PLAYER_SENTINEL;
+    if matches!(Region::of(address), Some(Region::Iwram | Region::Ewram))
+        && Region::of(sentinel.addr()) == Region::of(address)
+        && unsafe { sentinel.read_volatile() } == SENTINEL
+    {
+        game_boy_player_interrupt
+    } else {
+        game_boy_player_interrupt_rom
+    }
+}
+
+/// Handles a serial interrupt, reading protocol transitions from `transitions`.
+///
+/// This is inlined into each build of the handler, so that the build placed in RAM never calls
+/// into ROM.
+#[inline(always)]
+fn interrupt(transitions: &'static [Transition]) {
+    if cfg!(feature = "disabled") {
+        return;
+    }
+    let active = &raw mut GAME_BOY_PLAYER_INTERRUPT_ACTIVE;
+    unsafe {
+        if active.read_volatile() {
+            GAME_BOY_PLAYER_NESTED_INTERRUPTS = GAME_BOY_PLAYER_NESTED_INTERRUPTS.wrapping_add(1);
+            return;
+        }
+        active.write_volatile(true);
+    }
+    barrier();
+
+    handle_interrupt(transitions);
+
+    barrier();
+    unsafe {
+        active.write_volatile(false);
+    }
+}
+
+/// Keep the session with the Game Boy Player alive while [`update()`] is not being called.
+///
+/// This should be called from the vblank interrupt handler. On frames where [`update()`] was
+/// called since the previous vblank, it does nothing. Otherwise, such as while the main loop is
+/// stalled by a long load, it starts the next transfer in [`update()`]'s place, so that the Game
+/// Boy Player does not reset the link. Only the transfer is started: frame-based behavior
+/// configured through [`GbpConfig`], such as the watchdog, resumes once [`update()`] is called
+/// again.
+///
+/// Like [`game_boy_player_interrupt()`], this is placed in IWRAM, so that it keeps running
+/// while the game is busy reading from ROM, such as when decompressing data.
+///
+/// ``` rust,ignore
+/// use agb::interrupt::{Interrupt, add_interrupt_handler};
+///
+/// let _vblank = unsafe {
+///     add_interrupt_handler(Interrupt::VBlank, |_| gba_rumble::game_boy_player_keep_alive())
+/// };
+/// ```
+///
+/// [`GbpConfig`]: crate::GbpConfig
+/// [`update()`]: GameBoyPlayer::update()
+#[inline(never)]
+#[cfg_attr(
+    not(feature = "ewram-serial"),
+    unsafe(link_section = ".iwram.gba_rumble.game_boy_player_keep_alive")
+)]
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.game_boy_player_keep_alive")
+)]
+pub fn game_boy_player_keep_alive() {
+    if cfg!(feature = "disabled") {
+        return;
+    }
+    let updated = &raw mut GAME_BOY_PLAYER_UPDATED;
+    unsafe {
+        if updated.read_volatile() {
+            updated.write_volatile(false);
+            return;
+        }
+        if (&raw const GAME_BOY_PLAYER_LINK_FAILED).read_volatile()
+            || (&raw const GAME_BOY_PLAYER_AWAITING_SERIAL).read_volatile()
+        {
+            return;
+        }
+        let siocnt = SIOCNT.read_volatile();
+        if siocnt & (1 << 7) == 0 {
+            GAME_BOY_PLAYER_TRANSFERS = 0;
+            write_siocnt(siocnt | (1 << 7));
+        }
+    }
+}
+
+/// Returns the number of times [`game_boy_player_interrupt()`] was called while another call to it
+/// was still running.
+///
+/// This should always be zero. A nonzero count means nested interrupts are enabled while serial
+/// interrupts are being handled, and some serial interrupts were ignored as a result.
+pub fn game_boy_player_nested_interrupts() -> u32 {
+    unsafe { GAME_BOY_PLAYER_NESTED_INTERRUPTS }
+}
+
+/// Prevents the compiler from moving memory accesses across this point.
+///
+/// On ARMv4T, `compiler_fence()` is lowered to a call to `__sync_synchronize`, which lives in ROM.
+/// An empty `asm!` block has the same effect on the compiler without emitting any code.
+#[inline(always)]
+fn barrier() {
+    unsafe {
+        asm!("", options(nostack, preserves_flags));
+    }
+}
+
+/// Advances communication by responding to the value just received.
+#[inline(always)]
+fn handle_interrupt(transitions: &'static [Transition]) {
+    let input = unsafe { SIODATA.read_volatile() };
+
+    unsafe {
+        (&raw mut GAME_BOY_PLAYER_INBOUND).write_volatile(input);
+        (&raw mut GAME_BOY_PLAYER_INBOUND_COUNT).write_volatile(
+            (&raw const GAME_BOY_PLAYER_INBOUND_COUNT)
+                .read_volatile()
+                .wrapping_add(1),
+        );
+        #[cfg(feature = "diagnostics")]
+        {
+            GAME_BOY_PLAYER_SIO_STATS.interrupts =
+                GAME_BOY_PLAYER_SIO_STATS.interrupts.wrapping_add(1);
+        }
+        GAME_BOY_PLAYER_IDLE_FRAMES = 0;
+        let previous = GAME_BOY_PLAYER_SIO_STATE;
+        GAME_BOY_PLAYER_SIO_STATE = match previous {
+            GameBoyPlayerSioState::SendData => {
+                if input == RUMBLE_REQUEST {
+                    GAME_BOY_PLAYER_IGNORED_VALUES = 0;
+                    GAME_BOY_PLAYER_CONSECUTIVE_RESETS = 0;
+                    let read = GAME_BOY_PLAYER_PENDING_READ;
+                    // Under `OnePerFrame`, only the first transfer of a frame moves on.
+                    let due = !matches!(
+                        GAME_BOY_PLAYER_SETTINGS.command_delivery,
+                        CommandDelivery::OnePerFrame
+                    ) || GAME_BOY_PLAYER_TRANSFERS == 0;
+                    if due && read != (&raw const GAME_BOY_PLAYER_PENDING_WRITE).read_volatile() {
+                        GAME_BOY_PLAYER_RUMBLE =
+                            GAME_BOY_PLAYER_PENDING[read as usize % CommandDelivery::MAX_PENDING];
+                        GAME_BOY_PLAYER_PENDING_READ = read.wrapping_add(1);
+                    }
+                    let rumble = GAME_BOY_PLAYER_RUMBLE;
+                    write_outbound(match GAME_BOY_PLAYER_SETTINGS.keep_alive {
+                        KeepAlive::Word(word) if rumble == GAME_BOY_PLAYER_SENT_RUMBLE => word,
+                        _ => rumble as u32,
+                    });
+                    GAME_BOY_PLAYER_SENT_RUMBLE = rumble;
+                    #[cfg(feature = "diagnostics")]
+                    {
+                        GAME_BOY_PLAYER_SIO_STATS.transfers =
+                            GAME_BOY_PLAYER_SIO_STATS.transfers.wrapping_add(1);
+                    }
+                    GAME_BOY_PLAYER_TRANSFERS = GAME_BOY_PLAYER_TRANSFERS.saturating_add(1);
+                    let limit = GAME_BOY_PLAYER_SETTINGS.transfers_per_frame;
+                    // Throttled transfers are only started by `update()`.
+                    if (limit == 0 || GAME_BOY_PLAYER_TRANSFERS < limit)
+                        && GAME_BOY_PLAYER_SETTINGS.transfer_interval <= 1
+                    {
+                        write_siocnt(SIOCNT.read_volatile() | (1 << 7));
+                    }
+                    // We stay in this state until the input changes.
+                    GameBoyPlayerSioState::SendData
+                } else {
+                    unexpected_input(GameBoyPlayerSioState::SendData)
+                }
+            }
+            state => {
+                if let Some(transition) = state.transition(transitions, input) {
+                    GAME_BOY_PLAYER_IGNORED_VALUES = 0;
+                    GAME_BOY_PLAYER_CONSECUTIVE_RESETS = 0;
+                    write_outbound(transition.response);
+                    write_siocnt(SIOCNT.read_volatile() | (1 << 7));
+                    if transition.next == GameBoyPlayerSioState::SendData {
+                        GAME_BOY_PLAYER_FAILED_HANDSHAKES = 0;
+                        GAME_BOY_PLAYER_CONNECTIONS = GAME_BOY_PLAYER_CONNECTIONS.wrapping_add(1);
+                        #[cfg(feature = "diagnostics")]
+                        {
+                            GAME_BOY_PLAYER_SIO_STATS.connections =
+                                GAME_BOY_PLAYER_SIO_STATS.connections.wrapping_add(1);
+                        }
+                    }
+                    transition.next
+                } else {
+                    unexpected_input(state)
+                }
+            }
+        };
+        if GAME_BOY_PLAYER_SIO_STATE != previous {
+            GAME_BOY_PLAYER_STAGE_CHANGED = GAME_BOY_PLAYER_FRAME;
+        }
+    }
+}
+
+/// The bits of `SIOCNT` that read back as written.
+///
+/// The input line in bit 2 is read-only, and the start bit in bit 7 is cleared by the hardware as
+/// soon as the Game Boy Player completes the transfer.
+#[cfg(feature = "paranoid")]
+const SIOCNT_READ_BACK_MASK: u16 = 0x7000 | 8 | 2 | 1;
+
+/// Writes `value` to `SIOCNT`.
+///
+/// With the `paranoid` feature, this also reads it back, counting a mismatch if any bit that
+/// should hold its value differs.
+#[inline(always)]
+fn write_siocnt(value: u16) {
+    unsafe {
+        SIOCNT.write_volatile(value);
+        #[cfg(feature = "paranoid")]
+        if (SIOCNT.read_volatile() ^ value) & SIOCNT_READ_BACK_MASK != 0 {
+            GAME_BOY_PLAYER_WRITE_MISMATCHES = GAME_BOY_PLAYER_WRITE_MISMATCHES.wrapping_add(1);
+        }
+    }
+}
+
+/// Writes `word` to `SIODATA`, to be sent with the next transfer.
+///
+/// With the `sio-crc` feature, this also adds the word to the outbound CRC. With the `paranoid`
+/// feature, the word is read back, counting a mismatch if it differs.
+#[inline(always)]
+fn write_outbound(word: u32) {
+    unsafe {
+        SIODATA.write_volatile(word);
+        #[cfg(feature = "paranoid")]
+        if SIODATA.read_volatile() != word {
+            GAME_BOY_PLAYER_WRITE_MISMATCHES = GAME_BOY_PLAYER_WRITE_MISMATCHES.wrapping_add(1);
+        }
+        #[cfg(feature = "sio-crc")]
+        {
+            GAME_BOY_PLAYER_OUTBOUND_CRC = crc32_word(GAME_BOY_PLAYER_OUTBOUND_CRC, word);
+        }
+    }
+}
+
+/// Adds the four little-endian bytes of `word` to the running CRC-32 `crc`.
+///
+/// This is the reflected CRC-32 used by zip and PNG, computed a bit at a time to avoid a lookup
+/// table.
+#[cfg(feature = "sio-crc")]
+#[inline(always)]
+const fn crc32_word(crc: u32, word: u32) -> u32 {
+    let mut crc = crc ^ word;
+    let mut bit = 0;
+    while bit < 32 {
+        crc = (crc >> 1) ^ (0xEDB8_8320 & 0u32.wrapping_sub(crc & 1));
+        bit += 1;
+    }
+    crc
+}
+
+/// Handles an unexpected value received in `state`, returning the next state.
+///
+/// The value is ignored, leaving communication in `state`, if fewer consecutive values than the
+/// configured reset tolerance have been ignored so far. Otherwise, communication is reset.
+#[inline(always)]
+fn unexpected_input(state: GameBoyPlayerSioState) -> GameBoyPlayerSioState {
+    unsafe {
+        if state != GameBoyPlayerSioState::new()
+            && GAME_BOY_PLAYER_IGNORED_VALUES < GAME_BOY_PLAYER_SETTINGS.reset_tolerance
+        {
+            GAME_BOY_PLAYER_IGNORED_VALUES = GAME_BOY_PLAYER_IGNORED_VALUES.wrapping_add(1);
+            if GAME_BOY_PLAYER_SETTINGS.auto_update {
+                write_siocnt(SIOCNT.read_volatile() | (1 << 7));
+            }
+            return state;
+        }
+        GAME_BOY_PLAYER_IGNORED_VALUES = 0;
+    }
+    reset_sio_state(state)
+}
+
+/// Returns communication to the start of the handshake after receiving an unexpected value in
+/// `state`.
+///
+/// If auto-update is enabled, the serial port is re-armed so the handshake can begin again
+/// immediately, unless this reset causes the link to be given up on.
+#[inline(always)]
+fn reset_sio_state(state: GameBoyPlayerSioState) -> GameBoyPlayerSioState {
+    unsafe {
+        if state != GameBoyPlayerSioState::new() {
+            GAME_BOY_PLAYER_UNEXPECTED_RESETS = GAME_BOY_PLAYER_UNEXPECTED_RESETS.wrapping_add(1);
+        }
+        #[cfg(feature = "diagnostics")]
+        {
+            GAME_BOY_PLAYER_SIO_STATS.resets = GAME_BOY_PLAYER_SIO_STATS.resets.wrapping_add(1);
+        }
+        GAME_BOY_PLAYER_CONSECUTIVE_RESETS = GAME_BOY_PLAYER_CONSECUTIVE_RESETS.saturating_add(1);
+        if state != GameBoyPlayerSioState::new() && state != GameBoyPlayerSioState::SendData {
+            GAME_BOY_PLAYER_FAILED_HANDSHAKES = GAME_BOY_PLAYER_FAILED_HANDSHAKES.saturating_add(1);
+        }
+        let threshold = GAME_BOY_PLAYER_SETTINGS.link_failure_resets;
+        let attempts = GAME_BOY_PLAYER_SETTINGS.handshake_attempts;
+        let failed = (threshold > 0 && GAME_BOY_PLAYER_CONSECUTIVE_RESETS >= threshold)
+            || (attempts > 0 && GAME_BOY_PLAYER_FAILED_HANDSHAKES >= attempts);
+        if failed {
+            GAME_BOY_PLAYER_LINK_FAILED = true;
+        } else if GAME_BOY_PLAYER_SETTINGS.auto_update {
+            write_siocnt(SIOCNT.read_volatile() | (1 << 7));
+        }
+    }
+    GameBoyPlayerSioState::new()
+}
+
+/// Game Boy Player rumble functionality.
+///
+/// # Setup
+/// To interact with the Game Boy Player's rumble, it must first be detected at the beginning of
+/// your program. This is done using the [`detect()`] function.
+///
+/// To use the Game Boy Player's rumble
+/// after it is detected, [`game_boy_player_interrupt()`] must be called when handling any serial
+/// interrupts received by the interrupt handler. The setup for this will differ depending on your
+/// code; see [`/examples`](https://github.com/Anders429/gba_rumble/tree/master/examples) for
+/// examples of using this function in both the [`gba`](https://crates.io/crates/gba) or
+/// [`agb`](https://crates.io/crates/agba) crates.
+///
+/// The serial port must then be configured, which can be done using [`enable_serial()`].
+///
+/// # Usage
+/// Once a frame, [`update()`] should be called to reset communication with the the Game Boy
+/// Player. That enables communication with the Game Boy Player through the [`start()`],
+/// [`stop()`], and [`hard_stop()`] methods.
+///
+/// # Scene transitions
+/// Once detection has finished, nothing in the session depends on the display. The session's
+/// state lives in work RAM, and communication is driven only by the serial port, serial
+/// interrupts, and calls to [`update()`], counted as frames regardless of how long they take. VRAM,
+/// palette RAM, OAM, and the display registers can therefore be cleared or rewritten freely, such
+/// as when loading a new scene, with the screen blanked for any number of frames, without
+/// interrupting rumble. Only the serial registers belong to the session: resetting them, such as
+/// with the BIOS `RegisterRamReset` call's register flags, requires a call to
+/// [`restart_session()`] afterwards.
+///
+/// [`detect()`]: GameBoyPlayer::detect()
+/// [`enable_serial()`]: GameBoyPlayer::enable_serial()
+/// [`hard_stop()`]: GameBoyPlayer::hard_stop()
+/// [`restart_session()`]: GameBoyPlayer::restart_session()
+/// [`start()`]: GameBoyPlayer::start()
+/// [`stop()`]: GameBoyPlayer::stop()
+/// [`update()`]: GameBoyPlayer::update()
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct GameBoyPlayer {
+    private: (),
+}
+
+impl GameBoyPlayer {
+    /// Identifies a value returned by [`into_raw()`], in its highest byte.
+    ///
+    /// [`into_raw()`]: GameBoyPlayer::into_raw()
+    const RAW_TAG: u32 = 0x47;
+
+    /// The shortest interval accepted by [`enable_timer_updates()`], in ticks of 1024 CPU cycles.
+    ///
+    /// This is the length of one frame, rounded up.
+    ///
+    /// [`enable_timer_updates()`]: GameBoyPlayer::enable_timer_updates()
+    pub const MIN_TIMER_INTERVAL: u16 = 275;
+
+    /// The number of frames taken by [`detect_bounded()`].
+    ///
+    /// [`detect_bounded()`]: GameBoyPlayer::detect_bounded()
+    pub const MAX_DETECTION_FRAMES: u8 = DETECTION_FRAMES;
+
+    /// The most frames [`end_session()`] waits for a hard stop to be transmitted.
+    ///
+    /// [`end_session()`]: GameBoyPlayer::end_session()
+    pub const END_SESSION_FRAMES: u8 = 5;
+
+    /// Detect whether the program is being run on a Game Boy Player.
+    ///
+    /// This should be called at the beginning of your program. It will display the Game Boy Player
+    /// splash screen for a few seconds and listen for inputs from the Game Boy Player itself. If
+    /// the screen is blanked using the forced blank bit of `DISPCNT`, it is unblanked while the
+    /// splash screen is displayed, and blanked again afterwards.
+    ///
+    /// Note that if interrupts are enabled in `IME`, you must have vblank interrupts enabled, or
+    /// this function will hang forever. While `IME` is disabled, such as early in boot, frames are
+    /// waited for by polling `VCOUNT` instead.
+    pub fn detect() -> Option<Self> {
+        Self::detect_with(GbpConfig::new())
+    }
+
+    /// Detect whether the program is being run on a Game Boy Player within a fixed number of
+    /// frames, reporting how long detection took.
+    ///
+    /// This displays the splash screen and listens for the Game Boy Player in the same way as
+    /// [`detect()`], but waits for each frame by polling `VCOUNT` using [`VcountWaiter`], so it
+    /// does not depend on how interrupts are configured. It therefore always returns after exactly
+    /// [`MAX_DETECTION_FRAMES`] frames, which the returned [`Detection`] also reports, making it
+    /// suitable for budgeting boot time.
+    ///
+    /// ``` rust
+    /// use gba_rumble::{Detection, GameBoyPlayer};
+    ///
+    /// match GameBoyPlayer::detect_bounded() {
+    ///     Detection::Detected { after_frames, .. } => {
+    ///         // The Game Boy Player was found `after_frames` frames in.
+    ///     }
+    ///     Detection::NotDetected { frames_waited } => {
+    ///         assert!(frames_waited <= GameBoyPlayer::MAX_DETECTION_FRAMES);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`MAX_DETECTION_FRAMES`]: GameBoyPlayer::MAX_DETECTION_FRAMES
+    /// [`VcountWaiter`]: crate::VcountWaiter
+    /// [`detect()`]: GameBoyPlayer::detect()
+    pub fn detect_bounded() -> Detection {
+        let mut frames = Self::detection_frames(
+            GbpConfig::new().detection(DetectOptions::new().waiter(VcountWaiter)),
+        );
+        for _ in &mut frames {}
+        frames.detection()
+    }
+
+    /// Detect whether the program is being run on a Game Boy Player, using the given
+    /// [`GbpConfig`].
+    ///
+    /// This behaves the same as [`detect()`], except the configuration can customize how the
+    /// Game Boy Player logo is displayed, how long detection takes, and how the Game Boy Player
+    /// is communicated with afterwards. For example, programs that manage the display through a
+    /// framework can draw the logo themselves using a custom [`SplashRenderer`].
+    ///
+    /// Frames are waited for using the configured [`Waiter`]. With the default [`BiosWaiter`], you
+    /// must have vblank interrupts enabled whenever `IME` is, or this function will hang forever.
+    ///
+    /// If the configured [`DetectionPolicy`] skips detection on a warm boot, and this is a warm
+    /// boot, the result of the previous boot's detection is returned immediately instead.
+    ///
+    /// [`BiosWaiter`]: crate::BiosWaiter
+    /// [`DetectionPolicy`]: crate::DetectionPolicy
+    /// [`detect()`]: GameBoyPlayer::detect()
+    pub fn detect_with<R, W>(config: GbpConfig<R, W>) -> Option<Self>
+    where
+        R: SplashRenderer,
+        W: Waiter,
+    {
+        if cfg!(feature = "disabled") {
+            return None;
+        }
+        let policy = config.policy;
+        if policy == DetectionPolicy::SkipOnWarmBoot
+            && BootKind::current() == BootKind::Warm
+            && let Some(detected) = boot::cached_detection()
+        {
+            let detected = detection::forced(detected);
+            unsafe {
+                GAME_BOY_PLAYER_SETTINGS = config.settings;
+                (&raw mut GAME_BOY_PLAYER_DECLINED).write_volatile(false);
+                (&raw mut GAME_BOY_PLAYER_AWAITING_SERIAL)
+                    .write_volatile(config.settings.defer_serial && !config.detection.arm_serial);
+            }
+            restart_handshake_timeout();
+            return detected.then(Self::new);
+        }
+
+        let mut frames = Self::detection_frames(config);
+        for _ in &mut frames {}
+        let game_boy_player = frames.game_boy_player();
+        if policy == DetectionPolicy::SkipOnWarmBoot {
+            boot::cache_detection(game_boy_player.is_some());
+        }
+        game_boy_player
+    }
+
+    /// Detect the Game Boy Player and prepare it for communication.
+    ///
+    /// This detects the Game Boy Player as with [`detect()`]. If it is detected,
+    /// [`game_boy_player_interrupt()`] is registered as the serial interrupt handler using
+    /// `registrar`, and the serial port is configured using [`enable_serial()`]. The returned
+    /// `GameBoyPlayer` is then ready to use, only requiring [`update()`] to be called once a frame.
+    ///
+    /// ``` rust
+    /// use gba_rumble::{GameBoyPlayer, IrqRegistrar};
+    ///
+    /// struct Registrar;
+    ///
+    /// impl IrqRegistrar for Registrar {
+    ///     fn register_serial(&mut self, handler: fn()) {
+    ///         // Install `handler` using your interrupt framework, and enable serial interrupts.
+    ///     }
+    /// }
+    ///
+    /// if let Some(game_boy_player) = GameBoyPlayer::boot(Registrar) {
+    ///     game_boy_player.start();
+    /// }
+    /// ```
+    ///
+    /// [`detect()`]: GameBoyPlayer::detect()
+    /// [`enable_serial()`]: GameBoyPlayer::enable_serial()
+    /// [`update()`]: GameBoyPlayer::update()
+    pub fn boot<I>(registrar: I) -> Option<Self>
+    where
+        I: IrqRegistrar,
+    {
+        Self::boot_with(GbpConfig::new(), registrar)
+    }
+
+    /// Detect the Game Boy Player using the given [`GbpConfig`], and prepare it for
+    /// communication.
+    ///
+    /// This behaves the same as [`boot()`], except detection is performed with [`detect_with()`].
+    /// If the serial port is armed during detection using [`DetectOptions::arm_serial()`], the
+    /// handler is registered before detection begins instead, whether or not the Game Boy Player
+    /// is then detected. If the serial port is deferred using [`GbpConfig::defer_serial()`], the
+    /// handler is registered, but the serial port is left for a later call to
+    /// [`enable_serial()`].
+    ///
+    /// [`boot()`]: GameBoyPlayer::boot()
+    /// [`detect_with()`]: GameBoyPlayer::detect_with()
+    /// [`enable_serial()`]: GameBoyPlayer::enable_serial()
+    pub fn boot_with<R, W, I>(config: GbpConfig<R, W>, mut registrar: I) -> Option<Self>
+    where
+        R: SplashRenderer,
+        W: Waiter,
+        I: IrqRegistrar,
+    {
+        if cfg!(feature = "disabled") {
+            return None;
+        }
+        // The handler is registered first, so that no serial interrupt goes unhandled.
+        let arm_serial = config.detection.arm_serial;
+        if arm_serial {
+            registrar.register_serial(game_boy_player_interrupt_handler());
+        }
+        let defer_serial = config.settings.defer_serial;
+        let game_boy_player = Self::detect_with(config)?;
+        if !arm_serial {
+            let stopwatch = Stopwatch::start();
+            registrar.register_serial(game_boy_player_interrupt_handler());
+            if !defer_serial {
+                game_boy_player.enable_serial();
+            }
+            stopwatch.report(Phase::SerialArming);
+        }
+        Some(game_boy_player)
+    }
+
+    /// Begin detecting the Game Boy Player, one frame at a time, using the given [`GbpConfig`].
+    ///
+    /// This performs the same detection as [`detect_with()`], but returns a [`DetectionFrames`]
+    /// iterator that must be driven by the caller, performing one frame of detection on each
+    /// iteration.
+    ///
+    /// [`detect_with()`]: GameBoyPlayer::detect_with()
+    pub fn detection_frames<R, W>(config: GbpConfig<R, W>) -> DetectionFrames<R, W>
+    where
+        R: SplashRenderer,
+        W: Waiter,
+    {
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS = config.settings;
+            (&raw mut GAME_BOY_PLAYER_DECLINED).write_volatile(false);
+            (&raw mut GAME_BOY_PLAYER_AWAITING_SERIAL)
+                .write_volatile(config.settings.defer_serial && !config.detection.arm_serial);
+        }
+        restart_handshake_timeout();
+        DetectionFrames::new(config.detection)
+    }
+
+    pub(crate) const fn new() -> Self {
+        Self { private: () }
+    }
+
+    /// Activate rumble.
+    ///
+    /// This does nothing while rumble is disabled by [`set_enabled()`], or if rumble is already
+    /// active, so it can be called every frame, such as while a button is held.
+    ///
+    /// [`set_enabled()`]: crate::set_enabled()
+    pub fn start(&self) {
+        if !crate::is_enabled() {
+            return;
+        }
+        if set_rumble(GameBoyPlayerRumble::Start) {
+            unsafe {
+                GAME_BOY_PLAYER_HARD_STOP_FRAMES = 0;
+            }
+        }
+    }
+
+    /// Deactivate rumble.
+    ///
+    /// This does nothing if rumble is already stopped, so it can be called every frame.
+    pub fn stop(&self) {
+        if set_rumble(GameBoyPlayerRumble::Stop) {
+            unsafe {
+                GAME_BOY_PLAYER_HARD_STOP_FRAMES = 0;
+            }
+        }
+    }
+
+    /// Deactivate rumble with a "hard" stop. This has a different feel compared to the [`stop()`] method.
+    ///
+    /// If configured with [`GbpConfig::hard_stop_revert()`], this reverts to a normal stop after
+    /// the configured number of frames. Calling this again while already hard stopped does not
+    /// restart that count.
+    ///
+    /// By default, a hard stop replaced by another command before it is sent, such as by calling
+    /// [`start()`] on the next frame, is never sent. Configure
+    /// [`GbpConfig::command_delivery()`] with [`CommandDelivery::AtLeastOnce`] to send it anyway,
+    /// or with [`CommandDelivery::OnePerFrame`] to also hold it for a whole frame before the
+    /// start.
+    ///
+    /// [`start()`]: GameBoyPlayer::start()
+    /// [`stop()`]: GameBoyPlayer::stop()
+    pub fn hard_stop(&self) {
+        if set_rumble(GameBoyPlayerRumble::HardStop) {
+            unsafe {
+                GAME_BOY_PLAYER_HARD_STOP_FRAMES = GAME_BOY_PLAYER_SETTINGS.hard_stop_revert_frames;
+            }
+        }
+    }
+
+    /// Returns whether the most recently requested rumble state has not yet been sent to the Game
+    /// Boy Player.
+    ///
+    /// A change made by [`start()`], [`stop()`], or [`hard_stop()`] is sent on the next transfer,
+    /// after which this returns `false` until the state changes again.
+    ///
+    /// [`hard_stop()`]: GameBoyPlayer::hard_stop()
+    /// [`start()`]: GameBoyPlayer::start()
+    /// [`stop()`]: GameBoyPlayer::stop()
+    pub fn is_dirty(&self) -> bool {
+        requested_rumble() != unsafe { (&raw const GAME_BOY_PLAYER_SENT_RUMBLE).read_volatile() }
+    }
+
+    /// End the session with the Game Boy Player, hard-stopping the motor first.
+    ///
+    /// If a session is connected, a hard stop is sent, waiting up to [`END_SESSION_FRAMES`] frames
+    /// for it to be transmitted by polling `VCOUNT`. The session is then reset and the serial port
+    /// is disabled, so that the serial interrupt handler is no longer called. A new session can be
+    /// started later with [`enable_serial()`].
+    ///
+    /// This is done automatically when a [`ManagedGameBoyPlayer`] is dropped.
+    ///
+    /// [`END_SESSION_FRAMES`]: GameBoyPlayer::END_SESSION_FRAMES
+    /// [`enable_serial()`]: GameBoyPlayer::enable_serial()
+    pub fn end_session(self) {
+        stop_before_reset(VcountWaiter, Self::END_SESSION_FRAMES);
+    }
+
+    /// Give up the detected Game Boy Player, falling back to cartridge rumble.
+    ///
+    /// This is for games that detect the Game Boy Player but cannot dedicate the serial port to
+    /// it, such as while a link cable feature needs the serial port for itself. The motor is
+    /// stopped, the session is reset, and the serial port is disabled, so that it is free for the
+    /// game's own use. That this happened is recorded, and reported by [`is_declined()`], so that
+    /// the choice can be shown to the player or in logs.
+    ///
+    /// The returned [`Gpio`] drives a rumble cartridge if one is inserted, and otherwise has no
+    /// effect, so it can be used unconditionally in place of the Game Boy Player. The Game Boy
+    /// Player can be used again after detecting it again.
+    ///
+    /// ``` rust
+    /// use gba_rumble::GameBoyPlayer;
+    ///
+    /// if let Some(game_boy_player) = GameBoyPlayer::detect() {
+    ///     // The serial port is needed for link cable play, so rumble through the cartridge instead.
+    ///     let gpio = game_boy_player.decline();
+    ///     gpio.start();
+    /// }
+    /// ```
+    ///
+    /// [`is_declined()`]: GameBoyPlayer::is_declined()
+    pub fn decline(self) -> Gpio {
+        force_stop();
+        disable_serial();
+        unsafe {
+            (&raw mut GAME_BOY_PLAYER_DECLINED).write_volatile(true);
+        }
+        Gpio
+    }
+
+    /// Returns whether the Game Boy Player was given up using [`decline()`] since it was last
+    /// detected.
+    ///
+    /// [`decline()`]: GameBoyPlayer::decline()
+    pub fn is_declined() -> bool {
+        unsafe { (&raw const GAME_BOY_PLAYER_DECLINED).read_volatile() }
+    }
+
+    /// Returns a handle that ends the session when dropped.
+    ///
+    /// See [`ManagedGameBoyPlayer`] for details.
+    pub fn managed(self) -> ManagedGameBoyPlayer {
+        ManagedGameBoyPlayer::new(self)
+    }
+
+    /// Configure the serial port for communication with the Game Boy Player.
+    ///
+    /// This sets `RCNT` and `SIOCNT` to 32-bit normal mode using the external clock, with serial
+    /// interrupts requested. Serial interrupts must still be enabled in `IE` by your interrupt
+    /// setup. This should be called once after detection, before the first call to [`update()`].
+    ///
+    /// If the serial port was deferred using [`GbpConfig::defer_serial()`], this begins
+    /// communication, and the handshake timeout starts from here.
+    ///
+    /// [`update()`]: GameBoyPlayer::update()
+    pub fn enable_serial(&self) {
+        unsafe {
+            RCNT.write_volatile(0);
+            write_siocnt(SIOCNT_CONFIG);
+            if (&raw const GAME_BOY_PLAYER_AWAITING_SERIAL).read_volatile() {
+                (&raw mut GAME_BOY_PLAYER_AWAITING_SERIAL).write_volatile(false);
+                restart_handshake_timeout();
+            }
+        }
+    }
+
+    /// Drive [`update()`] from a hardware timer interrupt, rather than calling it every frame.
+    ///
+    /// This starts `timer`, requesting an interrupt every `interval` ticks of 1024 CPU cycles
+    /// (about 61 microseconds). [`update()`] should then be called from the timer's interrupt
+    /// handler, and the timer's interrupt must be enabled in `IE` using
+    /// [`Timer::interrupt_bit()`]. This is useful for games whose logic does not run once per
+    /// frame.
+    ///
+    /// The Game Boy Player responds at most once a frame, so `interval` is raised to
+    /// [`MIN_TIMER_INTERVAL`] if it is shorter, preventing transfers from being started faster
+    /// than they are answered. Frame counts configured through [`GbpConfig`] count timer
+    /// interrupts instead of frames.
+    ///
+    /// The timer reloads itself in hardware, rather than being re-armed from the handler, so delays
+    /// in servicing its interrupt, such as while the serial or VBlank handlers run, shift
+    /// individual updates without accumulating into drift.
+    ///
+    /// ``` rust
+    /// use gba_rumble::{GameBoyPlayer, Timer};
+    ///
+    /// if let Some(game_boy_player) = GameBoyPlayer::detect() {
+    ///     game_boy_player.enable_serial();
+    ///     // Update roughly every two frames, calling `update()` from the timer 3 interrupt.
+    ///     game_boy_player.enable_timer_updates(Timer::Timer3, 2 * GameBoyPlayer::MIN_TIMER_INTERVAL);
+    /// }
+    /// ```
+    ///
+    /// [`MIN_TIMER_INTERVAL`]: GameBoyPlayer::MIN_TIMER_INTERVAL
+    /// [`update()`]: GameBoyPlayer::update()
+    pub fn enable_timer_updates(&self, timer: Timer, interval: u16) {
+        let interval = interval.max(Self::MIN_TIMER_INTERVAL);
+        unsafe {
+            timer.control().write_volatile(0);
+            // Loaded into the counter on every overflow, so the period is independent of how long
+            // the interrupt takes to be serviced.
+            timer.counter().write_volatile(interval.wrapping_neg());
+            // Enabled, with interrupts requested, counting every 1024 cycles.
+            timer.control().write_volatile(0x80 | 0x40 | 3);
+        }
+    }
+
+    /// Check that the serial port is configured for communication with the Game Boy Player.
+    ///
+    /// If the configuration is wrong, serial interrupts are never received and rumble silently
+    /// does nothing. This is checked automatically by every call to [`update()`], with the result
+    /// available from [`serial_error()`].
+    ///
+    /// [`serial_error()`]: GameBoyPlayer::serial_error()
+    /// [`update()`]: GameBoyPlayer::update()
+    pub fn check_serial(&self) -> Result<(), SerialConfigError> {
+        let rcnt = unsafe { RCNT.read_volatile() };
+        let siocnt = unsafe { SIOCNT.read_volatile() };
+        let ie = unsafe { IE.read_volatile() };
+        if rcnt & (1 << 15) != 0 {
+            Err(SerialConfigError::NotSerialMode)
+        } else if siocnt & 0x3000 != 0x1000 {
+            Err(SerialConfigError::Not32BitNormalMode)
+        } else if siocnt & 1 != 0 {
+            Err(SerialConfigError::InternalClock)
+        } else if siocnt & (1 << 14) == 0 {
+            Err(SerialConfigError::InterruptRequestDisabled)
+        } else if ie & (1 << 7) == 0 {
+            Err(SerialConfigError::InterruptDisabled)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns the misconfiguration of the serial port found by the last call to [`update()`], if
+    /// any.
+    ///
+    /// Each time [`update()`] finds a new misconfiguration, it is also written as a warning through
+    /// the crate's diagnostic output. With the `strict` feature, [`update()`] panics instead.
+    ///
+    /// [`update()`]: GameBoyPlayer::update()
+    pub fn serial_error(&self) -> Option<SerialConfigError> {
+        unsafe { (&raw const GAME_BOY_PLAYER_SERIAL_ERROR).read_volatile() }
+    }
+
+    /// Record session events into `log`, returning the log previously in use, if any.
+    ///
+    /// Once a log is installed, every completed handshake, reset, and change of rumble state is
+    /// recorded into it, stamped with the number of calls to [`update()`] so far. Passing `None`
+    /// stops logging, which is the default, and hands back the log so that its events can be read.
+    /// See [`EventLog`] for details.
+    ///
+    /// Completed handshakes and resets caused by unexpected values are detected by the serial
+    /// interrupt handler, and are logged on the next call to [`update()`], with handshakes logged
+    /// first.
+    ///
+    /// [`update()`]: GameBoyPlayer::update()
+    pub fn set_event_log(&self, log: Option<EventLog<'static>>) -> Option<EventLog<'static>> {
+        unsafe { (&raw mut GAME_BOY_PLAYER_EVENT_LOG).replace(log) }
+    }
+
+    /// Returns the state of the link with the Game Boy Player.
+    pub fn link_status(&self) -> LinkStatus {
+        if unsafe { (&raw const GAME_BOY_PLAYER_AWAITING_SERIAL).read_volatile() } {
+            LinkStatus::AwaitingSerial
+        } else if unsafe { GAME_BOY_PLAYER_UNRESPONSIVE } {
+            LinkStatus::Unresponsive
+        } else if unsafe { (&raw const GAME_BOY_PLAYER_LINK_FAILED).read_volatile() } {
+            LinkStatus::LinkFailed
+        } else if is_connected() {
+            LinkStatus::Connected
+        } else {
+            LinkStatus::Handshaking
+        }
+    }
+
+    /// Returns the stage the session has reached in the protocol.
+    ///
+    /// This is more detailed than [`link_status()`], distinguishing each step of the handshake.
+    ///
+    /// [`link_status()`]: GameBoyPlayer::link_status()
+    pub fn protocol_stage(&self) -> Stage {
+        unsafe { (&raw const GAME_BOY_PLAYER_SIO_STATE).read_volatile() }.stage()
+    }
+
+    /// Returns the stage the session has reached in the protocol, along with the frame at which it
+    /// last changed.
+    ///
+    /// See [`SioState`] for how frames are counted.
+    pub fn sio_state(&self) -> SioState {
+        unsafe {
+            SioState {
+                stage: self.protocol_stage(),
+                changed_frame: (&raw const GAME_BOY_PLAYER_STAGE_CHANGED).read_volatile(),
+                frame: (&raw const GAME_BOY_PLAYER_FRAME).read_volatile(),
+            }
+        }
+    }
+
+    /// Returns the protocol's state machine along with the live state of the session.
+    ///
+    /// This is intended for reporting handshake problems. The returned [`StateMachine`] lists
+    /// every transition of the protocol as implemented by this crate, and can be logged, such as
+    /// through [`mgba_log`](https://docs.rs/mgba_log), to be pasted into a bug report:
+    ///
+    /// ``` rust
+    /// use gba_rumble::GameBoyPlayer;
+    ///
+    /// if let Some(game_boy_player) = GameBoyPlayer::detect() {
+    ///     let state_machine = game_boy_player.debug_state_machine();
+    ///     // log::info!("{state_machine}");
+    /// }
+    /// ```
+    pub fn debug_state_machine(&self) -> StateMachine {
+        let status = serial_status(*self);
+        StateMachine {
+            state: self.protocol_stage(),
+            link: status.link,
+            sent: status.sent,
+            received: session::raw_inbound(),
+        }
+    }
+
+    /// Returns the CRC-32 of every word written to the Game Boy Player so far.
+    ///
+    /// Each word is included as its four bytes in little-endian order, in the order they were
+    /// sent, starting from power on. Comparing this value between hardware and an emulator running
+    /// the same inputs confirms whether both sent identical streams, and narrows down where they
+    /// first diverge. The CRC is updated in the serial interrupt handler, so this is only available
+    /// with the `sio-crc` feature, which is intended for debugging.
+    #[cfg(feature = "sio-crc")]
+    pub fn outbound_crc(&self) -> u32 {
+        !unsafe { (&raw const GAME_BOY_PLAYER_OUTBOUND_CRC).read_volatile() }
+    }
+
+    /// Returns the number of frames between rumble transfers.
+    ///
+    /// This is set with [`GbpConfig::transfer_interval()`] or [`set_transfer_interval()`]. A value
+    /// of `0` or `1` means transfers are made every frame.
+    ///
+    /// [`GbpConfig::transfer_interval()`]: crate::GbpConfig::transfer_interval()
+    /// [`set_transfer_interval()`]: GameBoyPlayer::set_transfer_interval()
+    pub fn transfer_interval(&self) -> u8 {
+        unsafe { (&raw const GAME_BOY_PLAYER_SETTINGS.transfer_interval).read_volatile() }
+    }
+
+    /// Respond to rumble transfers only once every `frames` frames, as configured by
+    /// [`GbpConfig::transfer_interval()`].
+    ///
+    /// This allows throttling to be offered as a compatibility option in a settings menu, for
+    /// players whose link hardware corrupts transfers at full speed. It takes effect on the next
+    /// call to [`update()`]. A [`Driver`] picks up the new interval on its next tick.
+    ///
+    /// [`Driver`]: crate::Driver
+    /// [`GbpConfig::transfer_interval()`]: crate::GbpConfig::transfer_interval()
+    /// [`update()`]: GameBoyPlayer::update()
+    pub fn set_transfer_interval(&self, frames: u8) {
+        unsafe {
+            (&raw mut GAME_BOY_PLAYER_SETTINGS.transfer_interval).write_volatile(frames);
+        }
+    }
+
+    /// Returns the number of writes to `SIOCNT` or `SIODATA` that did not read back as written.
+    ///
+    /// Every write the crate makes to the serial registers is read back, ignoring bits that the
+    /// hardware changes on its own, such as the start bit once a transfer completes. A nonzero
+    /// count points to something else writing to the serial port at the same time, such as
+    /// another interrupt handler, or to an emulator that does not model the registers correctly.
+    /// Reading back costs time in the serial interrupt handler, so this is only available with the
+    /// `paranoid` feature, which is intended for debugging.
+    #[cfg(feature = "paranoid")]
+    pub fn write_mismatches(&self) -> u32 {
+        unsafe { (&raw const GAME_BOY_PLAYER_WRITE_MISMATCHES).read_volatile() }
+    }
+
+    /// Resume communication after the link was given up on.
+    ///
+    /// The handshake begins again on the next call to [`update()`]. Does nothing unless
+    /// [`link_status()`] is [`LinkStatus::LinkFailed`] or [`LinkStatus::Unresponsive`]. In the
+    /// latter case, the handshake timeout starts over.
+    ///
+    /// [`link_status()`]: GameBoyPlayer::link_status()
+    /// [`update()`]: GameBoyPlayer::update()
+    pub fn retry_link(&self) {
+        unsafe {
+            GAME_BOY_PLAYER_CONSECUTIVE_RESETS = 0;
+            GAME_BOY_PLAYER_FAILED_HANDSHAKES = 0;
+            GAME_BOY_PLAYER_LOGGED_FAILURE = false;
+            if GAME_BOY_PLAYER_UNRESPONSIVE {
+                GAME_BOY_PLAYER_UNRESPONSIVE = false;
+                restart_handshake_timeout();
+            }
+            (&raw mut GAME_BOY_PLAYER_LINK_FAILED).write_volatile(false);
+        }
+    }
+
+    /// Start a new session with the Game Boy Player, such as after the serial port was used for
+    /// something else.
+    ///
+    /// Code that takes over the serial port, such as a link cable mode, leaves the session out of
+    /// step with the Game Boy Player. This returns the protocol to the start of the handshake,
+    /// reconfigures the serial port as [`enable_serial()`] does, and starts a transfer so that the
+    /// handshake begins right away. Unlike [`detect()`], the logo is not displayed again.
+    ///
+    /// The most recently requested rumble state is kept, and is sent once the handshake completes.
+    /// A link failure is also cleared, as by [`retry_link()`]. Serial interrupts must still be
+    /// enabled in `IE`.
+    ///
+    /// [`detect()`]: GameBoyPlayer::detect()
+    /// [`enable_serial()`]: GameBoyPlayer::enable_serial()
+    /// [`retry_link()`]: GameBoyPlayer::retry_link()
+    pub fn restart_session(&self) {
+        unsafe {
+            // Stop serial interrupts from being requested while the session is reset.
+            write_siocnt(0);
+            let rumble = requested_rumble();
+            let hard_stop_frames = GAME_BOY_PLAYER_HARD_STOP_FRAMES;
+            reset_session();
+            GAME_BOY_PLAYER_RUMBLE = rumble;
+            GAME_BOY_PLAYER_HARD_STOP_FRAMES = hard_stop_frames;
+            GAME_BOY_PLAYER_TRANSFERS = 0;
+        }
+        self.enable_serial();
+        write_siocnt(SIOCNT_CONFIG | (1 << 7));
+    }
+
+    /// Reset the connection with the Game Boy Player to allow further communication.
+    ///
+    /// This should be called once a frame, or from a timer interrupt configured with
+    /// [`enable_timer_updates()`]. Frame-based behavior configured through [`GbpConfig`] is also
+    /// advanced here.
+    ///
+    /// If the previous transfer is still in progress, such as when the Game Boy Player is slow to
+    /// respond, a new transfer is not started, as doing so could corrupt the exchange. Nor is one
+    /// started while the link has failed, as configured with [`GbpConfig::link_failure_after()`].
+    /// Returns whether a new transfer was started.
+    ///
+    /// Games whose main loop can stall for several frames, such as during long loads, should also
+    /// call [`game_boy_player_keep_alive()`] from their vblank interrupt handler.
+    ///
+    /// While the serial port is deferred using [`GbpConfig::defer_serial()`], this does nothing
+    /// and returns `false`, so it can be called every frame from the start.
+    ///
+    /// The serial configuration is also checked, as by [`check_serial()`]. A misconfiguration is
+    /// written as a warning through the crate's diagnostic output when first found, and is
+    /// available from [`serial_error()`] until it is fixed.
+    ///
+    /// # Panics
+    /// When the `strict` feature is enabled, this panics if the serial port is misconfigured.
+    ///
+    /// [`check_serial()`]: GameBoyPlayer::check_serial()
+    /// [`serial_error()`]: GameBoyPlayer::serial_error()
+    /// [`enable_timer_updates()`]: GameBoyPlayer::enable_timer_updates()
+    pub fn update(&self) -> bool {
+        if unsafe { (&raw const GAME_BOY_PLAYER_AWAITING_SERIAL).read_volatile() } {
+            return false;
+        }
+        let error = self.check_serial().err();
+        #[cfg(feature = "strict")]
+        if let Some(error) = error {
+            panic!("Game Boy Player serial port is misconfigured: {error}");
+        }
+        if let Some(error) = error
+            && Some(error) != self.serial_error()
+        {
+            crate::logging::warning!("Game Boy Player serial port is misconfigured: {error}");
+        }
+        unsafe {
+            (&raw mut GAME_BOY_PLAYER_SERIAL_ERROR).write_volatile(error);
+        }
+        unsafe {
+            let failed = (&raw const GAME_BOY_PLAYER_LINK_FAILED).read_volatile();
+            let interval = (&raw const GAME_BOY_PLAYER_SETTINGS.transfer_interval).read_volatile();
+            let throttled = interval > 1
+                && GAME_BOY_PLAYER_SIO_STATE == GameBoyPlayerSioState::SendData
+                && !GAME_BOY_PLAYER_FRAME.is_multiple_of(u32::from(interval));
+            let siocnt = SIOCNT.read_volatile();
+            let started = !failed && !throttled && siocnt & (1 << 7) == 0;
+            if started {
+                write_siocnt(siocnt | (1 << 7));
+            } else if !failed && !throttled {
+                #[cfg(feature = "diagnostics")]
+                {
+                    GAME_BOY_PLAYER_SIO_STATS.skipped =
+                        GAME_BOY_PLAYER_SIO_STATS.skipped.wrapping_add(1);
+                }
+            }
+            GAME_BOY_PLAYER_FRAMES_SINCE_TRANSFER =
+                if (&raw const GAME_BOY_PLAYER_TRANSFERS).read_volatile() > 0 {
+                    Some(0)
+                } else {
+                    GAME_BOY_PLAYER_FRAMES_SINCE_TRANSFER.map(|frames| frames.saturating_add(1))
+                };
+            metrics::finish_serial_frame(
+                (&raw const GAME_BOY_PLAYER_INBOUND_COUNT).read_volatile(),
+                (&raw const GAME_BOY_PLAYER_TRANSFERS).read_volatile(),
+            );
+            GAME_BOY_PLAYER_TRANSFERS = 0;
+            (&raw mut GAME_BOY_PLAYER_UPDATED).write_volatile(true);
+
+            if let Some(frames) = GAME_BOY_PLAYER_SILENT_FRAMES {
+                if (&raw const GAME_BOY_PLAYER_INBOUND_COUNT).read_volatile()
+                    != GAME_BOY_PLAYER_SESSION_INBOUND
+                {
+                    GAME_BOY_PLAYER_SILENT_FRAMES = None;
+                } else {
+                    let frames = frames.saturating_add(1);
+                    GAME_BOY_PLAYER_SILENT_FRAMES = Some(frames);
+                    let timeout = GAME_BOY_PLAYER_SETTINGS.handshake_timeout_frames;
+                    if timeout > 0 && frames >= timeout && !GAME_BOY_PLAYER_UNRESPONSIVE {
+                        GAME_BOY_PLAYER_UNRESPONSIVE = true;
+                        (&raw mut GAME_BOY_PLAYER_LINK_FAILED).write_volatile(true);
+                        log_event(SessionEvent::LinkFailed);
+                        GAME_BOY_PLAYER_LOGGED_FAILURE = true;
+                    }
+                }
+            }
+
+            let connections = (&raw const GAME_BOY_PLAYER_CONNECTIONS).read_volatile();
+            for _ in 0..connections.wrapping_sub(GAME_BOY_PLAYER_LOGGED_CONNECTIONS) {
+                log_event(SessionEvent::Connected);
+            }
+            GAME_BOY_PLAYER_LOGGED_CONNECTIONS = connections;
+
+            let unexpected_resets = (&raw const GAME_BOY_PLAYER_UNEXPECTED_RESETS).read_volatile();
+            for _ in 0..unexpected_resets.wrapping_sub(GAME_BOY_PLAYER_REPORTED_RESETS) {
+                report_reset(ResetReason::UnexpectedValue);
+            }
+            GAME_BOY_PLAYER_REPORTED_RESETS = unexpected_resets;
+
+            if failed && !GAME_BOY_PLAYER_LOGGED_FAILURE {
+                log_event(SessionEvent::LinkFailed);
+                GAME_BOY_PLAYER_LOGGED_FAILURE = true;
+            }
+
+            let watchdog_frames = GAME_BOY_PLAYER_SETTINGS.watchdog_frames;
+            // Frames without a transfer are only counted if one could have been started.
+            if watchdog_frames > 0 && !throttled {
+                GAME_BOY_PLAYER_IDLE_FRAMES = GAME_BOY_PLAYER_IDLE_FRAMES.saturating_add(1);
+                if GAME_BOY_PLAYER_IDLE_FRAMES >= watchdog_frames {
+                    let reset = GAME_BOY_PLAYER_SIO_STATE != GameBoyPlayerSioState::new();
+                    GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::new();
+                    GAME_BOY_PLAYER_IDLE_FRAMES = 0;
+                    GAME_BOY_PLAYER_IGNORED_VALUES = 0;
+                    if reset {
+                        report_reset(if started {
+                            ResetReason::Timeout
+                        } else {
+                            ResetReason::Busy
+                        });
+                    }
+                }
+            }
+
+            if GAME_BOY_PLAYER_HARD_STOP_FRAMES > 0 {
+                GAME_BOY_PLAYER_HARD_STOP_FRAMES -= 1;
+                if GAME_BOY_PLAYER_HARD_STOP_FRAMES == 0 {
+                    set_rumble(GameBoyPlayerRumble::Stop);
+                }
+            }
+
+            GAME_BOY_PLAYER_FRAME = GAME_BOY_PLAYER_FRAME.wrapping_add(1);
+            started
+        }
+    }
+}
+
+impl GameBoyPlayer {
+    /// Converts the handle into a raw value capturing the state of the Game Boy Player session.
+    ///
+    /// This includes the position of the serial protocol, the current rumble command, and the
+    /// progress of frame-based behavior such as the watchdog. Commands still waiting to be sent
+    /// under [`CommandDelivery::AtLeastOnce`] or [`CommandDelivery::OnePerFrame`] are collapsed
+    /// into the most recent one. Along with [`from_raw()`], this allows the session to be
+    /// persisted by save-state tooling or passed across an FFI boundary. Configuration set through
+    /// [`GbpConfig`] is not included.
+    ///
+    /// The value is laid out as follows:
+    ///
+    /// | Bits    | Contents                                                                 |
+    /// |---------|--------------------------------------------------------------------------|
+    /// | `0-2`   | Protocol position: `0-3` handshake, `4-6` magic values, `7` sending data |
+    /// | `4-5`   | Rumble: `0` for stopped, `1` for started, `2` for hard stopped           |
+    /// | `8-15`  | Frames until a hard stop reverts to a normal stop                        |
+    /// | `16-23` | Frames since the last serial interrupt                                   |
+    /// | `24-31` | Always `0x47`                                                            |
+    ///
+    /// [`from_raw()`]: GameBoyPlayer::from_raw()
+    pub fn into_raw(self) -> u32 {
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE.to_raw()
+                | requested_rumble().to_raw() << 4
+                | (GAME_BOY_PLAYER_HARD_STOP_FRAMES as u32) << 8
+                | (GAME_BOY_PLAYER_IDLE_FRAMES as u32) << 16
+                | Self::RAW_TAG << 24
+        }
+    }
+
+    /// Reconstructs a handle from a value returned by [`into_raw()`], restoring the session state
+    /// it captured.
+    ///
+    /// Returns `None` if `raw` was not returned by [`into_raw()`].
+    ///
+    /// # Safety
+    /// The program must be running on a Game Boy Player whose rumble functionality has been
+    /// unlocked by displaying its logo, such as by an earlier call to [`detect()`] that was then
+    /// restored from a save state.
+    ///
+    /// [`detect()`]: GameBoyPlayer::detect()
+    /// [`into_raw()`]: GameBoyPlayer::into_raw()
+    pub unsafe fn from_raw(raw: u32) -> Option<Self> {
+        Self::restore_raw(raw)?;
+        Some(Self { private: () })
+    }
+
+    /// Restores the session state captured by [`into_raw()`].
+    ///
+    /// [`into_raw()`]: GameBoyPlayer::into_raw()
+    fn restore_raw(raw: u32) -> Option<()> {
+        if raw >> 24 != Self::RAW_TAG || raw & 0b1100_1000 != 0 {
+            return None;
+        }
+        let sio_state = GameBoyPlayerSioState::from_raw(raw & 0b111)?;
+        let rumble = GameBoyPlayerRumble::from_raw(raw >> 4 & 0b11)?;
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = sio_state;
+            GAME_BOY_PLAYER_PENDING_WRITE =
+                (&raw const GAME_BOY_PLAYER_PENDING_READ).read_volatile();
+            GAME_BOY_PLAYER_RUMBLE = rumble;
+            GAME_BOY_PLAYER_HARD_STOP_FRAMES = (raw >> 8) as u8;
+            GAME_BOY_PLAYER_IDLE_FRAMES = (raw >> 16) as u8;
+        }
+        Some(())
+    }
+}
+
+/// Saves the Game Boy Player session state, as returned by [`GameBoyPlayer::into_raw()`].
+impl SaveState for GameBoyPlayer {
+    const STATE_LEN: usize = 4;
+
+    fn serialize_state(&self, buffer: &mut [u8]) -> Result<usize, StateError> {
+        let mut writer = StateWriter::new(buffer, Self::STATE_LEN)?;
+        writer.u32(self.into_raw());
+        Ok(writer.finish())
+    }
+
+    fn restore_state(&mut self, buffer: &[u8]) -> Result<usize, StateError> {
+        let mut reader = StateReader::new(buffer, Self::STATE_LEN)?;
+        Self::restore_raw(reader.u32()?).ok_or(StateError::Invalid)?;
+        Ok(reader.finish())
+    }
+}
+
+/// Returns the session to the start of the handshake, with rumble stopped.
+pub(crate) fn reset_session() {
+    unsafe {
+        if GAME_BOY_PLAYER_SIO_STATE != GameBoyPlayerSioState::new() {
+            GAME_BOY_PLAYER_STAGE_CHANGED = GAME_BOY_PLAYER_FRAME;
+        }
+        GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::new();
+        GAME_BOY_PLAYER_PENDING_WRITE = (&raw const GAME_BOY_PLAYER_PENDING_READ).read_volatile();
+        GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Stop;
+        GAME_BOY_PLAYER_SENT_RUMBLE = GameBoyPlayerRumble::Stop;
+        GAME_BOY_PLAYER_HARD_STOP_FRAMES = 0;
+        GAME_BOY_PLAYER_IDLE_FRAMES = 0;
+        GAME_BOY_PLAYER_IGNORED_VALUES = 0;
+        GAME_BOY_PLAYER_CONSECUTIVE_RESETS = 0;
+        GAME_BOY_PLAYER_FAILED_HANDSHAKES = 0;
+        GAME_BOY_PLAYER_LINK_FAILED = false;
+        GAME_BOY_PLAYER_LOGGED_FAILURE = false;
+        GAME_BOY_PLAYER_FRAMES_SINCE_TRANSFER = None;
+        GAME_BOY_PLAYER_UNRESPONSIVE = false;
+    }
+    restart_handshake_timeout();
+}
+
+/// Starts counting the handshake timeout from the current frame.
+fn restart_handshake_timeout() {
+    unsafe {
+        GAME_BOY_PLAYER_SILENT_FRAMES = Some(0);
+        GAME_BOY_PLAYER_SESSION_INBOUND =
+            (&raw const GAME_BOY_PLAYER_INBOUND_COUNT).read_volatile();
+    }
+}
+
+/// Returns whether rumble is requested, whether or not it has been sent yet.
+pub(crate) fn is_rumble_requested() -> bool {
+    requested_rumble() == GameBoyPlayerRumble::Start
+}
+
+/// Returns a snapshot of the session, for [`Haptics::debug_explain()`].
+///
+/// [`Haptics::debug_explain()`]: crate::Haptics::debug_explain()
+pub(crate) fn serial_status(game_boy_player: GameBoyPlayer) -> SerialStatus {
+    unsafe {
+        SerialStatus {
+            link: game_boy_player.link_status(),
+            sent: match (&raw const GAME_BOY_PLAYER_SENT_RUMBLE).read_volatile() {
+                GameBoyPlayerRumble::Stop => Command::Stop,
+                GameBoyPlayerRumble::HardStop => Command::HardStop,
+                GameBoyPlayerRumble::Start => Command::Start,
+            },
+            frames_since_transfer: GAME_BOY_PLAYER_FRAMES_SINCE_TRANSFER,
+        }
+    }
+}
+
+/// Returns whether the handshake has completed, and rumble states are being sent.
+pub(crate) fn is_connected() -> bool {
+    unsafe {
+        (&raw const GAME_BOY_PLAYER_SIO_STATE).read_volatile() == GameBoyPlayerSioState::SendData
+    }
+}
+
+/// Hard-stops rumble ahead of a soft reset, then ends the session and disables the serial port.
+///
+/// If the Game Boy Player is connected, this first waits up to `frames` frames for the hard stop to
+/// be sent.
+pub(crate) fn stop_before_reset<W>(mut waiter: W, frames: u8)
+where
+    W: Waiter,
+{
+    set_rumble(GameBoyPlayerRumble::HardStop);
+    unsafe {
+        GAME_BOY_PLAYER_HARD_STOP_FRAMES = 0;
+    }
+    let game_boy_player = GameBoyPlayer::new();
+    if is_connected() && game_boy_player.check_serial().is_ok() {
+        // A state is sent in the transfer after the one that prepared it, so the hard stop has been
+        // sent once two transfers complete after every earlier command was sent.
+        let mut transfers = 0u8;
+        for _ in 0..frames {
+            let drained = unsafe {
+                GAME_BOY_PLAYER_PENDING_WRITE
+                    == (&raw const GAME_BOY_PLAYER_PENDING_READ).read_volatile()
+            };
+            game_boy_player.update();
+            waiter.wait_for_vblank();
+            if drained {
+                transfers = transfers.saturating_add(unsafe {
+                    (&raw const GAME_BOY_PLAYER_TRANSFERS).read_volatile()
+                });
+                if transfers >= 2 {
+                    break;
+                }
+            }
+        }
+    }
+    disable_serial();
+}
+
+/// Disables the serial port and ends the session.
+pub(crate) fn disable_serial() {
+    write_siocnt(0);
+    reset_session();
+}
+
+/// Returns whether the Game Boy Player has sent the first key of its handshake since the session
+/// was last reset.
+pub(crate) fn is_handshake_started() -> bool {
+    unsafe {
+        (&raw const GAME_BOY_PLAYER_SIO_STATE).read_volatile() != GameBoyPlayerSioState::new()
+    }
+}
+
+/// Deactivate rumble, without requiring a detected [`GameBoyPlayer`].
+pub(crate) fn force_stop() {
+    set_rumble(GameBoyPlayerRumble::Stop);
+    unsafe {
+        GAME_BOY_PLAYER_HARD_STOP_FRAMES = 0;
+    }
+}
+
+/// Sets the rumble state sent to the Game Boy Player, logging it if it changed. Returns whether
+/// it changed.
+///
+/// Under [`CommandDelivery::AtLeastOnce`] and [`CommandDelivery::OnePerFrame`], the state is
+/// queued to be sent after any states still waiting, rather than replacing them.
+fn set_rumble(rumble: GameBoyPlayerRumble) -> bool {
+    if requested_rumble() == rumble {
+        return false;
+    }
+    log_event(SessionEvent::Command(rumble.to_command()));
+    unsafe {
+        match GAME_BOY_PLAYER_SETTINGS.command_delivery {
+            CommandDelivery::LatestWins => GAME_BOY_PLAYER_RUMBLE = rumble,
+            CommandDelivery::AtLeastOnce | CommandDelivery::OnePerFrame => {
+                let write = GAME_BOY_PLAYER_PENDING_WRITE;
+                let read = (&raw const GAME_BOY_PLAYER_PENDING_READ).read_volatile();
+                if write.wrapping_sub(read) as usize >= CommandDelivery::MAX_PENDING {
+                    // The queue is full, so the newest waiting state is replaced instead.
+                    GAME_BOY_PLAYER_PENDING
+                        [write.wrapping_sub(1) as usize % CommandDelivery::MAX_PENDING] = rumble;
+                } else {
+                    GAME_BOY_PLAYER_PENDING[write as usize % CommandDelivery::MAX_PENDING] = rumble;
+                    barrier();
+                    (&raw mut GAME_BOY_PLAYER_PENDING_WRITE).write_volatile(write.wrapping_add(1));
+                }
+            }
+        }
+    }
+    true
+}
+
+/// Returns the most recently requested rumble state, whether or not it has been sent yet.
+fn requested_rumble() -> GameBoyPlayerRumble {
+    unsafe {
+        let write = GAME_BOY_PLAYER_PENDING_WRITE;
+        if write != (&raw const GAME_BOY_PLAYER_PENDING_READ).read_volatile() {
+            GAME_BOY_PLAYER_PENDING[write.wrapping_sub(1) as usize % CommandDelivery::MAX_PENDING]
+        } else {
+            GAME_BOY_PLAYER_RUMBLE
+        }
+    }
+}
+
+/// Logs a reset, and reports it to the callback registered with [`GbpConfig::on_reset()`].
+fn report_reset(reason: ResetReason) {
+    log_event(SessionEvent::Reset(reason));
+    if let Some(on_reset) = unsafe { GAME_BOY_PLAYER_SETTINGS.on_reset } {
+        on_reset(reason);
+    }
+}
+
+/// Records `event` in the installed event log, if any.
+fn log_event(event: SessionEvent) {
+    unsafe {
+        if let Some(Some(log)) = (&raw mut GAME_BOY_PLAYER_EVENT_LOG).as_mut() {
+            log.push(TimedEvent {
+                frame: GAME_BOY_PLAYER_FRAME,
+                event,
+            });
+        }
+    }
+}
+
+impl Backend for GameBoyPlayer {
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            has_intensity: false,
+            has_brake: true,
+            // Commands are transmitted on the next transfer, which is started by `update()`.
+            latency_frames: 1,
+            shared_with_sio: true,
+        }
+    }
+
+    fn calibration(&self) -> Calibration {
+        Calibration::GAMECUBE_CONTROLLER
+    }
+
+    /// Returns the [`transfer_interval()`], as each command must wait for the next transfer.
+    ///
+    /// [`transfer_interval()`]: GameBoyPlayer::transfer_interval()
+    fn min_command_frames(&self) -> u8 {
+        self.transfer_interval().max(1)
+    }
+
+    fn send(&mut self, command: Command) {
+        match command {
+            Command::Start => self.start(),
+            Command::Stop => self.stop(),
+            Command::HardStop => self.hard_stop(),
+        }
+    }
+
+    /// Returns whether the state last sent in reply to the Game Boy Player's request was
+    /// [`Command::Start`].
+    ///
+    /// A request is only answered once the handshake has completed, so this is `false` until the
+    /// Game Boy Player has acknowledged the session.
+    fn read_back(&self) -> Option<bool> {
+        Some(
+            is_connected()
+                && unsafe { (&raw const GAME_BOY_PLAYER_SENT_RUMBLE).read_volatile() }
+                    == GameBoyPlayerRumble::Start,
+        )
+    }
+}
+
+impl Debug for GameBoyPlayer {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str("GameBoyPlayer")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(static_mut_refs)]
+
+    #[cfg(feature = "sio-crc")]
+    use super::GAME_BOY_PLAYER_OUTBOUND_CRC;
+    use super::{
+        GAME_BOY_PLAYER_AWAITING_SERIAL, GAME_BOY_PLAYER_CONNECTIONS,
+        GAME_BOY_PLAYER_CONSECUTIVE_RESETS, GAME_BOY_PLAYER_FAILED_HANDSHAKES,
+        GAME_BOY_PLAYER_FRAME, GAME_BOY_PLAYER_HARD_STOP_FRAMES, GAME_BOY_PLAYER_IDLE_FRAMES,
+        GAME_BOY_PLAYER_IGNORED_VALUES, GAME_BOY_PLAYER_INTERRUPT_ACTIVE,
+        GAME_BOY_PLAYER_LINK_FAILED, GAME_BOY_PLAYER_LOGGED_CONNECTIONS,
+        GAME_BOY_PLAYER_LOGGED_FAILURE, GAME_BOY_PLAYER_NESTED_INTERRUPTS, GAME_BOY_PLAYER_PENDING,
+        GAME_BOY_PLAYER_PENDING_READ, GAME_BOY_PLAYER_PENDING_WRITE,
+        GAME_BOY_PLAYER_REPORTED_RESETS, GAME_BOY_PLAYER_RUMBLE, GAME_BOY_PLAYER_SENT_RUMBLE,
+        GAME_BOY_PLAYER_SETTINGS, GAME_BOY_PLAYER_SIO_STATE, GAME_BOY_PLAYER_STAGE_CHANGED,
+        GAME_BOY_PLAYER_TRANSFERS, GAME_BOY_PLAYER_TRANSITIONS, GAME_BOY_PLAYER_UNEXPECTED_RESETS,
+        GAME_BOY_PLAYER_UPDATED, GameBoyPlayer, GameBoyPlayerRumble, GameBoyPlayerSioState,
+        LinkStatus, ResetReason, SIODATA, SerialConfigError, SioState, game_boy_player_interrupt,
+        game_boy_player_interrupt_handler, game_boy_player_interrupt_rom,
+        game_boy_player_keep_alive, game_boy_player_nested_interrupts, restart_handshake_timeout,
+        serial_status, stop_before_reset,
+    };
+    use crate::config::GbpSettings;
+    use crate::ranged::RangedUsize;
+    use crate::{
+        Backend, BootKind, Capabilities, Command, CommandDelivery, DetectOptions, DetectionPolicy,
+        EventLog, GbpConfig, IrqRegistrarFn, KeepAlive, SaveState, SessionEvent, SplashRenderer,
+        StateError, TimedEvent, Timer, VcountWaiter, WaitFn, Waiter, boot,
+        protocol::Stage,
+        session::{self, RawInbound},
+    };
+    use alloc::format;
+    use claims::{
+        assert_err_eq, assert_matches, assert_none, assert_ok, assert_ok_eq, assert_some,
+        assert_some_eq,
+    };
+    use core::ptr;
+    use gba_test::test;
+
+    const DISPCNT: *mut u16 = ptr::with_exposed_provenance_mut(0x0400_0000);
+    const DISPSTAT: *mut u16 = ptr::with_exposed_provenance_mut(0x0400_0004);
+    const IME: *mut bool = ptr::with_exposed_provenance_mut(0x0400_0208);
+    const IE: *mut u16 = ptr::with_exposed_provenance_mut(0x0400_0200);
+    const RCNT: *mut u16 = ptr::with_exposed_provenance_mut(0x0400_0134);
+    const SIOCNT: *mut u16 = ptr::with_exposed_provenance_mut(0x0400_0128);
+
+    /// Configures the serial port as required by `GameBoyPlayer::update()`.
+    fn configure_serial() {
+        GameBoyPlayer { private: () }.enable_serial();
+        unsafe {
+            IE.write_volatile(IE.read_volatile() | (1 << 7));
+        }
+    }
+
+    #[derive(Default)]
+    struct CountingSplash {
+        prepared: u8,
+        frames: u8,
+        torn_down: u8,
+    }
+
+    impl SplashRenderer for CountingSplash {
+        fn prepare(&mut self) {
+            self.prepared += 1;
+        }
+
+        fn render_frame(&mut self, frame: u8) {
+            assert_eq!(frame, self.frames);
+            self.frames += 1;
+        }
+
+        fn teardown(&mut self, _waiter: &mut dyn Waiter) {
+            self.torn_down += 1;
+        }
+    }
+
+    fn enable_vblank_interrupts() {
+        unsafe {
+            DISPSTAT.write_volatile(8);
+            IE.write_volatile(1);
+            IME.write(true);
+        }
+    }
+
+    #[test]
+    fn game_boy_player_debug() {
+        assert_eq!(
+            format!("{:?}", GameBoyPlayer { private: () }),
+            "GameBoyPlayer"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(game_boy_player),
+        ignore = "This test should be run on a Game Boy Player (or emulator with Game Boy Player functionality). Pass `--cfg game_boy_player` to enable."
+    )]
+    fn game_boy_player_detect_successful() {
+        unsafe {
+            DISPSTAT.write_volatile(8);
+            IE.write_volatile(1);
+            IME.write(true);
+        }
+        assert_some_eq!(GameBoyPlayer::detect(), GameBoyPlayer { private: () });
+    }
+
+    #[test]
+    #[cfg_attr(
+        game_boy_player,
+        ignore = "This test should be run on a console that is not a Game Boy Player (or emulator with Game Boy Player functionality disabled). Omit `--cfg game_boy_player` to enable."
+    )]
+    fn game_boy_player_detect_failure() {
+        unsafe {
+            DISPSTAT.write_volatile(8);
+            IE.write_volatile(1);
+            IME.write(true);
+        }
+        assert_none!(GameBoyPlayer::detect());
+    }
+
+    #[test]
+    fn game_boy_player_detect_with_calls_splash_renderer() {
+        enable_vblank_interrupts();
+        let mut splash = CountingSplash::default();
+
+        GameBoyPlayer::detect_with(
+            GbpConfig::new().detection(DetectOptions::new().splash(&mut splash)),
+        );
+
+        assert_eq!(splash.prepared, 1);
+        assert_eq!(splash.frames, 125);
+        assert_eq!(splash.torn_down, 1);
+    }
+
+    #[test]
+    fn game_boy_player_detect_with_skips_on_warm_boot() {
+        let mut splash = CountingSplash::default();
+        boot::cache_detection(true);
+        unsafe {
+            boot::CURRENT = Some(BootKind::Warm);
+        }
+
+        let game_boy_player = GameBoyPlayer::detect_with(
+            GbpConfig::new()
+                .detection_policy(DetectionPolicy::SkipOnWarmBoot)
+                .detection(DetectOptions::new().splash(&mut splash)),
+        );
+
+        assert_some!(game_boy_player);
+        assert_eq!(splash.prepared, 0);
+    }
+
+    #[test]
+    fn game_boy_player_detect_with_caches_on_cold_boot() {
+        enable_vblank_interrupts();
+        boot::cache_detection(true);
+        unsafe {
+            boot::CURRENT = Some(BootKind::Cold);
+        }
+
+        let game_boy_player = GameBoyPlayer::detect_with(
+            GbpConfig::new().detection_policy(DetectionPolicy::SkipOnWarmBoot),
+        );
+
+        assert_some_eq!(boot::cached_detection(), game_boy_player.is_some());
+    }
+
+    #[test]
+    fn game_boy_player_detect_with_calls_on_frame() {
+        static mut FRAMES: u8 = 0;
+        fn on_frame() {
+            unsafe {
+                FRAMES += 1;
+            }
+        }
+        enable_vblank_interrupts();
+
+        GameBoyPlayer::detect_with(
+            GbpConfig::new().detection(DetectOptions::new().on_frame(on_frame)),
+        );
+
+        assert_eq!(unsafe { FRAMES }, 125);
+    }
+
+    #[test]
+    fn game_boy_player_detect_with_clears_forced_blank() {
+        /// Records whether the screen was ever blanked while the logo was displayed.
+        #[derive(Default)]
+        struct BlankSplash {
+            blanked: bool,
+        }
+
+        impl SplashRenderer for BlankSplash {
+            fn prepare(&mut self) {}
+
+            fn render_frame(&mut self, _frame: u8) {
+                self.blanked |= unsafe { DISPCNT.read_volatile() } & (1 << 7) != 0;
+            }
+
+            fn teardown(&mut self, _waiter: &mut dyn Waiter) {}
+        }
+
+        let mut splash = BlankSplash::default();
+        unsafe {
+            DISPCNT.write_volatile(0x0403 | (1 << 7));
+        }
+
+        GameBoyPlayer::detect_with(
+            GbpConfig::new().detection(
+                DetectOptions::new()
+                    .splash(&mut splash)
+                    .waiter(WaitFn::new(|| {})),
+            ),
+        );
+        let dispcnt = unsafe { DISPCNT.read_volatile() };
+
+        unsafe {
+            DISPCNT.write_volatile(0);
+        }
+        assert!(!splash.blanked);
+        assert_eq!(dispcnt, 0x0403 | (1 << 7));
+    }
+
+    #[test]
+    fn game_boy_player_detect_with_forced_blank_not_set() {
+        unsafe {
+            DISPCNT.write_volatile(0x0403);
+        }
+
+        GameBoyPlayer::detect_with(
+            GbpConfig::new().detection(
+                DetectOptions::new()
+                    .splash(CountingSplash::default())
+                    .waiter(WaitFn::new(|| {})),
+            ),
+        );
+        let dispcnt = unsafe { DISPCNT.read_volatile() };
+
+        unsafe {
+            DISPCNT.write_volatile(0);
+        }
+        assert_eq!(dispcnt, 0x0403);
+    }
+
+    #[test]
+    fn game_boy_player_detect_with_waiter() {
+        let mut waits = 0;
+        let mut splash = CountingSplash::default();
+
+        // No interrupts are needed when waiting by polling VCOUNT.
+        GameBoyPlayer::detect_with(
+            GbpConfig::new().detection(DetectOptions::new().splash(&mut splash).waiter(
+                WaitFn::new(|| {
+                    waits += 1;
+                    VcountWaiter.wait_for_vblank();
+                }),
+            )),
+        );
+
+        assert_eq!(waits, 125);
+        assert_eq!(splash.frames, 125);
+    }
+
+    #[test]
+    fn game_boy_player_detection_frames() {
+        enable_vblank_interrupts();
+        let mut splash = CountingSplash::default();
+        let mut frames = GameBoyPlayer::detection_frames(
+            GbpConfig::new().detection(DetectOptions::new().splash(&mut splash)),
+        );
+
+        let mut count = 0;
+        for result in &mut frames {
+            assert_eq!(result.frame, count);
+            count += 1;
+        }
+        assert_none!(frames.next());
+
+        assert_eq!(count, 125);
+        assert_eq!(splash.prepared, 1);
+        assert_eq!(splash.frames, 125);
+        assert_eq!(splash.torn_down, 1);
+    }
+
+    #[test]
+    fn game_boy_player_detection_frames_arm_serial() {
+        enable_vblank_interrupts();
+        let mut frames = GameBoyPlayer::detection_frames(
+            GbpConfig::new().detection(
+                DetectOptions::new()
+                    .splash(CountingSplash::default())
+                    .arm_serial(true),
+            ),
+        );
+
+        assert_ne!(unsafe { SIOCNT.read_volatile() } & (1 << 14), 0);
+        let result = assert_some!(frames.next());
+        assert!(!result.handshake);
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::handshake::<1>();
+        }
+        let result = assert_some!(frames.next());
+        assert!(result.handshake);
+        assert!(result.detected);
+        for _ in &mut frames {}
+
+        assert_some!(frames.game_boy_player());
+        assert_ne!(unsafe { SIOCNT.read_volatile() } & (1 << 14), 0);
+        super::disable_serial();
+    }
+
+    #[test]
+    #[cfg_attr(
+        game_boy_player,
+        ignore = "This test should be run on a console that is not a Game Boy Player (or emulator with Game Boy Player functionality disabled). Omit `--cfg game_boy_player` to enable."
+    )]
+    fn game_boy_player_detection_frames_arm_serial_not_detected() {
+        enable_vblank_interrupts();
+        let mut frames = GameBoyPlayer::detection_frames(
+            GbpConfig::new().detection(
+                DetectOptions::new()
+                    .splash(CountingSplash::default())
+                    .arm_serial(true),
+            ),
+        );
+
+        for result in &mut frames {
+            assert!(!result.handshake);
+        }
+
+        assert_none!(frames.game_boy_player());
+        assert_eq!(unsafe { SIOCNT.read_volatile() }, 0);
+    }
+
+    #[test]
+    fn game_boy_player_decline() {
+        configure_serial();
+        let game_boy_player = GameBoyPlayer { private: () };
+        game_boy_player.start();
+
+        game_boy_player.decline();
+
+        assert!(GameBoyPlayer::is_declined());
+        assert_eq!(unsafe { SIOCNT.read_volatile() }, 0);
+        assert_eq!(unsafe { GAME_BOY_PLAYER_RUMBLE }, GameBoyPlayerRumble::Stop);
+    }
+
+    #[test]
+    fn game_boy_player_detection_clears_decline() {
+        GameBoyPlayer { private: () }.decline();
+
+        GameBoyPlayer::detect_with(
+            GbpConfig::new().detection(
+                DetectOptions::new()
+                    .splash(CountingSplash::default())
+                    .waiter(VcountWaiter),
+            ),
+        );
+
+        assert!(!GameBoyPlayer::is_declined());
+    }
+
+    #[test]
+    fn game_boy_player_defer_serial() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        unsafe {
+            SIOCNT.write_volatile(0);
+            (&raw mut GAME_BOY_PLAYER_AWAITING_SERIAL).write_volatile(true);
+        }
+
+        assert_eq!(game_boy_player.link_status(), LinkStatus::AwaitingSerial);
+        assert!(!game_boy_player.update());
+        game_boy_player_keep_alive();
+        assert_eq!(unsafe { SIOCNT.read_volatile() }, 0);
+
+        game_boy_player.enable_serial();
+        let status = game_boy_player.link_status();
+
+        unsafe {
+            SIOCNT.write_volatile(0);
+        }
+        assert_eq!(status, LinkStatus::Handshaking);
+    }
+
+    #[test]
+    fn game_boy_player_detection_defers_serial() {
+        GameBoyPlayer::detect_with(
+            GbpConfig::new()
+                .detection(
+                    DetectOptions::new()
+                        .splash(CountingSplash::default())
+                        .waiter(VcountWaiter),
+                )
+                .defer_serial(true),
+        );
+        let status = GameBoyPlayer { private: () }.link_status();
+
+        GameBoyPlayer { private: () }.enable_serial();
+        unsafe {
+            SIOCNT.write_volatile(0);
+        }
+        assert_eq!(status, LinkStatus::AwaitingSerial);
+    }
+
+    #[test]
+    fn game_boy_player_detection_frames_unfinished() {
+        enable_vblank_interrupts();
+        let mut splash = CountingSplash::default();
+        let mut frames = GameBoyPlayer::detection_frames(
+            GbpConfig::new().detection(DetectOptions::new().splash(&mut splash)),
+        );
+
+        for _ in 0..10 {
+            assert_some!(frames.next());
+        }
+
+        assert_eq!(splash.frames, 10);
+        assert_eq!(splash.torn_down, 0);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(game_boy_player),
+        ignore = "This test should be run on a Game Boy Player (or emulator with Game Boy Player functionality). Pass `--cfg game_boy_player` to enable."
+    )]
+    fn game_boy_player_boot_successful() {
+        enable_vblank_interrupts();
+        let mut registered = None;
+
+        assert_some!(GameBoyPlayer::boot(IrqRegistrarFn::new(|handler| {
+            registered = Some(handler);
+            unsafe {
+                IE.write_volatile(IE.read_volatile() | (1 << 7));
+            }
+        })));
+
+        assert_some!(registered);
+        assert_ok!(GameBoyPlayer { private: () }.check_serial());
+    }
+
+    #[test]
+    #[cfg_attr(
+        game_boy_player,
+        ignore = "This test should be run on a console that is not a Game Boy Player (or emulator with Game Boy Player functionality disabled). Omit `--cfg game_boy_player` to enable."
+    )]
+    fn game_boy_player_boot_failure() {
+        enable_vblank_interrupts();
+        let mut registered = false;
+
+        assert_none!(GameBoyPlayer::boot(IrqRegistrarFn::new(
+            |_| registered = true
+        )));
+
+        assert!(!registered);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(game_boy_player),
+        ignore = "This test should be run on a Game Boy Player (or emulator with Game Boy Player functionality). Pass `--cfg game_boy_player` to enable."
+    )]
+    fn game_boy_player_detect_with_early_exit_successful() {
+        enable_vblank_interrupts();
+        let mut splash = CountingSplash::default();
+
+        assert_some!(GameBoyPlayer::detect_with(GbpConfig::new().detection(
+            DetectOptions::new().splash(&mut splash).early_exit(true)
+        )));
+        assert!(splash.frames < 125);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(game_boy_player),
+        ignore = "This test should be run on a Game Boy Player (or emulator with Game Boy Player functionality). Pass `--cfg game_boy_player` to enable."
+    )]
+    fn game_boy_player_detect_with_early_exit_min_frames() {
+        enable_vblank_interrupts();
+        let mut splash = CountingSplash::default();
+
+        assert_some!(GameBoyPlayer::detect_with(
+            GbpConfig::new().detection(
+                DetectOptions::new()
+                    .splash(&mut splash)
+                    .early_exit(true)
+                    .min_frames(100)
+            )
+        ));
+        assert_eq!(splash.frames, 100);
+    }
+
+    #[test]
+    #[cfg_attr(
+        game_boy_player,
+        ignore = "This test should be run on a console that is not a Game Boy Player (or emulator with Game Boy Player functionality disabled). Omit `--cfg game_boy_player` to enable."
+    )]
+    fn game_boy_player_detect_with_early_exit_failure() {
+        enable_vblank_interrupts();
+        let mut splash = CountingSplash::default();
+
+        assert_none!(GameBoyPlayer::detect_with(GbpConfig::new().detection(
+            DetectOptions::new().splash(&mut splash).early_exit(true)
+        )));
+        assert_eq!(splash.frames, 125);
+    }
+
+    #[test]
+    fn game_boy_player_start() {
+        let game_boy_player = GameBoyPlayer { private: () };
+
+        game_boy_player.start();
+
+        assert_matches!(
+            unsafe { GAME_BOY_PLAYER_RUMBLE },
+            GameBoyPlayerRumble::Start
+        );
+    }
+
+    #[test]
+    fn game_boy_player_start_disabled() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        game_boy_player.stop();
+        crate::set_enabled(false);
+
+        game_boy_player.start();
+        let rumble = unsafe { GAME_BOY_PLAYER_RUMBLE };
+        crate::set_enabled(true);
+
+        assert_matches!(rumble, GameBoyPlayerRumble::Stop);
+    }
+
+    #[test]
+    fn game_boy_player_disable_stops() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        game_boy_player.start();
+
+        crate::set_enabled(false);
+        let rumble = unsafe { GAME_BOY_PLAYER_RUMBLE };
+        crate::set_enabled(true);
+
+        assert_matches!(rumble, GameBoyPlayerRumble::Stop);
+    }
+
+    #[test]
+    fn game_boy_player_stop() {
+        let game_boy_player = GameBoyPlayer { private: () };
+
+        game_boy_player.stop();
+
+        assert_matches!(unsafe { GAME_BOY_PLAYER_RUMBLE }, GameBoyPlayerRumble::Stop);
+    }
+
+    #[test]
+    fn game_boy_player_hard_stop() {
+        let game_boy_player = GameBoyPlayer { private: () };
+
+        game_boy_player.hard_stop();
+
+        assert_matches!(
+            unsafe { GAME_BOY_PLAYER_RUMBLE },
+            GameBoyPlayerRumble::HardStop
+        );
+    }
+
+    #[test]
+    fn game_boy_player_capabilities() {
+        assert_eq!(
+            GameBoyPlayer { private: () }.capabilities(),
+            Capabilities {
+                has_intensity: false,
+                has_brake: true,
+                latency_frames: 1,
+                shared_with_sio: true,
+            }
+        );
+    }
+
+    #[test]
+    fn game_boy_player_send_hard_stop() {
+        let mut game_boy_player = GameBoyPlayer { private: () };
+
+        game_boy_player.send(Command::HardStop);
+
+        assert_matches!(
+            unsafe { GAME_BOY_PLAYER_RUMBLE },
+            GameBoyPlayerRumble::HardStop
+        );
+    }
+
+    #[test]
+    fn game_boy_player_hard_stop_revert() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings {
+                hard_stop_revert_frames: 2,
+                ..GbpSettings::DEFAULT
+            };
+        }
+
+        game_boy_player.hard_stop();
+        game_boy_player.update();
+        assert_eq!(
+            unsafe { GAME_BOY_PLAYER_RUMBLE },
+            GameBoyPlayerRumble::HardStop
+        );
+        game_boy_player.update();
+        assert_eq!(unsafe { GAME_BOY_PLAYER_RUMBLE }, GameBoyPlayerRumble::Stop);
+
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings::DEFAULT;
+        }
+    }
+
+    #[test]
+    fn game_boy_player_hard_stop_revert_repeated() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+        unsafe {
+            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Stop;
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings {
+                hard_stop_revert_frames: 2,
+                ..GbpSettings::DEFAULT
+            };
+        }
+
+        // Hard stopping every frame does not restart the count.
+        game_boy_player.hard_stop();
+        game_boy_player.update();
+        game_boy_player.hard_stop();
+        game_boy_player.update();
+        assert_eq!(unsafe { GAME_BOY_PLAYER_RUMBLE }, GameBoyPlayerRumble::Stop);
+
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings::DEFAULT;
+        }
+    }
+
+    #[test]
+    fn game_boy_player_is_dirty() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        unsafe {
+            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Stop;
+            GAME_BOY_PLAYER_SENT_RUMBLE = GameBoyPlayerRumble::Stop;
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+        }
+        assert!(!game_boy_player.is_dirty());
+
+        game_boy_player.start();
+        assert!(game_boy_player.is_dirty());
+        unsafe {
+            SIODATA.write_volatile(0x30000003);
+        }
+        game_boy_player_interrupt();
+        assert!(!game_boy_player.is_dirty());
+        // Requesting the same state again leaves nothing to send.
+        game_boy_player.start();
+        let dirty = game_boy_player.is_dirty();
+
+        game_boy_player.stop();
+        unsafe {
+            SIOCNT.write_volatile(0);
+        }
+        assert!(!dirty);
+        assert!(game_boy_player.is_dirty());
+    }
+
+    #[test]
+    fn game_boy_player_hard_stop_revert_interrupted() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings {
+                hard_stop_revert_frames: 1,
+                ..GbpSettings::DEFAULT
+            };
+        }
+
+        game_boy_player.hard_stop();
+        game_boy_player.start();
+        game_boy_player.update();
+
+        assert_eq!(
+            unsafe { GAME_BOY_PLAYER_RUMBLE },
+            GameBoyPlayerRumble::Start
+        );
+
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings::DEFAULT;
+        }
+    }
+
+    #[test]
+    fn game_boy_player_hard_stop_no_revert() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+
+        game_boy_player.hard_stop();
+        game_boy_player.update();
+
+        assert_eq!(
+            unsafe { GAME_BOY_PLAYER_RUMBLE },
+            GameBoyPlayerRumble::HardStop
+        );
+    }
+
+    #[test]
+    fn game_boy_player_enable_serial() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        unsafe {
+            RCNT.write_volatile(0x8000);
+            SIOCNT.write_volatile(0);
+            IE.write_volatile(IE.read_volatile() | (1 << 7));
+        }
+
+        game_boy_player.enable_serial();
+
+        assert_ok!(game_boy_player.check_serial());
+    }
+
+    #[test]
+    fn game_boy_player_enable_timer_updates() {
+        let game_boy_player = GameBoyPlayer { private: () };
+
+        game_boy_player.enable_timer_updates(Timer::Timer3, 1000);
+        let counter = unsafe { Timer::Timer3.counter().read_volatile() };
+        let control = unsafe { Timer::Timer3.control().read_volatile() };
+
+        unsafe {
+            Timer::Timer3.control().write_volatile(0);
+        }
+        assert_eq!(control, 0x80 | 0x40 | 3);
+        // The counter may have ticked once since starting.
+        assert!((0u16.wrapping_sub(1000)..=0u16.wrapping_sub(999)).contains(&counter));
+    }
+
+    #[test]
+    fn game_boy_player_enable_timer_updates_min_interval() {
+        let game_boy_player = GameBoyPlayer { private: () };
+
+        game_boy_player.enable_timer_updates(Timer::Timer2, 1);
+        let counter = unsafe { Timer::Timer2.counter().read_volatile() };
+
+        unsafe {
+            Timer::Timer2.control().write_volatile(0);
+        }
+        let reload = GameBoyPlayer::MIN_TIMER_INTERVAL.wrapping_neg();
+        assert!((reload..=reload + 1).contains(&counter));
+    }
+
+    #[test]
+    fn game_boy_player_check_serial_not_serial_mode() {
+        configure_serial();
+        unsafe {
+            RCNT.write_volatile(0x8000);
+        }
+
+        let result = GameBoyPlayer { private: () }.check_serial();
+
+        configure_serial();
+        assert_err_eq!(result, SerialConfigError::NotSerialMode);
+    }
+
+    #[test]
+    fn game_boy_player_check_serial_multiplayer() {
+        configure_serial();
+        unsafe {
+            SIOCNT.write_volatile(0x4000 | 0x2000);
+        }
+
+        let result = GameBoyPlayer { private: () }.check_serial();
+
+        configure_serial();
+        assert_err_eq!(result, SerialConfigError::Not32BitNormalMode);
+    }
+
+    #[test]
+    fn game_boy_player_check_serial_internal_clock() {
+        configure_serial();
+        unsafe {
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 1);
+        }
+
+        let result = GameBoyPlayer { private: () }.check_serial();
+
+        configure_serial();
+        assert_err_eq!(result, SerialConfigError::InternalClock);
+    }
+
+    #[test]
+    fn game_boy_player_check_serial_interrupt_request_disabled() {
+        configure_serial();
+        unsafe {
+            SIOCNT.write_volatile(0x1000);
+        }
+
+        let result = GameBoyPlayer { private: () }.check_serial();
+
+        configure_serial();
+        assert_err_eq!(result, SerialConfigError::InterruptRequestDisabled);
+    }
+
+    #[test]
+    fn game_boy_player_check_serial_interrupt_disabled() {
+        configure_serial();
+        unsafe {
+            IE.write_volatile(IE.read_volatile() & !(1 << 7));
+        }
+
+        let result = GameBoyPlayer { private: () }.check_serial();
+
+        configure_serial();
+        assert_err_eq!(result, SerialConfigError::InterruptDisabled);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(feature = "strict"),
+        ignore = "misconfiguration only panics with the `strict` feature"
+    )]
+    #[should_panic(
+        expected = "Game Boy Player serial port is misconfigured: SIOCNT is not set to 32-bit normal mode (bits 12-13 must be 0b01)"
+    )]
+    fn game_boy_player_update_misconfigured() {
+        configure_serial();
+        unsafe {
+            SIOCNT.write_volatile(0x4000 | 0x3000);
+        }
+
+        GameBoyPlayer { private: () }.update();
+    }
+
+    #[test]
+    #[cfg(not(feature = "strict"))]
+    fn game_boy_player_update_reports_misconfiguration() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+        unsafe {
+            SIOCNT.write_volatile(0x4000 | 0x3000);
+        }
+
+        game_boy_player.update();
+        let error = game_boy_player.serial_error();
+        configure_serial();
+        game_boy_player.update();
+
+        unsafe {
+            SIOCNT.write_volatile(0);
+        }
+        assert_some_eq!(error, SerialConfigError::Not32BitNormalMode);
+        assert_none!(game_boy_player.serial_error());
+    }
+
+    #[test]
+    fn game_boy_player_update_starts_transfer() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+        unsafe {
+            SIOCNT.write_volatile(0x4000 | 0x1000);
+        }
+
+        let started = game_boy_player.update();
+        let siocnt = unsafe { SIOCNT.read_volatile() };
+
+        unsafe {
+            SIOCNT.write_volatile(0);
+        }
+        assert!(started);
+        assert_eq!(siocnt & (1 << 7), 1 << 7);
+    }
+
+    #[test]
+    fn game_boy_player_update_transfer_in_progress() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+        unsafe {
+            SIOCNT.write_volatile(0x4000 | 0x1000 | (1 << 7));
+        }
+
+        let started = game_boy_player.update();
+
+        unsafe {
+            SIOCNT.write_volatile(0);
+        }
+        assert!(!started);
+    }
+
+    #[test]
+    fn game_boy_player_keep_alive_starts_transfer() {
+        unsafe {
+            GAME_BOY_PLAYER_UPDATED = false;
+            GAME_BOY_PLAYER_TRANSFERS = 3;
+            SIOCNT.write_volatile(0x4000 | 0x1000);
+        }
+
+        game_boy_player_keep_alive();
+        let siocnt = unsafe { SIOCNT.read_volatile() };
+
+        unsafe {
+            SIOCNT.write_volatile(0);
+        }
+        assert_eq!(siocnt & (1 << 7), 1 << 7);
+        assert_eq!(unsafe { GAME_BOY_PLAYER_TRANSFERS }, 0);
+    }
+
+    #[test]
+    fn game_boy_player_keep_alive_after_update() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+        game_boy_player.update();
+        unsafe {
+            SIOCNT.write_volatile(0x4000 | 0x1000);
+        }
+
+        // The frame was already updated, so no transfer is started.
+        game_boy_player_keep_alive();
+        let first = unsafe { SIOCNT.read_volatile() };
+        // The main loop has since stalled, so the session is kept alive.
+        game_boy_player_keep_alive();
+        let second = unsafe { SIOCNT.read_volatile() };
+
+        unsafe {
+            SIOCNT.write_volatile(0);
+        }
+        assert_eq!(first & (1 << 7), 0);
+        assert_eq!(second & (1 << 7), 1 << 7);
+    }
+
+    #[test]
+    fn game_boy_player_keep_alive_transfer_in_progress() {
+        unsafe {
+            GAME_BOY_PLAYER_UPDATED = false;
+            GAME_BOY_PLAYER_TRANSFERS = 3;
+            SIOCNT.write_volatile(0x4000 | 0x1000 | (1 << 7));
+        }
+
+        game_boy_player_keep_alive();
+
+        unsafe {
+            SIOCNT.write_volatile(0);
+        }
+        assert_eq!(unsafe { GAME_BOY_PLAYER_TRANSFERS }, 3);
+        unsafe {
+            GAME_BOY_PLAYER_TRANSFERS = 0;
+        }
+    }
+
+    #[test]
+    fn game_boy_player_serial_status_frames_since_transfer() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+            GAME_BOY_PLAYER_SENT_RUMBLE = GameBoyPlayerRumble::Start;
+            GAME_BOY_PLAYER_TRANSFERS = 1;
+        }
+
+        game_boy_player.update();
+        game_boy_player.update();
+        let status = serial_status(game_boy_player);
+
+        unsafe {
+            SIOCNT.write_volatile(0);
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::new();
+            GAME_BOY_PLAYER_SENT_RUMBLE = GameBoyPlayerRumble::Stop;
+        }
+        assert_eq!(status.link, LinkStatus::Connected);
+        assert_eq!(status.sent, Command::Start);
+        assert_some_eq!(status.frames_since_transfer, 1);
+    }
+
+    #[test]
+    fn game_boy_player_session_survives_scene_transition() {
+        const VRAM: *mut u16 = ptr::with_exposed_provenance_mut(0x0600_0000);
+        const PALETTE: *mut u16 = ptr::with_exposed_provenance_mut(0x0500_0000);
+
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Start;
+        }
+        let dispcnt = unsafe { DISPCNT.read_volatile() };
+
+        // Load a new scene: blank the screen, switch modes, and overwrite all of VRAM and palette
+        // RAM, before clearing them again.
+        unsafe {
+            DISPCNT.write_volatile(0x0403 | (1 << 7));
+            for index in 0..0xC000 {
+                VRAM.add(index).write_volatile(0xFFFF);
+            }
+            for index in 0..0x200 {
+                PALETTE.add(index).write_volatile(0x7FFF);
+            }
+        }
+        crate::bios::reset_vram();
+        for _ in 0..3 {
+            game_boy_player.update();
+        }
+        unsafe {
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0x30000003);
+        }
+        game_boy_player_interrupt();
+
+        let sent = unsafe { SIODATA.read_volatile() };
+        let status = game_boy_player.link_status();
+        unsafe {
+            DISPCNT.write_volatile(dispcnt);
+            SIOCNT.write_volatile(0);
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::new();
+            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Stop;
+        }
+        assert_eq!(sent, GameBoyPlayerRumble::Start as u32);
+        assert_eq!(status, LinkStatus::Connected);
+    }
+
+    #[test]
+    fn game_boy_player_update_watchdog() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings {
+                watchdog_frames: 2,
+                ..GbpSettings::DEFAULT
+            };
+            GAME_BOY_PLAYER_IDLE_FRAMES = 0;
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+        }
+
+        game_boy_player.update();
+        assert_eq!(
+            unsafe { &GAME_BOY_PLAYER_SIO_STATE },
+            &GameBoyPlayerSioState::SendData
+        );
+        game_boy_player.update();
+        assert_eq!(
+            unsafe { &GAME_BOY_PLAYER_SIO_STATE },
+            &GameBoyPlayerSioState::new()
+        );
+
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings::DEFAULT;
+        }
+    }
+
+    #[test]
+    fn game_boy_player_update_watchdog_disabled() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+        }
+
+        for _ in 0..=u8::MAX {
+            game_boy_player.update();
+        }
+
+        assert_eq!(
+            unsafe { &GAME_BOY_PLAYER_SIO_STATE },
+            &GameBoyPlayerSioState::SendData
+        );
+    }
+
+    static mut RESETS: [Option<ResetReason>; 2] = [None; 2];
+
+    fn record_reset(reason: ResetReason) {
+        unsafe {
+            RESETS = [RESETS[1], Some(reason)];
+        }
+    }
+
+    fn configure_on_reset() -> GameBoyPlayer {
+        configure_serial();
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings {
+                watchdog_frames: 2,
+                on_reset: Some(record_reset),
+                ..GbpSettings::DEFAULT
+            };
+            GAME_BOY_PLAYER_IDLE_FRAMES = 0;
+            GAME_BOY_PLAYER_UNEXPECTED_RESETS = 0;
+            GAME_BOY_PLAYER_REPORTED_RESETS = 0;
+            RESETS = [None; 2];
+        }
+        GameBoyPlayer { private: () }
+    }
+
+    #[test]
+    fn game_boy_player_event_log() {
+        static mut EVENTS: [Option<TimedEvent>; 8] = [None; 8];
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+        unsafe {
+            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Stop;
+            GAME_BOY_PLAYER_FRAME = 0;
+            GAME_BOY_PLAYER_CONNECTIONS = 0;
+            GAME_BOY_PLAYER_LOGGED_CONNECTIONS = 0;
+            GAME_BOY_PLAYER_UNEXPECTED_RESETS = 0;
+            GAME_BOY_PLAYER_REPORTED_RESETS = 0;
+        }
+        game_boy_player.set_event_log(Some(EventLog::new(
+            unsafe { (&raw mut EVENTS).as_mut() }.unwrap(),
+        )));
+
+        // Stopping while already stopped is not logged.
+        game_boy_player.stop();
+        game_boy_player.start();
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::magic::<3>();
+            SIODATA.write_volatile(0x20000013);
+        }
+        game_boy_player_interrupt();
+        unsafe {
+            SIODATA.write_volatile(0x1234_5678);
+        }
+        game_boy_player_interrupt();
+        game_boy_player.update();
+        game_boy_player.hard_stop();
+
+        let log = assert_some!(game_boy_player.set_event_log(None));
+        unsafe {
+            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Stop;
+            SIOCNT.write_volatile(0);
+        }
+        let mut events = log.iter();
+        assert_some_eq!(
+            events.next(),
+            TimedEvent {
+                frame: 0,
+                event: SessionEvent::Command(Command::Start),
+            }
+        );
+        assert_some_eq!(
+            events.next(),
+            TimedEvent {
+                frame: 0,
+                event: SessionEvent::Connected,
+            }
+        );
+        assert_some_eq!(
+            events.next(),
+            TimedEvent {
+                frame: 0,
+                event: SessionEvent::Reset(ResetReason::UnexpectedValue),
+            }
+        );
+        assert_some_eq!(
+            events.next(),
+            TimedEvent {
+                frame: 1,
+                event: SessionEvent::Command(Command::HardStop),
+            }
+        );
+        assert_none!(events.next());
+    }
+
+    #[test]
+    fn game_boy_player_event_log_hard_stop_revert() {
+        static mut EVENTS: [Option<TimedEvent>; 4] = [None; 4];
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS.hard_stop_revert_frames = 1;
+        }
+        game_boy_player.hard_stop();
+        game_boy_player.set_event_log(Some(EventLog::new(
+            unsafe { (&raw mut EVENTS).as_mut() }.unwrap(),
+        )));
+
+        game_boy_player.update();
+
+        let log = assert_some!(game_boy_player.set_event_log(None));
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings::DEFAULT;
+            SIOCNT.write_volatile(0);
+        }
+        let mut events = log.iter();
+        assert_matches!(
+            events.next(),
+            Some(TimedEvent {
+                event: SessionEvent::Command(Command::Stop),
+                ..
+            })
+        );
+        assert_none!(events.next());
+    }
+
+    #[test]
+    fn game_boy_player_on_reset_timeout() {
+        let game_boy_player = configure_on_reset();
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+        }
+
+        game_boy_player.update();
+        // Complete the transfer without an interrupt, as if the Game Boy Player went quiet.
+        unsafe {
+            SIOCNT.write_volatile(SIOCNT.read_volatile() & !(1 << 7));
+        }
+        game_boy_player.update();
+
+        assert_eq!(unsafe { RESETS }, [None, Some(ResetReason::Timeout)]);
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings::DEFAULT;
+        }
+    }
+
+    #[test]
+    fn game_boy_player_on_reset_busy() {
+        let game_boy_player = configure_on_reset();
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+        }
+
+        // Nothing clocks the transfer, so it is still in progress on the second update.
+        game_boy_player.update();
+        game_boy_player.update();
+
+        assert_eq!(unsafe { RESETS }, [None, Some(ResetReason::Busy)]);
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings::DEFAULT;
+        }
+    }
+
+    #[test]
+    fn game_boy_player_on_reset_unexpected_value() {
+        let game_boy_player = configure_on_reset();
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+            SIODATA.write_volatile(0x1234_5678);
+        }
+        game_boy_player_interrupt();
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+        }
+        game_boy_player_interrupt();
+
+        // Resets from the start of the handshake are not reported.
+        game_boy_player_interrupt();
+        game_boy_player.update();
+
+        assert_eq!(
+            unsafe { RESETS },
+            [
+                Some(ResetReason::UnexpectedValue),
+                Some(ResetReason::UnexpectedValue)
+            ]
+        );
+        unsafe {
+            RESETS = [None; 2];
+        }
+        game_boy_player.update();
+        assert_eq!(unsafe { RESETS }, [None; 2]);
+
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings::DEFAULT;
+        }
+    }
+
+    #[test]
+    fn game_boy_player_on_reset_not_reported_from_start() {
+        let game_boy_player = configure_on_reset();
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::new();
+        }
+
+        game_boy_player.update();
+        game_boy_player.update();
+
+        assert_eq!(unsafe { RESETS }, [None; 2]);
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings::DEFAULT;
+        }
+    }
+
+    /// The handshake and magic phases as originally implemented, with separate key arrays and
+    /// branching, returning the response and next state.
+    fn reference_transition(
+        state: GameBoyPlayerSioState,
+        input: u32,
+    ) -> Option<(u32, GameBoyPlayerSioState)> {
+        const HANDSHAKE: [u16; 4] = [0x494e, 0x544e, 0x4e45, 0x4f44];
+        const MAGIC_VALUES: [u32; 4] = [0xB0BB8002, 0x10000010, 0x20000013, 0x40000004];
+
+        match state {
+            GameBoyPlayerSioState::Handshake { index } => {
+                let key = HANDSHAKE[index.get()];
+                if input as u16 != key {
+                    None
+                } else if (input >> 16) as u16 == !key {
+                    match index.checked_add(1) {
+                        Some(new_index) => Some((
+                            input >> 16 | ((HANDSHAKE[new_index.get()] as u32) << 16),
+                            GameBoyPlayerSioState::Handshake { index: new_index },
+                        )),
+                        None => Some((
+                            0x8000B0BB,
+                            GameBoyPlayerSioState::Magic {
+                                index: RangedUsize::new_static::<1>(),
+                            },
+                        )),
+                    }
+                } else {
+                    Some(((!key) as u32 | ((key as u32) << 16), state))
+                }
+            }
+            GameBoyPlayerSioState::Magic { index } => {
+                if input != MAGIC_VALUES[index.get() - 1] {
+                    None
+                } else {
+                    Some((
+                        MAGIC_VALUES[index.get()],
+                        match index.checked_add(1) {
+                            Some(new_index) => GameBoyPlayerSioState::Magic { index: new_index },
+                            None => GameBoyPlayerSioState::SendData,
+                        },
+                    ))
+                }
+            }
+            GameBoyPlayerSioState::SendData => None,
+        }
+    }
+
+    const TABLE_STATES: [GameBoyPlayerSioState; 7] = [
+        GameBoyPlayerSioState::handshake::<0>(),
+        GameBoyPlayerSioState::handshake::<1>(),
+        GameBoyPlayerSioState::handshake::<2>(),
+        GameBoyPlayerSioState::handshake::<3>(),
+        GameBoyPlayerSioState::magic::<1>(),
+        GameBoyPlayerSioState::magic::<2>(),
+        GameBoyPlayerSioState::magic::<3>(),
+    ];
+
+    fn assert_transition_matches_reference(state: GameBoyPlayerSioState, input: u32) {
+        assert_eq!(
+            state
+                .transition(&GAME_BOY_PLAYER_TRANSITIONS, input)
+                .map(|transition| (transition.response, transition.next)),
+            reference_transition(state, input),
+            "state {state:?}, input {input:#010X}"
+        );
+    }
+
+    #[test]
+    fn game_boy_player_sio_state_transitions_match_reference_expected_inputs() {
+        for state in TABLE_STATES {
+            for transition in &GAME_BOY_PLAYER_TRANSITIONS {
+                assert_transition_matches_reference(state, transition.input);
+                assert_transition_matches_reference(state, transition.response);
+                // Vary the high half-word, which only some transitions care about.
+                for high in [0x0000, 0xFFFF, 0x1234, transition.input >> 16] {
+                    assert_transition_matches_reference(
+                        state,
+                        transition.input & 0xFFFF | high << 16,
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn game_boy_player_sio_state_transitions_match_reference_arbitrary_inputs() {
+        let mut input: u32 = 0x1234_5678;
+        for _ in 0..1000 {
+            for state in TABLE_STATES {
+                assert_transition_matches_reference(state, input);
+            }
+            input = input.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        }
+    }
+
+    #[test]
+    fn game_boy_player_sio_state_send_data_not_in_table() {
+        assert_none!(
+            GameBoyPlayerSioState::SendData.transition(&GAME_BOY_PLAYER_TRANSITIONS, 0x30000003)
+        );
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_handshake_partial_match_0() {
+        unsafe {
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0x0000494E);
+        }
+
+        game_boy_player_interrupt();
+
+        unsafe {
+            assert_eq!(SIODATA.read_volatile(), 0x494EB6B1);
+            assert_eq!(
+                GAME_BOY_PLAYER_SIO_STATE,
+                GameBoyPlayerSioState::Handshake {
+                    index: RangedUsize::new_static::<0>()
+                }
+            );
+        }
+    }
+
+    #[cfg(feature = "sio-crc")]
+    #[test]
+    fn crc32_word() {
+        assert_eq!(!super::crc32_word(u32::MAX, 0), 0x2144_DF1C);
+    }
+
+    #[cfg(feature = "sio-crc")]
+    #[test]
+    fn game_boy_player_interrupt_outbound_crc() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        let before = unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::new();
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0x0000494E);
+            GAME_BOY_PLAYER_OUTBOUND_CRC
+        };
+
+        game_boy_player_interrupt();
+
+        assert_eq!(
+            game_boy_player.outbound_crc(),
+            !super::crc32_word(before, 0x494EB6B1)
+        );
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_sio_state() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::new();
+            GAME_BOY_PLAYER_FRAME = 5;
+            GAME_BOY_PLAYER_STAGE_CHANGED = 0;
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0xB6B1494E);
+        }
+
+        game_boy_player_interrupt();
+        unsafe {
+            GAME_BOY_PLAYER_FRAME = 9;
+        }
+
+        let state = game_boy_player.sio_state();
+        assert_eq!(
+            state,
+            SioState {
+                stage: Stage::Handshake { key: 1 },
+                changed_frame: 5,
+                frame: 9,
+            }
+        );
+        assert_eq!(state.frames_since_change(), 4);
+    }
+
+    #[cfg(feature = "paranoid")]
+    #[test]
+    fn game_boy_player_interrupt_write_mismatches() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+        let before = game_boy_player.write_mismatches();
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::new();
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0xB6B1494E);
+        }
+
+        game_boy_player_interrupt();
+        game_boy_player.update();
+
+        unsafe {
+            SIOCNT.write_volatile(0);
+        }
+        assert_eq!(game_boy_player.write_mismatches(), before);
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_records_inbound() {
+        let before = session::raw_inbound().map_or(0, |inbound| inbound.sequence);
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::new();
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0x0000494E);
+        }
+
+        game_boy_player_interrupt();
+
+        assert_some_eq!(
+            session::raw_inbound(),
+            RawInbound {
+                word: 0x0000494E,
+                sequence: before.wrapping_add(1),
+            }
+        );
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_handshake_partial_match_1() {
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Handshake {
+                index: RangedUsize::new_static::<1>(),
+            };
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0xB6B1544E);
+        }
+
+        game_boy_player_interrupt();
+
+        unsafe {
+            assert_eq!(SIODATA.read_volatile(), 0x544EABB1);
+
+            assert_eq!(
+                GAME_BOY_PLAYER_SIO_STATE,
+                GameBoyPlayerSioState::Handshake {
+                    index: RangedUsize::new_static::<1>()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_handshake_partial_match_2() {
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Handshake {
+                index: RangedUsize::new_static::<2>(),
+            };
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0xABB14E45);
+        }
+
+        game_boy_player_interrupt();
+
+        unsafe {
+            assert_eq!(SIODATA.read_volatile(), 0x4E45B1BA);
+
+            assert_eq!(
+                GAME_BOY_PLAYER_SIO_STATE,
+                GameBoyPlayerSioState::Handshake {
+                    index: RangedUsize::new_static::<2>()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_handshake_partial_match_3() {
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Handshake {
+                index: RangedUsize::new_static::<3>(),
+            };
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0xB1BA4F44);
+        }
+
+        game_boy_player_interrupt();
+
+        unsafe {
+            assert_eq!(SIODATA.read_volatile(), 0x4F44B0BB);
+
+            assert_eq!(
+                GAME_BOY_PLAYER_SIO_STATE,
+                GameBoyPlayerSioState::Handshake {
+                    index: RangedUsize::new_static::<3>()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_handshake_full_match_0() {
+        unsafe {
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0xB6B1494E);
+        }
+
+        game_boy_player_interrupt();
+
+        unsafe {
+            assert_eq!(SIODATA.read_volatile(), 0x544EB6B1);
+            assert_eq!(
+                GAME_BOY_PLAYER_SIO_STATE,
+                GameBoyPlayerSioState::Handshake {
+                    index: RangedUsize::new_static::<1>()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_rom_handshake() {
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::new();
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0xB6B1494E);
+        }
+
+        game_boy_player_interrupt_rom();
+
+        unsafe {
+            assert_eq!(SIODATA.read_volatile(), 0x544EB6B1);
+            assert_eq!(
+                GAME_BOY_PLAYER_SIO_STATE,
+                GameBoyPlayerSioState::handshake::<1>()
+            );
+        }
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_handler_copied() {
+        // The test runner's linker script copies the handler into RAM.
+        assert_eq!(
+            (game_boy_player_interrupt_handler() as *const ()).addr(),
+            (game_boy_player_interrupt as *const ()).addr()
+        );
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_handshake_full_match_1() {
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Handshake {
+                index: RangedUsize::new_static::<1>(),
+            };
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0xABB1544E);
+        }
+
+        game_boy_player_interrupt();
+
+        unsafe {
+            assert_eq!(SIODATA.read_volatile(), 0x4E45ABB1);
+            assert_eq!(
+                GAME_BOY_PLAYER_SIO_STATE,
+                GameBoyPlayerSioState::Handshake {
+                    index: RangedUsize::new_static::<2>()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_handshake_full_match_2() {
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Handshake {
+                index: RangedUsize::new_static::<2>(),
+            };
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0xB1BA4E45);
+        }
+
+        game_boy_player_interrupt();
+
+        unsafe {
+            assert_eq!(SIODATA.read_volatile(), 0x4F44B1BA);
+            assert_eq!(
+                GAME_BOY_PLAYER_SIO_STATE,
+                GameBoyPlayerSioState::Handshake {
+                    index: RangedUsize::new_static::<3>()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_handshake_full_match_3() {
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Handshake {
+                index: RangedUsize::new_static::<3>(),
+            };
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0xB0BB4F44);
+        }
+
+        game_boy_player_interrupt();
+
+        unsafe {
+            assert_eq!(SIODATA.read_volatile(), 0x8000B0BB);
+            assert_eq!(
+                GAME_BOY_PLAYER_SIO_STATE,
+                GameBoyPlayerSioState::Magic {
+                    index: RangedUsize::new_static::<1>()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_handshake_no_match_0() {
+        unsafe {
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0x12345678);
+        }
+
+        game_boy_player_interrupt();
+
+        unsafe {
+            assert_eq!(SIODATA.read_volatile(), 0x12345678);
+            assert_eq!(
+                GAME_BOY_PLAYER_SIO_STATE,
+                GameBoyPlayerSioState::Handshake {
+                    index: RangedUsize::new_static::<0>()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_handshake_no_match_1() {
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Handshake {
+                index: RangedUsize::new_static::<1>(),
+            };
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0x12345678);
+        }
+
+        game_boy_player_interrupt();
+
+        unsafe {
+            assert_eq!(SIODATA.read_volatile(), 0x12345678);
+            assert_eq!(
+                GAME_BOY_PLAYER_SIO_STATE,
+                GameBoyPlayerSioState::Handshake {
+                    index: RangedUsize::new_static::<0>()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_handshake_no_match_2() {
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Handshake {
+                index: RangedUsize::new_static::<2>(),
+            };
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0x12345678);
+        }
+
+        game_boy_player_interrupt();
+
+        unsafe {
+            assert_eq!(SIODATA.read_volatile(), 0x12345678);
+            assert_eq!(
+                GAME_BOY_PLAYER_SIO_STATE,
+                GameBoyPlayerSioState::Handshake {
+                    index: RangedUsize::new_static::<0>()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_handshake_no_match_3() {
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Handshake {
+                index: RangedUsize::new_static::<3>(),
+            };
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0x12345678);
+        }
+
+        game_boy_player_interrupt();
+
+        unsafe {
+            assert_eq!(SIODATA.read_volatile(), 0x12345678);
+            assert_eq!(
+                GAME_BOY_PLAYER_SIO_STATE,
+                GameBoyPlayerSioState::Handshake {
+                    index: RangedUsize::new_static::<0>()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_magic_match_1() {
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Magic {
+                index: RangedUsize::new_static::<1>(),
+            };
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0xB0BB8002);
+        }
+
+        game_boy_player_interrupt();
+
+        unsafe {
+            assert_eq!(SIODATA.read_volatile(), 0x10000010);
+            assert_eq!(
+                GAME_BOY_PLAYER_SIO_STATE,
+                GameBoyPlayerSioState::Magic {
+                    index: RangedUsize::new_static::<2>()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_magic_match_2() {
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Magic {
+                index: RangedUsize::new_static::<2>(),
+            };
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0x10000010);
+        }
+
+        game_boy_player_interrupt();
+
+        unsafe {
+            assert_eq!(SIODATA.read_volatile(), 0x20000013);
+            assert_eq!(
+                GAME_BOY_PLAYER_SIO_STATE,
+                GameBoyPlayerSioState::Magic {
+                    index: RangedUsize::new_static::<3>()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_magic_match_3() {
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Magic {
+                index: RangedUsize::new_static::<3>(),
+            };
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0x20000013);
+        }
+
+        game_boy_player_interrupt();
+
+        unsafe {
+            assert_eq!(SIODATA.read_volatile(), 0x40000004);
+            assert_eq!(GAME_BOY_PLAYER_SIO_STATE, GameBoyPlayerSioState::SendData);
+        }
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_magic_no_match_1() {
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Magic {
+                index: RangedUsize::new_static::<1>(),
+            };
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0x12345678);
+        }
+
+        game_boy_player_interrupt();
+
+        unsafe {
+            assert_eq!(SIODATA.read_volatile(), 0x12345678);
+            assert_eq!(
+                GAME_BOY_PLAYER_SIO_STATE,
+                GameBoyPlayerSioState::Handshake {
+                    index: RangedUsize::new_static::<0>()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_magic_no_match_2() {
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Magic {
+                index: RangedUsize::new_static::<2>(),
+            };
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0x12345678);
+        }
+
+        game_boy_player_interrupt();
+
+        unsafe {
+            assert_eq!(SIODATA.read_volatile(), 0x12345678);
+            assert_eq!(
+                GAME_BOY_PLAYER_SIO_STATE,
+                GameBoyPlayerSioState::Handshake {
+                    index: RangedUsize::new_static::<0>()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_magic_no_match_3() {
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Magic {
+                index: RangedUsize::new_static::<3>(),
+            };
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0x12345678);
+        }
+
+        game_boy_player_interrupt();
+
+        unsafe {
+            assert_eq!(SIODATA.read_volatile(), 0x12345678);
+            assert_eq!(
+                GAME_BOY_PLAYER_SIO_STATE,
+                GameBoyPlayerSioState::Handshake {
+                    index: RangedUsize::new_static::<0>()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_send_data_start() {
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0x30000003);
+            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Start;
+        }
+
+        game_boy_player_interrupt();
+
+        unsafe {
+            assert_eq!(SIODATA.read_volatile(), GameBoyPlayerRumble::Start as u32);
+            assert_eq!(GAME_BOY_PLAYER_SIO_STATE, GameBoyPlayerSioState::SendData);
+        }
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_send_data_stop() {
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0x30000003);
+            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Stop;
+        }
+
+        game_boy_player_interrupt();
+
+        unsafe {
+            assert_eq!(SIODATA.read_volatile(), GameBoyPlayerRumble::Stop as u32);
+            assert_eq!(GAME_BOY_PLAYER_SIO_STATE, GameBoyPlayerSioState::SendData);
+        }
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_send_data_hard_stop() {
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0x30000003);
+            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::HardStop;
+        }
+
+        game_boy_player_interrupt();
+
+        unsafe {
+            assert_eq!(
+                SIODATA.read_volatile(),
+                GameBoyPlayerRumble::HardStop as u32
+            );
+            assert_eq!(GAME_BOY_PLAYER_SIO_STATE, GameBoyPlayerSioState::SendData);
+        }
+    }
+
+    /// Responds to a rumble transfer, returning the rumble state sent.
+    fn send_data() -> u32 {
+        unsafe {
+            SIODATA.write_volatile(0x30000003);
+        }
+        game_boy_player_interrupt();
+        unsafe { SIODATA.read_volatile() }
+    }
+
+    #[test]
+    fn game_boy_player_keep_alive_word() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings {
+                keep_alive: KeepAlive::Word(0x4000_0000),
+                ..GbpSettings::DEFAULT
+            };
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Stop;
+            GAME_BOY_PLAYER_SENT_RUMBLE = GameBoyPlayerRumble::Stop;
+        }
+
+        let unchanged = send_data();
+        game_boy_player.start();
+        let changed = send_data();
+        let repeated = send_data();
+
+        unsafe {
+            SIOCNT.write_volatile(0);
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings::DEFAULT;
+            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Stop;
+            GAME_BOY_PLAYER_SENT_RUMBLE = GameBoyPlayerRumble::Stop;
+        }
+        assert_eq!(unchanged, 0x4000_0000);
+        assert_eq!(changed, GameBoyPlayerRumble::Start as u32);
+        assert_eq!(repeated, 0x4000_0000);
+    }
+
+    #[test]
+    fn game_boy_player_latest_wins() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Stop;
+        }
+
+        game_boy_player.hard_stop();
+        game_boy_player.start();
+
+        assert_eq!(send_data(), GameBoyPlayerRumble::Start as u32);
+
+        unsafe {
+            SIOCNT.write_volatile(0);
+        }
+    }
+
+    #[test]
+    fn game_boy_player_at_least_once() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS.command_delivery = CommandDelivery::AtLeastOnce;
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Stop;
+        }
+
+        game_boy_player.hard_stop();
+        game_boy_player.start();
+
+        assert_eq!(send_data(), GameBoyPlayerRumble::HardStop as u32);
+        assert_eq!(send_data(), GameBoyPlayerRumble::Start as u32);
+        assert_eq!(send_data(), GameBoyPlayerRumble::Start as u32);
+
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings::DEFAULT;
+            SIOCNT.write_volatile(0);
+        }
+    }
+
+    #[test]
+    fn game_boy_player_one_per_frame() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS.command_delivery = CommandDelivery::OnePerFrame;
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Stop;
+            GAME_BOY_PLAYER_TRANSFERS = 0;
+        }
+
+        game_boy_player.hard_stop();
+        game_boy_player.start();
+
+        // The brake is held for the rest of the frame.
+        assert_eq!(send_data(), GameBoyPlayerRumble::HardStop as u32);
+        assert_eq!(send_data(), GameBoyPlayerRumble::HardStop as u32);
+        game_boy_player.update();
+        assert_eq!(send_data(), GameBoyPlayerRumble::Start as u32);
+
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings::DEFAULT;
+            SIOCNT.write_volatile(0);
+        }
+    }
+
+    #[test]
+    fn game_boy_player_at_least_once_skips_repeated_commands() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS.command_delivery = CommandDelivery::AtLeastOnce;
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Stop;
+        }
+
+        game_boy_player.stop();
+        game_boy_player.start();
+        game_boy_player.start();
+
+        assert_eq!(send_data(), GameBoyPlayerRumble::Start as u32);
+        assert_eq!(unsafe { GAME_BOY_PLAYER_PENDING_READ }, unsafe {
+            GAME_BOY_PLAYER_PENDING_WRITE
+        });
+
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings::DEFAULT;
+            SIOCNT.write_volatile(0);
+        }
+    }
+
+    #[test]
+    fn game_boy_player_at_least_once_full() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS.command_delivery = CommandDelivery::AtLeastOnce;
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Stop;
+        }
+
+        game_boy_player.start();
+        game_boy_player.hard_stop();
+        game_boy_player.start();
+        game_boy_player.stop();
+        // The queue is full, so this replaces the final stop.
+        game_boy_player.hard_stop();
+
+        assert_eq!(send_data(), GameBoyPlayerRumble::Start as u32);
+        assert_eq!(send_data(), GameBoyPlayerRumble::HardStop as u32);
+        assert_eq!(send_data(), GameBoyPlayerRumble::Start as u32);
+        assert_eq!(send_data(), GameBoyPlayerRumble::HardStop as u32);
+        assert_eq!(send_data(), GameBoyPlayerRumble::HardStop as u32);
+
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings::DEFAULT;
+            SIOCNT.write_volatile(0);
+        }
+    }
+
+    #[test]
+    fn stop_before_reset_not_connected() {
+        static mut EVENTS: [Option<TimedEvent>; 2] = [None; 2];
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::new();
+            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Start;
+        }
+        game_boy_player.set_event_log(Some(EventLog::new(
+            unsafe { (&raw mut EVENTS).as_mut() }.unwrap(),
+        )));
+
+        stop_before_reset(VcountWaiter, 5);
+
+        let log = assert_some!(game_boy_player.set_event_log(None));
+        assert_matches!(
+            log.iter().next(),
+            Some(TimedEvent {
+                event: SessionEvent::Command(Command::HardStop),
+                ..
+            })
+        );
+        unsafe {
+            assert_eq!(SIOCNT.read_volatile() & (1 << 14), 0);
+            assert_eq!(GAME_BOY_PLAYER_RUMBLE, GameBoyPlayerRumble::Stop);
+        }
+    }
+
+    #[test]
+    fn stop_before_reset_connected() {
+        configure_serial();
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Start;
+            GAME_BOY_PLAYER_HARD_STOP_FRAMES = 3;
+        }
+
+        // Without a Game Boy Player transferring, this gives up after waiting.
+        stop_before_reset(VcountWaiter, 2);
+
+        unsafe {
+            assert_eq!(SIOCNT.read_volatile() & (1 << 14), 0);
+            assert_eq!(GAME_BOY_PLAYER_SIO_STATE, GameBoyPlayerSioState::new());
+            assert_eq!(GAME_BOY_PLAYER_RUMBLE, GameBoyPlayerRumble::Stop);
+            assert_eq!(GAME_BOY_PLAYER_HARD_STOP_FRAMES, 0);
+        }
+    }
+
+    #[test]
+    fn game_boy_player_at_least_once_into_raw() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS.command_delivery = CommandDelivery::AtLeastOnce;
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Stop;
+        }
+        game_boy_player.hard_stop();
+        game_boy_player.start();
+
+        let raw = game_boy_player.into_raw();
+        assert_some!(unsafe { GameBoyPlayer::from_raw(raw) });
+
+        // Waiting commands are collapsed into the most recent one.
+        assert_eq!(send_data(), GameBoyPlayerRumble::Start as u32);
+
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings::DEFAULT;
+            SIOCNT.write_volatile(0);
+        }
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_send_data_no_match() {
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0x12345678);
+        }
+
+        game_boy_player_interrupt();
+
+        unsafe {
+            assert_eq!(SIODATA.read_volatile(), 0x12345678);
+            assert_eq!(
+                GAME_BOY_PLAYER_SIO_STATE,
+                GameBoyPlayerSioState::Handshake {
+                    index: RangedUsize::new_static::<0>()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_reset_tolerance() {
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS.reset_tolerance = 2;
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Magic {
+                index: RangedUsize::new_static::<1>(),
+            };
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+        }
+
+        for _ in 0..2 {
+            unsafe {
+                SIODATA.write_volatile(0x12345678);
+            }
+            game_boy_player_interrupt();
+            unsafe {
+                assert_eq!(
+                    GAME_BOY_PLAYER_SIO_STATE,
+                    GameBoyPlayerSioState::Magic {
+                        index: RangedUsize::new_static::<1>()
+                    }
+                );
+            }
+        }
+        unsafe {
+            SIODATA.write_volatile(0x12345678);
+        }
+        game_boy_player_interrupt();
+
+        unsafe {
+            assert_eq!(
+                GAME_BOY_PLAYER_SIO_STATE,
+                GameBoyPlayerSioState::Handshake {
+                    index: RangedUsize::new_static::<0>()
+                }
+            );
+            assert_eq!(GAME_BOY_PLAYER_IGNORED_VALUES, 0);
+            assert_eq!(GAME_BOY_PLAYER_UNEXPECTED_RESETS, 1);
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings::DEFAULT;
+        }
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_reset_tolerance_consecutive() {
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS.reset_tolerance = 1;
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+        }
+
+        // An expected value in between restarts the count of ignored values.
+        for input in [0x12345678, 0x30000003, 0x12345678] {
+            unsafe {
+                SIODATA.write_volatile(input);
+            }
+            game_boy_player_interrupt();
+        }
+
+        unsafe {
+            assert_eq!(GAME_BOY_PLAYER_SIO_STATE, GameBoyPlayerSioState::SendData);
+            assert_eq!(GAME_BOY_PLAYER_IGNORED_VALUES, 1);
+            SIOCNT.write_volatile(0);
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings::DEFAULT;
+        }
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_link_failure() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings {
+                auto_update: true,
+                link_failure_resets: 2,
+                ..GbpSettings::DEFAULT
+            };
+            GAME_BOY_PLAYER_CONSECUTIVE_RESETS = 0;
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0x12345678);
+        }
+
+        game_boy_player_interrupt();
+        assert_eq!(game_boy_player.link_status(), LinkStatus::Handshaking);
+        unsafe {
+            assert_eq!(SIOCNT.read_volatile() & (1 << 7), 1 << 7);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+        }
+        game_boy_player_interrupt();
+
+        let siocnt = unsafe { SIOCNT.read_volatile() };
+        let status = game_boy_player.link_status();
+        game_boy_player.retry_link();
+        unsafe {
+            SIOCNT.write_volatile(0);
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings::DEFAULT;
+        }
+        assert_eq!(siocnt & (1 << 7), 0);
+        assert_eq!(status, LinkStatus::LinkFailed);
+        assert_eq!(game_boy_player.link_status(), LinkStatus::Handshaking);
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_handshake_attempts() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings {
+                auto_update: true,
+                handshake_attempts: 2,
+                ..GbpSettings::DEFAULT
+            };
+            GAME_BOY_PLAYER_FAILED_HANDSHAKES = 0;
+            RCNT.write_volatile(0);
+        }
+
+        // Each attempt gets partway through the handshake before failing.
+        for _ in 0..2 {
+            unsafe {
+                GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::handshake::<2>();
+                SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+                SIODATA.write_volatile(0x12345678);
+            }
+            game_boy_player_interrupt();
+        }
+
+        let siocnt = unsafe { SIOCNT.read_volatile() };
+        let status = game_boy_player.link_status();
+        game_boy_player.retry_link();
+        let failed_handshakes = unsafe { GAME_BOY_PLAYER_FAILED_HANDSHAKES };
+        unsafe {
+            SIOCNT.write_volatile(0);
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings::DEFAULT;
+        }
+        assert_eq!(siocnt & (1 << 7), 0);
+        assert_eq!(status, LinkStatus::LinkFailed);
+        assert_eq!(failed_handshakes, 0);
+        assert_eq!(game_boy_player.link_status(), LinkStatus::Handshaking);
+    }
+
+    #[test]
+    fn game_boy_player_restart_session() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Start;
+            GAME_BOY_PLAYER_SENT_RUMBLE = GameBoyPlayerRumble::Start;
+            GAME_BOY_PLAYER_LINK_FAILED = true;
+            // Another system left the serial port in multiplayer mode.
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x2000);
+        }
+
+        game_boy_player.restart_session();
+
+        let siocnt = unsafe { SIOCNT.read_volatile() };
+        let status = game_boy_player.link_status();
+        let dirty = game_boy_player.is_dirty();
+        let rumble = unsafe { GAME_BOY_PLAYER_RUMBLE };
+        let state = unsafe { GAME_BOY_PLAYER_SIO_STATE };
+        game_boy_player.stop();
+        unsafe {
+            SIOCNT.write_volatile(0);
+        }
+        assert_eq!(siocnt & !(1 << 7), 0x4000 | 0x1000 | 8);
+        assert_eq!(status, LinkStatus::Handshaking);
+        assert_eq!(state, GameBoyPlayerSioState::new());
+        assert_eq!(rumble, GameBoyPlayerRumble::Start);
+        assert!(dirty);
+    }
+
+    #[test]
+    fn game_boy_player_debug_state_machine() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::magic::<2>();
+        }
+
+        let state_machine = game_boy_player.debug_state_machine();
+
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::new();
+        }
+        assert_eq!(state_machine.state, Stage::Magic { value: 2 });
+        assert_eq!(state_machine.link, LinkStatus::Handshaking);
+        assert_eq!(state_machine.sent, Command::Stop);
+    }
+
+    #[test]
+    fn game_boy_player_protocol_stage() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::handshake::<3>();
+        }
+
+        let stage = game_boy_player.protocol_stage();
+
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::new();
+        }
+        assert_eq!(stage, Stage::Handshake { key: 3 });
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_link_failure_consecutive() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS.link_failure_resets = 2;
+            GAME_BOY_PLAYER_CONSECUTIVE_RESETS = 0;
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::new();
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+        }
+
+        // An expected value in between restarts the count of resets.
+        for input in [0x12345678, 0x0000494E, 0x12345678] {
+            unsafe {
+                SIODATA.write_volatile(input);
+            }
+            game_boy_player_interrupt();
+        }
+
+        let status = game_boy_player.link_status();
+        game_boy_player.retry_link();
+        unsafe {
+            SIOCNT.write_volatile(0);
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings::DEFAULT;
+        }
+        assert_eq!(status, LinkStatus::Handshaking);
+    }
+
+    #[test]
+    fn game_boy_player_update_handshake_timeout() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings {
+                handshake_timeout_frames: 2,
+                ..GbpSettings::DEFAULT
+            };
+        }
+        restart_handshake_timeout();
+
+        game_boy_player.update();
+        let waiting = game_boy_player.link_status();
+        game_boy_player.update();
+        let status = game_boy_player.link_status();
+        let started = game_boy_player.update();
+        game_boy_player.retry_link();
+        let retried = game_boy_player.link_status();
+
+        unsafe {
+            SIOCNT.write_volatile(0);
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings::DEFAULT;
+        }
+        assert_eq!(waiting, LinkStatus::Handshaking);
+        assert_eq!(status, LinkStatus::Unresponsive);
+        assert!(!started);
+        assert_eq!(retried, LinkStatus::Handshaking);
+    }
+
+    #[test]
+    fn game_boy_player_update_link_failed() {
+        static mut EVENTS: [Option<TimedEvent>; 4] = [None; 4];
+        let game_boy_player = GameBoyPlayer { private: () };
+        configure_serial();
+        unsafe {
+            GAME_BOY_PLAYER_FRAME = 0;
+            GAME_BOY_PLAYER_LINK_FAILED = true;
+            GAME_BOY_PLAYER_LOGGED_FAILURE = false;
+            GAME_BOY_PLAYER_UNEXPECTED_RESETS = 0;
+            GAME_BOY_PLAYER_REPORTED_RESETS = 0;
+            GAME_BOY_PLAYER_CONNECTIONS = 0;
+            GAME_BOY_PLAYER_LOGGED_CONNECTIONS = 0;
+        }
+        game_boy_player.set_event_log(Some(EventLog::new(
+            unsafe { (&raw mut EVENTS).as_mut() }.unwrap(),
+        )));
+
+        let failed = [game_boy_player.update(), game_boy_player.update()];
+        let siocnt = unsafe { SIOCNT.read_volatile() };
+        game_boy_player.retry_link();
+        let retried = game_boy_player.update();
+
+        let log = assert_some!(game_boy_player.set_event_log(None));
+        unsafe {
+            SIOCNT.write_volatile(0);
+        }
+        assert_eq!(failed, [false, false]);
+        assert_eq!(siocnt & (1 << 7), 0);
+        assert!(retried);
+        let mut events = log.iter();
+        assert_some_eq!(
+            events.next(),
+            TimedEvent {
+                frame: 0,
+                event: SessionEvent::LinkFailed,
+            }
+        );
+        assert_none!(events.next());
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_send_data_transfer_limit() {
+        unsafe {
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings {
+                transfers_per_frame: 1,
+                ..GbpSettings::DEFAULT
+            };
+            GAME_BOY_PLAYER_TRANSFERS = 0;
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0x30000003);
+        }
+
+        game_boy_player_interrupt();
+
+        unsafe {
+            assert_eq!(SIOCNT.read_volatile() & (1 << 7), 0);
+            assert_eq!(GAME_BOY_PLAYER_SIO_STATE, GameBoyPlayerSioState::SendData);
+
+            GAME_BOY_PLAYER_SETTINGS = GbpSettings::DEFAULT;
+        }
+    }
+
+    #[test]
+    fn game_boy_player_transfer_interval() {
+        let game_boy_player = GameBoyPlayer { private: () };
+        game_boy_player.set_transfer_interval(3);
+        configure_serial();
+        unsafe {
+            GAME_BOY_PLAYER_FRAME = 0;
+            GAME_BOY_PLAYER_TRANSFERS = 0;
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0x30000003);
+        }
+
+        // The handler leaves the next transfer to `update()`.
+        game_boy_player_interrupt();
+        let rearmed = unsafe { SIOCNT.read_volatile() } & (1 << 7) != 0;
+        let started = [(); 6].map(|()| {
+            let started = game_boy_player.update();
+            unsafe {
+                SIOCNT.write_volatile(SIOCNT.read_volatile() & !(1 << 7));
+            }
+            started
+        });
+        let min_command_frames = game_boy_player.min_command_frames();
+        game_boy_player.set_transfer_interval(0);
+        unsafe {
+            SIOCNT.write_volatile(0);
+        }
+
+        assert!(!rearmed);
+        assert_eq!(started, [true, false, false, true, false, false]);
+        assert_eq!(min_command_frames, 3);
+        assert_eq!(game_boy_player.min_command_frames(), 1);
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_resets_idle_frames() {
+        unsafe {
+            GAME_BOY_PLAYER_IDLE_FRAMES = 5;
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0x30000003);
+        }
+
+        game_boy_player_interrupt();
+
+        assert_eq!(unsafe { GAME_BOY_PLAYER_IDLE_FRAMES }, 0);
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_nested() {
+        let nested = game_boy_player_nested_interrupts();
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::new();
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0xB6B1494E);
+            // Simulate interrupting a call that is already running.
+            GAME_BOY_PLAYER_INTERRUPT_ACTIVE = true;
+        }
+
+        game_boy_player_interrupt();
+
+        unsafe {
+            GAME_BOY_PLAYER_INTERRUPT_ACTIVE = false;
+            assert_eq!(SIODATA.read_volatile(), 0xB6B1494E);
+            assert_eq!(GAME_BOY_PLAYER_SIO_STATE, GameBoyPlayerSioState::new());
+        }
+        assert_eq!(game_boy_player_nested_interrupts(), nested + 1);
+    }
+
+    #[test]
+    fn game_boy_player_interrupt_not_nested() {
+        let nested = game_boy_player_nested_interrupts();
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::new();
+            RCNT.write_volatile(0);
+            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
+            SIODATA.write_volatile(0xB6B1494E);
+        }
+
+        game_boy_player_interrupt();
+        game_boy_player_interrupt();
+
+        assert!(!unsafe { GAME_BOY_PLAYER_INTERRUPT_ACTIVE });
+        assert_eq!(game_boy_player_nested_interrupts(), nested);
+    }
+
+    // The handler's calls and constant data are checked against the release build's relocations
+    // in CI, since debug builds call into ROM for the standard library's checks.
+    #[test]
+    fn interrupt_placement() {
+        #[cfg(not(feature = "ewram-serial"))]
+        let ram = 0x0300_0000..0x0300_8000;
+        #[cfg(feature = "ewram-serial")]
+        let ram = 0x0200_0000..0x0204_0000;
+
+        assert!(ram.contains(&((game_boy_player_interrupt as *const ()).addr())));
+        assert!(ram.contains(&((game_boy_player_keep_alive as *const ()).addr())));
+        assert!(ram.contains(&(GAME_BOY_PLAYER_TRANSITIONS.as_ptr().addr())));
+    }
+
+    #[test]
+    fn interrupt_state_not_in_rom() {
+        let rom = 0x0800_0000..0x1000_0000;
+
+        assert!(!rom.contains(&(&raw const GAME_BOY_PLAYER_RUMBLE).addr()));
+        assert!(!rom.contains(&(&raw const GAME_BOY_PLAYER_SIO_STATE).addr()));
+        assert!(!rom.contains(&(&raw const GAME_BOY_PLAYER_SETTINGS).addr()));
+        assert!(!rom.contains(&(&raw const GAME_BOY_PLAYER_IDLE_FRAMES).addr()));
+        assert!(!rom.contains(&(&raw const GAME_BOY_PLAYER_INTERRUPT_ACTIVE).addr()));
+        assert!(!rom.contains(&(&raw const GAME_BOY_PLAYER_NESTED_INTERRUPTS).addr()));
+        assert!(!rom.contains(&(&raw const GAME_BOY_PLAYER_TRANSFERS).addr()));
+        assert!(!rom.contains(&(&raw const GAME_BOY_PLAYER_UPDATED).addr()));
+        assert!(!rom.contains(&(&raw const GAME_BOY_PLAYER_IGNORED_VALUES).addr()));
+        assert!(!rom.contains(&(&raw const GAME_BOY_PLAYER_CONNECTIONS).addr()));
+        assert!(!rom.contains(&(&raw const GAME_BOY_PLAYER_PENDING).addr()));
+        assert!(!rom.contains(&(&raw const GAME_BOY_PLAYER_PENDING_READ).addr()));
+        assert!(!rom.contains(&(&raw const GAME_BOY_PLAYER_PENDING_WRITE).addr()));
+    }
+
+    #[test]
+    fn raw_round_trip() {
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Magic {
+                index: RangedUsize::new_static::<2>(),
+            };
+            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::HardStop;
+            GAME_BOY_PLAYER_HARD_STOP_FRAMES = 5;
+            GAME_BOY_PLAYER_IDLE_FRAMES = 3;
+        }
+
+        let raw = GameBoyPlayer { private: () }.into_raw();
+
+        assert_eq!(raw, 0x4703_0525);
+
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::new();
+            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Stop;
+            GAME_BOY_PLAYER_HARD_STOP_FRAMES = 0;
+            GAME_BOY_PLAYER_IDLE_FRAMES = 0;
+        }
+
+        assert_some_eq!(
+            unsafe { GameBoyPlayer::from_raw(raw) },
+            GameBoyPlayer { private: () }
+        );
+        unsafe {
+            assert_eq!(
+                GAME_BOY_PLAYER_SIO_STATE,
+                GameBoyPlayerSioState::Magic {
+                    index: RangedUsize::new_static::<2>(),
+                }
+            );
+            assert_eq!(GAME_BOY_PLAYER_RUMBLE, GameBoyPlayerRumble::HardStop);
+            assert_eq!(GAME_BOY_PLAYER_HARD_STOP_FRAMES, 5);
+            assert_eq!(GAME_BOY_PLAYER_IDLE_FRAMES, 3);
+        }
+    }
+
+    #[test]
+    fn raw_sio_states() {
+        for raw in 0..8 {
+            assert_eq!(
+                assert_some!(GameBoyPlayerSioState::from_raw(raw)).to_raw(),
+                raw
+            );
+        }
+        assert_none!(GameBoyPlayerSioState::from_raw(8));
+    }
+
+    #[test]
+    fn from_raw_invalid() {
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::new();
+        }
+
+        // Missing tag.
+        assert_none!(unsafe { GameBoyPlayer::from_raw(0x0000_0007) });
+        // Unknown rumble state.
+        assert_none!(unsafe { GameBoyPlayer::from_raw(0x4700_0037) });
+        // Reserved bits set.
+        assert_none!(unsafe { GameBoyPlayer::from_raw(0x4700_0087) });
+
+        assert_eq!(
+            unsafe { GAME_BOY_PLAYER_SIO_STATE },
+            GameBoyPlayerSioState::new()
+        );
+    }
+
+    #[test]
+    fn save_state() {
+        let mut game_boy_player = GameBoyPlayer { private: () };
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
+            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Start;
+            GAME_BOY_PLAYER_HARD_STOP_FRAMES = 0;
+            GAME_BOY_PLAYER_IDLE_FRAMES = 1;
+        }
+        let mut buffer = [0; GameBoyPlayer::STATE_LEN];
+
+        assert_ok_eq!(game_boy_player.serialize_state(&mut buffer), 4);
+        assert_eq!(buffer, [0x17, 0x00, 0x01, 0x47]);
+
+        unsafe {
+            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::new();
+            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Stop;
+        }
+
+        assert_ok_eq!(game_boy_player.restore_state(&buffer), 4);
+        unsafe {
+            assert_eq!(GAME_BOY_PLAYER_SIO_STATE, GameBoyPlayerSioState::SendData);
+            assert_eq!(GAME_BOY_PLAYER_RUMBLE, GameBoyPlayerRumble::Start);
+        }
+        assert_err_eq!(game_boy_player.restore_state(&[0; 4]), StateError::Invalid);
+    }
+}
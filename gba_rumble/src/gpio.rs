@@ -0,0 +1,482 @@
+//! Cartridge rumble and pin access through general purpose I/O.
+
+use crate::{Backend, Calibration, Capabilities, Command, logging, regs};
+use core::{
+    fmt,
+    fmt::{Display, Formatter},
+    ptr,
+};
+
+const DATA: *mut u16 = ptr::with_exposed_provenance_mut(regs::GPIO_DATA);
+const READ_WRITE: *mut u16 = ptr::with_exposed_provenance_mut(regs::GPIO_DIRECTION);
+const ENABLE: *mut u16 = ptr::with_exposed_provenance_mut(regs::GPIO_CONTROL);
+
+/// The bit of each GPIO register used by the rumble pin.
+const RUMBLE_PIN: u16 = Pin::P3.mask();
+
+/// The value last written to the direction register.
+///
+/// The port's registers are write-only unless made readable, so the values written are tracked
+/// here, allowing single pins to be changed without disturbing the others.
+static mut GPIO_DIRECTION_BITS: u16 = 0;
+/// The value last written to the data register.
+static mut GPIO_DATA_BITS: u16 = 0;
+/// Whether rumble was last started, rather than stopped.
+static mut GPIO_RUMBLING: bool = false;
+/// Whether writes to the port are read back to check that they took effect.
+static mut GPIO_VERIFY_WRITES: bool = false;
+
+/// A pin of the GPIO port.
+///
+/// Cartridges with rumble drive the motor with [`Pin::P3`]. The other pins are commonly wired to
+/// a real-time clock, or to whatever hardware a modified cartridge adds.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Pin {
+    /// Pin 0, the lowest bit of each GPIO register.
+    P0,
+    /// Pin 1.
+    P1,
+    /// Pin 2.
+    P2,
+    /// Pin 3, which drives the rumble motor.
+    P3,
+}
+
+impl Pin {
+    /// Returns the bit of each GPIO register corresponding to this pin.
+    const fn mask(self) -> u16 {
+        1 << self as u16
+    }
+}
+
+/// Whether a GPIO [`Pin`] is read from or written to.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PinDirection {
+    /// The pin is read from, as driven by the cartridge hardware. This is the state at boot.
+    Input,
+    /// The pin is written to, driving the cartridge hardware.
+    Output,
+}
+
+/// Writes `bits` to both the direction register and its tracked value.
+fn write_direction(bits: u16) {
+    unsafe {
+        (&raw mut GPIO_DIRECTION_BITS).write_volatile(bits);
+    }
+    write_verified(READ_WRITE, bits, PORT_MASK);
+}
+
+/// Writes `bits` to both the data register and its tracked value.
+fn write_data(bits: u16) {
+    unsafe {
+        (&raw mut GPIO_DATA_BITS).write_volatile(bits);
+    }
+    // Only output pins read back the value written.
+    write_verified(DATA, bits, unsafe {
+        (&raw const GPIO_DIRECTION_BITS).read_volatile()
+    });
+}
+
+/// The bits of each GPIO register corresponding to a pin.
+const PORT_MASK: u16 = 0b1111;
+
+/// Writes `bits` to `register`, then, if enabled with [`Gpio::set_verify_writes()`], reads back
+/// the bits in `mask` to check that the write took effect.
+///
+/// A write that did not take effect is retried, up to [`Gpio::WRITE_ATTEMPTS`] writes in total,
+/// after which [`GpioConflict::WriteLost`] is recorded. Nothing is checked while the port is
+/// write-only, since reads return the ROM contents instead.
+fn write_verified(register: *mut u16, bits: u16, mask: u16) {
+    unsafe {
+        register.write_volatile(bits);
+        if !(&raw const GPIO_VERIFY_WRITES).read_volatile() || ENABLE.read_volatile() & 1 == 0 {
+            return;
+        }
+        for _ in 1..Gpio::WRITE_ATTEMPTS {
+            if register.read_volatile() & mask == bits & mask {
+                return;
+            }
+            register.write_volatile(bits);
+        }
+        if register.read_volatile() & mask != bits & mask {
+            let conflict = GpioConflict::WriteLost;
+            logging::warning!("GPIO write not applied: {conflict}");
+            (&raw mut GPIO_CONFLICT).write_volatile(Some(conflict));
+        }
+    }
+}
+/// The most recently detected conflict that has not yet been taken.
+static mut GPIO_CONFLICT: Option<GpioConflict> = None;
+
+/// A change made to the GPIO port by other code while rumble was active.
+///
+/// The GPIO port is shared with other cartridge hardware, most commonly a real-time clock. Code
+/// driving that hardware can reconfigure the whole port, turning the rumble motor off without the
+/// rumble driver knowing. These conflicts are reported by [`Gpio::check_port()`] and
+/// [`Gpio::take_conflict()`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum GpioConflict {
+    /// The port was made write-only, so its state can no longer be read back.
+    ///
+    /// This does not stop the motor by itself, but means the remaining checks cannot be made.
+    ControlDisabled,
+    /// The rumble pin was switched to an input, stopping the motor.
+    DirectionChanged,
+    /// The rumble pin was driven low, stopping the motor.
+    DataCleared,
+    /// A write to the port did not take effect, even after being retried.
+    ///
+    /// This is only detected while writes are verified, as enabled by
+    /// [`Gpio::set_verify_writes()`].
+    WriteLost,
+}
+
+impl Display for GpioConflict {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::ControlDisabled => "GPIO control was cleared, making the port write-only",
+            Self::DirectionChanged => "GPIO direction of the rumble pin (bit 3) was set to input",
+            Self::DataCleared => "GPIO rumble pin (bit 3) was driven low",
+            Self::WriteLost => "GPIO register did not hold the value written to it",
+        })
+    }
+}
+
+impl core::error::Error for GpioConflict {}
+
+/// Cartridge rumble functionality.
+///
+/// Communication with the cartridge's rumble motor is done through General Purpose I/O (GPIO).
+/// Specifically, this interacts using bit 3 (which is the standard pin used for rumble). Note that
+/// this may interfere with other communications done through GPIO, such as with a real-time clock
+/// device (they do not use the same bits, but they share the same address space).
+///
+/// Unlike [`GameBoyPlayer`], no setup is required to interact with GPIO rumble. Simply use an
+/// instance of `Gpio` to start and stop rumble:
+///
+/// ```rust
+/// let gpio = gba_rumble::Gpio;
+///
+/// // Activate the cartridge's rumble. This will continue until `stop()` is called.
+/// gpio.start();
+///
+/// // Deactivate the cartridge's rumble.
+/// gpio.stop();
+/// ```
+///
+/// If other code also uses the GPIO port, it may reconfigure the port while rumble is active,
+/// silently stopping the motor. [`check_port()`] detects this, with the most recent conflict kept
+/// until it is read with [`take_conflict()`]. With the `diagnostics` feature enabled, each detected
+/// conflict is also logged. The port is only checked when asked to, since without GPIO hardware,
+/// reads return the ROM contents instead, which would be reported as conflicts.
+///
+/// # Pin access
+/// For advanced uses, such as modified cartridges, each pin of the port can also be controlled
+/// directly with [`set_direction()`], [`write_pin()`], and [`read_pin()`]. Changes to a single pin
+/// leave the others untouched, including the rumble pin, so pins can be used alongside rumble.
+/// Writing [`Pin::P3`] directly bypasses rumble, including [`set_enabled()`].
+///
+/// [`check_port()`]: Gpio::check_port()
+/// [`read_pin()`]: Gpio::read_pin()
+/// [`set_direction()`]: Gpio::set_direction()
+/// [`set_enabled()`]: crate::set_enabled()
+/// [`take_conflict()`]: Gpio::take_conflict()
+/// [`write_pin()`]: Gpio::write_pin()
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Gpio;
+
+impl Gpio {
+    /// The most times a write is made before giving up, while writes are verified.
+    ///
+    /// See [`set_verify_writes()`] for details.
+    ///
+    /// [`set_verify_writes()`]: Gpio::set_verify_writes()
+    pub const WRITE_ATTEMPTS: u8 = 3;
+
+    /// Activate rumble.
+    ///
+    /// This does nothing while rumble is disabled by [`set_enabled()`].
+    ///
+    /// The port is configured in a fixed order, so that the motor only ever sees a single edge:
+    /// 1. The port is enabled, making it readable, so that the writes after it can be verified.
+    /// 2. The rumble pin is made an output, driving the data bit last written, which [`stop()`]
+    ///    leaves low.
+    /// 3. The rumble pin is driven high, starting the motor.
+    ///
+    /// [`set_enabled()`]: crate::set_enabled()
+    /// [`stop()`]: Gpio::stop()
+    pub fn start(&self) {
+        if !crate::is_enabled() {
+            return;
+        }
+        unsafe {
+            ENABLE.write_volatile(1);
+            write_direction((&raw const GPIO_DIRECTION_BITS).read_volatile() | RUMBLE_PIN);
+            write_data((&raw const GPIO_DATA_BITS).read_volatile() | RUMBLE_PIN);
+            (&raw mut GPIO_RUMBLING).write_volatile(true);
+        }
+    }
+
+    /// Deactivate rumble.
+    ///
+    /// The port is not checked for conflicts, so that stopping is safe without GPIO hardware. Call
+    /// [`check_port()`] first to detect a conflict that happened during the rumble.
+    ///
+    /// Only the data bit is cleared. The rumble pin stays an output, actively driven low, since a
+    /// floating input can be picked up by the motor's driver as a brief pulse. Boards that still
+    /// glitch when stopping can have the write checked using [`set_verify_writes()`].
+    ///
+    /// [`check_port()`]: Gpio::check_port()
+    /// [`set_verify_writes()`]: Gpio::set_verify_writes()
+    pub fn stop(&self) {
+        if cfg!(feature = "disabled") {
+            return;
+        }
+        unsafe {
+            write_data((&raw const GPIO_DATA_BITS).read_volatile() & !RUMBLE_PIN);
+            (&raw mut GPIO_RUMBLING).write_volatile(false);
+        }
+    }
+
+    /// Checks that the port is still configured for rumble, if rumble is active.
+    ///
+    /// Returns the conflict found if other code has changed the port since [`start()`], such as a
+    /// real-time clock driver reconfiguring every pin. The conflict is also kept for
+    /// [`take_conflict()`]. Nothing is checked while rumble is stopped, since [`start()`]
+    /// configures the port afresh.
+    ///
+    /// This can be called once a frame to catch conflicts as soon as they happen.
+    ///
+    /// [`start()`]: Gpio::start()
+    /// [`take_conflict()`]: Gpio::take_conflict()
+    pub fn check_port(&self) -> Result<(), GpioConflict> {
+        if !unsafe { (&raw const GPIO_RUMBLING).read_volatile() } {
+            return Ok(());
+        }
+        // While the port is write-only, reads return the ROM contents instead.
+        let conflict = if unsafe { ENABLE.read_volatile() } & 1 == 0 {
+            GpioConflict::ControlDisabled
+        } else if unsafe { READ_WRITE.read_volatile() } & RUMBLE_PIN == 0 {
+            GpioConflict::DirectionChanged
+        } else if unsafe { DATA.read_volatile() } & RUMBLE_PIN == 0 {
+            GpioConflict::DataCleared
+        } else {
+            return Ok(());
+        };
+        logging::warning!("GPIO port changed while rumbling: {conflict}");
+        unsafe {
+            (&raw mut GPIO_CONFLICT).write_volatile(Some(conflict));
+        }
+        Err(conflict)
+    }
+
+    /// Returns the most recent conflict detected by [`check_port()`], clearing it.
+    ///
+    /// [`check_port()`]: Gpio::check_port()
+    pub fn take_conflict(&self) -> Option<GpioConflict> {
+        unsafe {
+            let conflict = (&raw const GPIO_CONFLICT).read_volatile();
+            (&raw mut GPIO_CONFLICT).write_volatile(None);
+            conflict
+        }
+    }
+
+    /// Sets whether each write to the port is read back to check that it took effect.
+    ///
+    /// Some boards occasionally drop a write, which can leave the motor running for a frame after
+    /// [`stop()`]. While verification is on, every register write made through `Gpio`, including
+    /// by [`start()`] and [`stop()`], is read back and retried until it holds, up to
+    /// [`WRITE_ATTEMPTS`] writes in total. A write that never takes effect is reported as
+    /// [`GpioConflict::WriteLost`] by [`take_conflict()`]. Nothing is checked while the port is
+    /// write-only, such as before the first [`start()`]. This is off by default.
+    ///
+    /// [`start()`]: Gpio::start()
+    /// [`stop()`]: Gpio::stop()
+    /// [`take_conflict()`]: Gpio::take_conflict()
+    /// [`WRITE_ATTEMPTS`]: Gpio::WRITE_ATTEMPTS
+    pub fn set_verify_writes(&self, verify: bool) {
+        unsafe {
+            (&raw mut GPIO_VERIFY_WRITES).write_volatile(verify);
+        }
+    }
+
+    /// Returns whether writes to the port are verified, as set by [`set_verify_writes()`].
+    ///
+    /// [`set_verify_writes()`]: Gpio::set_verify_writes()
+    pub fn verifies_writes(&self) -> bool {
+        unsafe { (&raw const GPIO_VERIFY_WRITES).read_volatile() }
+    }
+
+    /// Sets whether `pin` is read from or written to.
+    ///
+    /// Every other pin keeps its direction.
+    pub fn set_direction(&self, pin: Pin, direction: PinDirection) {
+        let bits = unsafe { (&raw const GPIO_DIRECTION_BITS).read_volatile() };
+        write_direction(match direction {
+            PinDirection::Input => bits & !pin.mask(),
+            PinDirection::Output => bits | pin.mask(),
+        });
+    }
+
+    /// Returns the direction `pin` was last set to.
+    ///
+    /// This is the direction set through this crate, including by [`start()`], which makes
+    /// [`Pin::P3`] an output. Every pin is an input at boot.
+    ///
+    /// [`start()`]: Gpio::start()
+    pub fn direction(&self, pin: Pin) -> PinDirection {
+        if unsafe { (&raw const GPIO_DIRECTION_BITS).read_volatile() } & pin.mask() == 0 {
+            PinDirection::Input
+        } else {
+            PinDirection::Output
+        }
+    }
+
+    /// Drives `pin` high or low.
+    ///
+    /// This only has an effect on the cartridge while `pin` is an output. Every other pin keeps its
+    /// value.
+    pub fn write_pin(&self, pin: Pin, high: bool) {
+        let bits = unsafe { (&raw const GPIO_DATA_BITS).read_volatile() };
+        write_data(if high {
+            bits | pin.mask()
+        } else {
+            bits & !pin.mask()
+        });
+    }
+
+    /// Reads whether `pin` is high.
+    ///
+    /// This makes the port readable if it is not already. For an output pin, this is the value
+    /// last written to it.
+    pub fn read_pin(&self, pin: Pin) -> bool {
+        unsafe {
+            ENABLE.write_volatile(1);
+            DATA.read_volatile() & pin.mask() != 0
+        }
+    }
+}
+
+/// Stops rumble and returns the GPIO port to its state at boot, with every pin an input and the
+/// port unreadable.
+pub(crate) fn release() {
+    write_data(0);
+    write_direction(0);
+    unsafe {
+        ENABLE.write_volatile(0);
+        (&raw mut GPIO_RUMBLING).write_volatile(false);
+    }
+}
+
+impl Backend for Gpio {
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            has_intensity: false,
+            has_brake: false,
+            latency_frames: 0,
+            shared_with_sio: false,
+        }
+    }
+
+    fn calibration(&self) -> Calibration {
+        Calibration::CARTRIDGE
+    }
+
+    /// Reads back the rumble pin, as by [`read_pin()`].
+    ///
+    /// Without a GPIO port, such as on a flash cartridge lacking one, this reads the ROM instead,
+    /// which rarely matches the state last written.
+    ///
+    /// [`read_pin()`]: Gpio::read_pin()
+    fn read_back(&self) -> Option<bool> {
+        Some(self.read_pin(Pin::P3))
+    }
+
+    fn send(&mut self, command: Command) {
+        match command {
+            Command::Start => self.start(),
+            Command::Stop | Command::HardStop => self.stop(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ENABLE, GPIO_RUMBLING, Gpio, Pin, PinDirection};
+    use crate::{Backend, Capabilities};
+    use claims::{assert_none, assert_ok};
+    use gba_test::test;
+
+    #[test]
+    fn capabilities() {
+        assert_eq!(
+            Gpio.capabilities(),
+            Capabilities {
+                has_intensity: false,
+                has_brake: false,
+                latency_frames: 0,
+                shared_with_sio: false,
+            }
+        );
+    }
+
+    #[test]
+    fn check_port_stopped() {
+        unsafe {
+            (&raw mut GPIO_RUMBLING).write_volatile(false);
+        }
+
+        assert_ok!(Gpio.check_port());
+        assert_none!(Gpio.take_conflict());
+    }
+
+    #[test]
+    fn stop_does_not_check_port() {
+        Gpio.start();
+        // Makes the port write-only, as reads behave without GPIO hardware.
+        unsafe {
+            ENABLE.write_volatile(0);
+        }
+        Gpio.stop();
+
+        assert_none!(Gpio.take_conflict());
+    }
+
+    #[test]
+    fn set_direction() {
+        Gpio.set_direction(Pin::P1, PinDirection::Output);
+        let p1 = Gpio.direction(Pin::P1);
+        let p0 = Gpio.direction(Pin::P0);
+        Gpio.set_direction(Pin::P1, PinDirection::Input);
+
+        assert_eq!(p1, PinDirection::Output);
+        assert_eq!(p0, PinDirection::Input);
+        assert_eq!(Gpio.direction(Pin::P1), PinDirection::Input);
+    }
+
+    #[test]
+    fn verify_writes() {
+        assert!(!Gpio.verifies_writes());
+
+        Gpio.set_verify_writes(true);
+        let verifies = Gpio.verifies_writes();
+        Gpio.set_verify_writes(false);
+
+        assert!(verifies);
+        assert!(!Gpio.verifies_writes());
+    }
+
+    #[test]
+    fn start_keeps_other_pins() {
+        Gpio.set_direction(Pin::P0, PinDirection::Output);
+        Gpio.start();
+        let p0 = Gpio.direction(Pin::P0);
+        let p3 = Gpio.direction(Pin::P3);
+        Gpio.stop();
+        Gpio.set_direction(Pin::P0, PinDirection::Input);
+        Gpio.set_direction(Pin::P3, PinDirection::Input);
+
+        assert_eq!(p0, PinDirection::Output);
+        assert_eq!(p3, PinDirection::Output);
+    }
+}
@@ -0,0 +1,859 @@
+//! A complete rumble subsystem behind a single type.
+
+use crate::{
+    Backend, Calibration, Capabilities, ChannelId, Clock, Command, Driver, EffectId, EffectParams,
+    Explanation, Fixed, Intensity, Mixer, MotorType, Pattern, Preemption, Preference, Queue,
+    Registry, Settings, SettingsStore, Tag, TimedCommand,
+    device::{Handoff, Mechanism},
+    is_enabled,
+    mixer::Source,
+    power_saving,
+};
+
+/// Everything needed to add rumble to a game, owned by a single value.
+///
+/// `Haptics` detects the best available rumble mechanism, plays effects on a [`Mixer`], applies
+/// the player's [`Settings`], and drives the motor through a [`Driver`]. Game code only deals in
+/// game-facing verbs: [`play()`] an effect by its [`EffectId`], [`set_ambient()`] rumble that
+/// plays underneath every effect, and [`pause()`] along with the game.
+///
+/// Effects are authored ahead of time as a table of [`Pattern`]s, and referred to by their index
+/// within it. Effects kept in a [`Registry`] can be played with [`play_from()`]. Cutscenes and
+/// music can queue effects ahead of time with [`schedule()`], rather than waiting for the right
+/// frame. [`tick()`] must be called once a frame.
+///
+/// ``` rust
+/// use gba_rumble::{EffectId, Haptics, Intensity, Pattern, Step, pattern};
+///
+/// const HIT: EffectId = EffectId::new(0);
+/// const EXPLOSION: EffectId = EffectId::new(1);
+/// const EFFECTS: &[Pattern] = &[
+///     pattern![Step::Hold { intensity: Intensity::MAX, frames: 10 }],
+///     pattern![Step::Hold { intensity: Intensity::MAX, frames: 60 }],
+/// ];
+///
+/// let mut haptics = Haptics::detect(EFFECTS);
+///
+/// // Rumble gently while the engine runs.
+/// haptics.set_ambient(Intensity::new(32));
+/// haptics.play(HIT);
+///
+/// // Once a frame:
+/// haptics.tick();
+/// ```
+///
+/// As with [`detect_all()`], detection may display the Game Boy Player logo, and
+/// [`game_boy_player_interrupt()`] must be called on serial interrupts if a Game Boy Player is
+/// used. Games needing more control, such as several prioritized channels or procedural effects,
+/// can assemble the same pieces themselves.
+///
+/// [`detect_all()`]: crate::detect_all()
+/// [`game_boy_player_interrupt()`]: crate::game_boy_player_interrupt()
+/// [`pause()`]: Haptics::pause()
+/// [`play()`]: Haptics::play()
+/// [`play_from()`]: Haptics::play_from()
+/// [`Registry`]: crate::Registry
+/// [`schedule()`]: Haptics::schedule()
+/// [`set_ambient()`]: Haptics::set_ambient()
+/// [`tick()`]: Haptics::tick()
+#[derive(Debug)]
+pub struct Haptics<'a> {
+    effects: &'a [Pattern<'a>],
+    mixer: Mixer<'a, 1>,
+    channel: ChannelId,
+    driver: Driver<Mechanism>,
+    ambient: Intensity,
+    /// Effects scheduled to play, along with the frame to play them on.
+    cues: Queue<(u32, EffectId), { Haptics::CUE_CAPACITY }>,
+    /// The number of times playback has advanced, excluding frames spent paused.
+    frame: u32,
+    paused: bool,
+    rumbling: bool,
+    spin_up_compensation: bool,
+    handoff: Handoff,
+}
+
+impl<'a> Haptics<'a> {
+    /// The factor ambient rumble is scaled by while an effect plays.
+    pub const AMBIENT_DUCK: Fixed = Fixed::HALF;
+
+    /// The number of cues that can be scheduled at once.
+    pub const CUE_CAPACITY: usize = 8;
+
+    /// Detects the best available rumble mechanism, then creates a subsystem playing `effects`.
+    ///
+    /// See [`detect_all()`] for the requirements of detection.
+    ///
+    /// [`detect_all()`]: crate::detect_all()
+    pub fn detect(effects: &'a [Pattern<'a>]) -> Self {
+        Self::new(Mechanism::detect(), effects)
+    }
+
+    fn new(mechanism: Mechanism, effects: &'a [Pattern<'a>]) -> Self {
+        let mut mixer = Mixer::new();
+        let channel = mixer
+            .add_channel(0, Preemption::Replace)
+            .expect("mixer has room for one channel");
+        Self {
+            effects,
+            mixer,
+            channel,
+            driver: Driver::new(mechanism),
+            ambient: Intensity::OFF,
+            cues: Queue::new(),
+            frame: 0,
+            paused: false,
+            rumbling: false,
+            spin_up_compensation: false,
+            handoff: Handoff::default(),
+        }
+    }
+
+    /// Returns the capabilities of the mechanism in use.
+    pub fn capabilities(&self) -> Capabilities {
+        self.driver.backend().capabilities()
+    }
+
+    /// Plays the effect identified by `effect` from the beginning, replacing any effect already
+    /// playing.
+    ///
+    /// Returns `false` if there is no effect with the given ID.
+    pub fn play(&mut self, effect: EffectId) -> bool {
+        self.play_with(effect, EffectParams::new())
+    }
+
+    /// Plays the effect identified by `effect` adjusted by `params`, as if by [`play()`].
+    ///
+    /// This allows one authored effect to serve several variations, such as explosions of
+    /// different sizes.
+    ///
+    /// [`play()`]: Haptics::play()
+    pub fn play_with(&mut self, effect: EffectId, params: EffectParams) -> bool {
+        self.play_pattern(
+            self.effects.get(effect.index() as usize).copied(),
+            params,
+            None,
+        )
+    }
+
+    /// Plays the effect identified by `effect` tagged with `tag`, as if by [`play()`].
+    ///
+    /// The effect can later be stopped through [`stop_tagged()`], such as when leaving a scene,
+    /// without affecting effects played under other tags.
+    ///
+    /// [`play()`]: Haptics::play()
+    /// [`stop_tagged()`]: Haptics::stop_tagged()
+    pub fn play_tagged(&mut self, effect: EffectId, tag: Tag) -> bool {
+        self.play_pattern(
+            self.effects.get(effect.index() as usize).copied(),
+            EffectParams::new(),
+            Some(tag),
+        )
+    }
+
+    /// Plays the effect registered under `effect` in `registry` from the beginning, replacing any
+    /// effect already playing.
+    ///
+    /// Returns `false` if no effect is registered under the given ID.
+    pub fn play_from<const N: usize>(&mut self, registry: &Registry<N>, effect: EffectId) -> bool {
+        self.play_from_with(registry, effect, EffectParams::new())
+    }
+
+    /// Plays the effect registered under `effect` in `registry` adjusted by `params`, as if by
+    /// [`play_from()`].
+    ///
+    /// [`play_from()`]: Haptics::play_from()
+    pub fn play_from_with<const N: usize>(
+        &mut self,
+        registry: &Registry<N>,
+        effect: EffectId,
+        params: EffectParams,
+    ) -> bool {
+        self.play_pattern(registry.get(effect), params, None)
+    }
+
+    fn play_pattern(
+        &mut self,
+        pattern: Option<Pattern<'a>>,
+        params: EffectParams,
+        tag: Option<Tag>,
+    ) -> bool {
+        let Some(pattern) = pattern else {
+            return false;
+        };
+        self.mixer
+            .play_source(self.channel, Source::Pattern(pattern, params), tag, None);
+        true
+    }
+
+    /// Schedules the effect identified by `effect` to play on frame `at_frame`.
+    ///
+    /// Frames are counted by [`frame()`]. The effect is played as if by [`play()`] at the start of
+    /// that frame's [`tick()`], or on the next tick if that frame has already passed. If several
+    /// cues fall on the same frame, the one scheduled last is felt.
+    ///
+    /// Returns `false` if there is no effect with the given ID, or if [`CUE_CAPACITY`] cues are
+    /// already scheduled.
+    ///
+    /// [`CUE_CAPACITY`]: Haptics::CUE_CAPACITY
+    /// [`frame()`]: Haptics::frame()
+    /// [`play()`]: Haptics::play()
+    /// [`tick()`]: Haptics::tick()
+    pub fn schedule(&mut self, effect: EffectId, at_frame: u32) -> bool {
+        (effect.index() as usize) < self.effects.len()
+            && self.cues.push(0, (at_frame, effect)).is_ok()
+    }
+
+    /// Schedules the effect identified by `effect` to play `delay_frames` frames from now.
+    ///
+    /// A delay of `0` plays the effect on the next [`tick()`]. See [`schedule()`] for details.
+    ///
+    /// [`schedule()`]: Haptics::schedule()
+    /// [`tick()`]: Haptics::tick()
+    pub fn schedule_in(&mut self, effect: EffectId, delay_frames: u32) -> bool {
+        self.schedule(effect, self.frame.wrapping_add(delay_frames))
+    }
+
+    /// Cancels every scheduled cue.
+    pub fn clear_schedule(&mut self) {
+        self.cues = Queue::new();
+    }
+
+    /// Returns the number of cues waiting to play.
+    pub fn scheduled(&self) -> usize {
+        self.cues.len()
+    }
+
+    /// Returns the current frame, which is the number of times playback has advanced.
+    ///
+    /// Frames spent paused are not counted, so cues stay in sync with a paused game.
+    pub fn frame(&self) -> u32 {
+        self.frame
+    }
+
+    /// Returns whether an effect is playing.
+    pub fn is_playing(&self) -> bool {
+        self.mixer.is_playing(self.channel)
+    }
+
+    /// Stops the effect currently playing, if any.
+    ///
+    /// Ambient rumble is unaffected.
+    pub fn stop(&mut self) {
+        self.mixer.stop(self.channel);
+    }
+
+    /// Stops the effect currently playing if it was played with [`play_tagged()`] under `tag`.
+    ///
+    /// Untagged effects, effects with other tags, ambient rumble, and scheduled cues are
+    /// unaffected.
+    ///
+    /// [`play_tagged()`]: Haptics::play_tagged()
+    pub fn stop_tagged(&mut self, tag: Tag) {
+        self.mixer.stop_tagged(tag);
+    }
+
+    /// Rumbles at `intensity` continuously, underneath any effects.
+    ///
+    /// While an effect plays, ambient rumble is scaled by [`AMBIENT_DUCK`], and the stronger of
+    /// the two is felt. Setting [`Intensity::OFF`] turns ambient rumble off.
+    ///
+    /// [`AMBIENT_DUCK`]: Haptics::AMBIENT_DUCK
+    pub fn set_ambient(&mut self, intensity: Intensity) {
+        self.ambient = intensity;
+    }
+
+    /// Returns the intensity of ambient rumble.
+    pub fn ambient(&self) -> Intensity {
+        self.ambient
+    }
+
+    /// Stops the motor and freezes playback, such as while the game is paused.
+    ///
+    /// Effects continue from where they left off when [`resume()`] is called.
+    ///
+    /// [`resume()`]: Haptics::resume()
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Continues playback after [`pause()`].
+    ///
+    /// [`pause()`]: Haptics::pause()
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Returns whether playback is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Calls `subscriber` with every command sent to the motor, stamped with its frame, or stops
+    /// calling it if `None`.
+    ///
+    /// See [`Driver::set_subscriber()`] for details.
+    pub fn set_subscriber(&mut self, subscriber: Option<fn(TimedCommand)>) {
+        self.driver.set_subscriber(subscriber);
+    }
+
+    /// Returns the player's settings.
+    pub fn settings(&self) -> Settings {
+        self.driver.settings()
+    }
+
+    /// Replaces the player's settings, such as after changing them in an options menu.
+    pub fn set_settings(&mut self, settings: Settings) {
+        self.driver.set_settings(settings);
+    }
+
+    /// Replaces the player's settings with those loaded from `store`.
+    ///
+    /// If `store` contains no settings, or fails to load them, the current settings are kept.
+    pub fn load_settings<S>(&mut self, store: &mut S) -> Result<(), S::Error>
+    where
+        S: SettingsStore + ?Sized,
+    {
+        self.driver.load_settings(store)
+    }
+
+    /// Turns spin-up compensation on or off.
+    ///
+    /// While on, the motor is kept on for at least the spin-up frames of the current
+    /// [`calibration()`] once started, so that short effects are felt even on motors that are slow
+    /// to spin up. This is off by default, so that effects last exactly as long as authored.
+    ///
+    /// [`calibration()`]: Haptics::calibration()
+    pub fn set_spin_up_compensation(&mut self, enabled: bool) {
+        self.spin_up_compensation = enabled;
+        let frames = if enabled {
+            self.driver.calibration().spin_up_frames
+        } else {
+            0
+        };
+        self.driver.set_spin_up_frames(frames);
+    }
+
+    /// Returns the calibration profile tuning intensities to the motor.
+    ///
+    /// This is the mechanism's [`Backend::calibration()`], unless overridden using
+    /// [`set_calibration()`].
+    ///
+    /// [`set_calibration()`]: Haptics::set_calibration()
+    pub fn calibration(&self) -> Calibration {
+        self.driver.calibration()
+    }
+
+    /// Overrides the calibration profile, such as with one tuned by the player in an options
+    /// menu.
+    ///
+    /// Passing `None` returns to the mechanism's own profile. If spin-up compensation is on, it
+    /// is updated to the new profile's spin-up frames.
+    pub fn set_calibration(&mut self, calibration: Option<Calibration>) {
+        self.driver.set_calibration(calibration);
+        self.set_spin_up_compensation(self.spin_up_compensation);
+    }
+
+    /// Overrides the calibration profile with the one suited to `motor`.
+    ///
+    /// [`MotorType::Unknown`] returns to the mechanism's own profile, as when passing `None` to
+    /// [`set_calibration()`].
+    ///
+    /// [`set_calibration()`]: Haptics::set_calibration()
+    pub fn set_motor_type(&mut self, motor: MotorType) {
+        self.set_calibration(motor.calibration());
+    }
+
+    /// Returns which mechanism is preferred.
+    pub fn preference(&self) -> Preference {
+        self.handoff.preference()
+    }
+
+    /// Switches to the mechanism matching `preference`, if it differs from the one in use.
+    ///
+    /// Playing effects, ambient rumble, and scheduled cues carry over: the motor is stopped on the
+    /// old mechanism and started on the new one, which then follows playback from the same frame.
+    /// The new mechanism's calibration is used from then on, unless one was set using
+    /// [`set_calibration()`]. Returns whether the mechanism changed.
+    ///
+    /// [`set_calibration()`]: Haptics::set_calibration()
+    pub fn set_preference(&mut self, preference: Preference) -> bool {
+        let changed =
+            self.handoff
+                .set_preference(preference, self.driver.backend_mut(), self.rumbling);
+        if changed {
+            self.set_spin_up_compensation(self.spin_up_compensation);
+        }
+        changed
+    }
+
+    /// Returns the fewest frames the motor is kept on once started, which is `0` unless spin-up
+    /// compensation is on.
+    pub fn spin_up_frames(&self) -> u8 {
+        self.driver.spin_up_frames()
+    }
+
+    /// Returns whether the motor is currently on.
+    pub fn is_rumbling(&self) -> bool {
+        self.rumbling
+    }
+
+    /// Returns a snapshot of everything deciding whether rumble is felt.
+    ///
+    /// This is intended for investigating reports of rumble not happening when expected. The
+    /// returned [`Explanation`] can be inspected directly, or logged as a single line, such as
+    /// through [`mgba_log`](https://docs.rs/mgba_log):
+    ///
+    /// ``` rust
+    /// use gba_rumble::Haptics;
+    ///
+    /// let haptics = Haptics::detect(&[]);
+    ///
+    /// let explanation = haptics.debug_explain();
+    /// if !explanation.enabled {
+    ///     // Rumble was turned off with `set_enabled()`.
+    /// }
+    /// ```
+    pub fn debug_explain(&self) -> Explanation {
+        let settings = self.driver.settings();
+        Explanation {
+            backend: self.driver.backend().name(),
+            enabled: is_enabled(),
+            settings_enabled: settings.enabled,
+            intensity_scale: settings.intensity_scale,
+            power_saving: power_saving(),
+            paused: self.paused,
+            throttled: self.driver.is_throttled(),
+            command: if self.rumbling {
+                Command::Start
+            } else {
+                Command::Stop
+            },
+            serial: self.driver.backend().serial_status(),
+        }
+    }
+
+    /// Advances playback by one frame, and services the rumble mechanism.
+    ///
+    /// This should be called once a frame. Scheduled cues that are due are played first. Games
+    /// whose logic does not run once a frame should use [`tick_with()`] instead.
+    ///
+    /// [`tick_with()`]: Haptics::tick_with()
+    pub fn tick(&mut self) {
+        if cfg!(feature = "disabled") {
+            return;
+        }
+        self.advance();
+        self.driver.backend_mut().update();
+        self.handoff.update();
+    }
+
+    /// Advances playback by the number of frames elapsed on `clock`, and services the rumble
+    /// mechanism.
+    ///
+    /// This can be called at any rate, such as once per logic step in a game running its logic at
+    /// 30 Hz, and effects will still last as many frames as they were authored with. Each elapsed
+    /// frame is advanced as if by [`tick()`], while the mechanism is serviced once per call.
+    ///
+    /// [`tick()`]: Haptics::tick()
+    pub fn tick_with<C>(&mut self, mut clock: C)
+    where
+        C: Clock,
+    {
+        if cfg!(feature = "disabled") {
+            return;
+        }
+        for _ in 0..clock.elapsed_frames() {
+            self.advance();
+        }
+        self.driver.backend_mut().update();
+        self.handoff.update();
+    }
+
+    /// Advances playback by a single frame, without servicing the rumble mechanism.
+    fn advance(&mut self) {
+        let intensity = if self.paused {
+            Intensity::OFF
+        } else {
+            let frame = self.frame;
+            while let Some((_, effect)) = self.cues.take_first(|_, &(at, _)| at <= frame) {
+                self.play(effect);
+            }
+            self.frame = frame.wrapping_add(1);
+            let effect = self.mixer.tick();
+            if self.is_playing() {
+                effect.max(self.ambient.scale(Self::AMBIENT_DUCK))
+            } else {
+                self.ambient
+            }
+        };
+        match self.driver.tick(intensity) {
+            Some(Command::Start) => self.rumbling = true,
+            Some(Command::Stop | Command::HardStop) => self.rumbling = false,
+            None => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Haptics;
+    use crate::{
+        Backend, Calibration, Command, EffectId, EffectParams, Fixed, FrameCounter, GameBoyPlayer,
+        Gpio, Intensity, Pattern, Preference, Registry, Settings, Step, Tag, device::Mechanism,
+        pattern,
+    };
+    use claims::{assert_none, assert_ok, assert_some};
+    use gba_test::test;
+
+    const EFFECTS: &[Pattern] = &[
+        pattern![Step::Hold {
+            intensity: Intensity::MAX,
+            frames: 2
+        }],
+        pattern![Step::Hold {
+            intensity: Intensity::OFF,
+            frames: 2
+        }],
+    ];
+
+    fn haptics() -> Haptics<'static> {
+        Haptics::new(Mechanism::Gpio(Gpio), EFFECTS)
+    }
+
+    #[test]
+    fn play() {
+        let mut haptics = haptics();
+
+        assert!(haptics.play(EffectId::new(0)));
+        haptics.tick();
+        assert!(haptics.is_rumbling());
+        haptics.tick();
+        haptics.tick();
+        assert!(!haptics.is_playing());
+        assert!(!haptics.is_rumbling());
+    }
+
+    #[test]
+    fn tick_with() {
+        let mut haptics = haptics();
+        let mut clock = FrameCounter::new();
+        haptics.play(EffectId::new(0));
+
+        clock.count(1);
+        haptics.tick_with(&mut clock);
+        assert!(haptics.is_rumbling());
+
+        // Logic running at 30 Hz finishes the effect in the same number of frames.
+        clock.count(2);
+        haptics.tick_with(&mut clock);
+        assert!(!haptics.is_playing());
+        assert!(!haptics.is_rumbling());
+        assert_eq!(haptics.frame(), 3);
+    }
+
+    #[test]
+    fn tick_with_no_elapsed_frames() {
+        let mut haptics = haptics();
+        haptics.play(EffectId::new(0));
+
+        haptics.tick_with(FrameCounter::new());
+
+        assert!(haptics.is_playing());
+        assert!(!haptics.is_rumbling());
+        assert_eq!(haptics.frame(), 0);
+    }
+
+    #[test]
+    fn set_preference_hands_off() {
+        let game_boy_player = assert_some!(unsafe { GameBoyPlayer::from_raw(0x4700_0000) });
+        game_boy_player.enable_serial();
+        let mut haptics = Haptics::new(Mechanism::GameBoyPlayer(game_boy_player), EFFECTS);
+        haptics.set_spin_up_compensation(true);
+        haptics.set_ambient(Intensity::MAX);
+        haptics.tick();
+
+        assert!(haptics.set_preference(Preference::Cartridge));
+        assert_eq!(haptics.preference(), Preference::Cartridge);
+        assert_eq!(haptics.capabilities(), Gpio.capabilities());
+        assert_eq!(haptics.spin_up_frames(), 3);
+        assert!(haptics.is_rumbling());
+
+        assert!(haptics.set_preference(Preference::Best));
+        assert_eq!(haptics.capabilities(), game_boy_player.capabilities());
+        assert_eq!(haptics.spin_up_frames(), 2);
+        haptics.set_ambient(Intensity::OFF);
+        haptics.tick();
+    }
+
+    #[test]
+    fn spin_up_compensation() {
+        let mut haptics = haptics();
+        haptics.set_spin_up_compensation(true);
+        assert_eq!(haptics.spin_up_frames(), 3);
+        haptics.play(EffectId::new(0));
+
+        haptics.tick();
+        haptics.tick();
+        haptics.tick();
+        assert!(!haptics.is_playing());
+        assert!(haptics.is_rumbling());
+
+        haptics.tick();
+        assert!(!haptics.is_rumbling());
+    }
+
+    #[test]
+    fn set_calibration_updates_spin_up_compensation() {
+        let mut haptics = haptics();
+        haptics.set_spin_up_compensation(true);
+
+        haptics.set_calibration(Some(Calibration::GAMECUBE_CONTROLLER));
+        assert_eq!(haptics.calibration(), Calibration::GAMECUBE_CONTROLLER);
+        assert_eq!(haptics.spin_up_frames(), 2);
+
+        haptics.set_calibration(None);
+        assert_eq!(haptics.calibration(), Calibration::CARTRIDGE);
+        assert_eq!(haptics.spin_up_frames(), 3);
+    }
+
+    #[test]
+    fn spin_up_compensation_off() {
+        let mut haptics = haptics();
+        haptics.set_spin_up_compensation(true);
+
+        haptics.set_spin_up_compensation(false);
+
+        assert_eq!(haptics.spin_up_frames(), 0);
+    }
+
+    #[test]
+    fn play_unknown() {
+        let mut haptics = haptics();
+
+        assert!(!haptics.play(EffectId::new(2)));
+        assert!(!haptics.is_playing());
+    }
+
+    #[test]
+    fn play_from() {
+        let mut haptics = haptics();
+        let mut registry = Registry::<4>::new();
+        assert_ok!(registry.register(EffectId::new(3), EFFECTS[0]));
+
+        assert!(!haptics.play_from(&registry, EffectId::new(0)));
+        assert!(haptics.play_from(&registry, EffectId::new(3)));
+        haptics.tick();
+        assert!(haptics.is_rumbling());
+    }
+
+    #[test]
+    fn stop() {
+        let mut haptics = haptics();
+        haptics.play(EffectId::new(0));
+        haptics.tick();
+
+        haptics.stop();
+        haptics.tick();
+
+        assert!(!haptics.is_rumbling());
+    }
+
+    #[test]
+    fn stop_tagged() {
+        const GAMEPLAY: Tag = Tag::new(0);
+        const UI: Tag = Tag::new(1);
+        let mut haptics = haptics();
+
+        assert!(haptics.play_tagged(EffectId::new(0), UI));
+        haptics.stop_tagged(GAMEPLAY);
+        assert!(haptics.is_playing());
+
+        haptics.stop_tagged(UI);
+        assert!(!haptics.is_playing());
+    }
+
+    #[test]
+    fn ambient() {
+        let mut haptics = haptics();
+
+        haptics.set_ambient(Intensity::new(1));
+        haptics.tick();
+
+        assert_eq!(haptics.ambient(), Intensity::new(1));
+        assert!(haptics.is_rumbling());
+    }
+
+    #[test]
+    fn ambient_ducked() {
+        let mut haptics = haptics();
+        haptics.set_ambient(Intensity::new(1));
+        haptics.play(EffectId::new(1));
+
+        // Halving the lowest intensity turns it off, so only the silent effect is felt.
+        haptics.tick();
+        assert!(!haptics.is_rumbling());
+
+        haptics.tick();
+        haptics.tick();
+        assert!(haptics.is_rumbling());
+    }
+
+    #[test]
+    fn pause() {
+        let mut haptics = haptics();
+        haptics.play(EffectId::new(0));
+        haptics.tick();
+
+        haptics.pause();
+        assert!(haptics.is_paused());
+        for _ in 0..10 {
+            haptics.tick();
+        }
+        assert!(!haptics.is_rumbling());
+        assert!(haptics.is_playing());
+
+        haptics.resume();
+        haptics.tick();
+        assert!(haptics.is_rumbling());
+    }
+
+    #[test]
+    fn schedule() {
+        let mut haptics = haptics();
+
+        assert!(haptics.schedule(EffectId::new(0), 2));
+        assert_eq!(haptics.scheduled(), 1);
+        haptics.tick();
+        haptics.tick();
+        assert!(!haptics.is_playing());
+
+        haptics.tick();
+        assert!(haptics.is_rumbling());
+        assert_eq!(haptics.scheduled(), 0);
+        assert_eq!(haptics.frame(), 3);
+    }
+
+    #[test]
+    fn schedule_in() {
+        let mut haptics = haptics();
+        haptics.tick();
+
+        assert!(haptics.schedule_in(EffectId::new(0), 0));
+        haptics.tick();
+
+        assert!(haptics.is_rumbling());
+    }
+
+    #[test]
+    fn schedule_past() {
+        let mut haptics = haptics();
+        haptics.tick();
+        haptics.tick();
+
+        assert!(haptics.schedule(EffectId::new(0), 0));
+        haptics.tick();
+
+        assert!(haptics.is_rumbling());
+    }
+
+    #[test]
+    fn schedule_unknown() {
+        let mut haptics = haptics();
+
+        assert!(!haptics.schedule(EffectId::new(2), 0));
+        assert_eq!(haptics.scheduled(), 0);
+    }
+
+    #[test]
+    fn schedule_full() {
+        let mut haptics = haptics();
+
+        for frame in 0..Haptics::CUE_CAPACITY as u32 {
+            assert!(haptics.schedule(EffectId::new(0), frame));
+        }
+
+        assert!(!haptics.schedule(EffectId::new(0), 0));
+    }
+
+    #[test]
+    fn schedule_while_paused() {
+        let mut haptics = haptics();
+        assert!(haptics.schedule_in(EffectId::new(0), 1));
+
+        haptics.pause();
+        for _ in 0..10 {
+            haptics.tick();
+        }
+        haptics.resume();
+        haptics.tick();
+        assert!(!haptics.is_playing());
+
+        haptics.tick();
+        assert!(haptics.is_rumbling());
+    }
+
+    #[test]
+    fn play_with() {
+        let mut haptics = haptics();
+
+        assert!(haptics.play_with(
+            EffectId::new(0),
+            EffectParams::new().with_duration(Fixed::from_int(2))
+        ));
+        for _ in 0..4 {
+            haptics.tick();
+            assert!(haptics.is_rumbling());
+        }
+        haptics.tick();
+        assert!(!haptics.is_rumbling());
+    }
+
+    #[test]
+    fn clear_schedule() {
+        let mut haptics = haptics();
+        assert!(haptics.schedule_in(EffectId::new(0), 0));
+
+        haptics.clear_schedule();
+        haptics.tick();
+
+        assert!(!haptics.is_playing());
+    }
+
+    #[test]
+    fn settings() {
+        let mut haptics = haptics();
+        let settings = Settings {
+            enabled: false,
+            intensity_scale: Intensity::MAX,
+        };
+
+        haptics.set_settings(settings);
+        haptics.set_ambient(Intensity::MAX);
+        haptics.tick();
+
+        assert_eq!(haptics.settings(), settings);
+        assert!(!haptics.is_rumbling());
+    }
+
+    #[test]
+    fn debug_explain() {
+        let mut haptics = haptics();
+        haptics.set_settings(Settings {
+            enabled: true,
+            intensity_scale: Intensity::new(64),
+        });
+        haptics.play(EffectId::new(0));
+        haptics.tick();
+        haptics.pause();
+
+        let explanation = haptics.debug_explain();
+
+        assert_eq!(explanation.backend, "GPIO");
+        assert!(explanation.settings_enabled);
+        assert_eq!(explanation.intensity_scale, Intensity::new(64));
+        assert!(explanation.paused);
+        assert_eq!(explanation.command, Command::Start);
+        assert_none!(explanation.serial);
+    }
+}
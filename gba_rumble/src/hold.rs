@@ -0,0 +1,198 @@
+//! Rumbling while a button is held.
+
+use crate::{Backend, Command, Driver, Intensity};
+
+/// Rumbles for as long as a button is held.
+///
+/// This wraps the most common use of rumble, such as for a charging attack or revving an engine,
+/// with the details handled: the motor starts on the first frame the button is held, keeps
+/// running through brief releases of up to [`debounce_frames()`], and runs for at least the
+/// backend's spin-up time so that even a quick tap is felt. Commands are only sent to the backend
+/// when the motor state changes.
+///
+/// Report the button's state with [`set_held()`], then call [`tick()`] once a frame:
+///
+/// ``` rust
+/// use gba_rumble::{Gpio, HoldToRumble};
+///
+/// let mut rumble = HoldToRumble::new(Gpio);
+///
+/// // Once a frame:
+/// let a_held = true;
+/// rumble.set_held(a_held);
+/// rumble.tick();
+/// ```
+///
+/// A [`GameBoyPlayer`] backend must still be updated every frame as usual.
+///
+/// [`debounce_frames()`]: HoldToRumble::debounce_frames()
+/// [`GameBoyPlayer`]: crate::GameBoyPlayer
+/// [`set_held()`]: HoldToRumble::set_held()
+/// [`tick()`]: HoldToRumble::tick()
+#[derive(Debug)]
+pub struct HoldToRumble<B> {
+    driver: Driver<B>,
+    held: bool,
+    /// Consecutive frames the button has been released while rumbling.
+    released_frames: u8,
+    debounce_frames: u8,
+    rumbling: bool,
+}
+
+impl<B> HoldToRumble<B>
+where
+    B: Backend,
+{
+    /// The number of frames a release is ignored for by default.
+    pub const DEFAULT_DEBOUNCE_FRAMES: u8 = 2;
+
+    /// Creates a helper driving `backend`, with the button released.
+    ///
+    /// Spin-up compensation uses the backend's [`Backend::calibration()`].
+    pub fn new(backend: B) -> Self {
+        let mut driver = Driver::new(backend);
+        driver.set_spin_up_frames(driver.calibration().spin_up_frames);
+        Self {
+            driver,
+            held: false,
+            released_frames: 0,
+            debounce_frames: Self::DEFAULT_DEBOUNCE_FRAMES,
+            rumbling: false,
+        }
+    }
+
+    /// Sets whether the button is held this frame.
+    pub const fn set_held(&mut self, held: bool) {
+        self.held = held;
+    }
+
+    /// Returns the number of consecutive frames a release is ignored for.
+    pub const fn debounce_frames(&self) -> u8 {
+        self.debounce_frames
+    }
+
+    /// Ignores releases lasting up to `frames` consecutive frames.
+    ///
+    /// This keeps the motor from stuttering when a worn button briefly loses contact. A value of
+    /// `0` stops the motor as soon as the button is released.
+    pub const fn set_debounce_frames(&mut self, frames: u8) {
+        self.debounce_frames = frames;
+    }
+
+    /// Returns whether the motor is currently on.
+    pub const fn is_rumbling(&self) -> bool {
+        self.rumbling
+    }
+
+    /// Advances to the next frame, starting or stopping the motor as needed.
+    ///
+    /// Returns the command sent to the backend, if any.
+    pub fn tick(&mut self) -> Option<Command> {
+        self.released_frames = if self.held {
+            0
+        } else {
+            self.released_frames.saturating_add(1)
+        };
+        let on = self.held || (self.rumbling && self.released_frames <= self.debounce_frames);
+        let command = self
+            .driver
+            .tick(if on { Intensity::MAX } else { Intensity::OFF });
+        if let Some(command) = command {
+            self.rumbling = command == Command::Start;
+        }
+        command
+    }
+
+    /// Returns the backend being driven.
+    pub const fn backend(&self) -> &B {
+        self.driver.backend()
+    }
+
+    /// Returns the backend being driven, mutably.
+    pub const fn backend_mut(&mut self) -> &mut B {
+        self.driver.backend_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HoldToRumble;
+    use crate::{Backend, Calibration, Capabilities, Command, Gpio};
+    use claims::{assert_none, assert_some_eq};
+    use gba_test::test;
+
+    struct Instant;
+
+    impl Backend for Instant {
+        fn capabilities(&self) -> Capabilities {
+            Capabilities {
+                has_intensity: false,
+                has_brake: false,
+                latency_frames: 0,
+                shared_with_sio: false,
+            }
+        }
+
+        fn calibration(&self) -> Calibration {
+            Calibration::UNCALIBRATED
+        }
+
+        fn send(&mut self, _command: Command) {}
+    }
+
+    #[test]
+    fn held() {
+        let mut rumble = HoldToRumble::new(Instant);
+        rumble.set_debounce_frames(0);
+
+        rumble.set_held(true);
+        assert_some_eq!(rumble.tick(), Command::Start);
+        assert!(rumble.is_rumbling());
+        assert_none!(rumble.tick());
+
+        rumble.set_held(false);
+        assert_some_eq!(rumble.tick(), Command::Stop);
+        assert!(!rumble.is_rumbling());
+    }
+
+    #[test]
+    fn debounce() {
+        let mut rumble = HoldToRumble::new(Instant);
+        rumble.set_held(true);
+        rumble.tick();
+
+        // A release of two frames is ignored.
+        rumble.set_held(false);
+        assert_none!(rumble.tick());
+        assert_none!(rumble.tick());
+        rumble.set_held(true);
+        assert_none!(rumble.tick());
+
+        rumble.set_held(false);
+        rumble.tick();
+        rumble.tick();
+        assert_some_eq!(rumble.tick(), Command::Stop);
+    }
+
+    #[test]
+    fn released_without_rumbling() {
+        let mut rumble = HoldToRumble::new(Instant);
+
+        assert_none!(rumble.tick());
+        assert!(!rumble.is_rumbling());
+    }
+
+    #[test]
+    fn spin_up() {
+        let mut rumble = HoldToRumble::new(Gpio);
+        rumble.set_debounce_frames(0);
+
+        // A single-frame tap still runs the cartridge motor for its spin-up time.
+        rumble.set_held(true);
+        assert_some_eq!(rumble.tick(), Command::Start);
+        rumble.set_held(false);
+        assert_none!(rumble.tick());
+        assert_none!(rumble.tick());
+        assert_some_eq!(rumble.tick(), Command::Stop);
+    }
+}
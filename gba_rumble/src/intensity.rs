@@ -0,0 +1,167 @@
+//! Logical rumble strength.
+
+use crate::Fixed;
+
+/// The strength of a rumble effect.
+///
+/// Intensity ranges from [`Intensity::OFF`] (no rumble) to [`Intensity::MAX`] (full strength).
+/// Backends that can only turn their motor on or off interpret any nonzero intensity as "on".
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Intensity(u8);
+
+impl Intensity {
+    /// No rumble.
+    pub const OFF: Self = Self(0);
+    /// Full strength rumble.
+    pub const MAX: Self = Self(u8::MAX);
+
+    /// Creates an intensity from a raw value, where `0` is off and `255` is full strength.
+    pub const fn new(value: u8) -> Self {
+        Self(value)
+    }
+
+    /// Creates an intensity that fades out with distance from its source.
+    ///
+    /// The intensity is [`Intensity::MAX`] at a `distance` of `0`, decreasing linearly until it
+    /// reaches [`Intensity::OFF`] at `falloff` and beyond. Distances can be in any unit, such as
+    /// pixels, as long as both arguments use the same one. The result rounds up, so any distance
+    /// within `falloff` is felt.
+    ///
+    /// ``` rust
+    /// use gba_rumble::Intensity;
+    ///
+    /// // An explosion felt up to 120 pixels away.
+    /// assert_eq!(Intensity::from_distance(0, 120), Intensity::MAX);
+    /// assert_eq!(Intensity::from_distance(60, 120), Intensity::new(128));
+    /// assert_eq!(Intensity::from_distance(120, 120), Intensity::OFF);
+    /// ```
+    pub const fn from_distance(distance: u16, falloff: u16) -> Self {
+        if distance >= falloff {
+            return Self::OFF;
+        }
+        let remaining = (falloff - distance) as u32;
+        Self(((remaining * u8::MAX as u32).div_ceil(falloff as u32)) as u8)
+    }
+
+    /// Creates an intensity from the strength of an impulse, such as a collision.
+    ///
+    /// Impulses are compressed using a square root, so that small impulses are still felt while
+    /// large ones don't all saturate at full strength. An impulse of `256` gives an intensity of
+    /// `16`, and the largest impulse of `65535` gives [`Intensity::MAX`]. Games should scale their
+    /// physics quantities into this range consistently.
+    ///
+    /// ``` rust
+    /// use gba_rumble::Intensity;
+    ///
+    /// assert_eq!(Intensity::from_impulse(0), Intensity::OFF);
+    /// assert_eq!(Intensity::from_impulse(1), Intensity::new(1));
+    /// assert_eq!(Intensity::from_impulse(u16::MAX), Intensity::MAX);
+    /// ```
+    pub const fn from_impulse(impulse: u16) -> Self {
+        Self(impulse.isqrt() as u8)
+    }
+
+    /// Multiplies this intensity by `factor`, saturating at [`Intensity::MAX`].
+    ///
+    /// The result is truncated towards zero.
+    pub const fn scale(self, factor: Fixed) -> Self {
+        let scaled = (self.0 as u32 * factor.to_bits() as u32) >> Fixed::FRACTIONAL_BITS;
+        if scaled > u8::MAX as u32 {
+            Self::MAX
+        } else {
+            Self(scaled as u8)
+        }
+    }
+
+    /// Returns the raw value of this intensity.
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+
+    /// Returns whether this intensity is [`Intensity::OFF`].
+    pub const fn is_off(self) -> bool {
+        self.0 == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Intensity;
+    use crate::Fixed;
+    use gba_test::test;
+
+    #[test]
+    fn new_get() {
+        assert_eq!(Intensity::new(42).get(), 42);
+    }
+
+    #[test]
+    fn off() {
+        assert!(Intensity::OFF.is_off());
+        assert!(!Intensity::MAX.is_off());
+    }
+
+    #[test]
+    fn default_is_off() {
+        assert_eq!(Intensity::default(), Intensity::OFF);
+    }
+
+    #[test]
+    fn from_distance() {
+        assert_eq!(Intensity::from_distance(0, 100), Intensity::MAX);
+        assert_eq!(Intensity::from_distance(50, 100), Intensity::new(128));
+        assert_eq!(Intensity::from_distance(99, 100), Intensity::new(3));
+        assert_eq!(Intensity::from_distance(100, 100), Intensity::OFF);
+        assert_eq!(Intensity::from_distance(u16::MAX, 100), Intensity::OFF);
+    }
+
+    #[test]
+    fn from_distance_large() {
+        assert_eq!(Intensity::from_distance(0, u16::MAX), Intensity::MAX);
+        assert_eq!(
+            Intensity::from_distance(u16::MAX - 1, u16::MAX),
+            Intensity::new(1)
+        );
+    }
+
+    #[test]
+    fn from_distance_no_falloff() {
+        assert_eq!(Intensity::from_distance(0, 0), Intensity::OFF);
+    }
+
+    #[test]
+    fn from_distance_decreasing() {
+        for distance in 1..=200 {
+            assert!(
+                Intensity::from_distance(distance, 200)
+                    <= Intensity::from_distance(distance - 1, 200)
+            );
+        }
+    }
+
+    #[test]
+    fn from_impulse() {
+        assert_eq!(Intensity::from_impulse(0), Intensity::OFF);
+        assert_eq!(Intensity::from_impulse(1), Intensity::new(1));
+        assert_eq!(Intensity::from_impulse(256), Intensity::new(16));
+        assert_eq!(Intensity::from_impulse(16384), Intensity::new(128));
+        assert_eq!(Intensity::from_impulse(u16::MAX), Intensity::MAX);
+    }
+
+    #[test]
+    fn scale() {
+        assert_eq!(Intensity::new(200).scale(Fixed::HALF), Intensity::new(100));
+        assert_eq!(Intensity::new(201).scale(Fixed::HALF), Intensity::new(100));
+        assert_eq!(Intensity::new(200).scale(Fixed::ONE), Intensity::new(200));
+        assert_eq!(Intensity::new(200).scale(Fixed::ZERO), Intensity::OFF);
+    }
+
+    #[test]
+    fn scale_saturates() {
+        assert_eq!(
+            Intensity::new(200).scale(Fixed::from_int(2)),
+            Intensity::MAX
+        );
+        assert_eq!(Intensity::MAX.scale(Fixed::MAX), Intensity::MAX);
+    }
+}
@@ -0,0 +1,427 @@
+//! Registering interrupt handlers.
+//!
+//! Programs using an interrupt framework, such as those provided by the
+//! [`gba`](https://crates.io/crates/gba) or [`agb`](https://crates.io/crates/agb) crates, register
+//! this crate's handlers through an [`IrqRegistrar`]. Programs without one can instead use the
+//! minimal dispatcher provided here, which is sufficient to run the serial and vblank hooks this
+//! crate needs.
+//!
+//! ``` rust,no_run
+//! use gba_rumble::{
+//!     GameBoyPlayer,
+//!     irq::{Dispatcher, Interrupt},
+//! };
+//!
+//! gba_rumble::irq::install();
+//! // Detection waits for vblank interrupts, which must be enabled even without a handler.
+//! gba_rumble::irq::enable(
+//!     Interrupt::VBlank,
+//!     Some(gba_rumble::game_boy_player_keep_alive),
+//! );
+//!
+//! // The dispatcher also registers the serial interrupt handler.
+//! if let Some(game_boy_player) = GameBoyPlayer::boot(Dispatcher) {
+//!     loop {
+//!         game_boy_player.update();
+//!         // Run the rest of the frame.
+//!     }
+//! }
+//! ```
+//!
+//! Programs with their own dispatcher, such as one written in assembly around the BIOS's
+//! `IntrWait` flags, can call [`on_serial_irq()`] from it directly. None of this crate's handlers
+//! acknowledge interrupts, as promised by [`ACKNOWLEDGES_IF`], so such dispatchers keep full
+//! control of `IF` and the BIOS's flags.
+
+pub use crate::game_boy_player::on_serial_irq;
+use crate::regs;
+use core::{
+    fmt,
+    fmt::{Debug, Formatter},
+    ptr,
+};
+
+const DISPSTAT: *mut u16 = ptr::with_exposed_provenance_mut(regs::DISPSTAT);
+const IE: *mut u16 = ptr::with_exposed_provenance_mut(regs::IE);
+const IF: *mut u16 = ptr::with_exposed_provenance_mut(regs::IF);
+const IME: *mut u16 = ptr::with_exposed_provenance_mut(regs::IME);
+/// The interrupt flags checked by the BIOS's `IntrWait` and `VBlankIntrWait` calls.
+const BIOS_IF: *mut u16 = ptr::with_exposed_provenance_mut(0x0300_7FF8);
+/// Where the BIOS looks for the program's interrupt handler.
+const BIOS_HANDLER: *mut extern "C" fn() = ptr::with_exposed_provenance_mut(0x0300_7FFC);
+
+/// The number of interrupt sources.
+const INTERRUPTS: usize = 14;
+
+/// Whether this crate's interrupt handlers acknowledge the interrupts they handle.
+///
+/// This is `false`, and will remain so. [`on_serial_irq()`], [`game_boy_player_interrupt()`],
+/// and [`game_boy_player_keep_alive()`] never read or write `IF`, nor the BIOS's interrupt flags
+/// at `0x03007FF8`. Whatever dispatches to them is responsible for acknowledging each interrupt
+/// exactly once in `IF`, and for setting its bit in the BIOS's flags if the program waits for it
+/// with `IntrWait` or `VBlankIntrWait`, as detection does for vblank. The dispatcher installed by
+/// [`install()`] does both on its handlers' behalf, so custom dispatchers that already do the same
+/// can call the handlers without any interrupt being acknowledged twice.
+///
+/// [`game_boy_player_interrupt()`]: crate::game_boy_player_interrupt()
+/// [`game_boy_player_keep_alive()`]: crate::game_boy_player_keep_alive()
+pub const ACKNOWLEDGES_IF: bool = false;
+
+/// The handler called for each interrupt source, indexed by its bit in `IE`.
+#[cfg_attr(
+    not(feature = "ewram-serial"),
+    unsafe(link_section = ".iwram.gba_rumble.irq_handlers")
+)]
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.irq_handlers")
+)]
+static mut IRQ_HANDLERS: [Option<fn()>; INTERRUPTS] = [None; INTERRUPTS];
+
+/// Registers interrupt handlers with the program's interrupt framework.
+///
+/// This is used by [`GameBoyPlayer::boot()`] to install [`game_boy_player_interrupt()`] without
+/// knowing how interrupts are dispatched. Frameworks that register closures can use an
+/// [`IrqRegistrarFn`].
+///
+/// [`GameBoyPlayer::boot()`]: crate::GameBoyPlayer::boot()
+/// [`game_boy_player_interrupt()`]: crate::game_boy_player_interrupt()
+pub trait IrqRegistrar {
+    /// Arrange for `handler` to be called whenever a serial interrupt is received.
+    ///
+    /// This must also enable serial interrupts in `IE`, if the framework does not do so already.
+    fn register_serial(&mut self, handler: fn());
+}
+
+impl<R> IrqRegistrar for &mut R
+where
+    R: IrqRegistrar + ?Sized,
+{
+    fn register_serial(&mut self, handler: fn()) {
+        (**self).register_serial(handler);
+    }
+}
+
+/// An [`IrqRegistrar`] that calls a closure.
+///
+/// ``` rust,ignore
+/// use agb::interrupt::{Interrupt, add_interrupt_handler};
+/// use gba_rumble::{GameBoyPlayer, IrqRegistrarFn};
+///
+/// let mut serial_interrupt = None;
+/// let game_boy_player = GameBoyPlayer::boot(IrqRegistrarFn::new(|handler: fn()| {
+///     serial_interrupt =
+///         Some(unsafe { add_interrupt_handler(Interrupt::Serial, move |_| handler()) });
+/// }));
+/// ```
+#[derive(Clone, Copy)]
+pub struct IrqRegistrarFn<F> {
+    f: F,
+}
+
+impl<F> IrqRegistrarFn<F>
+where
+    F: FnMut(fn()),
+{
+    /// Creates a registrar that calls `f` with each serial interrupt handler.
+    pub const fn new(f: F) -> Self {
+        Self { f }
+    }
+}
+
+impl<F> IrqRegistrar for IrqRegistrarFn<F>
+where
+    F: FnMut(fn()),
+{
+    fn register_serial(&mut self, handler: fn()) {
+        (self.f)(handler);
+    }
+}
+
+impl<F> Debug for IrqRegistrarFn<F> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("IrqRegistrarFn")
+            .finish_non_exhaustive()
+    }
+}
+
+/// A source of interrupts, as enabled in `IE`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Interrupt {
+    /// The start of vblank.
+    VBlank,
+    /// The start of hblank.
+    HBlank,
+    /// `VCOUNT` matching the value selected in `DISPSTAT`.
+    VCount,
+    /// Timer 0 overflowing.
+    Timer0,
+    /// Timer 1 overflowing.
+    Timer1,
+    /// Timer 2 overflowing.
+    Timer2,
+    /// Timer 3 overflowing.
+    Timer3,
+    /// A serial transfer completing.
+    Serial,
+    /// DMA 0 completing.
+    Dma0,
+    /// DMA 1 completing.
+    Dma1,
+    /// DMA 2 completing.
+    Dma2,
+    /// DMA 3 completing.
+    Dma3,
+    /// The keypad condition selected in `KEYCNT` being met.
+    Keypad,
+    /// The cartridge being removed.
+    GamePak,
+}
+
+impl Interrupt {
+    /// Returns the bit of `IE` and `IF` used by this interrupt.
+    pub const fn bit(self) -> u16 {
+        1 << self as u16
+    }
+}
+
+/// Installs the dispatcher as the program's interrupt handler, and enables interrupts in `IME`.
+///
+/// Once installed, each interrupt enabled with [`enable()`] is acknowledged in both `IF` and the
+/// BIOS's interrupt flags, so that BIOS calls such as `VBlankIntrWait` return, and its handler is
+/// called. Handlers are called in order of their bits in `IE`, with interrupts disabled, so they
+/// should return quickly.
+///
+/// The dispatcher and its table of handlers are placed in IWRAM alongside
+/// [`game_boy_player_interrupt()`], and are moved to EWRAM with it by the `ewram-serial` feature.
+///
+/// [`game_boy_player_interrupt()`]: crate::game_boy_player_interrupt()
+pub fn install() {
+    unsafe {
+        IME.write_volatile(0);
+        BIOS_HANDLER.write_volatile(dispatch);
+        IME.write_volatile(1);
+    }
+}
+
+/// Enables `interrupt` in `IE`, calling `handler` whenever it is received.
+///
+/// Passing `None` enables the interrupt without a handler, which is still acknowledged. This is
+/// useful for the vblank interrupt waited on by [`BiosWaiter`]. For [`Interrupt::VBlank`], the
+/// interrupt is also requested in `DISPSTAT`. Other sources must request their interrupts
+/// themselves, such as the serial port does in [`GameBoyPlayer::enable_serial()`].
+///
+/// [`BiosWaiter`]: crate::BiosWaiter
+/// [`GameBoyPlayer::enable_serial()`]: crate::GameBoyPlayer::enable_serial()
+pub fn enable(interrupt: Interrupt, handler: Option<fn()>) {
+    unsafe {
+        let ime = IME.read_volatile();
+        IME.write_volatile(0);
+        IRQ_HANDLERS[interrupt as usize] = handler;
+        if interrupt == Interrupt::VBlank {
+            DISPSTAT.write_volatile(DISPSTAT.read_volatile() | (1 << 3));
+        }
+        IE.write_volatile(IE.read_volatile() | interrupt.bit());
+        IME.write_volatile(ime);
+    }
+}
+
+/// Disables `interrupt` in `IE`, removing its handler.
+pub fn disable(interrupt: Interrupt) {
+    unsafe {
+        let ime = IME.read_volatile();
+        IME.write_volatile(0);
+        IE.write_volatile(IE.read_volatile() & !interrupt.bit());
+        IRQ_HANDLERS[interrupt as usize] = None;
+        IME.write_volatile(ime);
+    }
+}
+
+/// Acknowledges every pending interrupt and calls its handler.
+///
+/// The BIOS calls this in ARM mode, so it must be compiled as ARM code.
+#[instruction_set(arm::a32)]
+#[cfg_attr(
+    not(feature = "ewram-serial"),
+    unsafe(link_section = ".iwram.gba_rumble.irq_dispatch")
+)]
+#[cfg_attr(
+    feature = "ewram-serial",
+    unsafe(link_section = ".ewram.gba_rumble.irq_dispatch")
+)]
+extern "C" fn dispatch() {
+    unsafe {
+        let pending = IE.read_volatile() & IF.read_volatile();
+        IF.write_volatile(pending);
+        BIOS_IF.write_volatile(BIOS_IF.read_volatile() | pending);
+        let mut index = 0;
+        while index < INTERRUPTS {
+            if pending & (1 << index) != 0
+                && let Some(handler) = IRQ_HANDLERS[index]
+            {
+                handler();
+            }
+            index += 1;
+        }
+    }
+}
+
+/// An [`IrqRegistrar`] that registers handlers with this module's dispatcher.
+///
+/// The dispatcher must also be installed using [`install()`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Dispatcher;
+
+impl IrqRegistrar for Dispatcher {
+    fn register_serial(&mut self, handler: fn()) {
+        enable(Interrupt::Serial, Some(handler));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        BIOS_IF, Dispatcher, IE, IF, IME, IRQ_HANDLERS, Interrupt, IrqRegistrar, IrqRegistrarFn,
+        disable, dispatch, enable, on_serial_irq,
+    };
+    use crate::Timer;
+    use alloc::format;
+    use gba_test::test;
+
+    static mut CALLS: u8 = 0;
+
+    fn handler() {
+        unsafe {
+            CALLS += 1;
+        }
+    }
+
+    #[test]
+    fn irq_registrar_fn() {
+        let mut registered = None;
+        let mut registrar = IrqRegistrarFn::new(|handler| registered = Some(handler));
+
+        registrar.register_serial(handler);
+        registered.unwrap()();
+
+        assert_eq!(unsafe { CALLS }, 1);
+    }
+
+    #[test]
+    fn interrupt_bit() {
+        assert_eq!(Interrupt::VBlank.bit(), 1 << 0);
+        assert_eq!(Interrupt::Timer3.bit(), Timer::Timer3.interrupt_bit());
+        assert_eq!(Interrupt::Serial.bit(), 1 << 7);
+        assert_eq!(Interrupt::GamePak.bit(), 1 << 13);
+    }
+
+    #[test]
+    fn enable_disable() {
+        fn handler() {}
+        let ie = unsafe { IE.read_volatile() };
+
+        enable(Interrupt::Dma3, Some(handler));
+        assert_ne!(unsafe { IE.read_volatile() } & Interrupt::Dma3.bit(), 0);
+        assert!(unsafe { IRQ_HANDLERS[Interrupt::Dma3 as usize] }.is_some());
+
+        disable(Interrupt::Dma3);
+        assert_eq!(unsafe { IE.read_volatile() }, ie);
+        assert!(unsafe { IRQ_HANDLERS[Interrupt::Dma3 as usize] }.is_none());
+    }
+
+    #[test]
+    fn dispatcher_registers_serial() {
+        fn handler() {}
+        let ie = unsafe { IE.read_volatile() };
+        let previous = unsafe { IRQ_HANDLERS[Interrupt::Serial as usize] };
+
+        Dispatcher.register_serial(handler);
+
+        assert_ne!(unsafe { IE.read_volatile() } & Interrupt::Serial.bit(), 0);
+        assert!(unsafe { IRQ_HANDLERS[Interrupt::Serial as usize] }.is_some());
+        unsafe {
+            IRQ_HANDLERS[Interrupt::Serial as usize] = previous;
+            IE.write_volatile(ie);
+        }
+    }
+
+    #[test]
+    fn dispatch_calls_handler() {
+        static mut DISPATCHED: u8 = 0;
+        fn handler() {
+            unsafe {
+                DISPATCHED += 1;
+            }
+        }
+        let ime = unsafe { IME.read_volatile() };
+        let ie = unsafe { IE.read_volatile() };
+        unsafe {
+            IME.write_volatile(0);
+        }
+        enable(Interrupt::Timer3, Some(handler));
+        unsafe {
+            // Overflow timer 3 immediately, with its interrupt requested.
+            Timer::Timer3.counter().write_volatile(0xFFFF);
+            Timer::Timer3.control().write_volatile(0x80 | 0x40);
+            while IF.read_volatile() & Interrupt::Timer3.bit() == 0 {}
+            Timer::Timer3.control().write_volatile(0);
+            BIOS_IF.write_volatile(0);
+        }
+
+        dispatch();
+
+        assert_eq!(unsafe { DISPATCHED }, 1);
+        unsafe {
+            assert_eq!(IF.read_volatile() & Interrupt::Timer3.bit(), 0);
+            assert_eq!(BIOS_IF.read_volatile(), Interrupt::Timer3.bit());
+        }
+        disable(Interrupt::Timer3);
+        unsafe {
+            IE.write_volatile(ie);
+            IME.write_volatile(ime);
+        }
+    }
+
+    #[test]
+    fn on_serial_irq_leaves_flags() {
+        let ime = unsafe { IME.read_volatile() };
+        unsafe {
+            IME.write_volatile(0);
+            // Overflow timer 3 immediately, with its interrupt requested but not enabled.
+            Timer::Timer3.counter().write_volatile(0xFFFF);
+            Timer::Timer3.control().write_volatile(0x80 | 0x40);
+            while IF.read_volatile() & Interrupt::Timer3.bit() == 0 {}
+            Timer::Timer3.control().write_volatile(0);
+            BIOS_IF.write_volatile(0);
+        }
+
+        on_serial_irq();
+
+        unsafe {
+            assert_ne!(IF.read_volatile() & Interrupt::Timer3.bit(), 0);
+            assert_eq!(BIOS_IF.read_volatile(), 0);
+            IF.write_volatile(Interrupt::Timer3.bit());
+            IME.write_volatile(ime);
+        }
+    }
+
+    #[test]
+    fn dispatch_placement() {
+        #[cfg(not(feature = "ewram-serial"))]
+        let ram = 0x0300_0000..0x0300_8000;
+        #[cfg(feature = "ewram-serial")]
+        let ram = 0x0200_0000..0x0204_0000;
+
+        assert!(ram.contains(&((dispatch as *const ()).addr())));
+        assert!(ram.contains(&(&raw const IRQ_HANDLERS).addr()));
+    }
+
+    #[test]
+    fn irq_registrar_fn_debug() {
+        assert_eq!(
+            format!("{:?}", IrqRegistrarFn::new(|_| {})),
+            "IrqRegistrarFn { .. }"
+        );
+    }
+}
@@ -0,0 +1,199 @@
+//! Low-frequency oscillation of intensity.
+
+use crate::{Fixed, Intensity};
+
+/// Half a period of a sine wave, from its peak at index `0` to its trough at index `128`, with
+/// `256` phase steps per period.
+const SINE: [u8; 129] = [
+    255, 255, 255, 255, 254, 254, 254, 253, 253, 252, 251, 250, 250, 249, 248, 246, 245, 244, 243,
+    241, 240, 238, 237, 235, 234, 232, 230, 228, 226, 224, 222, 220, 218, 215, 213, 211, 208, 206,
+    203, 201, 198, 196, 193, 190, 188, 185, 182, 179, 176, 173, 170, 167, 165, 162, 158, 155, 152,
+    149, 146, 143, 140, 137, 134, 131, 128, 124, 121, 118, 115, 112, 109, 106, 103, 100, 97, 93,
+    90, 88, 85, 82, 79, 76, 73, 70, 67, 65, 62, 59, 57, 54, 52, 49, 47, 44, 42, 40, 37, 35, 33, 31,
+    29, 27, 25, 23, 21, 20, 18, 17, 15, 14, 12, 11, 10, 9, 7, 6, 5, 5, 4, 3, 2, 2, 1, 1, 1, 0, 0,
+    0, 0,
+];
+
+/// The shape of an [`Lfo`]'s oscillation.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Waveform {
+    /// Rises and falls smoothly, like a wobbling floor.
+    Sine,
+    /// Rises and falls at a constant rate.
+    Triangle,
+    /// Alternates between its peak and trough every half period, like helicopter blades.
+    Square,
+}
+
+impl Waveform {
+    /// Samples the waveform at `phase`, where `256` is a full period, returning a level from `0`
+    /// at the trough to `255` at the peak.
+    const fn sample(self, phase: u8) -> u8 {
+        // Every waveform starts at its peak and reaches its trough halfway through the period.
+        let half = if phase <= 128 {
+            phase
+        } else {
+            0u8.wrapping_sub(phase)
+        };
+        match self {
+            Self::Sine => SINE[half as usize],
+            Self::Triangle => (255 - (half as u16 * 255 / 128)) as u8,
+            Self::Square => {
+                if phase < 128 {
+                    255
+                } else {
+                    0
+                }
+            }
+        }
+    }
+}
+
+/// A low-frequency oscillator, modulating intensity periodically over time.
+///
+/// Each frame, an LFO scales an intensity by a gain that oscillates in the given [`Waveform`] once
+/// every `period` frames. At its peak, the gain is [`Fixed::ONE`], leaving the intensity
+/// unchanged. At its trough, the intensity is reduced by `depth`, so a `depth` of [`Fixed::ONE`]
+/// turns it off entirely. Oscillation starts at the peak.
+///
+/// An LFO can modulate everything played on a [`Mixer`] channel through
+/// [`Mixer::set_modulation()`], which suits effects like an unstable floor or a passing
+/// helicopter. It can also be composed with an [`Effect`], such as an envelope, by modulating the
+/// effect's samples with [`apply()`].
+///
+/// ``` rust
+/// use gba_rumble::{EffectFn, Fixed, Intensity, Lfo, Waveform};
+///
+/// // Wobble between full and half strength twice a second.
+/// let wobble = Lfo::new(Waveform::Sine, 30, Fixed::HALF);
+/// // Fade out over a second, wobbling all the while.
+/// let effect = EffectFn::new(|frame| {
+///     let envelope = Intensity::new(255u32.saturating_sub(frame * 255 / 60) as u8);
+///     wobble.apply(envelope, frame)
+/// });
+/// ```
+///
+/// [`apply()`]: Lfo::apply()
+/// [`Effect`]: crate::Effect
+/// [`Mixer`]: crate::Mixer
+/// [`Mixer::set_modulation()`]: crate::Mixer::set_modulation()
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Lfo {
+    /// The shape of the oscillation.
+    pub waveform: Waveform,
+    /// The number of frames in a single oscillation. A value of `0` disables oscillation.
+    pub period: u16,
+    /// How much intensity is reduced at the trough of the oscillation, up to [`Fixed::ONE`].
+    pub depth: Fixed,
+}
+
+impl Lfo {
+    /// Creates an oscillator with the given waveform, period in frames, and depth.
+    pub const fn new(waveform: Waveform, period: u16, depth: Fixed) -> Self {
+        Self {
+            waveform,
+            period,
+            depth,
+        }
+    }
+
+    /// Returns the gain on the given frame, counting from the start of the oscillation.
+    pub const fn gain(self, frame: u32) -> Fixed {
+        if self.period == 0 {
+            return Fixed::ONE;
+        }
+        let period = self.period as u32;
+        let phase = ((frame % period) * 256 / period) as u8;
+        let depth = if self.depth.to_bits() > Fixed::ONE.to_bits() {
+            Fixed::ONE
+        } else {
+            self.depth
+        };
+        let dip = (255 - self.waveform.sample(phase)) as u32 * depth.to_bits() as u32 / 255;
+        Fixed::ONE.saturating_sub(Fixed::from_bits(dip as u16))
+    }
+
+    /// Modulates `intensity` by the gain on the given frame.
+    pub const fn apply(self, intensity: Intensity, frame: u32) -> Intensity {
+        intensity.scale(self.gain(frame))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Lfo, Waveform};
+    use crate::{Fixed, Intensity};
+    use gba_test::test;
+
+    #[test]
+    fn waveform_peaks_and_troughs() {
+        for waveform in [Waveform::Sine, Waveform::Triangle, Waveform::Square] {
+            assert_eq!(waveform.sample(0), 255);
+            assert_eq!(waveform.sample(128), 0);
+        }
+    }
+
+    #[test]
+    fn sine_symmetric() {
+        for phase in 1..128 {
+            assert_eq!(
+                Waveform::Sine.sample(phase),
+                Waveform::Sine.sample(0u8.wrapping_sub(phase))
+            );
+        }
+        assert_eq!(Waveform::Sine.sample(64), 128);
+    }
+
+    #[test]
+    fn triangle() {
+        assert_eq!(Waveform::Triangle.sample(64), 128);
+        assert_eq!(Waveform::Triangle.sample(192), 128);
+    }
+
+    #[test]
+    fn square() {
+        assert_eq!(Waveform::Square.sample(127), 255);
+        assert_eq!(Waveform::Square.sample(255), 0);
+    }
+
+    #[test]
+    fn gain_full_depth() {
+        let lfo = Lfo::new(Waveform::Square, 4, Fixed::ONE);
+
+        assert_eq!(lfo.gain(0), Fixed::ONE);
+        assert_eq!(lfo.gain(1), Fixed::ONE);
+        assert_eq!(lfo.gain(2), Fixed::ZERO);
+        assert_eq!(lfo.gain(3), Fixed::ZERO);
+        assert_eq!(lfo.gain(4), Fixed::ONE);
+    }
+
+    #[test]
+    fn gain_half_depth() {
+        let lfo = Lfo::new(Waveform::Triangle, 60, Fixed::HALF);
+
+        assert_eq!(lfo.gain(0), Fixed::ONE);
+        assert_eq!(lfo.gain(30), Fixed::HALF);
+    }
+
+    #[test]
+    fn gain_depth_clamped() {
+        let lfo = Lfo::new(Waveform::Square, 2, Fixed::from_int(2));
+
+        assert_eq!(lfo.gain(1), Fixed::ZERO);
+    }
+
+    #[test]
+    fn gain_zero_period() {
+        let lfo = Lfo::new(Waveform::Sine, 0, Fixed::ONE);
+
+        assert_eq!(lfo.gain(17), Fixed::ONE);
+    }
+
+    #[test]
+    fn apply() {
+        let lfo = Lfo::new(Waveform::Square, 2, Fixed::HALF);
+
+        assert_eq!(lfo.apply(Intensity::new(200), 0), Intensity::new(200));
+        assert_eq!(lfo.apply(Intensity::new(200), 1), Intensity::new(100));
+    }
+}
@@ -11,12 +11,15 @@
 //! # Usage
 //! There are two ways to use this library: by using a cartridge's built-in rumble through [`Gpio`]
 //! and by using the Game Boy Player's rumble functionality through [`GameBoyPlayer`]. These two
-//! can also both be used to enable Game Boy Player rumble with cartridge rumble as a fallback.
+//! can also both be used to enable Game Boy Player rumble with cartridge rumble as a fallback,
+//! or driven together through [`CompositeRumble`], routing each effect to the cartridge's motor,
+//! the controller's motor, or both.
 //!
 //! ## Cartridge (GPIO) Rumble
 //! To use a cartridge's built-in rumble through general purpose I/O (GPIO), use the [`Gpio`]
 //! struct. No detection logic is available, as there is no reliable way to detect GPIO rumble.
-//! Calling these functions when rumble is not available will do nothing.
+//! Calling these functions when rumble is not available will do nothing. Programs that only use
+//! [`Gpio`] link none of the Game Boy Player's code or splash screen data.
 //!
 //! ``` rust
 //! let gpio = gba_rumble::Gpio;
@@ -28,6 +31,10 @@
 //! gpio.stop();
 //! ```
 //!
+//! For games that just need rumble on and off, such as jam games, [`SimpleRumble`] wraps [`Gpio`]
+//! in a single struct that also handles timed pulses. To rumble while a button is held, with
+//! debouncing and spin-up compensation handled, use [`HoldToRumble`] with any backend.
+//!
 //! ## Game Boy Player
 //! To use the Game Boy Player's rubmle functionality, you must detect the Game Boy Player by
 //! calling [`GameBoyPlayer::detect()`] at the beginning of your program. If the program is being
@@ -35,8 +42,9 @@
 //! start and stop rumble.
 //!
 //! Communication with the Game Boy Player is done through the serial point. This is done by
-//! calling [`game_boy_player_interrupt()`] when a serial interrupt is received. Setting this up
-//! will be specific to your own code and any frameworks you may be using; for examples using the
+//! calling [`game_boy_player_interrupt()`] when a serial interrupt is received, after configuring
+//! the serial port with [`GameBoyPlayer::enable_serial()`]. Setting up the interrupt will be
+//! specific to your own code and any frameworks you may be using; for examples using the
 //! [`gba`](https://crates.io/crates/gba) or [`agb`](https://crates.io/crates/agba) crates, see
 //! [`/examples`](https://github.com/Anders429/gba_rumble/tree/master/examples).
 //!
@@ -48,6 +56,7 @@
 //!     // To actually use it, you must also call `game_boy_player_interrupt()` when a serial
 //!     // interrupt is received. This will be specific to your own code and any frameworks you may
 //!     // be using.
+//!     game_boy_player.enable_serial();
 //!
 //!     // Update the serial connection once a frame.
 //!     game_boy_player.update();
@@ -64,969 +73,369 @@
 //!     game_boy_player.hard_stop();
 //! }
 //! ```
+//!
+//! Detection, registering the interrupt handler, and configuring the serial port can also be done
+//! in a single call to [`GameBoyPlayer::boot()`], given an [`IrqRegistrar`] for your interrupt
+//! framework. Programs without an interrupt framework can use the minimal dispatcher in the
+//! [`irq`] module instead.
+//!
+//! Detection displays the Game Boy Player logo, which the Game Boy Player requires before
+//! unlocking its functionality. Programs that manage the display through a framework can draw the
+//! logo themselves by implementing [`SplashRenderer`], and runtimes that acknowledge interrupts
+//! themselves can supply their own [`Waiter`]. This and other behavior, such as recovering from a
+//! stalled connection, can be customized by detecting with [`GameBoyPlayer::detect_with()`] and a
+//! [`GbpConfig`]. To run detection from the game's own main loop instead, iterate over the
+//! [`DetectionFrames`] returned by [`GameBoyPlayer::detection_frames()`]. Programs budgeting
+//! their boot time can use [`GameBoyPlayer::detect_bounded()`], which takes a fixed number of
+//! frames regardless of how interrupts are configured. Games that soft reset can skip the logo
+//! after a reset using [`DetectionPolicy::SkipOnWarmBoot`], as reported by [`BootKind`].
+//!
+//! Games whose logic does not run once per frame can update the Game Boy Player from a hardware
+//! [`Timer`] instead, using [`GameBoyPlayer::enable_timer_updates()`].
+//!
+//! To help triage reports of rumble no longer working, the events of a session with the Game Boy
+//! Player can be recorded into an [`EventLog`], installed with
+//! [`GameBoyPlayer::set_event_log()`]. For research into what else the Game Boy Player reveals
+//! over the link, the raw words it sends can be read using [`session::raw_inbound()`].
+//!
+//! ## Backends
+//! Both [`Gpio`] and [`GameBoyPlayer`] implement the [`Backend`] trait, allowing code to be
+//! written generically over either. Each backend describes what it is able to do through its
+//! [`Capabilities`].
+//!
+//! Games that don't need to know which mechanism is in use can call [`detect_all()`] instead,
+//! which returns a [`Device`] backed by the most capable mechanism available. A [`Preference`]
+//! lets players choose cartridge rumble over the Game Boy Player at runtime. Afterwards,
+//! [`backends()`] lists every mechanism along with whether it was detected, for hardware
+//! information screens.
+//!
+//! Engines that want the whole subsystem in one place can use [`Haptics`], which owns detection,
+//! effect playback, ambient rumble, and the player's settings behind a single value.
+//! Engines whose logic does not run once a frame can advance it with [`Haptics::tick_with()`]
+//! and a [`Clock`], such as a [`VBlankClock`] or [`TimerClock`], so that effects keep their
+//! authored length.
+//!
+//! Enabling the `ds-rumble-pak` feature adds a `DsRumblePak` backend for the DS Rumble Pak, which
+//! can be used by programs that do not run from a cartridge, such as multiboot programs.
+//!
+//! ## Patterns
+//! Rather than starting and stopping rumble by hand, authored [`Pattern`]s can be played back one
+//! frame at a time using a [`Sequencer`]. Patterns can contain [`Marker`]s where playback pauses
+//! until the game calls [`Sequencer::advance()`].
+//!
+//! ``` rust
+//! use gba_rumble::{Intensity, Marker, Pattern, Sequencer, Step, pattern};
+//!
+//! const RELEASE: Marker = Marker::new(0);
+//! const CHARGE: Pattern = pattern![
+//!     Step::Hold { intensity: Intensity::new(64), frames: 30 },
+//!     Step::Marker(RELEASE),
+//!     Step::Hold { intensity: Intensity::MAX, frames: 15 },
+//! ];
+//!
+//! let mut sequencer = Sequencer::new(CHARGE);
+//! // Once a frame, get the intensity to rumble at.
+//! let intensity = sequencer.tick();
+//! ```
+//!
+//! To play multiple effects at once, a [`Mixer`] combines prioritized channels of patterns. Each
+//! channel has a [`Preemption`] policy deciding what happens to new effects while a
+//! higher-priority effect is playing, including ducking it to a reduced intensity. Effects
+//! waiting to play are held in a fixed-capacity [`Queue`], so none of this requires an allocator.
+//! The number of effects playing at once can be capped, with an [`Eviction`] policy choosing which
+//! effect to stop when the cap is reached.
+//!
+//! Rumble that depends on live game state, such as an engine's RPM, can be computed each frame
+//! by implementing [`Effect`] (or wrapping a closure in [`EffectFn`]) and playing it on a channel
+//! with [`Mixer::play_effect()`]. Effects like a wobbling floor or a passing helicopter can be
+//! made by modulating a channel with a low-frequency oscillator, or [`Lfo`].
+//!
+//! Pattern data loaded from assets can be checked ahead of time with [`Pattern::validate()`],
+//! which reports encoding errors along with patterns unlikely to feel as intended, such as pulses
+//! too short for the motor to spin up. From a debug menu, a [`Preview`] plays a pattern at
+//! reduced speed or one frame at a time, so its envelope can be judged on hardware.
+//!
+//! Patterns can be registered under small integer [`EffectId`]s in a [`Registry`] at startup, then
+//! triggered by ID, such as from level scripts or serialized entity data. Each effect can be given
+//! a cooldown, keeping it from being retriggered every frame by collision code.
+//!
+//! A [`Driver`] sends the mixed intensity to a [`Backend`] each frame, logging the commands it
+//! sends. Intensities are shaped into motor duty cycles by a [`ResponseCurve`], so that effects can
+//! be authored in terms of how strong they feel. Playback is fully deterministic, so replay systems
+//! can compare these logs against a recorded run. Commands can also be captured with a
+//! [`Recorder`] and replayed later with a [`Player`], such as for attract-mode demos. For testing
+//! rumble logic without hardware, a [`RumbleSpy`] backend records commands and provides assertions
+//! about them.
+//!
+//! The GBA has no floating-point unit, so this crate never uses floating-point math. Fractional
+//! quantities, such as scaling factors, use the [`Fixed`] 8.8 fixed-point type instead.
+//!
+//! Player preferences, such as turning vibration off in an options menu, are represented by
+//! [`Settings`] and honored by the [`Driver`]. Games can persist them across reboots by
+//! implementing [`SettingsStore`] over their own save code. For a toggle that cannot be bypassed
+//! by code holding a backend directly, [`set_enabled()`] disables rumble on every backend at once.
+//! A "test vibration" option can pulse the motor with [`Driver::self_test()`], which reports a
+//! [`SelfTest`] confirming the pulse where the hardware allows it.
+//! Similarly, [`set_power_saving()`] caps how hard and how long the motor is driven, for players
+//! who want to save battery. Each [`Driver`] also tracks how much of the time its motor has been
+//! on, and can throttle the motor once a [`DutyBudget`] is exceeded.
+//!
+//! Games with suspend or save-state features can snapshot playback progress of a [`Sequencer`],
+//! [`Mixer`], [`Driver`], or [`GameBoyPlayer`] into a byte buffer through the [`SaveState`] trait,
+//! and restore it later so that rumble resumes exactly where it left off.
+//!
+//! Programs that soft reset, or jump to another ROM such as a flash cartridge's menu, should call
+//! [`before_soft_reset()`] first, so that the motor is not left running afterward. With the
+//! `panic-handler` feature, the `catch_panic_stop!` macro does the same when the program
+//! panics. Dropping a [`GameBoyPlayer`] handle does nothing, since it can be freely copied, but a
+//! [`ManagedGameBoyPlayer`] ends the session with the Game Boy Player when dropped.
+//!
+//! ## Memory placement
+//! The GBA's memory regions differ greatly in speed. The 32 KiB of IWRAM has a 32-bit bus with no
+//! wait states, so each access takes one cycle. EWRAM has a 16-bit bus with two wait states,
+//! taking three cycles for each 16-bit THUMB instruction. ROM, with the default `WAITCNT`
+//! settings, takes five cycles for a random access and three for a sequential one, helped by the
+//! prefetch buffer if it is enabled. IWRAM is therefore the fastest place to run code, but it is
+//! scarce and often claimed by audio mixers.
+//!
+//! By default, only [`game_boy_player_interrupt()`], [`game_boy_player_keep_alive()`], and the
+//! data they read are placed in IWRAM, since they must respond to the Game Boy Player quickly.
+//! They never access ROM, so they are unaffected by wait states or DMA transfers from ROM. The
+//! [`irq`] module's dispatcher, if used, is placed alongside them. Everything else runs from ROM.
+//! The following features change this:
+//!
+//! - `ewram-serial` places the serial interrupt handler, keep-alive, and interrupt dispatcher, and
+//!   the state they use, in EWRAM. This frees IWRAM, at the cost of roughly tripling the time
+//!   spent in the handler.
+//! - `iwram-tick` places the per-frame `tick()` code of [`Sequencer`], [`Mixer`], and [`Driver`] in
+//!   IWRAM, for games that tick from an interrupt handler and need it to be as short as possible.
+//! - `ewram-tick` places the same code in EWRAM instead, which avoids contending with other
+//!   accesses to ROM, such as DMA from ROM. `iwram-tick` takes precedence if both are enabled.
+//! - `splash-overlay` moves [`SPLASH_PALETTE`], [`SPLASH_MAP`], and [`SPLASH_TILES`] out of
+//!   `.rodata`, so that a custom linker script can place them in their own region of ROM, such as
+//!   an overlay region for large cold assets.
+//!
+//! Placing code in RAM requires the program's linker script to copy `.iwram.*` and `.ewram.*`
+//! sections from ROM at startup, as the linker scripts of the `gba` and `agb` crates do. For
+//! programs whose linker scripts do not, [`game_boy_player_interrupt_handler()`] returns a build of
+//! the serial interrupt handler that runs from ROM instead. The state of a [`Sequencer`],
+//! [`Mixer`], or [`Driver`] lives wherever the game stores the value itself. The [`sections`]
+//! module names the sections used for each of these, for checking a linker script against.
+//!
+//! Multiboot programs, such as homebrew loaded onto a Game Boy Player over the GameCube link
+//! cable, are run from EWRAM in place of ROM. Everything behaves the same as when running from a
+//! cartridge, as long as the program's linker script still copies `.iwram.*` sections into IWRAM
+//! at startup: the splash screen is uploaded from EWRAM rather than ROM, and the serial interrupt
+//! handler runs from IWRAM as usual.
+//!
+//! The addresses of the hardware registers used by this crate are available in the [`regs`]
+//! module, for programs that need to access them directly. The values exchanged with the Game Boy
+//! Player are documented in the [`protocol`] module, for emulators implementing its rumble.
+//!
+//! The [`presets`] module provides patterns for common interface feedback, such as menu
+//! navigation and errors, following the conventions players expect from other games.
+//!
+//! For profiling, [`metrics()`] returns a [`MetricsFrame`] counting the serial interrupts and
+//! transfers of the last frame, along with the cost of [`Driver::tick()`] if measured with
+//! [`set_tick_profiling()`], for drawing in a game's existing performance overlay.
+//!
+//! Enabling the `alloc` feature adds a `PatternBuilder` for constructing patterns at runtime, such
+//! as from physics events.
+//!
+//! Enabling the `bios` feature makes the crate's `bios` module public, exposing the BIOS calls it
+//! uses to wait for v-blank and reset VRAM for programs that need the same calls.
+//!
+//! Enabling the `diagnostics` feature adds a `diagnostics` module with an on-device menu for
+//! checking which backends are detected, firing test effects, and viewing serial statistics.
+//!
+//! Enabling the `conformance` feature adds a `conformance` module with a suite of checks that
+//! emulator developers can run to validate their Game Boy Player emulation against this crate.
+//!
+//! Diagnostic output, such as from the `diagnostics` and `conformance` modules, is discarded by
+//! default. Enabling the `log` feature writes it through the [`log`](https://docs.rs/log) crate to
+//! whichever logger the program installs, and enabling the `mgba-log` feature also installs
+//! [`mgba_log`](https://docs.rs/mgba_log) as the logger when either module is run. Enabling the
+//! `defmt` feature writes it through the [`defmt`](https://docs.rs/defmt) crate instead, or as
+//! well, to the global logger the program defines.
+//!
+//! For QA builds, the `force-gbp` and `force-gpio` features override the result of Game Boy Player
+//! detection, so that testers can exercise the Game Boy Player and cartridge rumble code paths on
+//! any hardware or emulator without code changes. Detection still displays the logo as usual, but
+//! then always reports the Game Boy Player as detected, or as not detected, respectively. If both
+//! are enabled, `force-gbp` takes precedence.
+//!
+//! When comparing behavior between hardware and an emulator, the `sio-crc` feature keeps a CRC-32
+//! of every word sent to the Game Boy Player, returned by `GameBoyPlayer::outbound_crc()`, so that
+//! both sides can quickly be confirmed to have sent identical streams. Similarly, the `paranoid`
+//! feature reads back every write to `SIOCNT` and `SIODATA`, counting those that do not match in
+//! `GameBoyPlayer::write_mismatches()`, to help track down other code or a misbehaving emulator
+//! interfering with the serial port.
+//!
+//! To see how much of a game's startup the crate accounts for, the `boot-report` feature measures
+//! each boot phase it owns: uploading the splash screen, the detection loop, clearing VRAM
+//! afterwards, and arming the serial port. The length of each phase is written through `log` in
+//! cycles and frames. Measuring uses timers 2 and 3, which must not be used by the program while
+//! booting with this feature enabled.
+//!
+//! Publishers shipping a version of a game without rumble from the same code can enable the
+//! `disabled` feature, which compiles the crate down to no-ops while keeping its API intact.
+//! Detection returns immediately without displaying the logo, never finding a mechanism, no
+//! hardware register is touched, and the per-frame updates of [`Driver`], [`Mixer`], [`Device`],
+//! [`Haptics`], and [`SimpleRumble`] return immediately, so that almost none of the crate remains
+//! in the final program. [`is_enabled()`] always returns `false` in such builds.
+//!
+//! The `deranged` feature, enabled by default, uses the `deranged` crate to track the protocol's
+//! bounded indices. Disabling default features replaces it with an internal equivalent, leaving
+//! the crate with no required dependencies, for builds with strict dependency policies.
 
 #![no_std]
 #![cfg_attr(test, no_main)]
 #![cfg_attr(test, feature(custom_test_frameworks))]
 #![cfg_attr(test, test_runner(gba_test::runner))]
 #![cfg_attr(test, reexport_test_harness_main = "test_harness")]
+#![cfg_attr(test, feature(strict_provenance_lints))]
+#![cfg_attr(test, deny(fuzzy_provenance_casts, lossy_provenance_casts))]
 
-#[cfg(test)]
+#[cfg(any(test, feature = "alloc"))]
 extern crate alloc;
 
+mod backend;
+#[cfg(feature = "bios")]
+pub mod bios;
+#[cfg(not(feature = "bios"))]
+mod bios;
+mod boot;
+mod boot_report;
+mod calibration;
+mod clock;
+mod composite;
+mod config;
+#[cfg(feature = "conformance")]
+pub mod conformance;
+mod curve;
+mod detection;
+mod device;
+#[cfg(feature = "diagnostics")]
+pub mod diagnostics;
+mod dma;
+mod driver;
+#[cfg(feature = "ds-rumble-pak")]
+mod ds_rumble_pak;
+mod duty;
+mod effect;
+mod enabled;
+mod error;
+mod event_log;
+mod explain;
+mod fixed;
+mod game_boy_player;
+mod gpio;
+mod haptics;
+mod hold;
+mod intensity;
+pub mod irq;
+mod lfo;
+mod logging;
+mod managed;
+mod metrics;
+mod mixer;
+mod params;
+mod pattern;
+#[cfg(feature = "alloc")]
+mod pattern_builder;
+mod power;
+pub mod presets;
+mod preview;
+pub mod protocol;
+mod queue;
+mod ranged;
+mod recorder;
+mod registry;
+pub mod regs;
+pub mod sections;
+mod self_test;
+mod sequencer;
+pub mod session;
+mod settings;
+mod simple;
+mod soft_reset;
+mod source_handle;
 mod splash_screen;
-
-use core::{
-    arch::asm,
-    fmt,
-    fmt::{Debug, Formatter},
+mod spy;
+mod state;
+mod timer;
+mod waiter;
+
+pub use backend::{Backend, Capabilities, Command};
+pub use boot::{BootKind, DetectionPolicy};
+pub use calibration::{Calibration, MotorType};
+pub use clock::{Clock, FrameCounter, TimerClock, VBlankClock};
+pub use composite::{CompositeRumble, Route};
+pub use config::{CommandDelivery, GbpConfig, KeepAlive};
+pub use curve::ResponseCurve;
+pub use detection::{DetectOptions, Detection, DetectionFrames, FrameResult};
+pub use device::{
+    BackendKind, BackendStatus, Backends, Device, Preference, Presence, backends, detect_all,
 };
-use deranged::RangedUsize;
-
-const DATA: *mut Data = 0x080000c4 as *mut Data;
-const READ_WRITE: *mut ReadWrite = 0x080000c6 as *mut ReadWrite;
-const ENABLE: *mut u16 = 0x080000c8 as *mut u16;
-const DISPCNT: *mut u16 = 0x0400_0000 as *mut u16;
-const BG0CNT: *mut u16 = 0x0400_0008 as *mut u16;
-const MAP: *mut [u8; 844] = 0x0600_0000 as *mut [u8; 844];
-const TILES: *mut [u8; 0x4000] = 0x0600_8000 as *mut [u8; 0x4000];
-const PALETTE: *mut [u8; 128] = 0x0500_0000 as *mut [u8; 128];
-const KEYINPUT: *mut u16 = 0x0400_0130 as *mut u16;
-const SIODATA: *mut u32 = 0x0400_0120 as *mut u32;
-const SIOCNT: *mut u16 = 0x0400_0128 as *mut u16;
-
-static mut GAME_BOY_PLAYER_RUMBLE: GameBoyPlayerRumble = GameBoyPlayerRumble::Stop;
-static mut GAME_BOY_PLAYER_SIO_STATE: GameBoyPlayerSioState = GameBoyPlayerSioState::Handshake {
-    index: RangedUsize::new_static::<0>(),
+pub use dma::Dma;
+pub use driver::{CommandLog, Driver, TimedCommand, frame, motor_on_frames};
+#[cfg(feature = "ds-rumble-pak")]
+pub use ds_rumble_pak::DsRumblePak;
+pub use duty::DutyBudget;
+pub use effect::{Effect, EffectFn};
+pub use enabled::{is_enabled, set_enabled};
+pub use error::Error;
+pub use event_log::{EventLog, SessionEvent, TimedEvent};
+pub use explain::{Explanation, SerialStatus};
+pub use fixed::Fixed;
+pub use game_boy_player::{
+    GameBoyPlayer, LinkStatus, ResetReason, SerialConfigError, SioState, game_boy_player_interrupt,
+    game_boy_player_interrupt_handler, game_boy_player_keep_alive,
+    game_boy_player_nested_interrupts,
 };
-
-#[derive(Debug)]
-#[repr(u16)]
-enum ReadWrite {
-    #[allow(dead_code)]
-    Read = 0,
-    Write = 8,
-}
-
-#[derive(Debug)]
-#[repr(u16)]
-enum Data {
-    Enabled = 8,
-    Disabled = 0,
-}
-
-/// Waits until a new v-blank interrupt occurs.
-#[instruction_set(arm::t32)]
-fn wait_for_vblank() {
-    unsafe {
-        asm! {
-            "swi #0x05",
-            out("r0") _,
-            out("r1") _,
-            out("r3") _,
-            options(preserves_flags),
-        }
-    };
-}
-
-/// Reset VRAM.
-///
-/// This resets both VRAM and palette data. It is called after detecting the Game Boy Player.
-#[inline]
-#[instruction_set(arm::t32)]
-fn reset_vram() {
-    unsafe {
-        asm! {
-            "swi #0x01",
-            in("r0") 12,
-        }
-    };
-}
-
-#[derive(Clone, Copy, Debug)]
-enum GameBoyPlayerRumble {
-    Stop = 0x4000_0004,
-    HardStop = 0x4000_0015,
-    Start = 0x4000_0026,
-}
-
-#[derive(Debug, Eq, PartialEq)]
-enum GameBoyPlayerSioState {
-    Handshake { index: RangedUsize<0, 3> },
-    Magic { index: RangedUsize<1, 3> },
-    SendData,
-}
-
-impl GameBoyPlayerSioState {
-    const HANDSHAKE: [u16; 4] = [0x494e, 0x544e, 0x4e45, 0x4f44];
-    const MAGIC_VALUES: [u32; 4] = [0xB0BB8002, 0x10000010, 0x20000013, 0x40000004];
-
-    fn new() -> Self {
-        Self::Handshake {
-            index: RangedUsize::new_static::<0>(),
-        }
-    }
-
-    fn get_handshake_key(index: RangedUsize<0, 3>) -> u16 {
-        unsafe { *Self::HANDSHAKE.get_unchecked(index.get()) }
-    }
-
-    fn get_magic_values(index: RangedUsize<1, 3>) -> (u32, u32) {
-        unsafe {
-            (
-                *Self::MAGIC_VALUES.get_unchecked(index.get().unchecked_sub(1)),
-                *Self::MAGIC_VALUES.get_unchecked(index.get()),
-            )
-        }
-    }
-}
-
-/// Handles SIO interrupts for every stage of the Game Boy Player communication process.
-///
-/// This function should be called within an interrupt handler when the SIO interrupt is triggered.
-/// See [`/examples`](https://github.com/Anders429/gba_rumble/tree/master/examples) for examples of
-/// using this function in both the [`gba`](https://crates.io/crates/gba) or
-/// [`agb`](https://crates.io/crates/agba) crates.
-#[unsafe(link_section = ".iwram")]
-pub fn game_boy_player_interrupt() {
-    let input = unsafe { SIODATA.read_volatile() };
-
-    unsafe {
-        GAME_BOY_PLAYER_SIO_STATE = match GAME_BOY_PLAYER_SIO_STATE {
-            GameBoyPlayerSioState::Handshake { index } => {
-                let key = GameBoyPlayerSioState::get_handshake_key(index);
-                if input as u16 == key {
-                    if (input >> 16) as u16 == !key {
-                        if let Some(new_index) = index.checked_add(1) {
-                            let new_key = GameBoyPlayerSioState::get_handshake_key(new_index);
-                            SIODATA.write_volatile(input >> 16 | ((new_key as u32) << 16));
-                            SIOCNT.write_volatile(SIOCNT.read_volatile() | (1 << 7));
-                            GameBoyPlayerSioState::Handshake { index: new_index }
-                        } else {
-                            SIODATA.write_volatile(0x8000B0BB);
-                            SIOCNT.write_volatile(SIOCNT.read_volatile() | (1 << 7));
-                            GameBoyPlayerSioState::Magic {
-                                index: RangedUsize::new_static::<1>(),
-                            }
-                        }
-                    } else {
-                        SIODATA.write_volatile((!key) as u32 | ((key as u32) << 16));
-                        SIOCNT.write_volatile(SIOCNT.read_volatile() | (1 << 7));
-                        GameBoyPlayerSioState::Handshake { index }
-                    }
-                } else {
-                    // Unexpected input value. Reset.
-                    GameBoyPlayerSioState::new()
-                }
-            }
-            GameBoyPlayerSioState::Magic { index } => {
-                let (old_key, new_key) = GameBoyPlayerSioState::get_magic_values(index);
-                if input == old_key {
-                    SIODATA.write_volatile(new_key);
-                    SIOCNT.write_volatile(SIOCNT.read_volatile() | (1 << 7));
-                    if let Some(new_index) = index.checked_add(1) {
-                        GameBoyPlayerSioState::Magic { index: new_index }
-                    } else {
-                        GameBoyPlayerSioState::SendData
-                    }
-                } else {
-                    // Unexpected input value. Reset.
-                    GameBoyPlayerSioState::new()
-                }
-            }
-            GameBoyPlayerSioState::SendData => {
-                if input == 0x30000003 {
-                    SIODATA.write_volatile(GAME_BOY_PLAYER_RUMBLE as u32);
-                    SIOCNT.write_volatile(SIOCNT.read_volatile() | (1 << 7));
-                    // We stay in this state until the input changes.
-                    GameBoyPlayerSioState::SendData
-                } else {
-                    GameBoyPlayerSioState::new()
-                }
-            }
-        }
-    }
-}
-
-/// Game Boy Player rumble functionality.
-///
-/// # Setup
-/// To interact with the Game Boy Player's rumble, it must first be detected at the beginning of
-/// your program. This is done using the [`detect()`] function.
-///
-/// To use the Game Boy Player's rumble
-/// after it is detected, [`game_boy_player_interrupt()`] must be called when handling any serial
-/// interrupts received by the interrupt handler. The setup for this will differ depending on your
-/// code; see [`/examples`](https://github.com/Anders429/gba_rumble/tree/master/examples) for
-/// examples of using this function in both the [`gba`](https://crates.io/crates/gba) or
-/// [`agb`](https://crates.io/crates/agba) crates.
-///
-/// # Usage
-/// Once a frame, [`update()`] should be called to reset communication with the the Game Boy
-/// Player. That enables communication with the Game Boy Player through the [`start()`],
-/// [`stop()`], and [`hard_stop()`] methods.
-///
-/// [`detect()`]: GameBoyPlayer::detect()
-/// [`hard_stop()`]: GameBoyPlayer::hard_stop()
-/// [`start()`]: GameBoyPlayer::start()
-/// [`stop()`]: GameBoyPlayer::stop()
-/// [`update()`]: GameBoyPlayer::update()
-#[derive(Clone, Copy, Eq, PartialEq)]
-pub struct GameBoyPlayer {
-    private: (),
-}
-
-impl GameBoyPlayer {
-    /// Detect whether the program is being run on a Game Boy Player.
-    ///
-    /// This should be called at the beginning of your program. It will display the Game Boy Player
-    /// splash screen for a few seconds and listen for inputs from the Game Boy Player itself.
-    ///
-    /// Note that you must have vblank interrupts enabled, or this function will hang forever.
-    pub fn detect() -> Option<Self> {
-        // Draw the Game Boy Player splash screen.
-        let old_dispcnt = unsafe { DISPCNT.read_volatile() };
-        let old_bg0cnt = unsafe { BG0CNT.read_volatile() };
-        unsafe {
-            // Mode 0 with BG 0 enabled;
-            DISPCNT.write_volatile(256);
-            // Character Base Block 2, Screen Base Block 15.
-            BG0CNT.write_volatile(0x88);
-
-            TILES.write_volatile(splash_screen::TILES);
-            MAP.write_volatile(splash_screen::MAP);
-            PALETTE.write_volatile(splash_screen::PALETTE);
-        }
-
-        let mut detected = None;
-        // Detect Game Boy Player.
-        for _ in 0..125 {
-            wait_for_vblank();
-            // 0x030F indicates that all 4 directional values are pressed at once. This is not
-            // possible on a normal console, so the game boy player uses this value to indicate
-            // that its extra functionality has been unlocked. See GBATEK for more information.
-            if unsafe { KEYINPUT.read_volatile() } == 0x030F {
-                detected = Some(GameBoyPlayer { private: () });
-            }
-        }
-
-        unsafe {
-            DISPCNT.write_volatile(old_dispcnt);
-            BG0CNT.write_volatile(old_bg0cnt);
-        }
-        reset_vram();
-
-        detected
-    }
-
-    /// Activate rumble.
-    pub fn start(&self) {
-        unsafe {
-            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Start;
-        }
-    }
-
-    /// Deactivate rumble.
-    pub fn stop(&self) {
-        unsafe {
-            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Stop;
-        }
-    }
-
-    /// Deactivate rumble with a "hard" stop. This has a different feel compared to the [`stop()`] method.
-    ///
-    /// [`stop()`]: GameBoyPlayer::stop()
-    pub fn hard_stop(&self) {
-        unsafe {
-            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::HardStop;
-        }
-    }
-
-    /// Reset the connection with the Game Boy Player to allow further communication.
-    ///
-    /// This should be called once a frame.
-    pub fn update(&self) {
-        unsafe {
-            SIOCNT.write_volatile(SIOCNT.read_volatile() | (1 << 7));
-        }
-    }
-}
-
-impl Debug for GameBoyPlayer {
-    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
-        formatter.write_str("GameBoyPlayer")
-    }
-}
-
-/// Cartridge rumble functionality.
-///
-/// Communication with the cartridge's rumble motor is done through General Purpose I/O (GPIO).
-/// Specifically, this interacts using bit 3 (which is the standard pin used for rumble). Note that
-/// this may interfere with other communications done through GPIO, such as with a real-time clock
-/// device (they do not use the same bits, but they share the same address space).
-///
-/// Unlike [`GameBoyPlayer`], no setup is required to interact with GPIO rumble. Simply use an
-/// instance of `Gpio` to start and stop rumble:
-///
-/// ```rust
-/// let gpio = gba_rumble::Gpio;
-///
-/// // Activate the cartridge's rumble. This will continue until `stop()` is called.
-/// gpio.start();
-///
-/// // Deactivate the cartridge's rumble.
-/// gpio.stop();
-/// ```
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub struct Gpio;
-
-impl Gpio {
-    /// Activate rumble.
-    pub fn start(&self) {
-        unsafe {
-            ENABLE.write_volatile(1);
-            READ_WRITE.write_volatile(ReadWrite::Write);
-            DATA.write_volatile(Data::Enabled);
-        }
-    }
-
-    /// Deactivate rumble.
-    pub fn stop(&self) {
-        unsafe {
-            DATA.write_volatile(Data::Disabled);
-        }
-    }
-}
+pub use gpio::{Gpio, GpioConflict, Pin, PinDirection};
+pub use haptics::Haptics;
+pub use hold::HoldToRumble;
+pub use intensity::Intensity;
+pub use irq::{IrqRegistrar, IrqRegistrarFn};
+pub use lfo::{Lfo, Waveform};
+pub use managed::ManagedGameBoyPlayer;
+pub use metrics::{MetricsFrame, metrics, set_tick_profiling};
+pub use mixer::{ChannelId, Eviction, Mixer, PlayOutcome, Preemption, Tag};
+pub use params::EffectParams;
+pub use pattern::{
+    Issues, Marker, Pattern, PatternError, PatternIssue, Step, Steps, ValidationRules,
+};
+#[cfg(feature = "alloc")]
+pub use pattern_builder::{PatternBuf, PatternBuilder};
+pub use power::{PowerSaving, power_saving, set_power_saving};
+pub use preview::Preview;
+pub use queue::Queue;
+pub use recorder::{Player, Recorder};
+pub use registry::{EffectId, Footprint, Registry, RegistryError};
+pub use self_test::SelfTest;
+pub use sequencer::Sequencer;
+pub use settings::{Settings, SettingsStore};
+pub use simple::SimpleRumble;
+pub use soft_reset::before_soft_reset;
+pub use source_handle::SourceHandle;
+pub use splash_screen::{
+    Background, BuiltinSplash, SPLASH_MAP, SPLASH_PALETTE, SPLASH_TILES, SplashRenderer,
+};
+pub use spy::RumbleSpy;
+pub use state::{SaveState, StateError};
+pub use timer::Timer;
+pub use waiter::{BiosWaiter, VcountWaiter, WaitFn, Waiter};
 
 #[cfg(test)]
 #[unsafe(no_mangle)]
 pub fn main() {
+    // Soft resets between tests must return to the start of EWRAM, where a multiboot image is
+    // run from, rather than to ROM. The BIOS clears this flag on each reset.
+    #[cfg(multiboot)]
+    unsafe {
+        core::ptr::with_exposed_provenance_mut::<u8>(0x0300_7FFA).write_volatile(1);
+    }
     let _ = mgba_log::init();
     test_harness()
 }
-
-#[cfg(test)]
-mod tests {
-    #![allow(static_mut_refs)]
-
-    use super::{GAME_BOY_PLAYER_RUMBLE, GameBoyPlayer};
-    use crate::{
-        GAME_BOY_PLAYER_SIO_STATE, GameBoyPlayerRumble, GameBoyPlayerSioState, SIODATA,
-        game_boy_player_interrupt,
-    };
-    use alloc::format;
-    use claims::{assert_matches, assert_none, assert_some_eq};
-    use deranged::RangedUsize;
-    use gba_test::test;
-
-    const DISPSTAT: *mut u16 = 0x0400_0004 as *mut u16;
-    const IME: *mut bool = 0x0400_0208 as *mut bool;
-    const IE: *mut u16 = 0x0400_0200 as *mut u16;
-    const RCNT: *mut u16 = 0x0400_0134 as *mut u16;
-    const SIOCNT: *mut u16 = 0x0400_0128 as *mut u16;
-
-    #[test]
-    fn game_boy_player_debug() {
-        assert_eq!(
-            format!("{:?}", GameBoyPlayer { private: () }),
-            "GameBoyPlayer"
-        );
-    }
-
-    #[test]
-    #[cfg_attr(
-        not(game_boy_player),
-        ignore = "This test should be run on a Game Boy Player (or emulator with Game Boy Player functionality). Pass `--cfg game_boy_player` to enable."
-    )]
-    fn game_boy_player_detect_successful() {
-        unsafe {
-            DISPSTAT.write_volatile(8);
-            IE.write_volatile(1);
-            IME.write(true);
-        }
-        assert_some_eq!(GameBoyPlayer::detect(), GameBoyPlayer { private: () });
-    }
-
-    #[test]
-    #[cfg_attr(
-        game_boy_player,
-        ignore = "This test should be run on a console that is not a Game Boy Player (or emulator with Game Boy Player functionality disabled). Omit `--cfg game_boy_player` to enable."
-    )]
-    fn game_boy_player_detect_failure() {
-        unsafe {
-            DISPSTAT.write_volatile(8);
-            IE.write_volatile(1);
-            IME.write(true);
-        }
-        assert_none!(GameBoyPlayer::detect());
-    }
-
-    #[test]
-    fn game_boy_player_start() {
-        let game_boy_player = GameBoyPlayer { private: () };
-
-        game_boy_player.start();
-
-        assert_matches!(
-            unsafe { GAME_BOY_PLAYER_RUMBLE },
-            GameBoyPlayerRumble::Start
-        );
-    }
-
-    #[test]
-    fn game_boy_player_stop() {
-        let game_boy_player = GameBoyPlayer { private: () };
-
-        game_boy_player.stop();
-
-        assert_matches!(unsafe { GAME_BOY_PLAYER_RUMBLE }, GameBoyPlayerRumble::Stop);
-    }
-
-    #[test]
-    fn game_boy_player_hard_stop() {
-        let game_boy_player = GameBoyPlayer { private: () };
-
-        game_boy_player.hard_stop();
-
-        assert_matches!(
-            unsafe { GAME_BOY_PLAYER_RUMBLE },
-            GameBoyPlayerRumble::HardStop
-        );
-    }
-
-    #[test]
-    fn game_boy_player_sio_state_get_handshake_key() {
-        assert_eq!(
-            GameBoyPlayerSioState::get_handshake_key(RangedUsize::new_static::<0>()),
-            0x494e
-        );
-        assert_eq!(
-            GameBoyPlayerSioState::get_handshake_key(RangedUsize::new_static::<1>()),
-            0x544e
-        );
-        assert_eq!(
-            GameBoyPlayerSioState::get_handshake_key(RangedUsize::new_static::<2>()),
-            0x4e45
-        );
-        assert_eq!(
-            GameBoyPlayerSioState::get_handshake_key(RangedUsize::new_static::<3>()),
-            0x4f44
-        );
-    }
-
-    #[test]
-    fn game_boy_player_sio_state_get_magic_values() {
-        assert_eq!(
-            GameBoyPlayerSioState::get_magic_values(RangedUsize::new_static::<1>()),
-            (0xB0BB8002, 0x10000010)
-        );
-        assert_eq!(
-            GameBoyPlayerSioState::get_magic_values(RangedUsize::new_static::<2>()),
-            (0x10000010, 0x20000013)
-        );
-        assert_eq!(
-            GameBoyPlayerSioState::get_magic_values(RangedUsize::new_static::<3>()),
-            (0x20000013, 0x40000004)
-        );
-    }
-
-    #[test]
-    fn game_boy_player_interrupt_handshake_partial_match_0() {
-        unsafe {
-            RCNT.write_volatile(0);
-            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
-            SIODATA.write_volatile(0x0000494E);
-        }
-
-        game_boy_player_interrupt();
-
-        unsafe {
-            assert_eq!(SIODATA.read_volatile(), 0x494EB6B1);
-            assert_eq!(
-                GAME_BOY_PLAYER_SIO_STATE,
-                GameBoyPlayerSioState::Handshake {
-                    index: RangedUsize::new_static::<0>()
-                }
-            );
-        }
-    }
-
-    #[test]
-    fn game_boy_player_interrupt_handshake_partial_match_1() {
-        unsafe {
-            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Handshake {
-                index: RangedUsize::new_static::<1>(),
-            };
-            RCNT.write_volatile(0);
-            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
-            SIODATA.write_volatile(0xB6B1544E);
-        }
-
-        game_boy_player_interrupt();
-
-        unsafe {
-            assert_eq!(SIODATA.read_volatile(), 0x544EABB1);
-
-            assert_eq!(
-                GAME_BOY_PLAYER_SIO_STATE,
-                GameBoyPlayerSioState::Handshake {
-                    index: RangedUsize::new_static::<1>()
-                }
-            );
-        }
-    }
-
-    #[test]
-    fn game_boy_player_interrupt_handshake_partial_match_2() {
-        unsafe {
-            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Handshake {
-                index: RangedUsize::new_static::<2>(),
-            };
-            RCNT.write_volatile(0);
-            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
-            SIODATA.write_volatile(0xABB14E45);
-        }
-
-        game_boy_player_interrupt();
-
-        unsafe {
-            assert_eq!(SIODATA.read_volatile(), 0x4E45B1BA);
-
-            assert_eq!(
-                GAME_BOY_PLAYER_SIO_STATE,
-                GameBoyPlayerSioState::Handshake {
-                    index: RangedUsize::new_static::<2>()
-                }
-            );
-        }
-    }
-
-    #[test]
-    fn game_boy_player_interrupt_handshake_partial_match_3() {
-        unsafe {
-            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Handshake {
-                index: RangedUsize::new_static::<3>(),
-            };
-            RCNT.write_volatile(0);
-            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
-            SIODATA.write_volatile(0xB1BA4F44);
-        }
-
-        game_boy_player_interrupt();
-
-        unsafe {
-            assert_eq!(SIODATA.read_volatile(), 0x4F44B0BB);
-
-            assert_eq!(
-                GAME_BOY_PLAYER_SIO_STATE,
-                GameBoyPlayerSioState::Handshake {
-                    index: RangedUsize::new_static::<3>()
-                }
-            );
-        }
-    }
-
-    #[test]
-    fn game_boy_player_interrupt_handshake_full_match_0() {
-        unsafe {
-            RCNT.write_volatile(0);
-            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
-            SIODATA.write_volatile(0xB6B1494E);
-        }
-
-        game_boy_player_interrupt();
-
-        unsafe {
-            assert_eq!(SIODATA.read_volatile(), 0x544EB6B1);
-            assert_eq!(
-                GAME_BOY_PLAYER_SIO_STATE,
-                GameBoyPlayerSioState::Handshake {
-                    index: RangedUsize::new_static::<1>()
-                }
-            );
-        }
-    }
-
-    #[test]
-    fn game_boy_player_interrupt_handshake_full_match_1() {
-        unsafe {
-            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Handshake {
-                index: RangedUsize::new_static::<1>(),
-            };
-            RCNT.write_volatile(0);
-            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
-            SIODATA.write_volatile(0xABB1544E);
-        }
-
-        game_boy_player_interrupt();
-
-        unsafe {
-            assert_eq!(SIODATA.read_volatile(), 0x4E45ABB1);
-            assert_eq!(
-                GAME_BOY_PLAYER_SIO_STATE,
-                GameBoyPlayerSioState::Handshake {
-                    index: RangedUsize::new_static::<2>()
-                }
-            );
-        }
-    }
-
-    #[test]
-    fn game_boy_player_interrupt_handshake_full_match_2() {
-        unsafe {
-            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Handshake {
-                index: RangedUsize::new_static::<2>(),
-            };
-            RCNT.write_volatile(0);
-            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
-            SIODATA.write_volatile(0xB1BA4E45);
-        }
-
-        game_boy_player_interrupt();
-
-        unsafe {
-            assert_eq!(SIODATA.read_volatile(), 0x4F44B1BA);
-            assert_eq!(
-                GAME_BOY_PLAYER_SIO_STATE,
-                GameBoyPlayerSioState::Handshake {
-                    index: RangedUsize::new_static::<3>()
-                }
-            );
-        }
-    }
-
-    #[test]
-    fn game_boy_player_interrupt_handshake_full_match_3() {
-        unsafe {
-            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Handshake {
-                index: RangedUsize::new_static::<3>(),
-            };
-            RCNT.write_volatile(0);
-            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
-            SIODATA.write_volatile(0xB0BB4F44);
-        }
-
-        game_boy_player_interrupt();
-
-        unsafe {
-            assert_eq!(SIODATA.read_volatile(), 0x8000B0BB);
-            assert_eq!(
-                GAME_BOY_PLAYER_SIO_STATE,
-                GameBoyPlayerSioState::Magic {
-                    index: RangedUsize::new_static::<1>()
-                }
-            );
-        }
-    }
-
-    #[test]
-    fn game_boy_player_interrupt_handshake_no_match_0() {
-        unsafe {
-            RCNT.write_volatile(0);
-            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
-            SIODATA.write_volatile(0x12345678);
-        }
-
-        game_boy_player_interrupt();
-
-        unsafe {
-            assert_eq!(SIODATA.read_volatile(), 0x12345678);
-            assert_eq!(
-                GAME_BOY_PLAYER_SIO_STATE,
-                GameBoyPlayerSioState::Handshake {
-                    index: RangedUsize::new_static::<0>()
-                }
-            );
-        }
-    }
-
-    #[test]
-    fn game_boy_player_interrupt_handshake_no_match_1() {
-        unsafe {
-            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Handshake {
-                index: RangedUsize::new_static::<1>(),
-            };
-            RCNT.write_volatile(0);
-            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
-            SIODATA.write_volatile(0x12345678);
-        }
-
-        game_boy_player_interrupt();
-
-        unsafe {
-            assert_eq!(SIODATA.read_volatile(), 0x12345678);
-            assert_eq!(
-                GAME_BOY_PLAYER_SIO_STATE,
-                GameBoyPlayerSioState::Handshake {
-                    index: RangedUsize::new_static::<0>()
-                }
-            );
-        }
-    }
-
-    #[test]
-    fn game_boy_player_interrupt_handshake_no_match_2() {
-        unsafe {
-            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Handshake {
-                index: RangedUsize::new_static::<2>(),
-            };
-            RCNT.write_volatile(0);
-            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
-            SIODATA.write_volatile(0x12345678);
-        }
-
-        game_boy_player_interrupt();
-
-        unsafe {
-            assert_eq!(SIODATA.read_volatile(), 0x12345678);
-            assert_eq!(
-                GAME_BOY_PLAYER_SIO_STATE,
-                GameBoyPlayerSioState::Handshake {
-                    index: RangedUsize::new_static::<0>()
-                }
-            );
-        }
-    }
-
-    #[test]
-    fn game_boy_player_interrupt_handshake_no_match_3() {
-        unsafe {
-            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Handshake {
-                index: RangedUsize::new_static::<3>(),
-            };
-            RCNT.write_volatile(0);
-            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
-            SIODATA.write_volatile(0x12345678);
-        }
-
-        game_boy_player_interrupt();
-
-        unsafe {
-            assert_eq!(SIODATA.read_volatile(), 0x12345678);
-            assert_eq!(
-                GAME_BOY_PLAYER_SIO_STATE,
-                GameBoyPlayerSioState::Handshake {
-                    index: RangedUsize::new_static::<0>()
-                }
-            );
-        }
-    }
-
-    #[test]
-    fn game_boy_player_interrupt_magic_match_1() {
-        unsafe {
-            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Magic {
-                index: RangedUsize::new_static::<1>(),
-            };
-            RCNT.write_volatile(0);
-            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
-            SIODATA.write_volatile(0xB0BB8002);
-        }
-
-        game_boy_player_interrupt();
-
-        unsafe {
-            assert_eq!(SIODATA.read_volatile(), 0x10000010);
-            assert_eq!(
-                GAME_BOY_PLAYER_SIO_STATE,
-                GameBoyPlayerSioState::Magic {
-                    index: RangedUsize::new_static::<2>()
-                }
-            );
-        }
-    }
-
-    #[test]
-    fn game_boy_player_interrupt_magic_match_2() {
-        unsafe {
-            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Magic {
-                index: RangedUsize::new_static::<2>(),
-            };
-            RCNT.write_volatile(0);
-            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
-            SIODATA.write_volatile(0x10000010);
-        }
-
-        game_boy_player_interrupt();
-
-        unsafe {
-            assert_eq!(SIODATA.read_volatile(), 0x20000013);
-            assert_eq!(
-                GAME_BOY_PLAYER_SIO_STATE,
-                GameBoyPlayerSioState::Magic {
-                    index: RangedUsize::new_static::<3>()
-                }
-            );
-        }
-    }
-
-    #[test]
-    fn game_boy_player_interrupt_magic_match_3() {
-        unsafe {
-            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Magic {
-                index: RangedUsize::new_static::<3>(),
-            };
-            RCNT.write_volatile(0);
-            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
-            SIODATA.write_volatile(0x20000013);
-        }
-
-        game_boy_player_interrupt();
-
-        unsafe {
-            assert_eq!(SIODATA.read_volatile(), 0x40000004);
-            assert_eq!(GAME_BOY_PLAYER_SIO_STATE, GameBoyPlayerSioState::SendData);
-        }
-    }
-
-    #[test]
-    fn game_boy_player_interrupt_magic_no_match_1() {
-        unsafe {
-            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Magic {
-                index: RangedUsize::new_static::<1>(),
-            };
-            RCNT.write_volatile(0);
-            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
-            SIODATA.write_volatile(0x12345678);
-        }
-
-        game_boy_player_interrupt();
-
-        unsafe {
-            assert_eq!(SIODATA.read_volatile(), 0x12345678);
-            assert_eq!(
-                GAME_BOY_PLAYER_SIO_STATE,
-                GameBoyPlayerSioState::Handshake {
-                    index: RangedUsize::new_static::<0>()
-                }
-            );
-        }
-    }
-
-    #[test]
-    fn game_boy_player_interrupt_magic_no_match_2() {
-        unsafe {
-            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Magic {
-                index: RangedUsize::new_static::<2>(),
-            };
-            RCNT.write_volatile(0);
-            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
-            SIODATA.write_volatile(0x12345678);
-        }
-
-        game_boy_player_interrupt();
-
-        unsafe {
-            assert_eq!(SIODATA.read_volatile(), 0x12345678);
-            assert_eq!(
-                GAME_BOY_PLAYER_SIO_STATE,
-                GameBoyPlayerSioState::Handshake {
-                    index: RangedUsize::new_static::<0>()
-                }
-            );
-        }
-    }
-
-    #[test]
-    fn game_boy_player_interrupt_magic_no_match_3() {
-        unsafe {
-            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::Magic {
-                index: RangedUsize::new_static::<3>(),
-            };
-            RCNT.write_volatile(0);
-            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
-            SIODATA.write_volatile(0x12345678);
-        }
-
-        game_boy_player_interrupt();
-
-        unsafe {
-            assert_eq!(SIODATA.read_volatile(), 0x12345678);
-            assert_eq!(
-                GAME_BOY_PLAYER_SIO_STATE,
-                GameBoyPlayerSioState::Handshake {
-                    index: RangedUsize::new_static::<0>()
-                }
-            );
-        }
-    }
-
-    #[test]
-    fn game_boy_player_interrupt_send_data_start() {
-        unsafe {
-            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
-            RCNT.write_volatile(0);
-            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
-            SIODATA.write_volatile(0x30000003);
-            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Start;
-        }
-
-        game_boy_player_interrupt();
-
-        unsafe {
-            assert_eq!(SIODATA.read_volatile(), GameBoyPlayerRumble::Start as u32);
-            assert_eq!(GAME_BOY_PLAYER_SIO_STATE, GameBoyPlayerSioState::SendData);
-        }
-    }
-
-    #[test]
-    fn game_boy_player_interrupt_send_data_stop() {
-        unsafe {
-            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
-            RCNT.write_volatile(0);
-            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
-            SIODATA.write_volatile(0x30000003);
-            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::Stop;
-        }
-
-        game_boy_player_interrupt();
-
-        unsafe {
-            assert_eq!(SIODATA.read_volatile(), GameBoyPlayerRumble::Stop as u32);
-            assert_eq!(GAME_BOY_PLAYER_SIO_STATE, GameBoyPlayerSioState::SendData);
-        }
-    }
-
-    #[test]
-    fn game_boy_player_interrupt_send_data_hard_stop() {
-        unsafe {
-            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
-            RCNT.write_volatile(0);
-            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
-            SIODATA.write_volatile(0x30000003);
-            GAME_BOY_PLAYER_RUMBLE = GameBoyPlayerRumble::HardStop;
-        }
-
-        game_boy_player_interrupt();
-
-        unsafe {
-            assert_eq!(
-                SIODATA.read_volatile(),
-                GameBoyPlayerRumble::HardStop as u32
-            );
-            assert_eq!(GAME_BOY_PLAYER_SIO_STATE, GameBoyPlayerSioState::SendData);
-        }
-    }
-
-    #[test]
-    fn game_boy_player_interrupt_send_data_no_match() {
-        unsafe {
-            GAME_BOY_PLAYER_SIO_STATE = GameBoyPlayerSioState::SendData;
-            RCNT.write_volatile(0);
-            SIOCNT.write_volatile(0x4000 | 0x1000 | 8);
-            SIODATA.write_volatile(0x12345678);
-        }
-
-        game_boy_player_interrupt();
-
-        unsafe {
-            assert_eq!(SIODATA.read_volatile(), 0x12345678);
-            assert_eq!(
-                GAME_BOY_PLAYER_SIO_STATE,
-                GameBoyPlayerSioState::Handshake {
-                    index: RangedUsize::new_static::<0>()
-                }
-            );
-        }
-    }
-}
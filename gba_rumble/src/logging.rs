@@ -0,0 +1,78 @@
+//! The crate's diagnostic output.
+//!
+//! Messages are written through the macros defined here rather than through a logging crate
+//! directly, so that the backend can be chosen by feature:
+//!
+//! - With the `log` feature, messages are passed to the [`log`](https://docs.rs/log) crate, to be
+//!   handled by whichever logger the program installs.
+//! - With the `mgba-log` feature, which implies `log`, [`init()`] also installs
+//!   [`mgba_log`](https://docs.rs/mgba_log) as the logger, so messages appear in mGBA.
+//! - With the `defmt` feature, messages are passed to the [`defmt`](https://docs.rs/defmt) crate,
+//!   to be handled by whichever global logger the program defines. They are formatted on the
+//!   device, since the crate's messages are written with `core::fmt` syntax.
+//! - Otherwise, messages are discarded, and their arguments are only type checked.
+//!
+//! If both `log` and `defmt` are enabled, messages are written to both.
+
+/// Writes a message at the given level, or discards it if no backend is enabled.
+macro_rules! message {
+    ($level:ident, $($arg:tt)+) => {{
+        #[cfg(feature = "log")]
+        ::log::$level!($($arg)+);
+        #[cfg(feature = "defmt")]
+        ::defmt::$level!("{}", ::defmt::Display2Format(&format_args!($($arg)+)));
+        #[cfg(not(any(feature = "log", feature = "defmt")))]
+        {
+            let _ = format_args!($($arg)+);
+        }
+    }};
+}
+
+/// Writes an informational message.
+#[cfg_attr(
+    not(any(
+        feature = "boot-report",
+        feature = "conformance",
+        feature = "diagnostics"
+    )),
+    expect(unused_macros)
+)]
+macro_rules! info {
+    ($($arg:tt)+) => {
+        $crate::logging::message!(info, $($arg)+)
+    };
+}
+
+/// Writes a message about something that may be wrong.
+macro_rules! warning {
+    ($($arg:tt)+) => {
+        $crate::logging::message!(warn, $($arg)+)
+    };
+}
+
+/// Writes a message about something that is wrong.
+#[cfg_attr(not(feature = "conformance"), expect(unused_macros))]
+macro_rules! error {
+    ($($arg:tt)+) => {
+        $crate::logging::message!(error, $($arg)+)
+    };
+}
+
+#[cfg(feature = "conformance")]
+pub(crate) use error;
+#[cfg(any(
+    feature = "boot-report",
+    feature = "conformance",
+    feature = "diagnostics"
+))]
+pub(crate) use info;
+pub(crate) use {message, warning};
+
+/// Installs the logger of the enabled backend, if it has one.
+///
+/// Failure is ignored, since the program may already have installed a logger.
+#[cfg(any(feature = "conformance", feature = "diagnostics"))]
+pub(crate) fn init() {
+    #[cfg(feature = "mgba-log")]
+    let _ = mgba_log::init();
+}
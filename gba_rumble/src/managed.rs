@@ -0,0 +1,90 @@
+//! Ending the Game Boy Player session when its handle is dropped.
+
+use crate::GameBoyPlayer;
+use core::{mem::ManuallyDrop, ops::Deref};
+
+/// A [`GameBoyPlayer`] handle that ends the session when dropped.
+///
+/// [`GameBoyPlayer`] is a `Copy` handle to global state, so dropping it does nothing: the serial
+/// interrupt handler keeps answering the Game Boy Player, and the motor keeps running if it was
+/// started, with nothing left to stop it. Wrapping the handle in a `ManagedGameBoyPlayer`, using
+/// [`GameBoyPlayer::managed()`], ties the session to a single owner instead. When it is dropped,
+/// the motor is hard-stopped and the session is ended with [`GameBoyPlayer::end_session()`].
+///
+/// Every method of [`GameBoyPlayer`] can be called through the wrapper. Games that intentionally
+/// keep the session running, such as by storing the handle in a static, can opt out with
+/// [`manually_managed()`].
+///
+/// ``` rust
+/// use gba_rumble::GameBoyPlayer;
+///
+/// if let Some(game_boy_player) = GameBoyPlayer::boot(gba_rumble::irq::Dispatcher) {
+///     let game_boy_player = game_boy_player.managed();
+///     game_boy_player.start();
+///     // The motor is stopped and the session ended once `game_boy_player` goes out of scope.
+/// }
+/// ```
+///
+/// [`manually_managed()`]: ManagedGameBoyPlayer::manually_managed()
+#[derive(Debug, Eq, PartialEq)]
+pub struct ManagedGameBoyPlayer {
+    game_boy_player: GameBoyPlayer,
+}
+
+impl ManagedGameBoyPlayer {
+    pub(crate) const fn new(game_boy_player: GameBoyPlayer) -> Self {
+        Self { game_boy_player }
+    }
+
+    /// Returns the underlying handle without ending the session, leaving it to be managed
+    /// manually.
+    pub fn manually_managed(self) -> GameBoyPlayer {
+        ManuallyDrop::new(self).game_boy_player
+    }
+}
+
+impl Deref for ManagedGameBoyPlayer {
+    type Target = GameBoyPlayer;
+
+    fn deref(&self) -> &Self::Target {
+        &self.game_boy_player
+    }
+}
+
+impl Drop for ManagedGameBoyPlayer {
+    fn drop(&mut self) {
+        self.game_boy_player.end_session();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{GameBoyPlayer, game_boy_player, regs};
+    use core::ptr;
+    use gba_test::test;
+
+    const SIOCNT: *mut u16 = ptr::with_exposed_provenance_mut(regs::SIOCNT);
+
+    #[test]
+    fn drop_ends_session() {
+        let game_boy_player = GameBoyPlayer::new().managed();
+        game_boy_player.enable_serial();
+
+        drop(game_boy_player);
+
+        assert!(!game_boy_player::is_connected());
+        assert_eq!(unsafe { SIOCNT.read_volatile() } & (1 << 14), 0);
+    }
+
+    #[test]
+    fn manually_managed() {
+        let game_boy_player = GameBoyPlayer::new().managed();
+        game_boy_player.enable_serial();
+
+        let game_boy_player = game_boy_player.manually_managed();
+
+        let siocnt = unsafe { SIOCNT.read_volatile() };
+        game_boy_player.end_session();
+        assert_ne!(siocnt & (1 << 14), 0);
+    }
+}
@@ -0,0 +1,171 @@
+//! Per-frame measurements, for drawing alongside a game's own performance overlay.
+
+use crate::Timer;
+use core::{
+    fmt,
+    fmt::{Display, Formatter},
+};
+
+/// The timer measuring [`Driver::tick()`], set by [`set_tick_profiling()`].
+///
+/// [`Driver::tick()`]: crate::Driver::tick()
+static mut PROFILING_TIMER: Option<Timer> = None;
+/// The metrics of the last completed frame.
+static mut LATEST: MetricsFrame = MetricsFrame::new();
+/// The serial interrupt count as of the end of the last completed frame.
+static mut INBOUND_COUNT: u32 = 0;
+
+/// A compact summary of the work rumble did during a single frame.
+///
+/// The summary returned by [`metrics()`] is refreshed once per frame, so it can be read by a
+/// performance overlay at any point in the frame and shown next to CPU and DMA usage. Its
+/// [`Display`] implementation writes a single short line, such as `irq 3 xfer 1 tick 412`,
+/// suitable for a debug font.
+///
+/// Serial counts are refreshed by [`GameBoyPlayer::update()`] and remain zero on other backends.
+/// The cost of [`Driver::tick()`] is only measured while a timer is set with
+/// [`set_tick_profiling()`].
+///
+/// [`Driver::tick()`]: crate::Driver::tick()
+/// [`GameBoyPlayer::update()`]: crate::GameBoyPlayer::update()
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct MetricsFrame {
+    /// Serial interrupts handled during the frame.
+    pub serial_interrupts: u16,
+    /// Rumble transfers responded to during the frame.
+    pub transfers: u8,
+    /// The number of CPU cycles taken by the most recent [`Driver::tick()`], or `None` if it was
+    /// not measured.
+    ///
+    /// [`Driver::tick()`]: crate::Driver::tick()
+    pub tick_cycles: Option<u16>,
+}
+
+impl MetricsFrame {
+    /// Creates metrics for a frame in which nothing was done.
+    pub const fn new() -> Self {
+        Self {
+            serial_interrupts: 0,
+            transfers: 0,
+            tick_cycles: None,
+        }
+    }
+}
+
+impl Display for MetricsFrame {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "irq {} xfer {}",
+            self.serial_interrupts, self.transfers
+        )?;
+        match self.tick_cycles {
+            Some(cycles) => write!(formatter, " tick {cycles}"),
+            None => formatter.write_str(" tick -"),
+        }
+    }
+}
+
+/// Returns the metrics of the last completed frame.
+pub fn metrics() -> MetricsFrame {
+    unsafe { (&raw const LATEST).read_volatile() }
+}
+
+/// Sets the timer used to measure the cost of each [`Driver::tick()`], or stops measuring if
+/// `None`.
+///
+/// The timer is started counting every cycle and must not be used for anything else while set.
+/// Ticks taking longer than 65,535 cycles wrap around. This is off by default.
+///
+/// [`Driver::tick()`]: crate::Driver::tick()
+pub fn set_tick_profiling(timer: Option<Timer>) {
+    unsafe {
+        if let Some(previous) = (&raw const PROFILING_TIMER).read_volatile() {
+            previous.control().write_volatile(0);
+        }
+        if let Some(timer) = timer {
+            timer.control().write_volatile(0);
+            timer.counter().write_volatile(0);
+            // Enabled, counting every cycle.
+            timer.control().write_volatile(0x80);
+        }
+        (&raw mut PROFILING_TIMER).write_volatile(timer);
+        (&raw mut LATEST.tick_cycles).write_volatile(None);
+    }
+}
+
+/// Returns the profiling timer's counter at the start of a tick, if ticks are being measured.
+#[inline(always)]
+pub(crate) fn tick_started() -> Option<u16> {
+    unsafe { (&raw const PROFILING_TIMER).read_volatile() }
+        .map(|timer| unsafe { timer.counter().read_volatile() })
+}
+
+/// Records the cost of a tick started when the profiling timer read `start`.
+#[inline(always)]
+pub(crate) fn tick_finished(start: Option<u16>) {
+    if let Some(start) = start
+        && let Some(timer) = unsafe { (&raw const PROFILING_TIMER).read_volatile() }
+    {
+        let cycles = unsafe { timer.counter().read_volatile() }.wrapping_sub(start);
+        unsafe {
+            (&raw mut LATEST.tick_cycles).write_volatile(Some(cycles));
+        }
+    }
+}
+
+/// Completes the serial metrics of a frame, given the total number of serial interrupts handled
+/// and the transfers responded to during the frame.
+pub(crate) fn finish_serial_frame(inbound_count: u32, transfers: u8) {
+    unsafe {
+        let interrupts = inbound_count.wrapping_sub((&raw const INBOUND_COUNT).read_volatile());
+        (&raw mut INBOUND_COUNT).write_volatile(inbound_count);
+        (&raw mut LATEST.serial_interrupts)
+            .write_volatile(u16::try_from(interrupts).unwrap_or(u16::MAX));
+        (&raw mut LATEST.transfers).write_volatile(transfers);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        MetricsFrame, finish_serial_frame, metrics, set_tick_profiling, tick_finished, tick_started,
+    };
+    use crate::Timer;
+    use alloc::format;
+    use claims::{assert_none, assert_some};
+    use gba_test::test;
+
+    #[test]
+    fn display() {
+        let frame = MetricsFrame {
+            serial_interrupts: 3,
+            transfers: 1,
+            tick_cycles: Some(412),
+        };
+
+        assert_eq!(format!("{frame}"), "irq 3 xfer 1 tick 412");
+        assert_eq!(format!("{}", MetricsFrame::new()), "irq 0 xfer 0 tick -");
+    }
+
+    #[test]
+    fn serial_counts_per_frame() {
+        finish_serial_frame(100, 0);
+        finish_serial_frame(104, 2);
+
+        assert_eq!(metrics().serial_interrupts, 4);
+        assert_eq!(metrics().transfers, 2);
+    }
+
+    #[test]
+    fn tick_profiling() {
+        set_tick_profiling(Some(Timer::Timer3));
+        let start = assert_some!(tick_started());
+        tick_finished(Some(start));
+        assert_some!(metrics().tick_cycles);
+
+        set_tick_profiling(None);
+        assert_none!(tick_started());
+        assert_none!(metrics().tick_cycles);
+    }
+}
@@ -0,0 +1,1580 @@
+//! Prioritized channels of rumble effects.
+
+use crate::{
+    Effect, EffectParams, Fixed, Intensity, Lfo, Marker, Pattern, Queue, SaveState, Sequencer,
+    SourceHandle, StateError,
+    source_handle::SourceKey,
+    state::{StateReader, StateWriter},
+};
+use core::{
+    cmp::Reverse,
+    fmt,
+    fmt::{Debug, Formatter},
+};
+
+/// How a channel handles a new effect while a higher-priority effect is playing.
+///
+/// Only effects on channels with a strictly higher priority are considered. A new effect always
+/// replaces an effect already playing on its own channel.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Preemption {
+    /// Stop the higher-priority effects and play the new effect immediately.
+    ///
+    /// This suits effects that must always be felt, such as hits in a fighting game.
+    Replace,
+    /// Wait until the higher-priority effects finish, then play the new effect.
+    ///
+    /// Waiting effects are held in the mixer's shared [`Queue`], and play in the order they were
+    /// queued once their channel is free. If the queue is full, the effect on the lowest-priority
+    /// channel is dropped.
+    Queue,
+    /// Discard the new effect.
+    ///
+    /// This suits ambient rumble that is not worth playing late.
+    Reject,
+    /// Play the new effect alongside the higher-priority effects, with its intensity scaled by the
+    /// given factor while they play.
+    ///
+    /// The channel's effect is mixed with the highest-priority effect by taking the stronger of the
+    /// two, and returns to full intensity once the higher-priority effects finish. This mirrors
+    /// ducking in audio mixing, layering effects rather than cutting them off entirely.
+    Duck(Fixed),
+}
+
+/// Which effect a [`Mixer`] stops to make room for a new one, once its limit on active effects is
+/// reached.
+///
+/// See [`Mixer::set_max_active()`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Eviction {
+    /// Stop the effect that started playing the longest time ago.
+    ///
+    /// This is the default.
+    #[default]
+    Oldest,
+    /// Stop the effect on the channel with the lowest priority, or the oldest such effect if
+    /// several channels share the lowest priority.
+    ///
+    /// If that priority is higher than the new effect's, the new effect is rejected instead.
+    LowestPriority,
+}
+
+/// The result of playing an effect on a channel.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PlayOutcome {
+    /// The effect began playing.
+    Started,
+    /// The effect is waiting for higher-priority effects to finish.
+    Queued,
+    /// The effect was discarded.
+    Rejected,
+}
+
+/// Identifies a channel within a [`Mixer`].
+///
+/// Channel identifiers are obtained from [`Mixer::add_channel()`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ChannelId(usize);
+
+/// A category that effects can be tagged with when they are played.
+///
+/// Tags group effects across channels, so that they can be stopped together with
+/// [`Mixer::stop_tagged()`]. For example, tagging gameplay effects allows a scene transition to
+/// cancel them all while leaving feedback from menus untouched. What each tag means is up to the
+/// game.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Tag(u8);
+
+impl Tag {
+    /// Creates a tag from its integer value.
+    pub const fn new(value: u8) -> Self {
+        Self(value)
+    }
+
+    /// Returns the integer value of this tag.
+    pub const fn value(self) -> u8 {
+        self.0
+    }
+}
+
+/// Something that can be played on a channel.
+pub(crate) enum Source<'a> {
+    Pattern(Pattern<'a>, EffectParams),
+    Effect(&'a mut dyn Effect),
+}
+
+impl Debug for Source<'_> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pattern(pattern, params) => formatter
+                .debug_tuple("Pattern")
+                .field(pattern)
+                .field(params)
+                .finish(),
+            Self::Effect(_) => formatter.debug_tuple("Effect").finish_non_exhaustive(),
+        }
+    }
+}
+
+/// A source being played on a channel.
+enum Playback<'a> {
+    Sequencer(Sequencer<'a>),
+    Effect {
+        effect: &'a mut dyn Effect,
+        frame: u32,
+    },
+}
+
+impl<'a> Playback<'a> {
+    fn new(source: Source<'a>) -> Self {
+        match source {
+            Source::Pattern(pattern, params) => {
+                Self::Sequencer(Sequencer::with_params(pattern, params))
+            }
+            Source::Effect(effect) => Self::Effect { effect, frame: 0 },
+        }
+    }
+
+    /// Advances by one frame, returning the intensity for that frame, or `None` if playback has
+    /// finished.
+    #[cfg_attr(
+        feature = "iwram-tick",
+        unsafe(link_section = ".iwram.gba_rumble.tick.playback")
+    )]
+    #[cfg_attr(
+        all(feature = "ewram-tick", not(feature = "iwram-tick")),
+        unsafe(link_section = ".ewram.gba_rumble.tick.playback")
+    )]
+    fn tick(&mut self) -> Option<Intensity> {
+        match self {
+            Self::Sequencer(sequencer) => {
+                let intensity = sequencer.tick();
+                (!sequencer.is_finished()).then_some(intensity)
+            }
+            Self::Effect { effect, frame } => {
+                let intensity = effect.sample(*frame);
+                *frame = frame.wrapping_add(1);
+                Some(intensity)
+            }
+        }
+    }
+}
+
+impl Debug for Playback<'_> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sequencer(sequencer) => {
+                formatter.debug_tuple("Sequencer").field(sequencer).finish()
+            }
+            Self::Effect { frame, .. } => formatter
+                .debug_struct("Effect")
+                .field("frame", frame)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Channel<'a> {
+    priority: u8,
+    preemption: Preemption,
+    playback: Option<Playback<'a>>,
+    muted: bool,
+    solo: bool,
+    /// The oscillator modulating the channel, along with the frames since it was set.
+    modulation: Option<(Lfo, u32)>,
+    /// The intensity output on the most recent tick, if anything was playing.
+    output: Option<Intensity>,
+    /// The tag of the effect playing, if it was played with one.
+    tag: Option<Tag>,
+    /// The handle the effect playing was played from, if any.
+    owner: Option<SourceKey>,
+    /// When the effect playing began, as counted by the mixer's `starts`.
+    started: u32,
+}
+
+/// A fixed number of prioritized channels, each playing a single effect at a time.
+///
+/// Channels play either authored [`Pattern`]s, through [`play()`], or procedural [`Effect`]s,
+/// through [`play_effect()`]. Each frame, [`tick()`] advances every channel and outputs the
+/// intensity of the playing channel with the highest priority. Ties are won by the channel that
+/// was added first. Channels using [`Preemption::Duck`] are mixed in underneath at a reduced
+/// intensity.
+///
+/// Effects can also be played with a [`Tag`], through [`play_tagged()`] or
+/// [`play_effect_tagged()`], and every effect with a given tag stopped at once with
+/// [`stop_tagged()`]. Effects played from a [`SourceHandle`], through [`play_from()`] or
+/// [`play_effect_from()`], are stopped automatically once the handle is dropped.
+///
+/// For debugging, individual channels can be muted with [`set_muted()`], or soloed with
+/// [`set_solo()`] so that only soloed channels are felt. What each channel output on the most
+/// recent frame can be read with [`channel_output()`], and the mixed result with [`output()`],
+/// such as for drawing live meters in a debug overlay.
+///
+/// The mixer holds up to `N` channels, and up to `Q` effects waiting to play through
+/// [`Preemption::Queue`]. Neither requires allocation. The number of effects playing at once can
+/// be capped further with [`set_max_active()`], so that a burst of effects in a hectic moment
+/// degrades gracefully rather than every channel rumbling at once.
+///
+/// ``` rust
+/// use gba_rumble::{Intensity, Mixer, Pattern, Preemption, Step, pattern};
+///
+/// const HIT: Pattern = pattern![Step::Hold { intensity: Intensity::MAX, frames: 10 }];
+///
+/// let mut mixer = Mixer::<2>::new();
+/// let ambient = mixer.add_channel(0, Preemption::Reject).unwrap();
+/// let hits = mixer.add_channel(1, Preemption::Replace).unwrap();
+///
+/// mixer.play(hits, HIT);
+/// // Once a frame, get the intensity to rumble at.
+/// let intensity = mixer.tick();
+/// ```
+///
+/// [`channel_output()`]: Mixer::channel_output()
+/// [`output()`]: Mixer::output()
+/// [`play()`]: Mixer::play()
+/// [`play_effect()`]: Mixer::play_effect()
+/// [`play_effect_from()`]: Mixer::play_effect_from()
+/// [`play_effect_tagged()`]: Mixer::play_effect_tagged()
+/// [`play_from()`]: Mixer::play_from()
+/// [`play_tagged()`]: Mixer::play_tagged()
+/// [`set_max_active()`]: Mixer::set_max_active()
+/// [`set_muted()`]: Mixer::set_muted()
+/// [`set_solo()`]: Mixer::set_solo()
+/// [`stop_tagged()`]: Mixer::stop_tagged()
+/// [`tick()`]: Mixer::tick()
+#[derive(Debug)]
+pub struct Mixer<'a, const N: usize, const Q: usize = 4> {
+    channels: [Option<Channel<'a>>; N],
+    queue: Queue<(ChannelId, Source<'a>, Option<Tag>, Option<SourceKey>), Q>,
+    /// The mixed intensity output on the most recent tick.
+    output: Intensity,
+    /// The most effects that may play at once.
+    max_active: usize,
+    eviction: Eviction,
+    /// The number of effects started so far, with wrapping.
+    starts: u32,
+}
+
+impl<'a, const N: usize, const Q: usize> Mixer<'a, N, Q> {
+    /// Creates a mixer with no channels.
+    pub const fn new() -> Self {
+        Self {
+            channels: [const { None }; N],
+            queue: Queue::new(),
+            output: Intensity::OFF,
+            max_active: N,
+            eviction: Eviction::Oldest,
+            starts: 0,
+        }
+    }
+
+    /// Adds a channel with the given priority and preemption policy.
+    ///
+    /// Higher `priority` values take precedence over lower ones. Returns `None` if all `N`
+    /// channels are already in use.
+    pub fn add_channel(&mut self, priority: u8, preemption: Preemption) -> Option<ChannelId> {
+        let index = self.channels.iter().position(Option::is_none)?;
+        self.channels[index] = Some(Channel {
+            priority,
+            preemption,
+            playback: None,
+            muted: false,
+            solo: false,
+            modulation: None,
+            output: None,
+            tag: None,
+            owner: None,
+            started: 0,
+        });
+        Some(ChannelId(index))
+    }
+
+    /// Plays `pattern` on `channel`, subject to the channel's [`Preemption`] policy.
+    pub fn play(&mut self, channel: ChannelId, pattern: Pattern<'a>) -> PlayOutcome {
+        self.play_with(channel, pattern, EffectParams::new())
+    }
+
+    /// Plays `pattern` on `channel` adjusted by `params`, subject to the channel's [`Preemption`]
+    /// policy.
+    pub fn play_with(
+        &mut self,
+        channel: ChannelId,
+        pattern: Pattern<'a>,
+        params: EffectParams,
+    ) -> PlayOutcome {
+        self.play_source(channel, Source::Pattern(pattern, params), None, None)
+    }
+
+    /// Plays `pattern` on `channel` tagged with `tag`, as if by [`play()`].
+    ///
+    /// The effect can later be stopped along with every other effect sharing its tag through
+    /// [`stop_tagged()`].
+    ///
+    /// [`play()`]: Mixer::play()
+    /// [`stop_tagged()`]: Mixer::stop_tagged()
+    pub fn play_tagged(
+        &mut self,
+        channel: ChannelId,
+        pattern: Pattern<'a>,
+        tag: Tag,
+    ) -> PlayOutcome {
+        self.play_source(
+            channel,
+            Source::Pattern(pattern, EffectParams::new()),
+            Some(tag),
+            None,
+        )
+    }
+
+    /// Plays `pattern` on `channel` on behalf of `source`, as if by [`play()`].
+    ///
+    /// The effect is stopped once `source` is dropped. See [`SourceHandle`] for details.
+    ///
+    /// [`play()`]: Mixer::play()
+    pub fn play_from(
+        &mut self,
+        channel: ChannelId,
+        pattern: Pattern<'a>,
+        source: &SourceHandle,
+    ) -> PlayOutcome {
+        self.play_source(
+            channel,
+            Source::Pattern(pattern, EffectParams::new()),
+            None,
+            Some(source.key()),
+        )
+    }
+
+    /// Plays `effect` on `channel`, subject to the channel's [`Preemption`] policy.
+    ///
+    /// The effect plays until it is stopped or replaced by another effect on the same channel.
+    pub fn play_effect(&mut self, channel: ChannelId, effect: &'a mut dyn Effect) -> PlayOutcome {
+        self.play_source(channel, Source::Effect(effect), None, None)
+    }
+
+    /// Plays `effect` on `channel` tagged with `tag`, as if by [`play_effect()`].
+    ///
+    /// [`play_effect()`]: Mixer::play_effect()
+    pub fn play_effect_tagged(
+        &mut self,
+        channel: ChannelId,
+        effect: &'a mut dyn Effect,
+        tag: Tag,
+    ) -> PlayOutcome {
+        self.play_source(channel, Source::Effect(effect), Some(tag), None)
+    }
+
+    /// Plays `effect` on `channel` on behalf of `source`, as if by [`play_effect()`].
+    ///
+    /// The effect is stopped once `source` is dropped. See [`SourceHandle`] for details.
+    ///
+    /// [`play_effect()`]: Mixer::play_effect()
+    pub fn play_effect_from(
+        &mut self,
+        channel: ChannelId,
+        effect: &'a mut dyn Effect,
+        source: &SourceHandle,
+    ) -> PlayOutcome {
+        self.play_source(channel, Source::Effect(effect), None, Some(source.key()))
+    }
+
+    /// Stops the effect playing on `channel`, along with any effects queued behind it.
+    pub fn stop(&mut self, channel: ChannelId) {
+        if let Some(channel) = self.channel_mut(channel) {
+            channel.playback = None;
+        }
+        self.queue.retain(|_, (queued, ..)| *queued != channel);
+    }
+
+    /// Stops every effect tagged with `tag`, on every channel, along with any such effects that
+    /// are queued.
+    ///
+    /// Untagged effects and effects with other tags are unaffected, and queued effects waiting on
+    /// a stopped effect become free to play.
+    pub fn stop_tagged(&mut self, tag: Tag) {
+        for channel in self.channels.iter_mut().flatten() {
+            if channel.tag == Some(tag) {
+                channel.playback = None;
+            }
+        }
+        self.queue
+            .retain(|_, (_, _, queued, _)| *queued != Some(tag));
+    }
+
+    /// Returns the tag of the effect playing on `channel`, or `None` if nothing is playing or the
+    /// effect was played without one.
+    pub fn tag(&self, channel: ChannelId) -> Option<Tag> {
+        self.channel(channel)
+            .filter(|channel| channel.playback.is_some())
+            .and_then(|channel| channel.tag)
+    }
+
+    /// Advances the pattern playing on `channel` past `marker`.
+    ///
+    /// See [`Sequencer::advance()`] for details. Returns `false` if no pattern is playing on the
+    /// channel or the marker was not found.
+    pub fn advance(&mut self, channel: ChannelId, marker: Marker) -> bool {
+        match self
+            .channel_mut(channel)
+            .and_then(|channel| channel.playback.as_mut())
+        {
+            Some(Playback::Sequencer(sequencer)) => sequencer.advance(marker),
+            _ => false,
+        }
+    }
+
+    /// Returns whether an effect is playing on `channel`.
+    pub fn is_playing(&self, channel: ChannelId) -> bool {
+        self.channel(channel)
+            .is_some_and(|channel| channel.playback.is_some())
+    }
+
+    /// Mutes or unmutes `channel`.
+    ///
+    /// A muted channel is left out of the mixed output, allowing the source of an unwanted rumble
+    /// to be tracked down during playtesting. Muting only affects what is felt: the channel's
+    /// effects keep advancing and preempting other channels as usual, so unmuting resumes them
+    /// wherever they are.
+    pub fn set_muted(&mut self, channel: ChannelId, muted: bool) {
+        if let Some(channel) = self.channel_mut(channel) {
+            channel.muted = muted;
+        }
+    }
+
+    /// Returns whether `channel` is muted.
+    pub fn is_muted(&self, channel: ChannelId) -> bool {
+        self.channel(channel).is_some_and(|channel| channel.muted)
+    }
+
+    /// Solos or unsolos `channel`.
+    ///
+    /// While any channel is soloed, only soloed channels are included in the mixed output. As with
+    /// [`set_muted()`], this only affects what is felt. A channel that is both soloed and muted is
+    /// left out.
+    ///
+    /// [`set_muted()`]: Mixer::set_muted()
+    pub fn set_solo(&mut self, channel: ChannelId, solo: bool) {
+        if let Some(channel) = self.channel_mut(channel) {
+            channel.solo = solo;
+        }
+    }
+
+    /// Returns whether `channel` is soloed.
+    pub fn is_solo(&self, channel: ChannelId) -> bool {
+        self.channel(channel).is_some_and(|channel| channel.solo)
+    }
+
+    /// Modulates everything played on `channel` with `lfo`, or stops modulating it if `None`.
+    ///
+    /// The oscillation starts from its peak when set, and runs continuously from then on, even
+    /// while nothing is playing, so that effects played in quick succession keep a steady rhythm.
+    /// Modulation is applied to the channel's own output, before it is mixed with other channels.
+    pub fn set_modulation(&mut self, channel: ChannelId, lfo: Option<Lfo>) {
+        if let Some(channel) = self.channel_mut(channel) {
+            channel.modulation = lfo.map(|lfo| (lfo, 0));
+        }
+    }
+
+    /// Returns the oscillator modulating `channel`, if any.
+    pub fn modulation(&self, channel: ChannelId) -> Option<Lfo> {
+        self.channel(channel)
+            .and_then(|channel| channel.modulation)
+            .map(|(lfo, _)| lfo)
+    }
+
+    /// Returns the intensity `channel` output on the most recent [`tick()`], or `None` if nothing
+    /// was playing on it.
+    ///
+    /// This is the channel's own output, after modulation but before mixing, so it is reported
+    /// even while the channel is muted or outranked by another channel.
+    ///
+    /// [`tick()`]: Mixer::tick()
+    pub fn channel_output(&self, channel: ChannelId) -> Option<Intensity> {
+        self.channel(channel).and_then(|channel| channel.output)
+    }
+
+    /// Limits the number of effects playing at once to `max`, stopping effects according to
+    /// `eviction` to make room for new ones.
+    ///
+    /// When an effect is started on a channel with nothing playing while `max` effects are
+    /// already playing, one of them is stopped first, as chosen by `eviction`. Effects queued
+    /// through [`Preemption::Queue`] are subject to the limit when they start, and are dropped if
+    /// no effect can be stopped for them. Replacing the effect
+    /// on a channel that is already playing never evicts anything. A `max` of `N` or more places
+    /// no limit, which is the default.
+    pub fn set_max_active(&mut self, max: usize, eviction: Eviction) {
+        self.max_active = max;
+        self.eviction = eviction;
+    }
+
+    /// Returns the number of effects currently playing.
+    pub fn active(&self) -> usize {
+        self.channels
+            .iter()
+            .flatten()
+            .filter(|channel| channel.playback.is_some())
+            .count()
+    }
+
+    /// Returns the mixed intensity output by the most recent [`tick()`].
+    ///
+    /// [`tick()`]: Mixer::tick()
+    pub fn output(&self) -> Intensity {
+        self.output
+    }
+
+    pub(crate) fn play_source(
+        &mut self,
+        channel: ChannelId,
+        source: Source<'a>,
+        tag: Option<Tag>,
+        owner: Option<SourceKey>,
+    ) -> PlayOutcome {
+        let Some(priority) = self.channel(channel).map(|channel| channel.priority) else {
+            return PlayOutcome::Rejected;
+        };
+        if self.is_blocked(channel) {
+            match self.channel(channel).map(|channel| channel.preemption) {
+                Some(Preemption::Replace) => {
+                    for other in self.channels.iter_mut().flatten() {
+                        if other.priority > priority {
+                            other.playback = None;
+                        }
+                    }
+                }
+                Some(Preemption::Queue) => {
+                    return match self.queue.push(priority, (channel, source, tag, owner)) {
+                        Ok(_) => PlayOutcome::Queued,
+                        Err(_) => PlayOutcome::Rejected,
+                    };
+                }
+                Some(Preemption::Duck(_)) => {}
+                Some(Preemption::Reject) | None => return PlayOutcome::Rejected,
+            }
+        }
+        if self.make_room(channel).is_err() {
+            return PlayOutcome::Rejected;
+        }
+        let started = self.next_start();
+        if let Some(channel) = self.channel_mut(channel) {
+            channel.playback = Some(Playback::new(source));
+            channel.tag = tag;
+            channel.owner = owner;
+            channel.started = started;
+        }
+        PlayOutcome::Started
+    }
+
+    /// Returns the start order of an effect starting now.
+    fn next_start(&mut self) -> u32 {
+        let started = self.starts;
+        self.starts = self.starts.wrapping_add(1);
+        started
+    }
+
+    /// Stops another effect if starting one on `channel` would exceed the limit set by
+    /// [`set_max_active()`], returning the index of the stopped channel.
+    ///
+    /// Fails if no effect can be stopped to make room.
+    ///
+    /// [`set_max_active()`]: Mixer::set_max_active()
+    fn make_room(&mut self, channel: ChannelId) -> Result<Option<usize>, ()> {
+        let Some(target) = self.channel(channel) else {
+            return Err(());
+        };
+        if target.playback.is_some() || self.active() < self.max_active {
+            return Ok(None);
+        }
+        let priority = target.priority;
+        let starts = self.starts;
+        let age = |channel: &Channel| starts.wrapping_sub(channel.started);
+        let playing = self
+            .channels
+            .iter()
+            .enumerate()
+            .filter_map(|(index, channel)| {
+                channel
+                    .as_ref()
+                    .filter(|channel| channel.playback.is_some())
+                    .map(|channel| (index, channel))
+            });
+        let victim = match self.eviction {
+            Eviction::Oldest => playing.max_by_key(|(_, channel)| age(channel)),
+            Eviction::LowestPriority => playing
+                .min_by_key(|(_, channel)| (channel.priority, Reverse(age(channel))))
+                .filter(|(_, channel)| channel.priority <= priority),
+        };
+        let Some((index, _)) = victim else {
+            return Err(());
+        };
+        if let Some(channel) = &mut self.channels[index] {
+            channel.playback = None;
+        }
+        Ok(Some(index))
+    }
+
+    /// Advances every channel by one frame, returning the mixed intensity for that frame.
+    ///
+    /// # Cost
+    /// The work done by a tick is bounded, so that it can safely be called from an interrupt
+    /// handler, such as on vblank. Each tick advances every playing channel by one frame, which
+    /// for patterns processes at most three steps, makes two passes over the queue, and starts
+    /// at most one queued effect. If several queued effects become free to play on the same
+    /// frame, the rest start on the following frames, in queue order. The cost therefore grows
+    /// linearly with `N` and `Q`, plus the cost of sampling any playing [`Effect`]s.
+    #[cfg_attr(any(feature = "iwram-tick", feature = "ewram-tick"), inline(never))]
+    #[cfg_attr(
+        feature = "iwram-tick",
+        unsafe(link_section = ".iwram.gba_rumble.tick.mixer")
+    )]
+    #[cfg_attr(
+        all(feature = "ewram-tick", not(feature = "iwram-tick")),
+        unsafe(link_section = ".ewram.gba_rumble.tick.mixer")
+    )]
+    pub fn tick(&mut self) -> Intensity {
+        if cfg!(feature = "disabled") {
+            return Intensity::OFF;
+        }
+        // Stop the effects of dropped source handles, before anything else is played.
+        for channel in self.channels.iter_mut().flatten() {
+            if channel.owner.is_some_and(|owner| !owner.is_live()) {
+                channel.playback = None;
+                channel.owner = None;
+            }
+        }
+        self.queue
+            .retain(|_, (.., owner)| owner.is_none_or(SourceKey::is_live));
+
+        let mut levels = [None; N];
+
+        for (level, channel) in levels.iter_mut().zip(&mut self.channels) {
+            if let Some(channel) = channel
+                && let Some(playback) = &mut channel.playback
+            {
+                *level = playback.tick();
+                if level.is_none() {
+                    channel.playback = None;
+                }
+            }
+        }
+
+        // Start a queued effect whose channel is free. Only one is started per frame, so that the
+        // cost of a tick does not depend on how many effects are waiting; any others start on
+        // later frames.
+        let top = self.top_priority();
+        let free: [bool; N] = core::array::from_fn(|index| {
+            self.channels[index].as_ref().is_some_and(|channel| {
+                channel.playback.is_none() && top.is_none_or(|top| channel.priority >= top)
+            })
+        });
+        if let Some((id, source, tag, owner)) = self.queue.take_first(|_, (id, ..)| free[id.0])
+            && let Ok(evicted) = self.make_room(id)
+        {
+            if let Some(evicted) = evicted {
+                levels[evicted] = None;
+            }
+            let started = self.next_start();
+            if let Some(channel) = &mut self.channels[id.0] {
+                let mut playback = Playback::new(source);
+                levels[id.0] = playback.tick();
+                if levels[id.0].is_some() {
+                    channel.playback = Some(playback);
+                    channel.tag = tag;
+                    channel.owner = owner;
+                    channel.started = started;
+                }
+            }
+        }
+
+        for (level, channel) in levels.iter_mut().zip(&mut self.channels) {
+            if let Some(channel) = channel
+                && let Some((lfo, frame)) = &mut channel.modulation
+            {
+                *level = level.map(|intensity| lfo.apply(intensity, *frame));
+                *frame = frame.wrapping_add(1);
+            }
+        }
+
+        for (level, channel) in levels.iter().zip(&mut self.channels) {
+            if let Some(channel) = channel {
+                channel.output = *level;
+            }
+        }
+        self.output = self.mix(&levels);
+        self.output
+    }
+
+    /// Combines the intensities output by each channel this frame.
+    #[cfg_attr(
+        feature = "iwram-tick",
+        unsafe(link_section = ".iwram.gba_rumble.tick.mixer_mix")
+    )]
+    #[cfg_attr(
+        all(feature = "ewram-tick", not(feature = "iwram-tick")),
+        unsafe(link_section = ".ewram.gba_rumble.tick.mixer_mix")
+    )]
+    fn mix(&self, levels: &[Option<Intensity>; N]) -> Intensity {
+        let soloing = self.channels.iter().flatten().any(|channel| channel.solo);
+        let audible = |channel: &Channel| !channel.muted && (channel.solo || !soloing);
+
+        let mut output: Option<(u8, Intensity)> = None;
+        for (level, channel) in levels.iter().zip(&self.channels) {
+            if let (Some(intensity), Some(channel)) = (level, channel)
+                && audible(channel)
+                && output.is_none_or(|(priority, _)| channel.priority > priority)
+            {
+                output = Some((channel.priority, *intensity));
+            }
+        }
+        let Some((top, mut output)) = output else {
+            return Intensity::OFF;
+        };
+
+        for (level, channel) in levels.iter().zip(&self.channels) {
+            if let (Some(intensity), Some(channel)) = (level, channel)
+                && audible(channel)
+                && let Preemption::Duck(factor) = channel.preemption
+                && channel.priority < top
+            {
+                output = output.max(intensity.scale(factor));
+            }
+        }
+
+        output
+    }
+
+    /// Returns the highest priority of any channel with an effect playing.
+    fn top_priority(&self) -> Option<u8> {
+        self.channels
+            .iter()
+            .flatten()
+            .filter(|channel| channel.playback.is_some())
+            .map(|channel| channel.priority)
+            .max()
+    }
+
+    /// Returns whether an effect with a higher priority than `channel` is playing.
+    fn is_blocked(&self, channel: ChannelId) -> bool {
+        let Some(priority) = self.channel(channel).map(|channel| channel.priority) else {
+            return false;
+        };
+        self.channels
+            .iter()
+            .flatten()
+            .any(|other| other.priority > priority && other.playback.is_some())
+    }
+
+    fn channel(&self, channel: ChannelId) -> Option<&Channel<'a>> {
+        self.channels.get(channel.0)?.as_ref()
+    }
+
+    fn channel_mut(&mut self, channel: ChannelId) -> Option<&mut Channel<'a>> {
+        self.channels.get_mut(channel.0)?.as_mut()
+    }
+}
+
+/// Saves the playback position of the effect on each channel.
+///
+/// Effects waiting in the queue are not saved, and neither are the phase of any modulation or the
+/// order effects started in. State can only be restored into a mixer with the
+/// same channels, playing the same kinds of effects on each channel as when the state was saved,
+/// and with the same patterns on channels playing patterns. Channels that were not playing when the
+/// state was saved are stopped.
+impl<const N: usize, const Q: usize> SaveState for Mixer<'_, N, Q> {
+    const STATE_LEN: usize = N * (1 + Sequencer::STATE_LEN);
+
+    fn serialize_state(&self, buffer: &mut [u8]) -> Result<usize, StateError> {
+        let mut writer = StateWriter::new(buffer, Self::STATE_LEN)?;
+        for channel in &self.channels {
+            let mut state = [0; Sequencer::STATE_LEN];
+            match channel
+                .as_ref()
+                .and_then(|channel| channel.playback.as_ref())
+            {
+                None => writer.u8(0),
+                Some(Playback::Sequencer(sequencer)) => {
+                    writer.u8(1);
+                    sequencer.serialize_state(&mut state)?;
+                }
+                Some(Playback::Effect { frame, .. }) => {
+                    writer.u8(2);
+                    state[..4].copy_from_slice(&frame.to_le_bytes());
+                }
+            }
+            for byte in state {
+                writer.u8(byte);
+            }
+        }
+        Ok(writer.finish())
+    }
+
+    fn restore_state(&mut self, buffer: &[u8]) -> Result<usize, StateError> {
+        /// A channel's state, validated but not yet applied.
+        enum Restored<'a> {
+            Stopped,
+            Sequencer(Sequencer<'a>),
+            Effect(u32),
+        }
+
+        let mut reader = StateReader::new(buffer, Self::STATE_LEN)?;
+        let mut restored = [const { None }; N];
+        for (restored, channel) in restored.iter_mut().zip(&self.channels) {
+            let kind = reader.u8()?;
+            let mut state = [0; Sequencer::STATE_LEN];
+            for byte in &mut state {
+                *byte = reader.u8()?;
+            }
+            let playback = channel
+                .as_ref()
+                .and_then(|channel| channel.playback.as_ref());
+            *restored = Some(match (kind, playback) {
+                (0, _) => Restored::Stopped,
+                (1, Some(Playback::Sequencer(sequencer))) => {
+                    let mut sequencer = sequencer.clone();
+                    sequencer.restore_state(&state)?;
+                    Restored::Sequencer(sequencer)
+                }
+                (2, Some(Playback::Effect { .. })) => {
+                    Restored::Effect(u32::from_le_bytes([state[0], state[1], state[2], state[3]]))
+                }
+                _ => return Err(StateError::Invalid),
+            });
+        }
+
+        for (restored, channel) in restored.into_iter().zip(&mut self.channels) {
+            let Some(channel) = channel else {
+                continue;
+            };
+            match (restored, &mut channel.playback) {
+                (Some(Restored::Stopped), playback) => *playback = None,
+                (Some(Restored::Sequencer(restored)), Some(Playback::Sequencer(sequencer))) => {
+                    *sequencer = restored;
+                }
+                (Some(Restored::Effect(restored)), Some(Playback::Effect { frame, .. })) => {
+                    *frame = restored;
+                }
+                _ => {}
+            }
+        }
+        Ok(reader.finish())
+    }
+}
+
+impl<const N: usize, const Q: usize> Default for Mixer<'_, N, Q> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Eviction, Mixer, PlayOutcome, Preemption, Tag};
+    use crate::{
+        EffectFn, Fixed, Intensity, Lfo, Marker, Pattern, SaveState, SourceHandle, StateError,
+        Step, Waveform, pattern,
+    };
+    use claims::{assert_err_eq, assert_none, assert_ok_eq, assert_some, assert_some_eq};
+    use gba_test::test;
+
+    const LOW: Pattern = pattern![Step::Hold {
+        intensity: Intensity::new(1),
+        frames: 2
+    }];
+    const HIGH: Pattern = pattern![Step::Hold {
+        intensity: Intensity::new(2),
+        frames: 2
+    }];
+
+    #[test]
+    fn max_active_evicts_oldest() {
+        let mut mixer = Mixer::<3>::new();
+        let first = mixer.add_channel(0, Preemption::Replace).unwrap();
+        let second = mixer.add_channel(0, Preemption::Replace).unwrap();
+        let third = mixer.add_channel(0, Preemption::Replace).unwrap();
+        mixer.set_max_active(2, Eviction::Oldest);
+
+        mixer.play(first, HIGH);
+        mixer.play(second, LOW);
+        assert_eq!(mixer.play(third, LOW), PlayOutcome::Started);
+
+        assert!(!mixer.is_playing(first));
+        assert!(mixer.is_playing(second));
+        assert!(mixer.is_playing(third));
+        assert_eq!(mixer.active(), 2);
+    }
+
+    #[test]
+    fn max_active_evicts_lowest_priority() {
+        let mut mixer = Mixer::<3>::new();
+        let high = mixer.add_channel(2, Preemption::Replace).unwrap();
+        let low = mixer.add_channel(0, Preemption::Duck(Fixed::ONE)).unwrap();
+        let middle = mixer.add_channel(1, Preemption::Duck(Fixed::ONE)).unwrap();
+        mixer.set_max_active(2, Eviction::LowestPriority);
+
+        mixer.play(low, LOW);
+        mixer.play(high, HIGH);
+        assert_eq!(mixer.play(middle, LOW), PlayOutcome::Started);
+
+        assert!(mixer.is_playing(high));
+        assert!(!mixer.is_playing(low));
+        assert!(mixer.is_playing(middle));
+    }
+
+    #[test]
+    fn max_active_rejects_lower_priority() {
+        let mut mixer = Mixer::<3>::new();
+        let high = mixer.add_channel(2, Preemption::Replace).unwrap();
+        let low = mixer.add_channel(0, Preemption::Duck(Fixed::ONE)).unwrap();
+        mixer.set_max_active(1, Eviction::LowestPriority);
+
+        mixer.play(high, HIGH);
+
+        assert_eq!(mixer.play(low, LOW), PlayOutcome::Rejected);
+        assert!(mixer.is_playing(high));
+    }
+
+    #[test]
+    fn max_active_replacing_does_not_evict() {
+        let mut mixer = Mixer::<2>::new();
+        let first = mixer.add_channel(0, Preemption::Replace).unwrap();
+        let second = mixer.add_channel(0, Preemption::Replace).unwrap();
+        mixer.set_max_active(2, Eviction::Oldest);
+
+        mixer.play(first, LOW);
+        mixer.play(second, LOW);
+        mixer.play(second, HIGH);
+
+        assert!(mixer.is_playing(first));
+        assert!(mixer.is_playing(second));
+    }
+
+    #[test]
+    fn add_channel_full() {
+        let mut mixer = Mixer::<1>::new();
+
+        assert_some!(mixer.add_channel(0, Preemption::Replace));
+        assert_none!(mixer.add_channel(0, Preemption::Replace));
+    }
+
+    #[test]
+    fn empty() {
+        let mut mixer = Mixer::<2>::new();
+
+        assert_eq!(mixer.tick(), Intensity::OFF);
+    }
+
+    #[test]
+    fn play() {
+        let mut mixer = Mixer::<1>::new();
+        let channel = assert_some!(mixer.add_channel(0, Preemption::Reject));
+
+        assert_eq!(mixer.play(channel, LOW), PlayOutcome::Started);
+        assert!(mixer.is_playing(channel));
+        assert_eq!(mixer.tick(), Intensity::new(1));
+        assert_eq!(mixer.tick(), Intensity::new(1));
+        assert_eq!(mixer.tick(), Intensity::OFF);
+        assert!(!mixer.is_playing(channel));
+    }
+
+    #[test]
+    fn highest_priority_wins() {
+        let mut mixer = Mixer::<2>::new();
+        let low = assert_some!(mixer.add_channel(0, Preemption::Reject));
+        let high = assert_some!(mixer.add_channel(1, Preemption::Reject));
+
+        mixer.play(high, HIGH);
+        mixer.play(low, LOW);
+
+        assert!(mixer.is_playing(high));
+        assert!(!mixer.is_playing(low));
+        assert_eq!(mixer.tick(), Intensity::new(2));
+    }
+
+    #[test]
+    fn lower_priority_does_not_block() {
+        let mut mixer = Mixer::<2>::new();
+        let low = assert_some!(mixer.add_channel(0, Preemption::Reject));
+        let high = assert_some!(mixer.add_channel(1, Preemption::Reject));
+
+        assert_eq!(mixer.play(low, LOW), PlayOutcome::Started);
+        assert_eq!(mixer.play(high, HIGH), PlayOutcome::Started);
+        assert_eq!(mixer.tick(), Intensity::new(2));
+        assert!(mixer.is_playing(low));
+    }
+
+    #[test]
+    fn preemption_replace() {
+        let mut mixer = Mixer::<2>::new();
+        let low = assert_some!(mixer.add_channel(0, Preemption::Replace));
+        let high = assert_some!(mixer.add_channel(1, Preemption::Reject));
+
+        mixer.play(high, HIGH);
+
+        assert_eq!(mixer.play(low, LOW), PlayOutcome::Started);
+        assert!(!mixer.is_playing(high));
+        assert_eq!(mixer.tick(), Intensity::new(1));
+    }
+
+    #[test]
+    fn preemption_queue() {
+        let mut mixer = Mixer::<2>::new();
+        let low = assert_some!(mixer.add_channel(0, Preemption::Queue));
+        let high = assert_some!(mixer.add_channel(1, Preemption::Reject));
+
+        mixer.play(high, HIGH);
+
+        assert_eq!(mixer.play(low, LOW), PlayOutcome::Queued);
+        assert_eq!(mixer.tick(), Intensity::new(2));
+        assert_eq!(mixer.tick(), Intensity::new(2));
+        assert_eq!(mixer.tick(), Intensity::new(1));
+        assert_eq!(mixer.tick(), Intensity::new(1));
+        assert_eq!(mixer.tick(), Intensity::OFF);
+    }
+
+    #[test]
+    fn preemption_queue_in_order() {
+        let mut mixer = Mixer::<2>::new();
+        let low = assert_some!(mixer.add_channel(0, Preemption::Queue));
+        let high = assert_some!(mixer.add_channel(1, Preemption::Reject));
+
+        mixer.play(high, HIGH);
+
+        assert_eq!(mixer.play(low, LOW), PlayOutcome::Queued);
+        assert_eq!(mixer.play(low, HIGH), PlayOutcome::Queued);
+        assert_eq!(mixer.tick(), Intensity::new(2));
+        assert_eq!(mixer.tick(), Intensity::new(2));
+        assert_eq!(mixer.tick(), Intensity::new(1));
+        assert_eq!(mixer.tick(), Intensity::new(1));
+        assert_eq!(mixer.tick(), Intensity::new(2));
+        assert_eq!(mixer.tick(), Intensity::new(2));
+        assert_eq!(mixer.tick(), Intensity::OFF);
+    }
+
+    #[test]
+    fn preemption_queue_full() {
+        let mut mixer = Mixer::<3, 1>::new();
+        let lowest = assert_some!(mixer.add_channel(0, Preemption::Queue));
+        let low = assert_some!(mixer.add_channel(1, Preemption::Queue));
+        let high = assert_some!(mixer.add_channel(2, Preemption::Reject));
+
+        mixer.play(high, HIGH);
+
+        assert_eq!(mixer.play(low, LOW), PlayOutcome::Queued);
+        assert_eq!(mixer.play(lowest, HIGH), PlayOutcome::Rejected);
+        assert_eq!(mixer.tick(), Intensity::new(2));
+        assert_eq!(mixer.tick(), Intensity::new(2));
+        assert_eq!(mixer.tick(), Intensity::new(1));
+        assert!(!mixer.is_playing(lowest));
+    }
+
+    #[test]
+    fn preemption_queue_full_drops_lowest_priority() {
+        let mut mixer = Mixer::<3, 1>::new();
+        let lowest = assert_some!(mixer.add_channel(0, Preemption::Queue));
+        let low = assert_some!(mixer.add_channel(1, Preemption::Queue));
+        let high = assert_some!(mixer.add_channel(2, Preemption::Reject));
+
+        mixer.play(high, HIGH);
+
+        assert_eq!(mixer.play(lowest, HIGH), PlayOutcome::Queued);
+        assert_eq!(mixer.play(low, LOW), PlayOutcome::Queued);
+        assert_eq!(mixer.tick(), Intensity::new(2));
+        assert_eq!(mixer.tick(), Intensity::new(2));
+        assert_eq!(mixer.tick(), Intensity::new(1));
+        assert!(!mixer.is_playing(lowest));
+    }
+
+    #[test]
+    fn preemption_reject() {
+        let mut mixer = Mixer::<2>::new();
+        let low = assert_some!(mixer.add_channel(0, Preemption::Reject));
+        let high = assert_some!(mixer.add_channel(1, Preemption::Reject));
+
+        mixer.play(high, HIGH);
+
+        assert_eq!(mixer.play(low, LOW), PlayOutcome::Rejected);
+        assert_eq!(mixer.tick(), Intensity::new(2));
+        assert_eq!(mixer.tick(), Intensity::new(2));
+        assert_eq!(mixer.tick(), Intensity::OFF);
+    }
+
+    #[test]
+    fn stop_clears_queue() {
+        let mut mixer = Mixer::<2>::new();
+        let low = assert_some!(mixer.add_channel(0, Preemption::Queue));
+        let high = assert_some!(mixer.add_channel(1, Preemption::Reject));
+
+        mixer.play(high, HIGH);
+        mixer.play(low, LOW);
+        mixer.stop(low);
+
+        assert_eq!(mixer.tick(), Intensity::new(2));
+        assert_eq!(mixer.tick(), Intensity::new(2));
+        assert_eq!(mixer.tick(), Intensity::OFF);
+    }
+
+    #[test]
+    fn stop_tagged() {
+        const GAMEPLAY: Tag = Tag::new(0);
+        const UI: Tag = Tag::new(1);
+
+        let mut mixer = Mixer::<3>::new();
+        let ambient = assert_some!(mixer.add_channel(0, Preemption::Duck(Fixed::ONE)));
+        let menu = assert_some!(mixer.add_channel(1, Preemption::Duck(Fixed::ONE)));
+        let hits = assert_some!(mixer.add_channel(2, Preemption::Replace));
+
+        assert_eq!(
+            mixer.play_tagged(ambient, LOW, GAMEPLAY),
+            PlayOutcome::Started
+        );
+        assert_eq!(mixer.play_tagged(menu, LOW, UI), PlayOutcome::Started);
+        assert_eq!(
+            mixer.play_tagged(hits, HIGH, GAMEPLAY),
+            PlayOutcome::Started
+        );
+        assert_some_eq!(mixer.tag(hits), GAMEPLAY);
+        mixer.stop_tagged(GAMEPLAY);
+
+        assert!(!mixer.is_playing(ambient));
+        assert!(mixer.is_playing(menu));
+        assert!(!mixer.is_playing(hits));
+        assert_none!(mixer.tag(hits));
+        assert_some_eq!(mixer.tag(menu), UI);
+        assert_eq!(mixer.tick(), Intensity::new(1));
+    }
+
+    #[test]
+    fn source_dropped() {
+        let mut mixer = Mixer::<3>::new();
+        let low = assert_some!(mixer.add_channel(0, Preemption::Queue));
+        let middle = assert_some!(mixer.add_channel(1, Preemption::Duck(Fixed::ONE)));
+        let high = assert_some!(mixer.add_channel(2, Preemption::Reject));
+        let source = assert_some!(SourceHandle::new());
+        let other = assert_some!(SourceHandle::new());
+
+        assert_eq!(mixer.play_from(high, HIGH, &source), PlayOutcome::Started);
+        assert_eq!(mixer.play_from(middle, LOW, &other), PlayOutcome::Started);
+        assert_eq!(mixer.play_from(low, LOW, &source), PlayOutcome::Queued);
+        drop(source);
+
+        assert_eq!(mixer.tick(), Intensity::new(1));
+        assert!(!mixer.is_playing(high));
+        assert!(mixer.is_playing(middle));
+        assert!(!mixer.is_playing(low));
+    }
+
+    #[test]
+    fn stop_tagged_clears_queue() {
+        const GAMEPLAY: Tag = Tag::new(0);
+
+        let mut mixer = Mixer::<3>::new();
+        let low = assert_some!(mixer.add_channel(0, Preemption::Queue));
+        let middle = assert_some!(mixer.add_channel(1, Preemption::Queue));
+        let high = assert_some!(mixer.add_channel(2, Preemption::Reject));
+
+        mixer.play(high, HIGH);
+        assert_eq!(mixer.play_tagged(low, LOW, GAMEPLAY), PlayOutcome::Queued);
+        assert_eq!(mixer.play(middle, LOW), PlayOutcome::Queued);
+        mixer.stop_tagged(GAMEPLAY);
+
+        assert_eq!(mixer.tick(), Intensity::new(2));
+        assert_eq!(mixer.tick(), Intensity::new(2));
+        assert_eq!(mixer.tick(), Intensity::new(1));
+        assert!(!mixer.is_playing(low));
+        assert_none!(mixer.tag(middle));
+    }
+
+    #[test]
+    fn advance() {
+        let mut mixer = Mixer::<1>::new();
+        let channel = assert_some!(mixer.add_channel(0, Preemption::Replace));
+
+        mixer.play(
+            channel,
+            pattern![
+                Step::Marker(Marker::new(0)),
+                Step::Hold {
+                    intensity: Intensity::MAX,
+                    frames: 1
+                },
+            ],
+        );
+
+        assert_eq!(mixer.tick(), Intensity::OFF);
+        assert!(mixer.is_playing(channel));
+        assert!(mixer.advance(channel, Marker::new(0)));
+        assert_eq!(mixer.tick(), Intensity::MAX);
+    }
+
+    #[test]
+    fn play_effect() {
+        let mut effect = EffectFn::new(|frame| Intensity::new(frame as u8 + 1));
+        let mut mixer = Mixer::<1>::new();
+        let channel = assert_some!(mixer.add_channel(0, Preemption::Reject));
+
+        assert_eq!(
+            mixer.play_effect(channel, &mut effect),
+            PlayOutcome::Started
+        );
+        assert_eq!(mixer.tick(), Intensity::new(1));
+        assert_eq!(mixer.tick(), Intensity::new(2));
+        assert_eq!(mixer.tick(), Intensity::new(3));
+        assert!(mixer.is_playing(channel));
+
+        mixer.stop(channel);
+
+        assert_eq!(mixer.tick(), Intensity::OFF);
+    }
+
+    #[test]
+    fn effect_blocks_lower_priority() {
+        let mut effect = EffectFn::new(|_| Intensity::MAX);
+        let mut mixer = Mixer::<2>::new();
+        let low = assert_some!(mixer.add_channel(0, Preemption::Queue));
+        let high = assert_some!(mixer.add_channel(1, Preemption::Reject));
+
+        mixer.play_effect(high, &mut effect);
+
+        assert_eq!(mixer.play(low, LOW), PlayOutcome::Queued);
+        assert_eq!(mixer.tick(), Intensity::MAX);
+        mixer.stop(high);
+        assert_eq!(mixer.tick(), Intensity::new(1));
+    }
+
+    #[test]
+    fn queued_effect_starts_from_first_frame() {
+        let mut effect = EffectFn::new(|frame| Intensity::new(frame as u8 + 10));
+        let mut mixer = Mixer::<2>::new();
+        let low = assert_some!(mixer.add_channel(0, Preemption::Queue));
+        let high = assert_some!(mixer.add_channel(1, Preemption::Reject));
+
+        mixer.play(high, HIGH);
+
+        assert_eq!(mixer.play_effect(low, &mut effect), PlayOutcome::Queued);
+        assert_eq!(mixer.tick(), Intensity::new(2));
+        assert_eq!(mixer.tick(), Intensity::new(2));
+        assert_eq!(mixer.tick(), Intensity::new(10));
+        assert_eq!(mixer.tick(), Intensity::new(11));
+    }
+
+    #[test]
+    fn advance_effect() {
+        let mut effect = EffectFn::new(|_| Intensity::MAX);
+        let mut mixer = Mixer::<1>::new();
+        let channel = assert_some!(mixer.add_channel(0, Preemption::Replace));
+
+        mixer.play_effect(channel, &mut effect);
+
+        assert!(!mixer.advance(channel, Marker::new(0)));
+    }
+
+    #[test]
+    fn preemption_duck() {
+        let mut mixer = Mixer::<2>::new();
+        let low = assert_some!(mixer.add_channel(0, Preemption::Duck(Fixed::HALF)));
+        let high = assert_some!(mixer.add_channel(1, Preemption::Reject));
+
+        mixer.play(high, HIGH);
+
+        assert_eq!(
+            mixer.play(
+                low,
+                pattern![Step::Hold {
+                    intensity: Intensity::new(200),
+                    frames: 3
+                }]
+            ),
+            PlayOutcome::Started
+        );
+        assert_eq!(mixer.tick(), Intensity::new(100));
+        assert_eq!(mixer.tick(), Intensity::new(100));
+        // Restored once the higher-priority effect finishes.
+        assert_eq!(mixer.tick(), Intensity::new(200));
+        assert_eq!(mixer.tick(), Intensity::OFF);
+    }
+
+    #[test]
+    fn preemption_duck_below_higher_priority() {
+        let mut mixer = Mixer::<2>::new();
+        let low = assert_some!(mixer.add_channel(0, Preemption::Duck(Fixed::HALF)));
+        let high = assert_some!(mixer.add_channel(1, Preemption::Reject));
+
+        mixer.play(low, HIGH);
+        mixer.play(
+            high,
+            pattern![Step::Hold {
+                intensity: Intensity::new(50),
+                frames: 1
+            }],
+        );
+
+        // The higher-priority effect is stronger than the ducked effect.
+        assert_eq!(mixer.tick(), Intensity::new(50));
+        assert_eq!(mixer.tick(), Intensity::new(2));
+    }
+
+    #[test]
+    fn preemption_duck_equal_priority_not_ducked() {
+        let mut mixer = Mixer::<2>::new();
+        let first = assert_some!(mixer.add_channel(0, Preemption::Reject));
+        let second = assert_some!(mixer.add_channel(0, Preemption::Duck(Fixed::HALF)));
+
+        mixer.play(first, LOW);
+        mixer.play(second, HIGH);
+
+        assert_eq!(mixer.tick(), Intensity::new(1));
+    }
+
+    #[test]
+    fn non_ducked_channels_not_mixed() {
+        let mut mixer = Mixer::<2>::new();
+        let low = assert_some!(mixer.add_channel(0, Preemption::Reject));
+        let high = assert_some!(mixer.add_channel(1, Preemption::Reject));
+
+        mixer.play(low, HIGH);
+        mixer.play(high, LOW);
+
+        assert_eq!(mixer.tick(), Intensity::new(1));
+    }
+
+    #[test]
+    fn one_queued_start_per_tick() {
+        let mut mixer = Mixer::<3>::new();
+        let first = assert_some!(mixer.add_channel(0, Preemption::Queue));
+        let second = assert_some!(mixer.add_channel(0, Preemption::Queue));
+        let high = assert_some!(mixer.add_channel(1, Preemption::Reject));
+
+        mixer.play(
+            high,
+            pattern![Step::Hold {
+                intensity: Intensity::new(3),
+                frames: 1
+            }],
+        );
+
+        assert_eq!(mixer.play(first, LOW), PlayOutcome::Queued);
+        assert_eq!(mixer.play(second, HIGH), PlayOutcome::Queued);
+        assert_eq!(mixer.tick(), Intensity::new(3));
+        // Both channels are now free, but only the first queued effect starts.
+        assert_eq!(mixer.tick(), Intensity::new(1));
+        assert!(!mixer.is_playing(second));
+        assert_eq!(mixer.tick(), Intensity::new(1));
+        assert!(mixer.is_playing(second));
+    }
+
+    #[test]
+    fn mute() {
+        let mut mixer = Mixer::<2>::new();
+        let low = assert_some!(mixer.add_channel(0, Preemption::Reject));
+        let high = assert_some!(mixer.add_channel(1, Preemption::Reject));
+        mixer.play(low, LOW);
+        mixer.play(high, HIGH);
+
+        mixer.set_muted(high, true);
+
+        assert!(mixer.is_muted(high));
+        assert!(!mixer.is_muted(low));
+        assert_eq!(mixer.tick(), Intensity::new(1));
+    }
+
+    #[test]
+    fn mute_keeps_advancing() {
+        let mut mixer = Mixer::<1>::new();
+        let channel = assert_some!(mixer.add_channel(0, Preemption::Reject));
+        mixer.play(channel, LOW);
+        mixer.set_muted(channel, true);
+
+        assert_eq!(mixer.tick(), Intensity::OFF);
+        mixer.set_muted(channel, false);
+        assert_eq!(mixer.tick(), Intensity::new(1));
+        assert_eq!(mixer.tick(), Intensity::OFF);
+    }
+
+    #[test]
+    fn mute_still_preempts() {
+        let mut mixer = Mixer::<2>::new();
+        let low = assert_some!(mixer.add_channel(0, Preemption::Reject));
+        let high = assert_some!(mixer.add_channel(1, Preemption::Reject));
+        mixer.set_muted(high, true);
+        mixer.play(high, HIGH);
+
+        assert_eq!(mixer.play(low, LOW), PlayOutcome::Rejected);
+    }
+
+    #[test]
+    fn solo() {
+        let mut mixer = Mixer::<3>::new();
+        let low = assert_some!(mixer.add_channel(0, Preemption::Reject));
+        let middle = assert_some!(mixer.add_channel(1, Preemption::Reject));
+        let high = assert_some!(mixer.add_channel(2, Preemption::Reject));
+        mixer.play(low, LOW);
+        mixer.play(middle, LOW);
+        mixer.play(high, HIGH);
+
+        mixer.set_solo(low, true);
+
+        assert!(mixer.is_solo(low));
+        assert!(!mixer.is_solo(high));
+        assert_eq!(mixer.tick(), Intensity::new(1));
+    }
+
+    #[test]
+    fn solo_and_muted() {
+        let mut mixer = Mixer::<2>::new();
+        let low = assert_some!(mixer.add_channel(0, Preemption::Reject));
+        let high = assert_some!(mixer.add_channel(1, Preemption::Reject));
+        mixer.play(low, LOW);
+        mixer.play(high, HIGH);
+
+        mixer.set_solo(high, true);
+        mixer.set_muted(high, true);
+
+        assert_eq!(mixer.tick(), Intensity::OFF);
+    }
+
+    #[test]
+    fn output() {
+        let mut mixer = Mixer::<3>::new();
+        let low = assert_some!(mixer.add_channel(0, Preemption::Reject));
+        let high = assert_some!(mixer.add_channel(1, Preemption::Reject));
+        let idle = assert_some!(mixer.add_channel(2, Preemption::Reject));
+        mixer.play(low, LOW);
+        mixer.play(high, HIGH);
+        mixer.set_muted(high, true);
+
+        mixer.tick();
+
+        assert_some_eq!(mixer.channel_output(low), Intensity::new(1));
+        assert_some_eq!(mixer.channel_output(high), Intensity::new(2));
+        assert_none!(mixer.channel_output(idle));
+        assert_eq!(mixer.output(), Intensity::new(1));
+    }
+
+    #[test]
+    fn modulation() {
+        let mut mixer = Mixer::<1>::new();
+        let channel = assert_some!(mixer.add_channel(0, Preemption::Reject));
+        let lfo = Lfo::new(Waveform::Square, 2, Fixed::HALF);
+        let mut effect = EffectFn::new(|_| Intensity::new(200));
+        mixer.play_effect(channel, &mut effect);
+
+        mixer.set_modulation(channel, Some(lfo));
+
+        assert_some_eq!(mixer.modulation(channel), lfo);
+        assert_eq!(mixer.tick(), Intensity::new(200));
+        assert_eq!(mixer.tick(), Intensity::new(100));
+        assert_eq!(mixer.tick(), Intensity::new(200));
+    }
+
+    #[test]
+    fn modulation_runs_while_idle() {
+        let mut mixer = Mixer::<1>::new();
+        let channel = assert_some!(mixer.add_channel(0, Preemption::Reject));
+        mixer.set_modulation(channel, Some(Lfo::new(Waveform::Square, 2, Fixed::ONE)));
+
+        assert_eq!(mixer.tick(), Intensity::OFF);
+        mixer.play(channel, HIGH);
+
+        // The oscillation is past its peak by the time the pattern starts.
+        assert_eq!(mixer.tick(), Intensity::OFF);
+        assert_eq!(mixer.tick(), Intensity::new(2));
+    }
+
+    #[test]
+    fn modulation_removed() {
+        let mut mixer = Mixer::<1>::new();
+        let channel = assert_some!(mixer.add_channel(0, Preemption::Reject));
+        mixer.set_modulation(channel, Some(Lfo::new(Waveform::Square, 2, Fixed::ONE)));
+        mixer.play(channel, HIGH);
+        mixer.tick();
+
+        mixer.set_modulation(channel, None);
+
+        assert_none!(mixer.modulation(channel));
+        assert_eq!(mixer.tick(), Intensity::new(2));
+    }
+
+    #[test]
+    fn muted_duck_not_mixed() {
+        let mut mixer = Mixer::<2>::new();
+        let low = assert_some!(mixer.add_channel(0, Preemption::Duck(Fixed::ONE)));
+        let high = assert_some!(mixer.add_channel(1, Preemption::Reject));
+        mixer.play(high, LOW);
+        mixer.play(low, HIGH);
+
+        mixer.set_muted(low, true);
+
+        assert_eq!(mixer.tick(), Intensity::new(1));
+    }
+
+    #[test]
+    fn save_state_round_trip() {
+        let long = pattern![Step::Hold {
+            intensity: Intensity::new(1),
+            frames: 10
+        }];
+        let mut effect = EffectFn::new(|frame| Intensity::new(frame as u8 + 10));
+        let mut mixer = Mixer::<3>::new();
+        let pattern = assert_some!(mixer.add_channel(0, Preemption::Duck(Fixed::ONE)));
+        let effect_channel = assert_some!(mixer.add_channel(1, Preemption::Reject));
+        let idle = assert_some!(mixer.add_channel(2, Preemption::Reject));
+        mixer.play(pattern, long);
+        mixer.play_effect(effect_channel, &mut effect);
+        mixer.tick();
+        mixer.tick();
+        let mut buffer = [0; Mixer::<3>::STATE_LEN];
+
+        assert_ok_eq!(mixer.serialize_state(&mut buffer), Mixer::<3>::STATE_LEN);
+
+        mixer.tick();
+        mixer.tick();
+        mixer.play(idle, HIGH);
+
+        assert_ok_eq!(mixer.restore_state(&buffer), Mixer::<3>::STATE_LEN);
+        assert!(!mixer.is_playing(idle));
+        assert_eq!(mixer.tick(), Intensity::new(12));
+        mixer.stop(effect_channel);
+        for _ in 3..10 {
+            assert_eq!(mixer.tick(), Intensity::new(1));
+        }
+        assert_eq!(mixer.tick(), Intensity::OFF);
+    }
+
+    #[test]
+    fn restore_state_not_playing() {
+        let mut source = Mixer::<1>::new();
+        let channel = assert_some!(source.add_channel(0, Preemption::Reject));
+        source.play(channel, LOW);
+        let mut buffer = [0; Mixer::<1>::STATE_LEN];
+        assert_ok_eq!(source.serialize_state(&mut buffer), Mixer::<1>::STATE_LEN);
+
+        let mut mixer = Mixer::<1>::new();
+        assert_some!(mixer.add_channel(0, Preemption::Reject));
+
+        assert_err_eq!(mixer.restore_state(&buffer), StateError::Invalid);
+    }
+
+    #[test]
+    fn restore_state_unchanged_on_error() {
+        let mut source = Mixer::<2>::new();
+        let first = assert_some!(source.add_channel(0, Preemption::Reject));
+        assert_some!(source.add_channel(0, Preemption::Reject));
+        source.play(first, LOW);
+        source.tick();
+        let mut buffer = [0; Mixer::<2>::STATE_LEN];
+        assert_ok_eq!(source.serialize_state(&mut buffer), Mixer::<2>::STATE_LEN);
+        // Claim the second channel was playing a pattern.
+        buffer[1 + super::Sequencer::STATE_LEN] = 1;
+
+        let mut mixer = Mixer::<2>::new();
+        let first = assert_some!(mixer.add_channel(0, Preemption::Reject));
+        assert_some!(mixer.add_channel(0, Preemption::Reject));
+        mixer.play(first, LOW);
+
+        assert_err_eq!(mixer.restore_state(&buffer), StateError::Invalid);
+        assert_eq!(mixer.tick(), Intensity::new(1));
+        assert_eq!(mixer.tick(), Intensity::new(1));
+        assert_eq!(mixer.tick(), Intensity::OFF);
+    }
+}
@@ -0,0 +1,126 @@
+//! Adjustments applied to an effect when it is played.
+
+use crate::{Fixed, Intensity};
+
+/// Adjustments applied to a pattern each time it is played.
+///
+/// Rather than authoring a separate pattern for every variation of an effect, one pattern can be
+/// played with different parameters: a single explosion pattern can serve small, medium, and large
+/// explosions by scaling its intensity and stretching its duration.
+///
+/// ``` rust
+/// use gba_rumble::{EffectParams, Fixed, Intensity, Sequencer, Step, pattern};
+///
+/// const EXPLOSION: gba_rumble::Pattern = pattern![
+///     Step::Hold { intensity: Intensity::MAX, frames: 20 },
+///     Step::Hold { intensity: Intensity::new(128), frames: 20 },
+/// ];
+///
+/// // A small explosion: half as strong, and over twice as fast.
+/// let small = EffectParams::new()
+///     .with_scale(Fixed::HALF)
+///     .with_speed(Fixed::from_int(2));
+/// let mut sequencer = Sequencer::with_params(EXPLOSION, small);
+/// assert_eq!(sequencer.tick(), Intensity::new(127));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct EffectParams {
+    /// The factor every intensity of the pattern is scaled by.
+    pub scale: Fixed,
+    /// The factor the length of every hold in the pattern is multiplied by.
+    ///
+    /// Holds are never shortened to less than a single frame, nor lengthened past `255` frames.
+    pub duration: Fixed,
+}
+
+impl EffectParams {
+    /// Creates parameters that play a pattern exactly as authored.
+    pub const fn new() -> Self {
+        Self {
+            scale: Fixed::ONE,
+            duration: Fixed::ONE,
+        }
+    }
+
+    /// Returns these parameters with intensities scaled by `scale`.
+    pub const fn with_scale(self, scale: Fixed) -> Self {
+        Self { scale, ..self }
+    }
+
+    /// Returns these parameters with holds lasting `duration` times as long.
+    pub const fn with_duration(self, duration: Fixed) -> Self {
+        Self { duration, ..self }
+    }
+
+    /// Returns these parameters with the pattern played `speed` times as fast.
+    ///
+    /// This is the same as a [`duration`] of `1 / speed`.
+    ///
+    /// [`duration`]: EffectParams::duration
+    pub const fn with_speed(self, speed: Fixed) -> Self {
+        self.with_duration(Fixed::from_ratio(Fixed::ONE.to_bits(), speed.to_bits()))
+    }
+
+    /// Applies these parameters to a hold step.
+    pub(crate) const fn apply(self, intensity: Intensity, frames: u8) -> (Intensity, u8) {
+        let frames = (frames as u32 * self.duration.to_bits() as u32) >> Fixed::FRACTIONAL_BITS;
+        let frames = if frames == 0 {
+            1
+        } else if frames > u8::MAX as u32 {
+            u8::MAX
+        } else {
+            frames as u8
+        };
+        (intensity.scale(self.scale), frames)
+    }
+}
+
+impl Default for EffectParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EffectParams;
+    use crate::{Fixed, Intensity};
+    use gba_test::test;
+
+    #[test]
+    fn new_unchanged() {
+        assert_eq!(
+            EffectParams::new().apply(Intensity::new(200), 30),
+            (Intensity::new(200), 30)
+        );
+    }
+
+    #[test]
+    fn apply() {
+        let params = EffectParams::new()
+            .with_scale(Fixed::HALF)
+            .with_duration(Fixed::from_int(2));
+
+        assert_eq!(
+            params.apply(Intensity::new(200), 30),
+            (Intensity::new(100), 60)
+        );
+    }
+
+    #[test]
+    fn with_speed() {
+        let params = EffectParams::new().with_speed(Fixed::from_int(4));
+
+        assert_eq!(params.duration, Fixed::from_ratio(1, 4));
+        assert_eq!(params.apply(Intensity::MAX, 20), (Intensity::MAX, 5));
+    }
+
+    #[test]
+    fn apply_clamps_frames() {
+        let params = EffectParams::new().with_duration(Fixed::ZERO);
+        assert_eq!(params.apply(Intensity::MAX, 20), (Intensity::MAX, 1));
+
+        let params = EffectParams::new().with_duration(Fixed::from_int(4));
+        assert_eq!(params.apply(Intensity::MAX, 100), (Intensity::MAX, 255));
+    }
+}
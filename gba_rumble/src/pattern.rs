@@ -0,0 +1,746 @@
+//! Authored rumble patterns.
+
+mod validation;
+
+pub use validation::{Issues, PatternIssue, ValidationRules};
+
+use crate::Intensity;
+use core::{
+    fmt,
+    fmt::{Display, Formatter},
+};
+
+/// A named point within a [`Pattern`] where playback pauses.
+///
+/// When a [`Sequencer`] reaches a marker, it holds the current intensity until
+/// [`Sequencer::advance()`] is called with the same marker. This allows a single pattern to wait
+/// on game events, such as a charging attack that releases its final burst only once the player
+/// lets go of the button.
+///
+/// Markers are identified by a small integer. Naming them with constants keeps patterns and game
+/// code in agreement:
+///
+/// ``` rust
+/// use gba_rumble::Marker;
+///
+/// const RELEASE: Marker = Marker::new(0);
+/// ```
+///
+/// [`Sequencer`]: crate::Sequencer
+/// [`Sequencer::advance()`]: crate::Sequencer::advance()
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Marker(u8);
+
+impl Marker {
+    /// Creates a marker with the given identifier.
+    pub const fn new(id: u8) -> Self {
+        Self(id)
+    }
+
+    /// Returns the identifier of this marker.
+    pub const fn id(self) -> u8 {
+        self.0
+    }
+}
+
+/// A single step of a [`Pattern`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Step {
+    /// Rumble at `intensity` for `frames` frames.
+    ///
+    /// `frames` must be nonzero.
+    Hold { intensity: Intensity, frames: u8 },
+    /// Pause playback, holding the current intensity, until the marker is advanced past.
+    Marker(Marker),
+    /// Jump back to the step at index `step`, repeating the steps in between `count` more times.
+    ///
+    /// A `count` of `0` repeats forever. `step` must refer to an earlier step, and loops may not
+    /// be nested within each other.
+    Loop { step: u8, count: u8 },
+}
+
+impl Step {
+    /// Not a step, but the first byte of a header.
+    const HEADER: u8 = 0x00;
+    const HOLD: u8 = 0x01;
+    const MARKER: u8 = 0x02;
+    const LOOP: u8 = 0x03;
+    const HOLD_OFF: u8 = 0x04;
+    const HOLD_MAX: u8 = 0x05;
+
+    /// The number of bytes used to encode this step.
+    pub(crate) const fn encoded_len(self) -> usize {
+        match self {
+            Self::Hold { .. } | Self::Loop { .. } => 3,
+            Self::Marker(_) => 2,
+        }
+    }
+
+    /// Encodes this step. Only the first [`encoded_len()`] bytes are used.
+    ///
+    /// [`encoded_len()`]: Step::encoded_len()
+    pub(crate) const fn encode(self) -> [u8; 3] {
+        match self {
+            Self::Hold { intensity, frames } => [Self::HOLD, intensity.get(), frames],
+            Self::Marker(marker) => [Self::MARKER, marker.id(), 0],
+            Self::Loop { step, count } => [Self::LOOP, step, count],
+        }
+    }
+
+    /// The number of bytes used to encode this step in the compressed format.
+    pub(crate) const fn compressed_len(self) -> usize {
+        match self {
+            Self::Hold { intensity, .. } if intensity.is_off() || intensity.get() == u8::MAX => 2,
+            _ => self.encoded_len(),
+        }
+    }
+
+    /// Encodes this step in the compressed format. Only the first [`compressed_len()`] bytes are
+    /// used.
+    ///
+    /// [`compressed_len()`]: Step::compressed_len()
+    pub(crate) const fn encode_compressed(self) -> [u8; 3] {
+        match self {
+            Self::Hold { intensity, frames } if intensity.is_off() => [Self::HOLD_OFF, frames, 0],
+            Self::Hold { intensity, frames } if intensity.get() == u8::MAX => {
+                [Self::HOLD_MAX, frames, 0]
+            }
+            _ => self.encode(),
+        }
+    }
+}
+
+/// An error encountered while decoding a [`Pattern`] from bytes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PatternError {
+    /// The byte at `offset` is not a known step opcode.
+    UnknownOpcode { offset: usize },
+    /// The final step is missing some of its bytes.
+    Truncated,
+    /// The hold step at `offset` has a length of zero frames.
+    ZeroFrames { offset: usize },
+    /// The loop step at `offset` does not jump backwards, or its body contains another loop.
+    InvalidLoop { offset: usize },
+    /// The header gives a format `version` other than [`Pattern::FORMAT_VERSION`].
+    UnsupportedVersion { version: u8 },
+    /// The header's checksum does not match the steps following it.
+    ChecksumMismatch,
+}
+
+impl Display for PatternError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownOpcode { offset } => {
+                write!(formatter, "unknown opcode at offset {offset}")
+            }
+            Self::Truncated => formatter.write_str("final step is truncated"),
+            Self::ZeroFrames { offset } => {
+                write!(formatter, "hold step at offset {offset} has zero frames")
+            }
+            Self::InvalidLoop { offset } => {
+                write!(formatter, "invalid loop step at offset {offset}")
+            }
+            Self::UnsupportedVersion { version } => {
+                write!(formatter, "unsupported pattern format version {version}")
+            }
+            Self::ChecksumMismatch => formatter.write_str("pattern checksum does not match"),
+        }
+    }
+}
+
+impl core::error::Error for PatternError {}
+
+/// A sequence of rumble [`Step`]s, played back using a [`Sequencer`].
+///
+/// Patterns are stored in a compact byte format, allowing them to live in ROM or be loaded from
+/// external asset data using [`Pattern::from_bytes()`]. Patterns written in code are most easily
+/// created with the [`pattern!`] macro:
+///
+/// ``` rust
+/// use gba_rumble::{Intensity, Marker, Pattern, Step, pattern};
+///
+/// const RELEASE: Marker = Marker::new(0);
+///
+/// // Rumble gently while charging, then release a strong burst.
+/// const CHARGE: Pattern = pattern![
+///     Step::Hold { intensity: Intensity::new(64), frames: 30 },
+///     Step::Marker(RELEASE),
+///     Step::Hold { intensity: Intensity::MAX, frames: 15 },
+/// ];
+/// ```
+///
+/// # Format
+/// Each step begins with an opcode byte, followed by its operands:
+///
+/// | Step             | Encoding                        |
+/// |------------------|---------------------------------|
+/// | [`Step::Hold`]   | `0x01`, `intensity`, `frames`   |
+/// | [`Step::Marker`] | `0x02`, `marker`                |
+/// | [`Step::Loop`]   | `0x03`, `step`, `count`         |
+///
+/// ## Compression
+/// Libraries of many authored patterns can be stored in less ROM using the compressed format,
+/// produced by [`Pattern::encode_compressed()`] or the [`compressed_pattern!`] macro. It adds short
+/// forms of the hold step for the two intensities that make up most patterns written for on/off
+/// motors:
+///
+/// | Step                                 | Encoding         |
+/// |--------------------------------------|------------------|
+/// | [`Step::Hold`] at [`Intensity::OFF`] | `0x04`, `frames` |
+/// | [`Step::Hold`] at [`Intensity::MAX`] | `0x05`, `frames` |
+///
+/// Steps are otherwise encoded as above, and both forms may be mixed freely within a pattern.
+/// Compressed patterns are decoded one step at a time as they play, exactly as uncompressed
+/// patterns are, costing only a few extra cycles per step. Pattern data using the compressed
+/// format is accepted by [`Pattern::from_bytes()`] as is.
+///
+/// ## Header
+/// Pattern data exported by authoring tools should begin with a header, so that stale or
+/// corrupted assets fail to load rather than producing subtly wrong vibration:
+///
+/// | Byte  | Contents                                                             |
+/// |-------|----------------------------------------------------------------------|
+/// | `0`   | `0x00`, marking the header                                           |
+/// | `1`   | The format version, [`Pattern::FORMAT_VERSION`]                      |
+/// | `2-3` | The [`Pattern::checksum()`] of the steps that follow, little-endian |
+///
+/// [`Pattern::from_bytes()`] verifies the header when one is present, and returns a pattern of the
+/// steps following it. [`Pattern::header()`] computes the header for a sequence of encoded steps.
+/// Data without a header, such as that produced by [`pattern!`], is accepted as before.
+///
+/// [`Sequencer`]: crate::Sequencer
+/// [`compressed_pattern!`]: crate::compressed_pattern!
+/// [`pattern!`]: crate::pattern!
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Pattern<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Pattern<'a> {
+    /// The version of the pattern format expected in a header.
+    ///
+    /// See the [type documentation](Pattern#header) for details.
+    pub const FORMAT_VERSION: u8 = 1;
+
+    /// The number of bytes in a header.
+    pub const HEADER_LEN: usize = 4;
+
+    /// Creates a pattern from its encoded bytes, validating the encoding.
+    ///
+    /// If the bytes begin with a [header](Pattern#header), its version and checksum are verified,
+    /// and the pattern consists of the steps following it. Offsets in errors about steps count
+    /// from the first step, after any header.
+    pub const fn from_bytes(bytes: &'a [u8]) -> Result<Self, PatternError> {
+        let bytes = match strip_header(bytes) {
+            Ok(steps) => steps,
+            Err(error) => return Err(error),
+        };
+        let mut offset = 0;
+        let mut index = 0;
+        // Loop bodies must begin after the most recent loop step to prevent nesting.
+        let mut loop_start = 0;
+        while offset < bytes.len() {
+            let (step, len) = match decode(bytes, offset) {
+                Ok(decoded) => decoded,
+                Err(error) => return Err(error),
+            };
+            match step {
+                Step::Hold { frames: 0, .. } => return Err(PatternError::ZeroFrames { offset }),
+                Step::Loop { step, .. } => {
+                    if (step as usize) < loop_start || step as usize >= index {
+                        return Err(PatternError::InvalidLoop { offset });
+                    }
+                    loop_start = index + 1;
+                }
+                _ => {}
+            }
+            offset += len;
+            index += 1;
+        }
+        Ok(Self { bytes })
+    }
+
+    /// Creates a pattern from bytes that have already been validated.
+    #[cfg(feature = "alloc")]
+    pub(crate) const fn from_bytes_unchecked(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns the encoded bytes of this pattern, excluding any header.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns the number of bytes of encoded data in this pattern.
+    ///
+    /// This is the space the pattern's data occupies in ROM or RAM, for accounting for haptic data
+    /// in asset budgets. The `Pattern` itself, which only references the data, is not included.
+    pub const fn size_in_bytes(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns an iterator over the steps of this pattern.
+    pub const fn steps(&self) -> Steps<'a> {
+        Steps {
+            bytes: self.bytes,
+            offset: 0,
+        }
+    }
+
+    /// Returns the number of bytes needed to encode `steps`.
+    ///
+    /// This is used by the [`pattern!`] macro to size the encoded pattern.
+    ///
+    /// [`pattern!`]: crate::pattern!
+    pub const fn encoded_len(steps: &[Step]) -> usize {
+        let mut len = 0;
+        let mut index = 0;
+        while index < steps.len() {
+            len += steps[index].encoded_len();
+            index += 1;
+        }
+        len
+    }
+
+    /// Encodes `steps` into the pattern byte format.
+    ///
+    /// # Panics
+    /// Panics if `N` is not equal to [`Pattern::encoded_len(steps)`].
+    ///
+    /// [`Pattern::encoded_len(steps)`]: Pattern::encoded_len()
+    pub const fn encode<const N: usize>(steps: &[Step]) -> [u8; N] {
+        assert!(N == Self::encoded_len(steps), "incorrect encoded length");
+        let mut bytes = [0; N];
+        let mut offset = 0;
+        let mut index = 0;
+        while index < steps.len() {
+            let encoded = steps[index].encode();
+            let len = steps[index].encoded_len();
+            let mut byte = 0;
+            while byte < len {
+                bytes[offset + byte] = encoded[byte];
+                byte += 1;
+            }
+            offset += len;
+            index += 1;
+        }
+        bytes
+    }
+
+    /// Returns the number of bytes needed to encode `steps` in the compressed format.
+    ///
+    /// This is used by the [`compressed_pattern!`] macro to size the encoded pattern.
+    ///
+    /// [`compressed_pattern!`]: crate::compressed_pattern!
+    pub const fn compressed_len(steps: &[Step]) -> usize {
+        let mut len = 0;
+        let mut index = 0;
+        while index < steps.len() {
+            len += steps[index].compressed_len();
+            index += 1;
+        }
+        len
+    }
+
+    /// Encodes `steps` into the compressed pattern byte format, described in the
+    /// [type documentation](Pattern#compression).
+    ///
+    /// # Panics
+    /// Panics if `N` is not equal to [`Pattern::compressed_len(steps)`].
+    ///
+    /// [`Pattern::compressed_len(steps)`]: Pattern::compressed_len()
+    pub const fn encode_compressed<const N: usize>(steps: &[Step]) -> [u8; N] {
+        assert!(N == Self::compressed_len(steps), "incorrect encoded length");
+        let mut bytes = [0; N];
+        let mut offset = 0;
+        let mut index = 0;
+        while index < steps.len() {
+            let encoded = steps[index].encode_compressed();
+            let len = steps[index].compressed_len();
+            let mut byte = 0;
+            while byte < len {
+                bytes[offset + byte] = encoded[byte];
+                byte += 1;
+            }
+            offset += len;
+            index += 1;
+        }
+        bytes
+    }
+
+    /// Computes the checksum stored in a [header](Pattern#header) for the encoded `steps`.
+    ///
+    /// This is the Fletcher-16 checksum of the bytes, with the first sum in the low byte.
+    pub const fn checksum(steps: &[u8]) -> u16 {
+        let mut low: u16 = 0;
+        let mut high: u16 = 0;
+        let mut index = 0;
+        while index < steps.len() {
+            low = (low + steps[index] as u16) % 255;
+            high = (high + low) % 255;
+            index += 1;
+        }
+        high << 8 | low
+    }
+
+    /// Returns the [header](Pattern#header) to place before the encoded `steps`.
+    ///
+    /// ``` rust
+    /// use gba_rumble::{Intensity, Pattern, Step};
+    ///
+    /// const STEPS: &[Step] = &[Step::Hold { intensity: Intensity::MAX, frames: 10 }];
+    /// const ENCODED: [u8; Pattern::encoded_len(STEPS)] = Pattern::encode(STEPS);
+    ///
+    /// let mut asset = [0; Pattern::HEADER_LEN + ENCODED.len()];
+    /// asset[..Pattern::HEADER_LEN].copy_from_slice(&Pattern::header(&ENCODED));
+    /// asset[Pattern::HEADER_LEN..].copy_from_slice(&ENCODED);
+    /// assert_eq!(Pattern::from_bytes(&asset).map(|pattern| pattern.as_bytes()), Ok(&ENCODED[..]));
+    /// ```
+    pub const fn header(steps: &[u8]) -> [u8; 4] {
+        let [low, high] = Self::checksum(steps).to_le_bytes();
+        [Step::HEADER, Self::FORMAT_VERSION, low, high]
+    }
+
+    /// Decodes the step at `offset`, returning it along with the offset of the following step.
+    ///
+    /// Returns `None` if `offset` is at the end of the pattern.
+    pub(crate) fn step_at(&self, offset: usize) -> Option<(Step, usize)> {
+        if offset >= self.bytes.len() {
+            return None;
+        }
+        // The pattern was validated on creation, so decoding cannot fail.
+        let (step, len) = decode(self.bytes, offset).ok()?;
+        Some((step, offset + len))
+    }
+
+    /// Returns the byte offset of the step at `index`.
+    pub(crate) fn offset_of(&self, index: usize) -> Option<usize> {
+        let mut offset = 0;
+        for _ in 0..index {
+            offset = self.step_at(offset)?.1;
+        }
+        Some(offset)
+    }
+}
+
+/// Verifies and removes the header from the start of `bytes`, if it has one, returning the steps.
+pub(crate) const fn strip_header(bytes: &[u8]) -> Result<&[u8], PatternError> {
+    if bytes.is_empty() || bytes[0] != Step::HEADER {
+        return Ok(bytes);
+    }
+    if bytes.len() < Pattern::HEADER_LEN {
+        return Err(PatternError::Truncated);
+    }
+    let (header, steps) = bytes.split_at(Pattern::HEADER_LEN);
+    if header[1] != Pattern::FORMAT_VERSION {
+        return Err(PatternError::UnsupportedVersion { version: header[1] });
+    }
+    if u16::from_le_bytes([header[2], header[3]]) != Pattern::checksum(steps) {
+        return Err(PatternError::ChecksumMismatch);
+    }
+    Ok(steps)
+}
+
+/// Decodes the step beginning at `offset`, returning it along with the number of bytes it was
+/// encoded in.
+const fn decode(bytes: &[u8], offset: usize) -> Result<(Step, usize), PatternError> {
+    let opcode = bytes[offset];
+    let len = match opcode {
+        Step::HOLD | Step::LOOP => 3,
+        Step::MARKER | Step::HOLD_OFF | Step::HOLD_MAX => 2,
+        _ => return Err(PatternError::UnknownOpcode { offset }),
+    };
+    if offset + len > bytes.len() {
+        return Err(PatternError::Truncated);
+    }
+    let step = match opcode {
+        Step::HOLD => Step::Hold {
+            intensity: Intensity::new(bytes[offset + 1]),
+            frames: bytes[offset + 2],
+        },
+        Step::HOLD_OFF => Step::Hold {
+            intensity: Intensity::OFF,
+            frames: bytes[offset + 1],
+        },
+        Step::HOLD_MAX => Step::Hold {
+            intensity: Intensity::MAX,
+            frames: bytes[offset + 1],
+        },
+        Step::MARKER => Step::Marker(Marker::new(bytes[offset + 1])),
+        _ => Step::Loop {
+            step: bytes[offset + 1],
+            count: bytes[offset + 2],
+        },
+    };
+    Ok((step, len))
+}
+
+/// An iterator over the [`Step`]s of a [`Pattern`].
+///
+/// This `struct` is created by the [`steps()`] method on [`Pattern`].
+///
+/// [`steps()`]: Pattern::steps()
+#[derive(Clone, Debug)]
+pub struct Steps<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl Iterator for Steps<'_> {
+    type Item = Step;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (step, offset) = Pattern { bytes: self.bytes }.step_at(self.offset)?;
+        self.offset = offset;
+        Some(step)
+    }
+}
+
+/// Creates a [`Pattern`] from a list of [`Step`]s at compile time.
+///
+/// The steps are validated during compilation, so an invalid pattern fails to build rather than
+/// misbehaving at runtime.
+///
+/// ``` rust
+/// use gba_rumble::{Intensity, Pattern, Step, pattern};
+///
+/// // Pulse three times.
+/// const PULSE: Pattern = pattern![
+///     Step::Hold { intensity: Intensity::MAX, frames: 4 },
+///     Step::Hold { intensity: Intensity::OFF, frames: 4 },
+///     Step::Loop { step: 0, count: 2 },
+/// ];
+/// ```
+#[macro_export]
+macro_rules! pattern {
+    ($($step:expr),* $(,)?) => {{
+        const STEPS: &[$crate::Step] = &[$($step),*];
+        const BYTES: [u8; $crate::Pattern::encoded_len(STEPS)] = $crate::Pattern::encode(STEPS);
+        const PATTERN: $crate::Pattern<'static> = match $crate::Pattern::from_bytes(&BYTES) {
+            Ok(pattern) => pattern,
+            Err(_) => panic!("invalid pattern"),
+        };
+        PATTERN
+    }};
+}
+
+/// Creates a [`Pattern`] in the compressed format from a list of [`Step`]s at compile time.
+///
+/// This behaves the same as [`pattern!`], except the pattern is encoded using
+/// [`Pattern::encode_compressed()`], taking less space wherever it holds the motor fully on or
+/// off.
+///
+/// ``` rust
+/// use gba_rumble::{Intensity, Pattern, Step, compressed_pattern};
+///
+/// // Pulse three times, in 8 bytes rather than 9.
+/// const PULSE: Pattern = compressed_pattern![
+///     Step::Hold { intensity: Intensity::MAX, frames: 4 },
+///     Step::Hold { intensity: Intensity::OFF, frames: 4 },
+///     Step::Loop { step: 0, count: 2 },
+/// ];
+/// ```
+///
+/// [`pattern!`]: crate::pattern!
+#[macro_export]
+macro_rules! compressed_pattern {
+    ($($step:expr),* $(,)?) => {{
+        const STEPS: &[$crate::Step] = &[$($step),*];
+        const BYTES: [u8; $crate::Pattern::compressed_len(STEPS)] =
+            $crate::Pattern::encode_compressed(STEPS);
+        const PATTERN: $crate::Pattern<'static> = match $crate::Pattern::from_bytes(&BYTES) {
+            Ok(pattern) => pattern,
+            Err(_) => panic!("invalid pattern"),
+        };
+        PATTERN
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Marker, Pattern, PatternError, Step};
+    use crate::Intensity;
+    use alloc::vec::Vec;
+    use claims::{assert_err_eq, assert_ok};
+    use gba_test::test;
+
+    #[test]
+    fn size_in_bytes() {
+        let pattern = assert_ok!(Pattern::from_bytes(&[0x01, 0xFF, 0x10, 0x02, 0x00]));
+
+        assert_eq!(pattern.size_in_bytes(), 5);
+    }
+
+    #[test]
+    fn compressed() {
+        const STEPS: &[Step] = &[
+            Step::Hold {
+                intensity: Intensity::MAX,
+                frames: 4,
+            },
+            Step::Hold {
+                intensity: Intensity::new(128),
+                frames: 2,
+            },
+            Step::Hold {
+                intensity: Intensity::OFF,
+                frames: 4,
+            },
+            Step::Loop { step: 0, count: 2 },
+        ];
+        const BYTES: [u8; Pattern::compressed_len(STEPS)] = Pattern::encode_compressed(STEPS);
+
+        assert_eq!(
+            BYTES,
+            [0x05, 0x04, 0x01, 0x80, 0x02, 0x04, 0x04, 0x03, 0x00, 0x02]
+        );
+        let pattern = assert_ok!(Pattern::from_bytes(&BYTES));
+        assert_eq!(pattern.steps().collect::<Vec<_>>(), STEPS);
+    }
+
+    #[test]
+    fn header() {
+        let steps = [0x01, 0xFF, 0x10, 0x04, 0x08];
+        let mut bytes = [0; Pattern::HEADER_LEN + 5];
+        bytes[..Pattern::HEADER_LEN].copy_from_slice(&Pattern::header(&steps));
+        bytes[Pattern::HEADER_LEN..].copy_from_slice(&steps);
+
+        let pattern = assert_ok!(Pattern::from_bytes(&bytes));
+        assert_eq!(pattern.as_bytes(), steps);
+    }
+
+    #[test]
+    fn header_checksum_mismatch() {
+        let steps = [0x01, 0xFF, 0x10];
+        let mut bytes = [0; Pattern::HEADER_LEN + 3];
+        bytes[..Pattern::HEADER_LEN].copy_from_slice(&Pattern::header(&steps));
+        bytes[Pattern::HEADER_LEN..].copy_from_slice(&[0x01, 0xFE, 0x10]);
+
+        assert_err_eq!(Pattern::from_bytes(&bytes), PatternError::ChecksumMismatch);
+    }
+
+    #[test]
+    fn header_unsupported_version() {
+        assert_err_eq!(
+            Pattern::from_bytes(&[0x00, 0x02, 0x00, 0x00]),
+            PatternError::UnsupportedVersion { version: 2 }
+        );
+    }
+
+    #[test]
+    fn header_truncated() {
+        assert_err_eq!(Pattern::from_bytes(&[0x00, 0x01]), PatternError::Truncated);
+    }
+
+    #[test]
+    fn checksum() {
+        assert_eq!(Pattern::checksum(&[]), 0);
+        assert_eq!(Pattern::checksum(&[0x01, 0x02]), 0x0403);
+    }
+
+    #[test]
+    fn compressed_truncated() {
+        assert_err_eq!(
+            Pattern::from_bytes(&[0x01, 0xFF, 0x10, 0x05]),
+            PatternError::Truncated
+        );
+    }
+
+    #[test]
+    fn from_bytes_empty() {
+        assert_ok!(Pattern::from_bytes(&[]));
+    }
+
+    #[test]
+    fn from_bytes_valid() {
+        assert_ok!(Pattern::from_bytes(&[
+            0x01, 0xFF, 0x10, 0x02, 0x00, 0x01, 0x00, 0x05, 0x03, 0x02, 0x00
+        ]));
+    }
+
+    #[test]
+    fn from_bytes_unknown_opcode() {
+        assert_err_eq!(
+            Pattern::from_bytes(&[0x01, 0xFF, 0x10, 0x07]),
+            PatternError::UnknownOpcode { offset: 3 }
+        );
+    }
+
+    #[test]
+    fn from_bytes_truncated() {
+        assert_err_eq!(Pattern::from_bytes(&[0x01, 0xFF]), PatternError::Truncated);
+    }
+
+    #[test]
+    fn from_bytes_zero_frames() {
+        assert_err_eq!(
+            Pattern::from_bytes(&[0x01, 0xFF, 0x00]),
+            PatternError::ZeroFrames { offset: 0 }
+        );
+    }
+
+    #[test]
+    fn from_bytes_loop_forwards() {
+        assert_err_eq!(
+            Pattern::from_bytes(&[0x01, 0xFF, 0x01, 0x03, 0x01, 0x00]),
+            PatternError::InvalidLoop { offset: 3 }
+        );
+    }
+
+    #[test]
+    fn from_bytes_nested_loop() {
+        assert_err_eq!(
+            Pattern::from_bytes(&[0x01, 0xFF, 0x01, 0x03, 0x00, 0x01, 0x03, 0x00, 0x01]),
+            PatternError::InvalidLoop { offset: 6 }
+        );
+    }
+
+    #[test]
+    fn steps() {
+        let pattern = assert_ok!(Pattern::from_bytes(&[
+            0x01, 0xFF, 0x10, 0x02, 0x04, 0x03, 0x00, 0x02
+        ]));
+
+        assert_eq!(
+            pattern.steps().collect::<Vec<_>>(),
+            [
+                Step::Hold {
+                    intensity: Intensity::MAX,
+                    frames: 0x10
+                },
+                Step::Marker(Marker::new(4)),
+                Step::Loop { step: 0, count: 2 },
+            ]
+        );
+    }
+
+    #[test]
+    fn pattern_macro() {
+        let pattern = pattern![
+            Step::Hold {
+                intensity: Intensity::new(64),
+                frames: 30
+            },
+            Step::Marker(Marker::new(1)),
+            Step::Loop { step: 1, count: 0 },
+        ];
+
+        assert_eq!(
+            pattern.as_bytes(),
+            [0x01, 0x40, 0x1E, 0x02, 0x01, 0x03, 0x01, 0x00]
+        );
+    }
+
+    #[test]
+    fn offset_of() {
+        let pattern = assert_ok!(Pattern::from_bytes(&[
+            0x01, 0xFF, 0x10, 0x02, 0x04, 0x03, 0x00, 0x02
+        ]));
+
+        assert_eq!(pattern.offset_of(0), Some(0));
+        assert_eq!(pattern.offset_of(1), Some(3));
+        assert_eq!(pattern.offset_of(2), Some(5));
+        assert_eq!(pattern.offset_of(3), Some(8));
+        assert_eq!(pattern.offset_of(4), None);
+    }
+}
@@ -0,0 +1,525 @@
+//! Checking authored patterns for problems.
+
+use super::{Pattern, PatternError, Step, decode, strip_header};
+use crate::{DutyBudget, Fixed};
+
+/// The number of frames in a second.
+const FRAMES_PER_SECOND: u32 = 60;
+
+/// A problem found in a pattern by [`Pattern::validate()`].
+///
+/// Offsets are byte offsets of the offending step within the encoded pattern, counting from the
+/// first step after any header, as in [`PatternError`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PatternIssue {
+    /// The pattern could not be decoded any further.
+    ///
+    /// This is never an error about a specific step that has an issue of its own, such as
+    /// [`PatternError::ZeroFrames`], and is always the last issue reported. A header that fails
+    /// verification is reported as the only issue.
+    Malformed(PatternError),
+    /// The hold step at `offset` has a length of zero frames.
+    ZeroFrames { offset: usize },
+    /// The loop step at `offset` does not jump backwards, or its body contains another loop.
+    InvalidLoop { offset: usize },
+    /// The loop step at `offset` repeats forever, so the pattern never finishes on its own.
+    ///
+    /// This is intended for patterns that are stopped by the game, such as an engine's hum, but is
+    /// otherwise a mistake.
+    EndlessLoop { offset: usize },
+    /// The motor is turned on for only `frames` frames, starting at the hold step at `offset`,
+    /// which is shorter than [`ValidationRules::spin_up_frames`].
+    ShortPulse { offset: usize, frames: u32 },
+    /// The motor is on for `duty` of the time, which exceeds [`ValidationRules::duty_budget`].
+    OverBudget { duty: Fixed },
+}
+
+/// What [`Pattern::validate()`] checks patterns against, beyond their encoding.
+///
+/// Checks against a rule are skipped when the rule is unset, which is the default.
+///
+/// ``` rust
+/// use gba_rumble::{Backend, DutyBudget, Fixed, Gpio, ValidationRules};
+///
+/// let rules = ValidationRules {
+///     duty_budget: Some(DutyBudget {
+///         max_duty: Fixed::from_ratio(3, 4),
+///         window_seconds: 30,
+///     }),
+///     spin_up_frames: Gpio.spin_up_frames(),
+/// };
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ValidationRules {
+    /// The budget the motor's duty must stay within, as enforced by a [`Driver`].
+    ///
+    /// A pattern shorter than the budget's window is assumed to be followed by silence. A pattern
+    /// that loops forever is judged by its loop alone, which is what it settles into.
+    ///
+    /// [`Driver`]: crate::Driver
+    pub duty_budget: Option<DutyBudget>,
+    /// The fewest frames the motor must be on at a time to be felt, such as a backend's
+    /// [`Backend::spin_up_frames()`]. A value of `0` skips this check.
+    ///
+    /// Pulses are only judged where they begin and end on a hold step, or at the ends of the
+    /// pattern, since the length of a pulse running into a marker or loop depends on the game.
+    ///
+    /// [`Backend::spin_up_frames()`]: crate::Backend::spin_up_frames()
+    pub spin_up_frames: u8,
+}
+
+impl ValidationRules {
+    /// Creates rules that only check a pattern's encoding.
+    pub const fn new() -> Self {
+        Self {
+            duty_budget: None,
+            spin_up_frames: 0,
+        }
+    }
+}
+
+/// An iterator over the [`PatternIssue`]s found in a pattern, in the order they occur.
+///
+/// This `struct` is created by [`Pattern::validate()`].
+#[derive(Clone, Debug)]
+pub struct Issues<'a> {
+    bytes: &'a [u8],
+    rules: ValidationRules,
+    offset: usize,
+    index: usize,
+    /// Loop bodies must begin at or after this step to prevent nesting.
+    loop_start: usize,
+    /// The offset of the first step of the current pulse, and its length so far.
+    pulse: Option<(usize, u32)>,
+    /// Frames the motor is on, and frames in total, over a single playback.
+    on_frames: u32,
+    total_frames: u32,
+    /// Frames the motor is on, and frames in total, during a single pass of an endless loop.
+    endless: Option<(u32, u32)>,
+    /// An issue found alongside the one most recently returned.
+    pending: Option<PatternIssue>,
+    finished: bool,
+}
+
+impl<'a> Issues<'a> {
+    pub(super) const fn new(bytes: &'a [u8], rules: ValidationRules) -> Self {
+        let (bytes, pending) = match strip_header(bytes) {
+            Ok(steps) => (steps, None),
+            Err(error) => (bytes, Some(PatternIssue::Malformed(error))),
+        };
+        Self {
+            bytes,
+            rules,
+            offset: 0,
+            index: 0,
+            loop_start: 0,
+            pulse: None,
+            on_frames: 0,
+            total_frames: 0,
+            endless: None,
+            finished: pending.is_some(),
+            pending,
+        }
+    }
+
+    /// Ends the current pulse, returning an issue if it was too short to be felt.
+    fn end_pulse(&mut self) -> Option<PatternIssue> {
+        let (offset, frames) = self.pulse.take()?;
+        (frames < self.rules.spin_up_frames as u32)
+            .then_some(PatternIssue::ShortPulse { offset, frames })
+    }
+
+    /// Returns the frames the motor is on, and frames in total, from the step at `index` up to the
+    /// current step.
+    fn frames_since(&self, index: usize) -> (u32, u32) {
+        let mut on_frames = 0;
+        let mut total_frames = 0;
+        let mut offset = 0;
+        for current in 0..self.index {
+            let Ok((step, len)) = decode(self.bytes, offset) else {
+                break;
+            };
+            if current >= index
+                && let Step::Hold { intensity, frames } = step
+            {
+                total_frames += frames as u32;
+                if !intensity.is_off() {
+                    on_frames += frames as u32;
+                }
+            }
+            offset += len;
+        }
+        (on_frames, total_frames)
+    }
+
+    /// Checks the step at the current offset, returning the issues it causes.
+    fn check_step(&mut self, step: Step) -> (Option<PatternIssue>, Option<PatternIssue>) {
+        let offset = self.offset;
+        match step {
+            Step::Hold { intensity, frames } => {
+                let zero_frames = (frames == 0).then_some(PatternIssue::ZeroFrames { offset });
+                self.total_frames = self.total_frames.saturating_add(frames as u32);
+                if intensity.is_off() {
+                    (zero_frames, self.end_pulse())
+                } else {
+                    self.on_frames = self.on_frames.saturating_add(frames as u32);
+                    let (start, length) = self.pulse.unwrap_or((offset, 0));
+                    self.pulse = Some((start, length + frames as u32));
+                    (zero_frames, None)
+                }
+            }
+            // The length of a pulse running into a marker depends on the game.
+            Step::Marker(_) => {
+                self.pulse = None;
+                (None, None)
+            }
+            Step::Loop { step, count } => {
+                self.pulse = None;
+                if (step as usize) < self.loop_start || step as usize >= self.index {
+                    return (Some(PatternIssue::InvalidLoop { offset }), None);
+                }
+                self.loop_start = self.index + 1;
+                let (on_frames, total_frames) = self.frames_since(step as usize);
+                if count == 0 {
+                    self.endless.get_or_insert((on_frames, total_frames));
+                    (Some(PatternIssue::EndlessLoop { offset }), None)
+                } else {
+                    let repeats = count as u32;
+                    self.on_frames = self
+                        .on_frames
+                        .saturating_add(on_frames.saturating_mul(repeats));
+                    self.total_frames = self
+                        .total_frames
+                        .saturating_add(total_frames.saturating_mul(repeats));
+                    (None, None)
+                }
+            }
+        }
+    }
+
+    /// Checks the pattern as a whole against the duty budget.
+    fn check_duty(&self) -> Option<PatternIssue> {
+        let budget = self.rules.duty_budget?;
+        let duty = match self.endless {
+            Some((on_frames, total_frames)) => ratio(on_frames, total_frames),
+            None => {
+                let window = budget.window_seconds as u32 * FRAMES_PER_SECOND;
+                ratio(self.on_frames, self.total_frames.max(window))
+            }
+        };
+        (duty > budget.max_duty).then_some(PatternIssue::OverBudget { duty })
+    }
+}
+
+impl Iterator for Issues<'_> {
+    type Item = PatternIssue;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(issue) = self.pending.take() {
+            return Some(issue);
+        }
+        while !self.finished {
+            if self.offset >= self.bytes.len() {
+                self.finished = true;
+                let short_pulse = self.end_pulse();
+                self.pending = self.check_duty();
+                return short_pulse.or_else(|| self.pending.take());
+            }
+            let (step, len) = match decode(self.bytes, self.offset) {
+                Ok(decoded) => decoded,
+                Err(error) => {
+                    // Nothing past an undecodable step can be checked.
+                    self.finished = true;
+                    return Some(PatternIssue::Malformed(error));
+                }
+            };
+            let (first, second) = self.check_step(step);
+            self.offset += len;
+            self.index += 1;
+            match (first, second) {
+                (Some(first), second) => {
+                    self.pending = second;
+                    return Some(first);
+                }
+                (None, Some(second)) => return Some(second),
+                (None, None) => {}
+            }
+        }
+        None
+    }
+}
+
+/// Returns `numerator / denominator`, reducing both to fit [`Fixed::from_ratio()`].
+///
+/// Returns [`Fixed::ZERO`] if `denominator` is `0`, since no time passes.
+fn ratio(mut numerator: u32, mut denominator: u32) -> Fixed {
+    if denominator == 0 {
+        return Fixed::ZERO;
+    }
+    while denominator > u16::MAX as u32 {
+        numerator >>= 1;
+        denominator >>= 1;
+    }
+    Fixed::from_ratio(numerator as u16, denominator as u16)
+}
+
+impl<'a> Pattern<'a> {
+    /// Checks encoded pattern bytes for problems, returning an iterator over every issue found.
+    ///
+    /// Unlike [`from_bytes()`], which stops at the first encoding error, this reports every
+    /// problem it can find, including patterns that decode fine but are unlikely to feel as
+    /// intended under `rules`. This allows an asset pipeline to reject bad haptic data at build
+    /// time, rather than discovering it on hardware. Bytes with no issues at all can be passed to
+    /// [`from_bytes()`] without error.
+    ///
+    /// ``` rust
+    /// use gba_rumble::{Intensity, Pattern, PatternIssue, Step, ValidationRules};
+    ///
+    /// const STEPS: &[Step] = &[
+    ///     Step::Hold { intensity: Intensity::MAX, frames: 1 },
+    ///     Step::Hold { intensity: Intensity::OFF, frames: 10 },
+    /// ];
+    /// let bytes: [u8; Pattern::encoded_len(STEPS)] = Pattern::encode(STEPS);
+    ///
+    /// let mut issues = Pattern::validate(&bytes, ValidationRules {
+    ///     spin_up_frames: 3,
+    ///     ..ValidationRules::new()
+    /// });
+    /// assert_eq!(issues.next(), Some(PatternIssue::ShortPulse { offset: 0, frames: 1 }));
+    /// assert_eq!(issues.next(), None);
+    /// ```
+    ///
+    /// [`from_bytes()`]: Pattern::from_bytes()
+    pub const fn validate(bytes: &'a [u8], rules: ValidationRules) -> Issues<'a> {
+        Issues::new(bytes, rules)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PatternIssue, ValidationRules};
+    use crate::{DutyBudget, Fixed, Intensity, Marker, Pattern, PatternError, Step};
+    use alloc::vec::Vec;
+    use claims::assert_ok;
+    use gba_test::test;
+
+    const fn hold(intensity: u8, frames: u8) -> Step {
+        Step::Hold {
+            intensity: Intensity::new(intensity),
+            frames,
+        }
+    }
+
+    fn validate(bytes: &[u8], rules: ValidationRules) -> Vec<PatternIssue> {
+        Pattern::validate(bytes, rules).collect()
+    }
+
+    fn encode(steps: &[Step]) -> Vec<u8> {
+        steps
+            .iter()
+            .flat_map(|step| step.encode().into_iter().take(step.encoded_len()))
+            .collect()
+    }
+
+    const SPIN_UP: ValidationRules = ValidationRules {
+        duty_budget: None,
+        spin_up_frames: 3,
+    };
+
+    const BUDGET: ValidationRules = ValidationRules {
+        duty_budget: Some(DutyBudget {
+            max_duty: Fixed::HALF,
+            window_seconds: 1,
+        }),
+        spin_up_frames: 0,
+    };
+
+    #[test]
+    fn empty() {
+        assert_eq!(validate(&[], SPIN_UP), []);
+    }
+
+    #[test]
+    fn no_issues() {
+        let bytes = encode(&[hold(255, 4), hold(0, 4), Step::Loop { step: 0, count: 2 }]);
+
+        assert_eq!(validate(&bytes, SPIN_UP), []);
+    }
+
+    #[test]
+    fn header() {
+        let steps = encode(&[hold(255, 1), hold(0, 10)]);
+        let mut bytes = Pattern::header(&steps).to_vec();
+        bytes.extend_from_slice(&steps);
+
+        assert_eq!(
+            validate(&bytes, SPIN_UP),
+            [PatternIssue::ShortPulse {
+                offset: 0,
+                frames: 1
+            }]
+        );
+        bytes[Pattern::HEADER_LEN] = 0x04;
+        assert_eq!(
+            validate(&bytes, SPIN_UP),
+            [PatternIssue::Malformed(PatternError::ChecksumMismatch)]
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            validate(&[0x01, 0xFF, 0x10, 0x07, 0x01], ValidationRules::new()),
+            [PatternIssue::Malformed(PatternError::UnknownOpcode {
+                offset: 3
+            })]
+        );
+        assert_eq!(
+            validate(&[0x01, 0xFF], ValidationRules::new()),
+            [PatternIssue::Malformed(PatternError::Truncated)]
+        );
+    }
+
+    #[test]
+    fn zero_frames() {
+        let bytes = encode(&[hold(255, 4), hold(0, 0), hold(0, 0)]);
+
+        assert_eq!(
+            validate(&bytes, ValidationRules::new()),
+            [
+                PatternIssue::ZeroFrames { offset: 3 },
+                PatternIssue::ZeroFrames { offset: 6 }
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_loop() {
+        let bytes = encode(&[
+            hold(255, 4),
+            Step::Loop { step: 1, count: 1 },
+            Step::Loop { step: 0, count: 1 },
+            Step::Loop { step: 0, count: 1 },
+        ]);
+
+        assert_eq!(
+            validate(&bytes, ValidationRules::new()),
+            [
+                PatternIssue::InvalidLoop { offset: 3 },
+                PatternIssue::InvalidLoop { offset: 9 }
+            ]
+        );
+    }
+
+    #[test]
+    fn endless_loop() {
+        let bytes = encode(&[hold(255, 4), Step::Loop { step: 0, count: 0 }]);
+
+        assert_eq!(
+            validate(&bytes, ValidationRules::new()),
+            [PatternIssue::EndlessLoop { offset: 3 }]
+        );
+    }
+
+    #[test]
+    fn short_pulse() {
+        let bytes = encode(&[hold(255, 1), hold(128, 1), hold(0, 4), hold(255, 2)]);
+
+        assert_eq!(
+            validate(&bytes, SPIN_UP),
+            [
+                PatternIssue::ShortPulse {
+                    offset: 0,
+                    frames: 2
+                },
+                PatternIssue::ShortPulse {
+                    offset: 9,
+                    frames: 2
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn short_pulse_with_zero_frames() {
+        let bytes = encode(&[hold(255, 1), hold(0, 0)]);
+
+        assert_eq!(
+            validate(&bytes, SPIN_UP),
+            [
+                PatternIssue::ZeroFrames { offset: 3 },
+                PatternIssue::ShortPulse {
+                    offset: 0,
+                    frames: 1
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn short_pulse_into_marker_ignored() {
+        let bytes = encode(&[
+            hold(255, 1),
+            Step::Marker(Marker::new(0)),
+            hold(255, 1),
+            Step::Loop { step: 2, count: 3 },
+        ]);
+
+        assert_eq!(validate(&bytes, SPIN_UP), []);
+    }
+
+    #[test]
+    fn short_pulse_unchecked_without_rule() {
+        let bytes = encode(&[hold(255, 1)]);
+
+        assert_eq!(validate(&bytes, ValidationRules::new()), []);
+    }
+
+    #[test]
+    fn within_budget() {
+        // On for 30 of the 60 frames in the window.
+        let bytes = encode(&[hold(255, 10), hold(0, 10), Step::Loop { step: 0, count: 2 }]);
+
+        assert_eq!(validate(&bytes, BUDGET), []);
+    }
+
+    #[test]
+    fn over_budget() {
+        let bytes = encode(&[hold(255, 40)]);
+
+        assert_eq!(
+            validate(&bytes, BUDGET),
+            [PatternIssue::OverBudget {
+                duty: Fixed::from_ratio(40, 60)
+            }]
+        );
+    }
+
+    #[test]
+    fn over_budget_endless_loop() {
+        let bytes = encode(&[
+            hold(0, 60),
+            hold(255, 3),
+            hold(0, 1),
+            Step::Loop { step: 1, count: 0 },
+        ]);
+
+        assert_eq!(
+            validate(&bytes, BUDGET),
+            [
+                PatternIssue::EndlessLoop { offset: 9 },
+                PatternIssue::OverBudget {
+                    duty: Fixed::from_ratio(3, 4)
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn valid_patterns_decode() {
+        let bytes = encode(&[hold(255, 4), hold(0, 4), Step::Loop { step: 0, count: 2 }]);
+
+        assert_eq!(validate(&bytes, ValidationRules::new()), []);
+        assert_ok!(Pattern::from_bytes(&bytes));
+    }
+}
@@ -0,0 +1,128 @@
+//! Construction of patterns at runtime.
+
+use crate::{Pattern, PatternError, Step};
+use alloc::vec::Vec;
+
+/// Builds a [`Pattern`] one [`Step`] at a time at runtime.
+///
+/// This is useful for patterns that can't be authored ahead of time, such as rumble generated
+/// from physics events. Patterns known ahead of time should be written using the [`pattern!`]
+/// macro instead, which does not allocate.
+///
+/// ``` rust
+/// use gba_rumble::{Intensity, PatternBuilder, Sequencer, Step};
+///
+/// // Rumble harder the faster the impact.
+/// let speed = 12;
+/// let mut builder = PatternBuilder::new();
+/// for frame in 0..speed {
+///     builder = builder.step(Step::Hold {
+///         intensity: Intensity::new(255 - frame * 16),
+///         frames: 1,
+///     });
+/// }
+/// let pattern = builder.build().unwrap();
+///
+/// let mut sequencer = Sequencer::new(pattern.as_pattern());
+/// ```
+///
+/// This type is only available with the `alloc` feature enabled.
+///
+/// [`pattern!`]: crate::pattern!
+#[derive(Clone, Debug, Default)]
+pub struct PatternBuilder {
+    bytes: Vec<u8>,
+}
+
+impl PatternBuilder {
+    /// Creates a builder with no steps.
+    pub const fn new() -> Self {
+        Self { bytes: Vec::new() }
+    }
+
+    /// Appends `step` to the pattern.
+    pub fn step(mut self, step: Step) -> Self {
+        self.bytes
+            .extend_from_slice(&step.encode()[..step.encoded_len()]);
+        self
+    }
+
+    /// Validates the steps, returning the finished pattern.
+    ///
+    /// This fails for the same reasons as [`Pattern::from_bytes()`], such as a hold step with a
+    /// length of zero frames.
+    pub fn build(self) -> Result<PatternBuf, PatternError> {
+        Pattern::from_bytes(&self.bytes)?;
+        Ok(PatternBuf { bytes: self.bytes })
+    }
+}
+
+/// An owned [`Pattern`], created by a [`PatternBuilder`].
+///
+/// This type is only available with the `alloc` feature enabled.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PatternBuf {
+    bytes: Vec<u8>,
+}
+
+impl PatternBuf {
+    /// Returns the pattern, borrowed from this buffer.
+    pub fn as_pattern(&self) -> Pattern<'_> {
+        // The bytes were validated when the pattern was built.
+        Pattern::from_bytes_unchecked(&self.bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PatternBuilder;
+    use crate::{Intensity, Marker, PatternError, Step, pattern};
+    use claims::{assert_err_eq, assert_ok};
+    use gba_test::test;
+
+    #[test]
+    fn empty() {
+        let pattern = assert_ok!(PatternBuilder::new().build());
+
+        assert_eq!(pattern.as_pattern().as_bytes(), &[]);
+    }
+
+    #[test]
+    fn matches_macro() {
+        let pattern = assert_ok!(
+            PatternBuilder::new()
+                .step(Step::Hold {
+                    intensity: Intensity::MAX,
+                    frames: 2,
+                })
+                .step(Step::Marker(Marker::new(3)))
+                .step(Step::Loop { step: 0, count: 1 })
+                .build()
+        );
+
+        assert_eq!(
+            pattern.as_pattern(),
+            pattern![
+                Step::Hold {
+                    intensity: Intensity::MAX,
+                    frames: 2
+                },
+                Step::Marker(Marker::new(3)),
+                Step::Loop { step: 0, count: 1 },
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_err_eq!(
+            PatternBuilder::new()
+                .step(Step::Hold {
+                    intensity: Intensity::MAX,
+                    frames: 0,
+                })
+                .build(),
+            PatternError::ZeroFrames { offset: 0 }
+        );
+    }
+}
@@ -0,0 +1,174 @@
+//! Global limits on motor usage, for saving battery.
+
+use crate::Intensity;
+
+static mut POWER_SAVING: Option<PowerSaving> = None;
+
+/// Limits on how hard and how long the motor is driven, to save battery.
+///
+/// Rumble motors draw a lot of power, which players on front-lit or older consoles may notice as
+/// shorter battery life. A power saving policy, set globally with [`set_power_saving()`], caps the
+/// duty cycle of the motor and cuts off effects that keep the motor running for too long.
+///
+/// The policy is applied by every [`Driver`] and [`Device`] after the player's [`Settings`] and
+/// the backend's [`ResponseCurve`], so it coexists with accessibility options like
+/// [`Settings::intensity_scale`]: the scale is applied first, and the result is then capped.
+///
+/// ``` rust
+/// use gba_rumble::{Intensity, PowerSaving};
+///
+/// // Never drive the motor above half strength, or for more than two seconds at a time.
+/// gba_rumble::set_power_saving(Some(PowerSaving {
+///     max_duty: Intensity::new(128),
+///     max_frames: 120,
+/// }));
+/// ```
+///
+/// [`Device`]: crate::Device
+/// [`Driver`]: crate::Driver
+/// [`ResponseCurve`]: crate::ResponseCurve
+/// [`Settings`]: crate::Settings
+/// [`Settings::intensity_scale`]: crate::Settings::intensity_scale
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct PowerSaving {
+    /// The highest duty cycle the motor is driven at.
+    ///
+    /// Backends that can only turn their motor on or off are unaffected by this, unless it is
+    /// [`Intensity::OFF`].
+    pub max_duty: Intensity,
+    /// The most consecutive frames the motor is kept on.
+    ///
+    /// Once the motor has been on for this many frames, it is stopped until the requested
+    /// intensity returns to [`Intensity::OFF`], such as when the effect ends. A value of `0` places
+    /// no limit on how long the motor stays on.
+    pub max_frames: u16,
+}
+
+impl PowerSaving {
+    /// Creates a policy that places no limits on the motor.
+    pub const fn new() -> Self {
+        Self {
+            max_duty: Intensity::MAX,
+            max_frames: 0,
+        }
+    }
+
+    /// Limits `duty` for the current frame, given the number of frames the motor has been on
+    /// continuously, which is updated.
+    pub(crate) fn limit(self, duty: Intensity, on_frames: &mut u16) -> Intensity {
+        if duty.is_off() {
+            *on_frames = 0;
+            return Intensity::OFF;
+        }
+        if self.max_frames > 0 && *on_frames >= self.max_frames {
+            return Intensity::OFF;
+        }
+        *on_frames = on_frames.saturating_add(1);
+        duty.min(self.max_duty)
+    }
+}
+
+impl Default for PowerSaving {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Sets the power saving policy applied to every [`Driver`] and [`Device`].
+///
+/// Passing `None` removes the policy, which is the default. The new policy takes effect on the
+/// next frame.
+///
+/// [`Device`]: crate::Device
+/// [`Driver`]: crate::Driver
+pub fn set_power_saving(policy: Option<PowerSaving>) {
+    unsafe {
+        POWER_SAVING = policy;
+    }
+}
+
+/// Returns the power saving policy set by [`set_power_saving()`].
+pub fn power_saving() -> Option<PowerSaving> {
+    unsafe { POWER_SAVING }
+}
+
+/// Applies the current power saving policy to `duty`, tracking continuous on-time in `on_frames`.
+pub(crate) fn limit(duty: Intensity, on_frames: &mut u16) -> Intensity {
+    match power_saving() {
+        Some(policy) => policy.limit(duty, on_frames),
+        None => {
+            *on_frames = 0;
+            duty
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PowerSaving, power_saving, set_power_saving};
+    use crate::Intensity;
+    use claims::{assert_none, assert_some_eq};
+    use gba_test::test;
+
+    #[test]
+    fn no_limits() {
+        let mut on_frames = 0;
+
+        for _ in 0..1000 {
+            assert_eq!(
+                PowerSaving::new().limit(Intensity::MAX, &mut on_frames),
+                Intensity::MAX
+            );
+        }
+    }
+
+    #[test]
+    fn max_duty() {
+        let policy = PowerSaving {
+            max_duty: Intensity::new(100),
+            max_frames: 0,
+        };
+        let mut on_frames = 0;
+
+        assert_eq!(
+            policy.limit(Intensity::MAX, &mut on_frames),
+            Intensity::new(100)
+        );
+        assert_eq!(
+            policy.limit(Intensity::new(50), &mut on_frames),
+            Intensity::new(50)
+        );
+    }
+
+    #[test]
+    fn max_frames() {
+        let policy = PowerSaving {
+            max_duty: Intensity::MAX,
+            max_frames: 2,
+        };
+        let mut on_frames = 0;
+
+        assert_eq!(policy.limit(Intensity::MAX, &mut on_frames), Intensity::MAX);
+        assert_eq!(policy.limit(Intensity::MAX, &mut on_frames), Intensity::MAX);
+        assert_eq!(policy.limit(Intensity::MAX, &mut on_frames), Intensity::OFF);
+        assert_eq!(policy.limit(Intensity::MAX, &mut on_frames), Intensity::OFF);
+
+        // The limit resets once the motor is no longer requested.
+        assert_eq!(policy.limit(Intensity::OFF, &mut on_frames), Intensity::OFF);
+        assert_eq!(policy.limit(Intensity::MAX, &mut on_frames), Intensity::MAX);
+    }
+
+    #[test]
+    fn set_power_saving_round_trip() {
+        let policy = PowerSaving {
+            max_duty: Intensity::new(128),
+            max_frames: 60,
+        };
+
+        set_power_saving(Some(policy));
+        assert_some_eq!(power_saving(), policy);
+
+        set_power_saving(None);
+        assert_none!(power_saving());
+    }
+}
@@ -0,0 +1,103 @@
+//! Patterns for common interface feedback.
+//!
+//! Players learn what rumble means from the games they play, and expect the same interaction to
+//! feel the same from one game to the next. These patterns follow the conventions most console
+//! games share, so that interface code can use them rather than each menu inventing its own
+//! slightly different feedback:
+//!
+//! - Feedback for frequent actions, such as moving a cursor, is short enough that it can repeat
+//!   many times a second without blurring together.
+//! - Feedback for deliberate actions, such as confirming a choice, is a single firm pulse.
+//! - Problems are reported with two pulses in quick succession, which is recognizable without
+//!   looking at the screen.
+//! - Notifications are softer and spaced further apart, so that they are noticed without being
+//!   mistaken for an error.
+//!
+//! Durations are given in frames, at roughly 60 frames a second. Very short pulses rely on the
+//! motor spinning up quickly, so on cartridges with slow motors, pair them with
+//! [`Haptics::set_spin_up_compensation()`].
+//!
+//! ``` rust
+//! use gba_rumble::{EffectId, Haptics, Pattern, presets};
+//!
+//! const EFFECTS: [Pattern; 2] = [presets::MENU_TICK, presets::CONFIRM];
+//! const CONFIRM: EffectId = EffectId::new(1);
+//!
+//! let mut haptics = Haptics::detect(&EFFECTS);
+//! // When the player confirms a menu choice:
+//! haptics.play(CONFIRM);
+//! ```
+//!
+//! [`Haptics::set_spin_up_compensation()`]: crate::Haptics::set_spin_up_compensation()
+
+use crate::{Intensity, Pattern, Step, pattern};
+
+/// A barely noticeable tap for moving between menu items, lasting 2 frames.
+pub const MENU_TICK: Pattern<'static> = pattern![Step::Hold {
+    intensity: Intensity::new(192),
+    frames: 2,
+}];
+
+/// A single firm pulse for confirming a choice, lasting 5 frames.
+pub const CONFIRM: Pattern<'static> = pattern![Step::Hold {
+    intensity: Intensity::MAX,
+    frames: 5,
+}];
+
+/// Two strong pulses in quick succession for an invalid action, lasting 17 frames.
+pub const ERROR: Pattern<'static> = pattern![
+    Step::Hold {
+        intensity: Intensity::MAX,
+        frames: 6,
+    },
+    Step::Hold {
+        intensity: Intensity::OFF,
+        frames: 5,
+    },
+    Step::Hold {
+        intensity: Intensity::MAX,
+        frames: 6,
+    },
+];
+
+/// Two gentle, spaced pulses for drawing attention to something new, lasting 26 frames.
+pub const NOTIFICATION: Pattern<'static> = pattern![
+    Step::Hold {
+        intensity: Intensity::new(160),
+        frames: 8,
+    },
+    Step::Hold {
+        intensity: Intensity::OFF,
+        frames: 10,
+    },
+    Step::Hold {
+        intensity: Intensity::new(160),
+        frames: 8,
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{CONFIRM, ERROR, MENU_TICK, NOTIFICATION};
+    use crate::{Pattern, Step};
+    use gba_test::test;
+
+    /// Returns the number of frames `pattern` plays for.
+    fn frames(pattern: Pattern) -> u32 {
+        pattern
+            .steps()
+            .map(|step| match step {
+                Step::Hold { frames, .. } => u32::from(frames),
+                _ => 0,
+            })
+            .sum()
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(frames(MENU_TICK), 2);
+        assert_eq!(frames(CONFIRM), 5);
+        assert_eq!(frames(ERROR), 17);
+        assert_eq!(frames(NOTIFICATION), 26);
+    }
+}
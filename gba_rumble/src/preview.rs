@@ -0,0 +1,287 @@
+//! Inspecting patterns at reduced speed.
+
+use crate::{Intensity, Pattern, Sequencer};
+
+/// Plays a [`Pattern`] for inspection, at reduced speed or one frame at a time.
+///
+/// This is meant for debug menus, where an effect's envelope can be felt on hardware slowly
+/// enough to judge its shape. While running, the pattern advances one frame every
+/// [`slowdown()`] calls to [`tick()`]. While paused, the current frame is held indefinitely, and
+/// can be moved with [`step()`], [`step_back()`], and [`seek()`], such as in response to button
+/// presses.
+///
+/// Markers are passed through as soon as they are reached, so that the whole pattern can be
+/// inspected without advancing them by hand.
+///
+/// ``` rust
+/// use gba_rumble::{Intensity, Pattern, Preview, Step, pattern};
+///
+/// const CHARGE: Pattern = pattern![
+///     Step::Hold { intensity: Intensity::new(64), frames: 30 },
+///     Step::Hold { intensity: Intensity::MAX, frames: 15 },
+/// ];
+///
+/// let mut preview = Preview::new(CHARGE);
+/// // Play at a quarter of normal speed.
+/// preview.set_slowdown(4);
+///
+/// // When the debug menu's pause button is pressed:
+/// preview.pause();
+/// // When the right button is pressed:
+/// preview.step();
+///
+/// // Once a frame, get the intensity to rumble at.
+/// let intensity = preview.tick();
+/// ```
+///
+/// [`seek()`]: Preview::seek()
+/// [`slowdown()`]: Preview::slowdown()
+/// [`step()`]: Preview::step()
+/// [`step_back()`]: Preview::step_back()
+/// [`tick()`]: Preview::tick()
+#[derive(Clone, Debug)]
+pub struct Preview<'a> {
+    sequencer: Sequencer<'a>,
+    /// The number of frames of the pattern played so far.
+    frame: u32,
+    slowdown: u8,
+    /// Ticks since the pattern last advanced.
+    wait: u8,
+    running: bool,
+}
+
+impl<'a> Preview<'a> {
+    /// Creates a preview of `pattern`, running at normal speed from its beginning.
+    pub const fn new(pattern: Pattern<'a>) -> Self {
+        Self {
+            sequencer: Sequencer::new(pattern),
+            frame: 0,
+            slowdown: 1,
+            wait: 0,
+            running: true,
+        }
+    }
+
+    /// Returns the pattern being previewed.
+    pub const fn pattern(&self) -> Pattern<'a> {
+        self.sequencer.pattern()
+    }
+
+    /// Called once a frame, returning the intensity to rumble at.
+    ///
+    /// While running, this advances the pattern once every [`slowdown()`] calls. Otherwise, the
+    /// intensity of the current frame is returned again.
+    ///
+    /// [`slowdown()`]: Preview::slowdown()
+    pub fn tick(&mut self) -> Intensity {
+        if self.running {
+            self.wait += 1;
+            if self.wait >= self.slowdown {
+                self.wait = 0;
+                self.advance();
+            }
+        }
+        self.sequencer.output()
+    }
+
+    /// Returns the number of calls to [`tick()`] each frame of the pattern is held for while
+    /// running.
+    ///
+    /// [`tick()`]: Preview::tick()
+    pub const fn slowdown(&self) -> u8 {
+        self.slowdown
+    }
+
+    /// Holds each frame of the pattern for `slowdown` calls to [`tick()`] while running.
+    ///
+    /// A value of `1` plays at normal speed, `2` at half speed, and so on. A value of `0` is
+    /// treated as `1`.
+    ///
+    /// [`tick()`]: Preview::tick()
+    pub const fn set_slowdown(&mut self, slowdown: u8) {
+        self.slowdown = if slowdown == 0 { 1 } else { slowdown };
+        self.wait = 0;
+    }
+
+    /// Stops the pattern from advancing, holding the current frame.
+    pub const fn pause(&mut self) {
+        self.running = false;
+    }
+
+    /// Continues advancing the pattern after [`pause()`].
+    ///
+    /// [`pause()`]: Preview::pause()
+    pub const fn resume(&mut self) {
+        self.running = true;
+        self.wait = 0;
+    }
+
+    /// Returns whether the pattern is advancing on its own.
+    pub const fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// Advances the pattern by a single frame, returning that frame's intensity.
+    pub fn step(&mut self) -> Intensity {
+        self.advance();
+        self.sequencer.output()
+    }
+
+    /// Moves the pattern back by a single frame, returning that frame's intensity.
+    ///
+    /// Since patterns can only be played forwards, this replays the pattern from its beginning, so
+    /// its cost grows with [`frame()`].
+    ///
+    /// [`frame()`]: Preview::frame()
+    pub fn step_back(&mut self) -> Intensity {
+        self.seek(self.frame.saturating_sub(1))
+    }
+
+    /// Moves to `frame`, returning that frame's intensity.
+    ///
+    /// Frame `0` is before the pattern begins, where nothing is felt, and frame `1` is the
+    /// pattern's first frame. Seeking past the end of the pattern stops at its end. As with
+    /// [`step_back()`], this replays the pattern from its beginning.
+    ///
+    /// [`step_back()`]: Preview::step_back()
+    pub fn seek(&mut self, frame: u32) -> Intensity {
+        self.sequencer = Sequencer::new(self.sequencer.pattern());
+        self.frame = 0;
+        self.wait = 0;
+        while self.frame < frame && !self.sequencer.is_finished() {
+            self.advance();
+        }
+        self.sequencer.output()
+    }
+
+    /// Returns to the beginning of the pattern.
+    pub fn restart(&mut self) {
+        self.seek(0);
+    }
+
+    /// Returns the number of frames of the pattern played so far.
+    pub const fn frame(&self) -> u32 {
+        self.frame
+    }
+
+    /// Returns whether every frame of the pattern has been played.
+    pub fn is_finished(&self) -> bool {
+        self.sequencer.is_finished()
+    }
+
+    /// Plays the next frame of the pattern, if it has not finished.
+    fn advance(&mut self) {
+        if self.sequencer.is_finished() {
+            return;
+        }
+        self.sequencer.tick();
+        if self.sequencer.is_finished() {
+            return;
+        }
+        self.frame += 1;
+        if let Some(marker) = self.sequencer.paused_at() {
+            self.sequencer.advance(marker);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Preview;
+    use crate::{Intensity, Marker, Pattern, Step, pattern};
+    use gba_test::test;
+
+    const PATTERN: Pattern = pattern![
+        Step::Hold {
+            intensity: Intensity::new(1),
+            frames: 2
+        },
+        Step::Marker(Marker::new(0)),
+        Step::Hold {
+            intensity: Intensity::new(2),
+            frames: 1
+        },
+    ];
+
+    #[test]
+    fn normal_speed() {
+        let mut preview = Preview::new(PATTERN);
+
+        assert_eq!(preview.tick(), Intensity::new(1));
+        assert_eq!(preview.tick(), Intensity::new(1));
+        // The marker is passed through.
+        assert_eq!(preview.tick(), Intensity::new(1));
+        assert_eq!(preview.tick(), Intensity::new(2));
+        assert_eq!(preview.tick(), Intensity::OFF);
+        assert!(preview.is_finished());
+        assert_eq!(preview.frame(), 4);
+    }
+
+    #[test]
+    fn slowdown() {
+        let mut preview = Preview::new(PATTERN);
+        preview.set_slowdown(2);
+
+        assert_eq!(preview.tick(), Intensity::OFF);
+        assert_eq!(preview.tick(), Intensity::new(1));
+        assert_eq!(preview.tick(), Intensity::new(1));
+        assert_eq!(preview.frame(), 1);
+    }
+
+    #[test]
+    fn slowdown_zero() {
+        let mut preview = Preview::new(PATTERN);
+        preview.set_slowdown(0);
+
+        assert_eq!(preview.slowdown(), 1);
+    }
+
+    #[test]
+    fn paused_holds_frame() {
+        let mut preview = Preview::new(PATTERN);
+        preview.tick();
+        preview.pause();
+
+        assert_eq!(preview.tick(), Intensity::new(1));
+        assert_eq!(preview.tick(), Intensity::new(1));
+        assert_eq!(preview.frame(), 1);
+    }
+
+    #[test]
+    fn step() {
+        let mut preview = Preview::new(PATTERN);
+        preview.pause();
+
+        assert_eq!(preview.step(), Intensity::new(1));
+        assert_eq!(preview.step(), Intensity::new(1));
+        assert_eq!(preview.step(), Intensity::new(1));
+        assert_eq!(preview.step(), Intensity::new(2));
+        assert_eq!(preview.frame(), 4);
+    }
+
+    #[test]
+    fn step_back() {
+        let mut preview = Preview::new(PATTERN);
+        assert_eq!(preview.seek(4), Intensity::new(2));
+
+        assert_eq!(preview.step_back(), Intensity::new(1));
+        assert_eq!(preview.frame(), 3);
+    }
+
+    #[test]
+    fn step_back_at_start() {
+        let mut preview = Preview::new(PATTERN);
+
+        assert_eq!(preview.step_back(), Intensity::OFF);
+        assert_eq!(preview.frame(), 0);
+    }
+
+    #[test]
+    fn seek_past_end() {
+        let mut preview = Preview::new(PATTERN);
+
+        assert_eq!(preview.seek(100), Intensity::OFF);
+        assert!(preview.is_finished());
+        assert_eq!(preview.frame(), 4);
+    }
+}
@@ -0,0 +1,41 @@
+//! The serial protocol used to control rumble through the Game Boy Player.
+//!
+//! Communication takes place over the serial port in 32-bit normal mode, with the Game Boy Player
+//! providing the clock. Each transfer exchanges one word in each direction, and the word the GBA
+//! sends is the one it prepared in response to the previous transfer. The Game Boy Player
+//! initiates every exchange, typically once a frame.
+//!
+//! A session proceeds in three stages:
+//!
+//! 1. **Handshake.** The Game Boy Player sends each of the [`HANDSHAKE_KEYS`], which spell out
+//!    `"NINTENDO"`, in the low half-word along with its complement in the high half-word, as listed
+//!    in [`HANDSHAKE_RECEIVED`]. The GBA answers each with the corresponding word of
+//!    [`HANDSHAKE_SENT`], echoing the complement alongside the next key. While the Game Boy Player
+//!    repeats a key without its complement, the GBA answers with the matching word of
+//!    [`HANDSHAKE_ECHOES`].
+//! 2. **Magic values.** The Game Boy Player sends each word of [`MAGIC_RECEIVED`] in turn, and the
+//!    GBA answers each with the matching word of [`MAGIC_SENT`].
+//! 3. **Rumble.** The Game Boy Player repeatedly sends [`RUMBLE_REQUEST`], and the GBA answers each
+//!    with the current rumble state: [`RUMBLE_STOP`], [`RUMBLE_START`], or [`RUMBLE_HARD_STOP`].
+//!
+//! Any other value returns the session to the start of the handshake. The values in [`consts`]
+//! are exactly those used by this crate, allowing emulators implementing Game Boy Player rumble to
+//! be tested against it.
+//!
+//! [`HANDSHAKE_ECHOES`]: consts::HANDSHAKE_ECHOES
+//! [`HANDSHAKE_KEYS`]: consts::HANDSHAKE_KEYS
+//! [`HANDSHAKE_RECEIVED`]: consts::HANDSHAKE_RECEIVED
+//! [`HANDSHAKE_SENT`]: consts::HANDSHAKE_SENT
+//! [`MAGIC_RECEIVED`]: consts::MAGIC_RECEIVED
+//! [`MAGIC_SENT`]: consts::MAGIC_SENT
+//! [`RUMBLE_HARD_STOP`]: consts::RUMBLE_HARD_STOP
+//! [`RUMBLE_REQUEST`]: consts::RUMBLE_REQUEST
+//! [`RUMBLE_START`]: consts::RUMBLE_START
+//! [`RUMBLE_STOP`]: consts::RUMBLE_STOP
+
+pub mod consts;
+mod stage;
+mod state_machine;
+
+pub use stage::Stage;
+pub use state_machine::StateMachine;
@@ -0,0 +1,132 @@
+//! The exact values exchanged with the Game Boy Player.
+//!
+//! Values are named from the GBA's point of view: words "received" are sent by the Game Boy
+//! Player, and words "sent" are the GBA's responses. See the [`protocol`] module for how they fit
+//! together. The values used to detect the Game Boy Player are also included, so that projects
+//! implementing their own detection, such as for unusual display setups, behave the same as
+//! [`GameBoyPlayer::detect()`].
+//!
+//! [`GameBoyPlayer::detect()`]: crate::GameBoyPlayer::detect()
+//!
+//! ``` rust
+//! use gba_rumble::protocol::consts;
+//!
+//! // The final magic value answered by the GBA is also the first rumble state it sends.
+//! assert_eq!(consts::MAGIC_SENT[2], consts::RUMBLE_STOP);
+//! ```
+//!
+//! [`protocol`]: crate::protocol
+
+// Detection.
+
+/// The number of frames the Game Boy Player logo is displayed for while detecting.
+///
+/// The Game Boy Player must see its logo for a while before unlocking its extra functionality, and
+/// reports having done so through [`DETECTION_KEYINPUT`] within this window.
+pub const DETECTION_FRAMES: u8 = 125;
+/// The value of `KEYINPUT` reported once the Game Boy Player unlocks its extra functionality.
+///
+/// This indicates that all four directions are pressed at once, which is impossible on a normal
+/// console.
+pub const DETECTION_KEYINPUT: u16 = 0x030F;
+/// The `DISPCNT` value used while displaying the logo: mode 0, with only BG 0 enabled.
+pub const SPLASH_DISPCNT: u16 = 0x0100;
+/// The `BG0CNT` value used while displaying the logo: 256 colors, with tiles in character base
+/// block 2 and the map in screen base block 0.
+pub const SPLASH_BG0CNT: u16 = 0x0088;
+
+// Handshake.
+
+/// The keys sent by the Game Boy Player during the handshake, spelling out `"NINTENDO"` in
+/// little-endian ASCII.
+pub const HANDSHAKE_KEYS: [u16; 4] = [0x494E, 0x544E, 0x4E45, 0x4F44];
+/// The words received during the handshake: each key in the low half-word, with its complement in
+/// the high half-word.
+pub const HANDSHAKE_RECEIVED: [u32; 4] = [0xB6B1_494E, 0xABB1_544E, 0xB1BA_4E45, 0xB0BB_4F44];
+/// The responses to [`HANDSHAKE_RECEIVED`]: the complement of the key received in the low
+/// half-word, with the next key in the high half-word.
+///
+/// The final response has [`HANDSHAKE_END`] in place of a next key.
+pub const HANDSHAKE_SENT: [u32; 4] = [0x544E_B6B1, 0x4E45_ABB1, 0x4F44_B1BA, 0x8000_B0BB];
+/// The responses while the Game Boy Player repeats a key without its complement: the complement of
+/// the key in the low half-word, with the key itself in the high half-word.
+pub const HANDSHAKE_ECHOES: [u32; 4] = [0x494E_B6B1, 0x544E_ABB1, 0x4E45_B1BA, 0x4F44_B0BB];
+/// The high half-word of the final handshake response, marking the end of the handshake.
+pub const HANDSHAKE_END: u16 = 0x8000;
+
+// Magic values.
+
+/// The magic values received after the handshake, in order.
+pub const MAGIC_RECEIVED: [u32; 3] = [0xB0BB_8002, 0x1000_0010, 0x2000_0013];
+/// The responses to [`MAGIC_RECEIVED`].
+///
+/// Each magic value is answered with the next one received, and the last with [`RUMBLE_STOP`].
+pub const MAGIC_SENT: [u32; 3] = [0x1000_0010, 0x2000_0013, RUMBLE_STOP];
+
+// Rumble.
+
+/// The word received each time the Game Boy Player requests the rumble state.
+pub const RUMBLE_REQUEST: u32 = 0x3000_0003;
+/// The rumble state stopping the motor.
+pub const RUMBLE_STOP: u32 = 0x4000_0004;
+/// The rumble state braking the motor with a hard stop.
+pub const RUMBLE_HARD_STOP: u32 = 0x4000_0015;
+/// The rumble state running the motor.
+pub const RUMBLE_START: u32 = 0x4000_0026;
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        DETECTION_KEYINPUT, HANDSHAKE_ECHOES, HANDSHAKE_END, HANDSHAKE_KEYS, HANDSHAKE_RECEIVED,
+        HANDSHAKE_SENT, MAGIC_RECEIVED, MAGIC_SENT, RUMBLE_STOP,
+    };
+    use gba_test::test;
+
+    #[test]
+    fn detection_keyinput_presses_every_direction() {
+        // `KEYINPUT` is active low, with the directions in bits 4 through 7.
+        assert_eq!(!DETECTION_KEYINPUT & 0x03FF, 0b1111 << 4);
+    }
+
+    #[test]
+    fn handshake_keys_spell_nintendo() {
+        let mut bytes = [0; 8];
+        for (chunk, key) in bytes.chunks_mut(2).zip(HANDSHAKE_KEYS) {
+            chunk.copy_from_slice(&key.to_le_bytes());
+        }
+
+        assert_eq!(&bytes, b"NINTENDO");
+    }
+
+    #[test]
+    fn handshake_received() {
+        for (word, key) in HANDSHAKE_RECEIVED.into_iter().zip(HANDSHAKE_KEYS) {
+            assert_eq!(word, (!key as u32) << 16 | key as u32);
+        }
+    }
+
+    #[test]
+    fn handshake_sent() {
+        let next_keys = HANDSHAKE_KEYS[1..].iter().copied().chain([HANDSHAKE_END]);
+        for ((word, key), next_key) in HANDSHAKE_SENT
+            .into_iter()
+            .zip(HANDSHAKE_KEYS)
+            .zip(next_keys)
+        {
+            assert_eq!(word, (next_key as u32) << 16 | !key as u32);
+        }
+    }
+
+    #[test]
+    fn handshake_echoes() {
+        for (word, key) in HANDSHAKE_ECHOES.into_iter().zip(HANDSHAKE_KEYS) {
+            assert_eq!(word, (key as u32) << 16 | !key as u32);
+        }
+    }
+
+    #[test]
+    fn magic_sent() {
+        assert_eq!(MAGIC_SENT[..2], MAGIC_RECEIVED[1..]);
+        assert_eq!(MAGIC_SENT[2], RUMBLE_STOP);
+    }
+}
@@ -0,0 +1,60 @@
+//! The stage a session has reached within the protocol.
+
+use core::{
+    fmt,
+    fmt::{Display, Formatter},
+};
+
+/// The point a session has reached in the protocol, as returned by
+/// [`GameBoyPlayer::protocol_stage()`].
+///
+/// Each variant corresponds to one of the three stages of a session described in the
+/// [module documentation](crate::protocol). Its [`Display`] implementation writes the short name
+/// used for the state in a [`StateMachine`]: `H0` to `H3`, `M1` to `M3`, or `D`.
+///
+/// [`GameBoyPlayer::protocol_stage()`]: crate::GameBoyPlayer::protocol_stage()
+/// [`StateMachine`]: crate::protocol::StateMachine
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Stage {
+    /// Waiting for the handshake key at index `key` of [`HANDSHAKE_KEYS`], from `0` to `3`.
+    ///
+    /// [`HANDSHAKE_KEYS`]: crate::protocol::consts::HANDSHAKE_KEYS
+    Handshake {
+        /// The index of the expected key.
+        key: u8,
+    },
+    /// Waiting for magic value number `value`, from `1` to `3`, found at index `value - 1` of
+    /// [`MAGIC_RECEIVED`].
+    ///
+    /// [`MAGIC_RECEIVED`]: crate::protocol::consts::MAGIC_RECEIVED
+    Magic {
+        /// The number of the expected magic value.
+        value: u8,
+    },
+    /// Exchanging rumble commands.
+    Rumble,
+}
+
+impl Display for Stage {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Handshake { key } => write!(formatter, "H{key}"),
+            Self::Magic { value } => write!(formatter, "M{value}"),
+            Self::Rumble => formatter.write_str("D"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Stage;
+    use alloc::format;
+    use gba_test::test;
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", Stage::Handshake { key: 2 }), "H2");
+        assert_eq!(format!("{}", Stage::Magic { value: 1 }), "M1");
+        assert_eq!(format!("{}", Stage::Rumble), "D");
+    }
+}
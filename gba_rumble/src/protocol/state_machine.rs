@@ -0,0 +1,140 @@
+//! A printable form of the protocol's state machine.
+
+use crate::{
+    Command, LinkStatus,
+    game_boy_player::{GAME_BOY_PLAYER_TRANSITIONS, GameBoyPlayerSioState},
+    protocol::{
+        Stage,
+        consts::{RUMBLE_HARD_STOP, RUMBLE_REQUEST, RUMBLE_START, RUMBLE_STOP},
+    },
+    session::RawInbound,
+};
+use core::{
+    fmt,
+    fmt::{Display, Formatter},
+};
+
+/// The protocol's state machine, along with the live state of the session, returned by
+/// [`GameBoyPlayer::debug_state_machine()`].
+///
+/// The [`Display`] implementation writes every transition the crate implements, one per line,
+/// followed by a final line describing the session. This is compact enough to log through
+/// [`mgba_log`](https://docs.rs/mgba_log) and paste into a bug report about handshake problems.
+///
+/// States are named `H0` to `H3` for each handshake key, `M1` to `M3` for each magic value, and
+/// `D` once rumble data is being sent. Each transition is written as
+/// `<state> <input>/<mask> -> <response> <next>`, in hexadecimal, where an input matches if it is
+/// equal to `<input>` in the bits set in `<mask>`. Transitions are tried in the order listed, and
+/// the final `*` line is taken by any input that matches none of them.
+///
+/// ``` text
+/// H0 B6B1494E/FFFFFFFF -> 544EB6B1 H1
+/// H0 0000494E/0000FFFF -> 494EB6B1 H0
+/// ...
+/// M3 20000013/FFFFFFFF -> 40000004 D
+/// D 30000003/FFFFFFFF -> 40000004|40000026|40000015 D
+/// * -> H0
+/// state: D, link: Connected, sent: Start, received: 30000003 #152
+/// ```
+///
+/// [`GameBoyPlayer::debug_state_machine()`]: crate::GameBoyPlayer::debug_state_machine()
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct StateMachine {
+    /// The stage the session has reached.
+    pub state: Stage,
+    /// The state of the link.
+    pub link: LinkStatus,
+    /// The most recent command actually sent to the Game Boy Player.
+    pub sent: Command,
+    /// The most recent word received, if any.
+    pub received: Option<RawInbound>,
+}
+
+impl Display for StateMachine {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        for transition in &GAME_BOY_PLAYER_TRANSITIONS {
+            writeln!(
+                formatter,
+                "{} {:08X}/{:08X} -> {:08X} {}",
+                transition.state.stage(),
+                transition.input,
+                transition.mask,
+                transition.response,
+                transition.next.stage(),
+            )?;
+        }
+        let connected = Stage::Rumble;
+        writeln!(
+            formatter,
+            "{connected} {RUMBLE_REQUEST:08X}/{:08X} -> {RUMBLE_STOP:08X}|{RUMBLE_START:08X}|{RUMBLE_HARD_STOP:08X} {connected}",
+            u32::MAX,
+        )?;
+        writeln!(formatter, "* -> {}", GameBoyPlayerSioState::new().stage())?;
+        write!(
+            formatter,
+            "state: {}, link: {:?}, sent: {:?}",
+            self.state, self.link, self.sent
+        )?;
+        match self.received {
+            Some(received) => write!(
+                formatter,
+                ", received: {:08X} #{}",
+                received.word, received.sequence
+            ),
+            None => formatter.write_str(", received: nothing"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Stage, StateMachine};
+    use crate::{Command, LinkStatus, session::RawInbound};
+    use alloc::{format, vec::Vec};
+    use gba_test::test;
+
+    #[test]
+    fn display() {
+        let state_machine = StateMachine {
+            state: Stage::Handshake { key: 1 },
+            link: LinkStatus::Handshaking,
+            sent: Command::Stop,
+            received: Some(RawInbound {
+                word: 0xB6B1_494E,
+                sequence: 1,
+            }),
+        };
+
+        let text = format!("{state_machine}");
+        let lines: Vec<_> = text.lines().collect();
+
+        assert_eq!(lines.len(), 14);
+        assert_eq!(lines[0], "H0 B6B1494E/FFFFFFFF -> 544EB6B1 H1");
+        assert_eq!(lines[1], "H0 0000494E/0000FFFF -> 494EB6B1 H0");
+        assert_eq!(lines[10], "M3 20000013/FFFFFFFF -> 40000004 D");
+        assert_eq!(
+            lines[11],
+            "D 30000003/FFFFFFFF -> 40000004|40000026|40000015 D"
+        );
+        assert_eq!(lines[12], "* -> H0");
+        assert_eq!(
+            lines[13],
+            "state: H1, link: Handshaking, sent: Stop, received: B6B1494E #1"
+        );
+    }
+
+    #[test]
+    fn display_nothing_received() {
+        let state_machine = StateMachine {
+            state: Stage::Handshake { key: 0 },
+            link: LinkStatus::Handshaking,
+            sent: Command::Stop,
+            received: None,
+        };
+
+        assert!(
+            format!("{state_machine}")
+                .ends_with("state: H0, link: Handshaking, sent: Stop, received: nothing")
+        );
+    }
+}
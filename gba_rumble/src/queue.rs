@@ -0,0 +1,243 @@
+//! Fixed-capacity queue of prioritized values.
+
+/// A first-in, first-out queue holding at most `N` prioritized values, without allocating.
+///
+/// # Overflow
+/// When a value is pushed onto a full queue, the value with the lowest priority is dropped. This
+/// may be the newly pushed value itself. Among values with equal priority, the most recently
+/// pushed one is dropped, so that older values are not starved by newer ones.
+///
+/// ``` rust
+/// use gba_rumble::Queue;
+///
+/// let mut queue = Queue::<char, 2>::new();
+/// queue.push(1, 'a').unwrap();
+/// queue.push(0, 'b').unwrap();
+///
+/// // The queue is full, so the lowest priority value is dropped to make room.
+/// assert_eq!(queue.push(2, 'c'), Ok(Some('b')));
+/// // A value with too low a priority is dropped itself.
+/// assert_eq!(queue.push(0, 'd'), Err('d'));
+/// assert_eq!(queue.pop(), Some('a'));
+/// assert_eq!(queue.pop(), Some('c'));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Queue<T, const N: usize> {
+    entries: [Option<(u8, T)>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> Queue<T, N> {
+    /// Creates an empty queue.
+    pub const fn new() -> Self {
+        Self {
+            entries: [const { None }; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the number of values in the queue.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the queue contains no values.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns whether the queue contains `N` values.
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Adds `value` to the back of the queue with the given `priority`.
+    ///
+    /// If the queue is full, the lowest-priority value is dropped, as described in
+    /// [Overflow](#overflow). Returns the dropped value if it was already in the queue, or `Err`
+    /// containing `value` if it was dropped instead.
+    pub fn push(&mut self, priority: u8, value: T) -> Result<Option<T>, T> {
+        if !self.is_full() {
+            self.entries[self.len] = Some((priority, value));
+            self.len += 1;
+            return Ok(None);
+        }
+
+        let lowest = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| entry.as_ref().map(|(priority, _)| (index, *priority)))
+            .min_by(|(index_a, priority_a), (index_b, priority_b)| {
+                priority_a.cmp(priority_b).then(index_b.cmp(index_a))
+            });
+        match lowest {
+            Some((index, lowest_priority)) if lowest_priority < priority => {
+                let dropped = self.remove(index);
+                self.entries[self.len] = Some((priority, value));
+                self.len += 1;
+                Ok(dropped)
+            }
+            _ => Err(value),
+        }
+    }
+
+    /// Removes and returns the value at the front of the queue.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            None
+        } else {
+            self.remove(0)
+        }
+    }
+
+    /// Removes and returns the first value, from front to back, for which `f` returns `true`.
+    pub fn take_first<F>(&mut self, mut f: F) -> Option<T>
+    where
+        F: FnMut(u8, &T) -> bool,
+    {
+        let index = self.entries[..self.len].iter().position(|entry| {
+            entry
+                .as_ref()
+                .is_some_and(|(priority, value)| f(*priority, value))
+        })?;
+        self.remove(index)
+    }
+
+    /// Retains only the values for which `f` returns `true`, preserving their order.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(u8, &T) -> bool,
+    {
+        let mut index = 0;
+        while index < self.len {
+            if self.entries[index]
+                .as_ref()
+                .is_some_and(|(priority, value)| f(*priority, value))
+            {
+                index += 1;
+            } else {
+                self.remove(index);
+            }
+        }
+    }
+
+    /// Returns an iterator over the priorities and values in the queue, from front to back.
+    pub fn iter(&self) -> impl Iterator<Item = (u8, &T)> {
+        self.entries[..self.len]
+            .iter()
+            .flatten()
+            .map(|(priority, value)| (*priority, value))
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        let removed = self.entries[index].take();
+        self.entries[index..self.len].rotate_left(1);
+        self.len -= 1;
+        removed.map(|(_, value)| value)
+    }
+}
+
+impl<T, const N: usize> Default for Queue<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Queue;
+    use claims::{assert_err_eq, assert_none, assert_ok, assert_ok_eq, assert_some_eq};
+    use gba_test::test;
+
+    #[test]
+    fn empty() {
+        let mut queue = Queue::<u8, 2>::new();
+
+        assert!(queue.is_empty());
+        assert_none!(queue.pop());
+    }
+
+    #[test]
+    fn fifo() {
+        let mut queue = Queue::<u8, 3>::new();
+
+        assert_ok_eq!(queue.push(0, 1), None);
+        assert_ok_eq!(queue.push(2, 2), None);
+        assert_ok_eq!(queue.push(1, 3), None);
+
+        assert!(queue.is_full());
+        assert_some_eq!(queue.pop(), 1);
+        assert_some_eq!(queue.pop(), 2);
+        assert_some_eq!(queue.pop(), 3);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn overflow_drops_lowest_priority() {
+        let mut queue = Queue::<u8, 2>::new();
+        assert_ok!(queue.push(1, 1));
+        assert_ok!(queue.push(0, 2));
+
+        assert_ok_eq!(queue.push(2, 3), Some(2));
+        assert_some_eq!(queue.pop(), 1);
+        assert_some_eq!(queue.pop(), 3);
+    }
+
+    #[test]
+    fn overflow_drops_new_value() {
+        let mut queue = Queue::<u8, 2>::new();
+        assert_ok!(queue.push(1, 1));
+        assert_ok!(queue.push(1, 2));
+
+        assert_err_eq!(queue.push(1, 3), 3);
+        assert_err_eq!(queue.push(0, 4), 4);
+        assert_eq!(queue.len(), 2);
+    }
+
+    #[test]
+    fn overflow_drops_newest_of_equal_priority() {
+        let mut queue = Queue::<u8, 2>::new();
+        assert_ok!(queue.push(0, 1));
+        assert_ok!(queue.push(0, 2));
+
+        assert_ok_eq!(queue.push(1, 3), Some(2));
+        assert_some_eq!(queue.pop(), 1);
+        assert_some_eq!(queue.pop(), 3);
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut queue = Queue::<u8, 0>::new();
+
+        assert_err_eq!(queue.push(u8::MAX, 1), 1);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn take_first() {
+        let mut queue = Queue::<u8, 3>::new();
+        assert_ok!(queue.push(0, 1));
+        assert_ok!(queue.push(0, 2));
+        assert_ok!(queue.push(0, 3));
+
+        assert_some_eq!(queue.take_first(|_, value| value % 2 == 0), 2);
+        assert_some_eq!(queue.pop(), 1);
+        assert_some_eq!(queue.pop(), 3);
+    }
+
+    #[test]
+    fn retain() {
+        let mut queue = Queue::<u8, 4>::new();
+        assert_ok!(queue.push(0, 1));
+        assert_ok!(queue.push(1, 2));
+        assert_ok!(queue.push(0, 3));
+        assert_ok!(queue.push(1, 4));
+
+        queue.retain(|priority, _| priority == 1);
+
+        assert_eq!(queue.len(), 2);
+        assert_some_eq!(queue.pop(), 2);
+        assert_some_eq!(queue.pop(), 4);
+    }
+}
@@ -0,0 +1,86 @@
+//! Bounded integers used for the protocol's index bookkeeping.
+//!
+//! These are provided by the `deranged` crate when the `deranged` feature is enabled, which it is
+//! by default. Otherwise, a minimal replacement storing the value in a `u8` is used instead, so
+//! that the crate has no required dependencies.
+
+#[cfg(feature = "deranged")]
+pub(crate) use deranged::RangedUsize;
+
+/// A `usize` known to be in the range `MIN..=MAX`.
+///
+/// This implements only the subset of `deranged::RangedUsize` used by this crate. The range must
+/// fit within a `u8`.
+#[cfg(not(feature = "deranged"))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub(crate) struct RangedUsize<const MIN: usize, const MAX: usize>(u8);
+
+#[cfg(not(feature = "deranged"))]
+impl<const MIN: usize, const MAX: usize> RangedUsize<MIN, MAX> {
+    /// Creates a bounded value, checking that `VALUE` is within range at compile time.
+    #[inline(always)]
+    pub(crate) const fn new_static<const VALUE: usize>() -> Self {
+        const {
+            assert!(MIN <= VALUE && VALUE <= MAX && MAX <= u8::MAX as usize);
+        }
+        Self(VALUE as u8)
+    }
+
+    /// Creates a bounded value, returning `None` if `value` is out of range.
+    #[inline(always)]
+    pub(crate) const fn new(value: usize) -> Option<Self> {
+        if MIN <= value && value <= MAX {
+            Some(Self(value as u8))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value.
+    #[inline(always)]
+    pub(crate) const fn get(self) -> usize {
+        debug_assert!(MIN <= self.0 as usize && self.0 as usize <= MAX);
+        self.0 as usize
+    }
+
+    /// Adds `rhs`, returning `None` if the result is out of range.
+    #[cfg_attr(not(test), expect(dead_code))]
+    #[inline(always)]
+    pub(crate) const fn checked_add(self, rhs: usize) -> Option<Self> {
+        match self.get().checked_add(rhs) {
+            Some(value) => Self::new(value),
+            None => None,
+        }
+    }
+}
+
+#[cfg(all(test, not(feature = "deranged")))]
+mod tests {
+    use super::RangedUsize;
+    use claims::{assert_none, assert_some_eq};
+    use gba_test::test;
+
+    #[test]
+    fn new_static() {
+        assert_eq!(RangedUsize::<1, 3>::new_static::<2>().get(), 2);
+    }
+
+    #[test]
+    fn new_in_range() {
+        assert_some_eq!(RangedUsize::<1, 3>::new(3).map(RangedUsize::get), 3);
+    }
+
+    #[test]
+    fn new_out_of_range() {
+        assert_none!(RangedUsize::<1, 3>::new(0));
+        assert_none!(RangedUsize::<1, 3>::new(4));
+    }
+
+    #[test]
+    fn checked_add() {
+        let value = RangedUsize::<0, 3>::new_static::<2>();
+
+        assert_some_eq!(value.checked_add(1).map(RangedUsize::get), 3);
+        assert_none!(value.checked_add(2));
+    }
+}
@@ -0,0 +1,325 @@
+//! Recording and playback of backend commands.
+
+use crate::{Backend, Calibration, Capabilities, Command, ResponseCurve, TimedCommand};
+
+/// A [`Backend`] that records every command sent through it.
+///
+/// Commands are forwarded to the wrapped backend, and recorded along with the frame they were sent
+/// on into a caller-provided buffer. Frames are counted by calls to [`tick()`], which should be
+/// made once per frame after that frame's commands have been sent. The recording can later be
+/// replayed using a [`Player`], such as for attract-mode demos or to reproduce exactly what the
+/// motor did when reporting a bug.
+///
+/// ``` rust
+/// use gba_rumble::{Command, Driver, Gpio, Intensity, Recorder, TimedCommand};
+///
+/// let mut buffer = [TimedCommand { frame: 0, command: Command::Stop }; 64];
+/// let mut driver = Driver::<_>::new(Recorder::new(Gpio, &mut buffer));
+///
+/// // Once a frame:
+/// driver.tick(Intensity::MAX);
+/// driver.backend_mut().tick();
+///
+/// let recording = driver.backend().recording();
+/// ```
+///
+/// [`tick()`]: Recorder::tick()
+#[derive(Debug)]
+pub struct Recorder<'a, B> {
+    backend: B,
+    buffer: &'a mut [TimedCommand],
+    len: usize,
+    frame: u32,
+}
+
+impl<'a, B> Recorder<'a, B>
+where
+    B: Backend,
+{
+    /// Creates a recorder wrapping `backend`, recording into `buffer`.
+    ///
+    /// Recording starts at frame `0`. Once `buffer` is full, commands are still forwarded to
+    /// `backend`, but are no longer recorded.
+    pub const fn new(backend: B, buffer: &'a mut [TimedCommand]) -> Self {
+        Self {
+            backend,
+            buffer,
+            len: 0,
+            frame: 0,
+        }
+    }
+
+    /// Advances to the next frame.
+    pub fn tick(&mut self) {
+        self.frame = self.frame.wrapping_add(1);
+    }
+
+    /// Returns the current frame.
+    pub const fn frame(&self) -> u32 {
+        self.frame
+    }
+
+    /// Returns the commands recorded so far, in the order they were sent.
+    pub fn recording(&self) -> &[TimedCommand] {
+        &self.buffer[..self.len]
+    }
+
+    /// Returns whether the buffer is full, meaning further commands will not be recorded.
+    pub const fn is_full(&self) -> bool {
+        self.len == self.buffer.len()
+    }
+
+    /// Returns the wrapped backend.
+    pub const fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Consumes the recorder, returning the wrapped backend.
+    pub fn into_inner(self) -> B {
+        self.backend
+    }
+}
+
+impl<B> Backend for Recorder<'_, B>
+where
+    B: Backend,
+{
+    fn capabilities(&self) -> Capabilities {
+        self.backend.capabilities()
+    }
+
+    fn response_curve(&self) -> ResponseCurve {
+        self.backend.response_curve()
+    }
+
+    fn calibration(&self) -> Calibration {
+        self.backend.calibration()
+    }
+
+    fn min_command_frames(&self) -> u8 {
+        self.backend.min_command_frames()
+    }
+
+    fn send(&mut self, command: Command) {
+        self.backend.send(command);
+        if let Some(entry) = self.buffer.get_mut(self.len) {
+            *entry = TimedCommand {
+                frame: self.frame,
+                command,
+            };
+            self.len += 1;
+        }
+    }
+}
+
+/// Replays commands captured by a [`Recorder`].
+///
+/// Each call to [`tick()`] sends the commands recorded on the current frame to a backend, then
+/// advances to the next frame. Playback starts at frame `0`.
+///
+/// ``` rust
+/// use gba_rumble::{Command, Gpio, Player, TimedCommand};
+///
+/// const DEMO: [TimedCommand; 2] = [
+///     TimedCommand { frame: 30, command: Command::Start },
+///     TimedCommand { frame: 45, command: Command::Stop },
+/// ];
+///
+/// let mut player = Player::new(&DEMO);
+/// let mut gpio = Gpio;
+/// while !player.is_finished() {
+///     // Once a frame:
+///     player.tick(&mut gpio);
+/// }
+/// ```
+///
+/// [`tick()`]: Player::tick()
+#[derive(Clone, Debug)]
+pub struct Player<'a> {
+    recording: &'a [TimedCommand],
+    position: usize,
+    frame: u32,
+}
+
+impl<'a> Player<'a> {
+    /// Creates a player for `recording`, which must be ordered by frame.
+    pub const fn new(recording: &'a [TimedCommand]) -> Self {
+        Self {
+            recording,
+            position: 0,
+            frame: 0,
+        }
+    }
+
+    /// Sends the commands recorded on the current frame to `backend`, then advances to the next
+    /// frame.
+    pub fn tick<B>(&mut self, backend: &mut B)
+    where
+        B: Backend + ?Sized,
+    {
+        while let Some(entry) = self.recording.get(self.position) {
+            if entry.frame > self.frame {
+                break;
+            }
+            backend.send(entry.command);
+            self.position += 1;
+        }
+        self.frame = self.frame.wrapping_add(1);
+    }
+
+    /// Returns whether every recorded command has been sent.
+    pub const fn is_finished(&self) -> bool {
+        self.position >= self.recording.len()
+    }
+
+    /// Returns to the beginning of the recording.
+    pub const fn restart(&mut self) {
+        self.position = 0;
+        self.frame = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Player, Recorder};
+    use crate::{Backend, Capabilities, Command, Driver, Intensity, TimedCommand};
+    use gba_test::test;
+
+    const EMPTY: TimedCommand = TimedCommand {
+        frame: 0,
+        command: Command::Stop,
+    };
+
+    #[derive(Default)]
+    struct Last {
+        command: Option<Command>,
+        sent: usize,
+    }
+
+    impl Backend for Last {
+        fn capabilities(&self) -> Capabilities {
+            Capabilities {
+                has_intensity: false,
+                has_brake: true,
+                latency_frames: 0,
+                shared_with_sio: false,
+            }
+        }
+
+        fn send(&mut self, command: Command) {
+            self.command = Some(command);
+            self.sent += 1;
+        }
+    }
+
+    #[test]
+    fn recorder_records_frames() {
+        let mut buffer = [EMPTY; 4];
+        let mut recorder = Recorder::new(Last::default(), &mut buffer);
+
+        recorder.send(Command::Start);
+        recorder.tick();
+        recorder.tick();
+        recorder.send(Command::HardStop);
+
+        assert_eq!(
+            recorder.recording(),
+            &[
+                TimedCommand {
+                    frame: 0,
+                    command: Command::Start
+                },
+                TimedCommand {
+                    frame: 2,
+                    command: Command::HardStop
+                },
+            ]
+        );
+        assert_eq!(recorder.backend().sent, 2);
+    }
+
+    #[test]
+    fn recorder_full() {
+        let mut buffer = [EMPTY; 1];
+        let mut recorder = Recorder::new(Last::default(), &mut buffer);
+
+        recorder.send(Command::Start);
+        recorder.send(Command::Stop);
+
+        assert!(recorder.is_full());
+        assert_eq!(recorder.recording().len(), 1);
+        assert_eq!(recorder.into_inner().command, Some(Command::Stop));
+    }
+
+    #[test]
+    fn recorder_capabilities() {
+        let mut buffer = [];
+        let recorder = Recorder::new(Last::default(), &mut buffer);
+
+        assert!(recorder.capabilities().has_brake);
+    }
+
+    #[test]
+    fn player_replays_frames() {
+        let recording = [
+            TimedCommand {
+                frame: 1,
+                command: Command::Start,
+            },
+            TimedCommand {
+                frame: 3,
+                command: Command::Stop,
+            },
+        ];
+        let mut player = Player::new(&recording);
+        let mut backend = Last::default();
+
+        player.tick(&mut backend);
+        assert_eq!(backend.command, None);
+        player.tick(&mut backend);
+        assert_eq!(backend.command, Some(Command::Start));
+        player.tick(&mut backend);
+        assert!(!player.is_finished());
+        player.tick(&mut backend);
+        assert_eq!(backend.command, Some(Command::Stop));
+        assert!(player.is_finished());
+    }
+
+    #[test]
+    fn player_restart() {
+        let recording = [TimedCommand {
+            frame: 0,
+            command: Command::Start,
+        }];
+        let mut player = Player::new(&recording);
+        let mut backend = Last::default();
+
+        player.tick(&mut backend);
+        player.restart();
+        player.tick(&mut backend);
+
+        assert_eq!(backend.sent, 2);
+    }
+
+    #[test]
+    fn round_trip() {
+        let intensities = [0, 5, 5, 0, 0, 9, 0];
+        let mut buffer = [EMPTY; 8];
+        let mut driver = Driver::<_>::new(Recorder::new(Last::default(), &mut buffer));
+        for intensity in intensities {
+            driver.tick(Intensity::new(intensity));
+            driver.backend_mut().tick();
+        }
+        let recording = driver.backend().recording();
+
+        let mut buffer = [EMPTY; 8];
+        let mut replayed = Recorder::new(Last::default(), &mut buffer);
+        let mut player = Player::new(recording);
+        for _ in intensities {
+            player.tick(&mut replayed);
+            replayed.tick();
+        }
+
+        assert_eq!(replayed.recording(), recording);
+    }
+}
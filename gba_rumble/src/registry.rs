@@ -0,0 +1,513 @@
+//! Looking up effects by ID.
+
+use crate::Pattern;
+use core::{
+    fmt,
+    fmt::{Display, Formatter},
+    mem, ptr,
+};
+
+/// Identifies an effect by a small integer.
+///
+/// This is the effect's index within the table given to [`Haptics`], or the ID it was registered
+/// under in a [`Registry`]. Because it is a plain integer, it can be stored in level scripts and
+/// serialized entity data.
+///
+/// [`Haptics`]: crate::Haptics
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct EffectId(u8);
+
+impl EffectId {
+    /// Creates an ID from its integer value.
+    pub const fn new(index: u8) -> Self {
+        Self(index)
+    }
+
+    /// Returns the integer value of this ID.
+    pub const fn index(self) -> u8 {
+        self.0
+    }
+}
+
+/// An error encountered while registering an effect in a [`Registry`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RegistryError {
+    /// The ID is not less than the registry's capacity.
+    OutOfRange,
+    /// Another effect is already registered under the ID.
+    Occupied,
+}
+
+impl Display for RegistryError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::OutOfRange => "effect ID is outside of the registry's capacity",
+            Self::Occupied => "an effect is already registered under this ID",
+        })
+    }
+}
+
+impl core::error::Error for RegistryError {}
+
+/// The memory used by the effects in a [`Registry`], as returned by [`Registry::footprint()`].
+///
+/// The [`Display`] implementation writes a one-line summary suitable for an asset report, such as
+/// `12 effects: 184 bytes of patterns, 128 bytes of registry`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Footprint {
+    /// The number of registered effects.
+    pub effects: usize,
+    /// The total size of the registered patterns' data, as reported by
+    /// [`Pattern::size_in_bytes()`].
+    ///
+    /// A pattern registered under more than one ID is only counted once.
+    pub pattern_bytes: usize,
+    /// The size of the registry itself.
+    pub registry_bytes: usize,
+}
+
+impl Footprint {
+    /// Returns the total number of bytes used by the registry and its patterns.
+    pub const fn total_bytes(&self) -> usize {
+        self.pattern_bytes + self.registry_bytes
+    }
+}
+
+impl Display for Footprint {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "{} effects: {} bytes of patterns, {} bytes of registry",
+            self.effects, self.pattern_bytes, self.registry_bytes
+        )
+    }
+}
+
+/// Effects registered under [`EffectId`]s, to be triggered by ID later.
+///
+/// Games register their patterns once at startup, then refer to them only by ID, such as from
+/// level scripts or serialized entity data, without holding references to the patterns
+/// themselves. The registry holds effects with IDs from `0` up to, but not including, `N`, and
+/// looking one up takes constant time. Since IDs are `u8`s, `N` need not be larger than `256`.
+///
+/// Registering is a `const fn`, so an entire effect library can also be built at compile time and
+/// stored as a `static` in ROM, costing nothing at startup. Registering an effect twice, or
+/// outside of the registry's capacity, then fails to build:
+///
+/// ``` rust
+/// use gba_rumble::{EffectId, Intensity, Registry, Step, pattern};
+///
+/// const HIT: EffectId = EffectId::new(0);
+/// const EXPLOSION: EffectId = EffectId::new(1);
+///
+/// static EFFECTS: Registry<16> = {
+///     let mut registry = Registry::new();
+///     registry.register_const(HIT, pattern![Step::Hold { intensity: Intensity::MAX, frames: 10 }]);
+///     registry.register_const(
+///         EXPLOSION,
+///         pattern![Step::Hold { intensity: Intensity::MAX, frames: 60 }],
+///     );
+///     registry
+/// };
+///
+/// assert!(EFFECTS.contains(HIT));
+/// ```
+///
+/// Effects can also be given a cooldown using [`set_cooldown()`], so that triggering an effect
+/// with [`trigger()`] does nothing until the given number of calls to [`tick()`] have passed since
+/// it was last triggered. This keeps collision code that fires every frame from turning rumble into
+/// constant noise. Cooldowns require the registry to be mutable, so a registry using them can't be
+/// stored in ROM.
+///
+/// ``` rust
+/// use gba_rumble::{EffectId, Intensity, Mixer, Pattern, Preemption, Registry, Step, pattern};
+///
+/// const HIT: EffectId = EffectId::new(0);
+/// const HIT_PATTERN: Pattern = pattern![Step::Hold { intensity: Intensity::MAX, frames: 10 }];
+///
+/// let mut registry = Registry::<16>::new();
+/// registry.register(HIT, HIT_PATTERN).unwrap();
+///
+/// let mut mixer = Mixer::<1>::new();
+/// let channel = mixer.add_channel(0, Preemption::Replace).unwrap();
+///
+/// // Later, when a script triggers effect 0:
+/// if let Some(pattern) = registry.get(EffectId::new(0)) {
+///     mixer.play(channel, pattern);
+/// }
+/// ```
+///
+/// [`set_cooldown()`]: Registry::set_cooldown()
+/// [`tick()`]: Registry::tick()
+/// [`trigger()`]: Registry::trigger()
+#[derive(Clone, Debug)]
+pub struct Registry<const N: usize> {
+    effects: [Option<Pattern<'static>>; N],
+    /// The minimum number of frames between triggers of each effect.
+    cooldowns: [u16; N],
+    /// The number of frames until each effect can be triggered again.
+    remaining: [u16; N],
+}
+
+impl<const N: usize> Registry<N> {
+    /// Creates a registry with no effects.
+    pub const fn new() -> Self {
+        Self {
+            effects: [None; N],
+            cooldowns: [0; N],
+            remaining: [0; N],
+        }
+    }
+
+    /// Registers `pattern` under `id`.
+    ///
+    /// Fails if `id` is not less than `N`, or if another effect is already registered under it.
+    pub const fn register(
+        &mut self,
+        id: EffectId,
+        pattern: Pattern<'static>,
+    ) -> Result<(), RegistryError> {
+        let index = id.index() as usize;
+        if index >= N {
+            return Err(RegistryError::OutOfRange);
+        }
+        if self.effects[index].is_some() {
+            return Err(RegistryError::Occupied);
+        }
+        self.effects[index] = Some(pattern);
+        Ok(())
+    }
+
+    /// Registers `pattern` under `id`, for building a registry at compile time.
+    ///
+    /// # Panics
+    /// Panics if `id` is not less than `N`, or if another effect is already registered under it.
+    /// When building a registry in a `const` or `static`, this fails compilation instead.
+    pub const fn register_const(&mut self, id: EffectId, pattern: Pattern<'static>) {
+        match self.register(id, pattern) {
+            Ok(()) => {}
+            Err(RegistryError::OutOfRange) => {
+                panic!("effect ID is outside of the registry's capacity")
+            }
+            Err(RegistryError::Occupied) => panic!("an effect is already registered under this ID"),
+        }
+    }
+
+    /// Removes the effect registered under `id`, returning it.
+    pub const fn unregister(&mut self, id: EffectId) -> Option<Pattern<'static>> {
+        let index = id.index() as usize;
+        if index >= N {
+            return None;
+        }
+        self.remaining[index] = 0;
+        self.effects[index].take()
+    }
+
+    /// Returns the effect registered under `id`, if any.
+    pub const fn get(&self, id: EffectId) -> Option<Pattern<'static>> {
+        let index = id.index() as usize;
+        if index >= N {
+            return None;
+        }
+        self.effects[index]
+    }
+
+    /// Returns whether an effect is registered under `id`.
+    pub const fn contains(&self, id: EffectId) -> bool {
+        self.get(id).is_some()
+    }
+
+    /// Sets the minimum number of frames between triggers of the effect under `id`.
+    ///
+    /// Once the effect is returned by [`trigger()`], it is not returned again until [`tick()`] has
+    /// been called `frames` times. A value of `0` disables the cooldown, which is the default.
+    ///
+    /// Fails if `id` is not less than `N`. The cooldown can be set before or after an effect is
+    /// registered under `id`.
+    ///
+    /// [`tick()`]: Registry::tick()
+    /// [`trigger()`]: Registry::trigger()
+    pub const fn set_cooldown(&mut self, id: EffectId, frames: u16) -> Result<(), RegistryError> {
+        let index = id.index() as usize;
+        if index >= N {
+            return Err(RegistryError::OutOfRange);
+        }
+        self.cooldowns[index] = frames;
+        Ok(())
+    }
+
+    /// Returns the number of frames until the effect under `id` can be triggered again.
+    ///
+    /// Returns `0` if the effect is not cooling down.
+    pub const fn cooldown_remaining(&self, id: EffectId) -> u16 {
+        let index = id.index() as usize;
+        if index >= N {
+            return 0;
+        }
+        self.remaining[index]
+    }
+
+    /// Returns the effect registered under `id` to be played, starting its cooldown.
+    ///
+    /// Returns `None` if no effect is registered under `id`, or if the effect is still cooling
+    /// down from being triggered previously. Unlike [`get()`], this respects the cooldown set by
+    /// [`set_cooldown()`].
+    ///
+    /// [`get()`]: Registry::get()
+    /// [`set_cooldown()`]: Registry::set_cooldown()
+    pub const fn trigger(&mut self, id: EffectId) -> Option<Pattern<'static>> {
+        let index = id.index() as usize;
+        if index >= N || self.remaining[index] > 0 {
+            return None;
+        }
+        let pattern = self.effects[index];
+        if pattern.is_some() {
+            self.remaining[index] = self.cooldowns[index];
+        }
+        pattern
+    }
+
+    /// Advances cooldowns by a single frame.
+    ///
+    /// This should be called once a frame, such as alongside [`Mixer::tick()`].
+    ///
+    /// [`Mixer::tick()`]: crate::Mixer::tick()
+    pub fn tick(&mut self) {
+        for remaining in &mut self.remaining {
+            *remaining = remaining.saturating_sub(1);
+        }
+    }
+
+    /// Returns an iterator over the registered effects and their IDs, in order of ID.
+    pub fn iter(&self) -> impl Iterator<Item = (EffectId, Pattern<'static>)> + '_ {
+        self.effects
+            .iter()
+            .enumerate()
+            .filter_map(|(index, pattern)| Some((EffectId::new(index as u8), (*pattern)?)))
+    }
+
+    /// Returns the memory used by the registry and the effects registered in it.
+    ///
+    /// This allows the effect library to be accounted for in ROM or RAM budgets.
+    pub fn footprint(&self) -> Footprint {
+        let mut footprint = Footprint {
+            effects: 0,
+            pattern_bytes: 0,
+            registry_bytes: mem::size_of::<Self>(),
+        };
+        for (index, (_, pattern)) in self.iter().enumerate() {
+            footprint.effects += 1;
+            let bytes = pattern.as_bytes();
+            if !self
+                .iter()
+                .take(index)
+                .any(|(_, other)| ptr::eq(other.as_bytes(), bytes))
+            {
+                footprint.pattern_bytes += pattern.size_in_bytes();
+            }
+        }
+        footprint
+    }
+}
+
+impl<const N: usize> Default for Registry<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EffectId, Footprint, Registry, RegistryError};
+    use crate::{Intensity, Pattern, Step, pattern};
+    use alloc::format;
+    use claims::{assert_err_eq, assert_none, assert_ok, assert_some_eq};
+    use gba_test::test;
+
+    const HIT: Pattern = pattern![Step::Hold {
+        intensity: Intensity::MAX,
+        frames: 10
+    }];
+    const RUMBLE: Pattern = pattern![Step::Hold {
+        intensity: Intensity::new(64),
+        frames: 60
+    }];
+
+    #[test]
+    fn empty() {
+        let registry = Registry::<4>::new();
+
+        assert_none!(registry.get(EffectId::new(0)));
+        assert_eq!(registry.iter().count(), 0);
+    }
+
+    #[test]
+    fn register() {
+        let mut registry = Registry::<4>::new();
+
+        assert_ok!(registry.register(EffectId::new(3), HIT));
+
+        assert_some_eq!(registry.get(EffectId::new(3)), HIT);
+        assert!(registry.contains(EffectId::new(3)));
+        assert!(!registry.contains(EffectId::new(2)));
+    }
+
+    #[test]
+    fn register_const() {
+        static REGISTRY: Registry<4> = {
+            let mut registry = Registry::new();
+            registry.register_const(EffectId::new(0), HIT);
+            registry.register_const(EffectId::new(2), RUMBLE);
+            registry
+        };
+
+        assert_some_eq!(REGISTRY.get(EffectId::new(0)), HIT);
+        assert_none!(REGISTRY.get(EffectId::new(1)));
+        assert_some_eq!(REGISTRY.get(EffectId::new(2)), RUMBLE);
+    }
+
+    #[test]
+    #[should_panic(expected = "an effect is already registered under this ID")]
+    fn register_const_occupied() {
+        let mut registry = Registry::<4>::new();
+        registry.register_const(EffectId::new(0), HIT);
+
+        registry.register_const(EffectId::new(0), RUMBLE);
+    }
+
+    #[test]
+    fn register_out_of_range() {
+        let mut registry = Registry::<4>::new();
+
+        assert_err_eq!(
+            registry.register(EffectId::new(4), HIT),
+            RegistryError::OutOfRange
+        );
+        assert_none!(registry.get(EffectId::new(4)));
+    }
+
+    #[test]
+    fn register_occupied() {
+        let mut registry = Registry::<4>::new();
+        assert_ok!(registry.register(EffectId::new(1), HIT));
+
+        assert_err_eq!(
+            registry.register(EffectId::new(1), RUMBLE),
+            RegistryError::Occupied
+        );
+        assert_some_eq!(registry.get(EffectId::new(1)), HIT);
+    }
+
+    #[test]
+    fn unregister() {
+        let mut registry = Registry::<4>::new();
+        assert_ok!(registry.register(EffectId::new(0), HIT));
+
+        assert_some_eq!(registry.unregister(EffectId::new(0)), HIT);
+        assert_none!(registry.get(EffectId::new(0)));
+        assert_ok!(registry.register(EffectId::new(0), RUMBLE));
+    }
+
+    #[test]
+    fn iter() {
+        let mut registry = Registry::<4>::new();
+        assert_ok!(registry.register(EffectId::new(2), RUMBLE));
+        assert_ok!(registry.register(EffectId::new(0), HIT));
+
+        let mut iter = registry.iter();
+
+        assert_some_eq!(iter.next(), (EffectId::new(0), HIT));
+        assert_some_eq!(iter.next(), (EffectId::new(2), RUMBLE));
+        assert_none!(iter.next());
+    }
+
+    #[test]
+    fn trigger_without_cooldown() {
+        let mut registry = Registry::<4>::new();
+        assert_ok!(registry.register(EffectId::new(0), HIT));
+
+        assert_some_eq!(registry.trigger(EffectId::new(0)), HIT);
+        assert_some_eq!(registry.trigger(EffectId::new(0)), HIT);
+    }
+
+    #[test]
+    fn trigger_cooldown() {
+        let mut registry = Registry::<4>::new();
+        assert_ok!(registry.register(EffectId::new(0), HIT));
+        assert_ok!(registry.set_cooldown(EffectId::new(0), 2));
+
+        assert_some_eq!(registry.trigger(EffectId::new(0)), HIT);
+        assert_none!(registry.trigger(EffectId::new(0)));
+        registry.tick();
+        assert_eq!(registry.cooldown_remaining(EffectId::new(0)), 1);
+        assert_none!(registry.trigger(EffectId::new(0)));
+        registry.tick();
+        assert_some_eq!(registry.trigger(EffectId::new(0)), HIT);
+    }
+
+    #[test]
+    fn trigger_cooldown_per_effect() {
+        let mut registry = Registry::<4>::new();
+        assert_ok!(registry.register(EffectId::new(0), HIT));
+        assert_ok!(registry.register(EffectId::new(1), RUMBLE));
+        assert_ok!(registry.set_cooldown(EffectId::new(0), 10));
+
+        assert_some_eq!(registry.trigger(EffectId::new(0)), HIT);
+        assert_some_eq!(registry.trigger(EffectId::new(1)), RUMBLE);
+        assert_some_eq!(registry.trigger(EffectId::new(1)), RUMBLE);
+    }
+
+    #[test]
+    fn trigger_unregistered() {
+        let mut registry = Registry::<4>::new();
+        assert_ok!(registry.set_cooldown(EffectId::new(0), 10));
+
+        assert_none!(registry.trigger(EffectId::new(0)));
+        assert_eq!(registry.cooldown_remaining(EffectId::new(0)), 0);
+    }
+
+    #[test]
+    fn set_cooldown_out_of_range() {
+        let mut registry = Registry::<4>::new();
+
+        assert_err_eq!(
+            registry.set_cooldown(EffectId::new(4), 10),
+            RegistryError::OutOfRange
+        );
+    }
+
+    #[test]
+    fn footprint() {
+        let shared = HIT;
+        let mut registry = Registry::<4>::new();
+        assert_ok!(registry.register(EffectId::new(0), shared));
+        assert_ok!(registry.register(EffectId::new(1), shared));
+        assert_ok!(registry.register(EffectId::new(3), RUMBLE));
+
+        let footprint = registry.footprint();
+
+        assert_eq!(
+            footprint,
+            Footprint {
+                effects: 3,
+                pattern_bytes: 6,
+                registry_bytes: 48,
+            }
+        );
+        assert_eq!(footprint.total_bytes(), 54);
+    }
+
+    #[test]
+    fn footprint_display() {
+        let footprint = Footprint {
+            effects: 12,
+            pattern_bytes: 184,
+            registry_bytes: 128,
+        };
+
+        assert_eq!(
+            format!("{footprint}"),
+            "12 effects: 184 bytes of patterns, 128 bytes of registry"
+        );
+    }
+}
@@ -0,0 +1,105 @@
+//! Addresses of the hardware registers used by this crate.
+//!
+//! These are the exact addresses this crate reads and writes, provided for programs that write
+//! their own interrupt handlers, save-state tooling, or debugging aids and want to reference the
+//! same registers without duplicating magic numbers. Register names follow
+//! [GBATEK](https://problemkaputt.de/gbatek.htm).
+//!
+//! Addresses are given as `usize`, to be turned into a pointer of the appropriate width. The
+//! registers lie outside of any Rust allocation, so this crate does so with
+//! [`with_exposed_provenance()`] rather than an `as` cast, keeping it clean under the strict
+//! provenance lints:
+//!
+//! ``` rust
+//! use gba_rumble::regs;
+//!
+//! let siocnt = core::ptr::with_exposed_provenance::<u16>(regs::SIOCNT);
+//! ```
+//!
+//! [`with_exposed_provenance()`]: core::ptr::with_exposed_provenance()
+
+// Display.
+
+/// `DISPCNT`, the display control register.
+pub const DISPCNT: usize = 0x0400_0000;
+/// `DISPSTAT`, the display status register, including which display interrupts are requested.
+pub const DISPSTAT: usize = 0x0400_0004;
+/// `VCOUNT`, the number of the scanline currently being drawn.
+pub const VCOUNT: usize = 0x0400_0006;
+/// `BG0CNT`, the control register for background 0.
+pub const BG0CNT: usize = 0x0400_0008;
+/// `BG0HOFS`, the horizontal scroll offset of background 0.
+pub const BG0HOFS: usize = 0x0400_0010;
+/// `BG0VOFS`, the vertical scroll offset of background 0.
+pub const BG0VOFS: usize = 0x0400_0012;
+/// `WININ`, the control register for the inside of windows 0 and 1.
+pub const WININ: usize = 0x0400_0048;
+/// `WINOUT`, the control register for the outside of windows and the object window.
+pub const WINOUT: usize = 0x0400_004A;
+/// `BLDCNT`, the color special effects selection register.
+pub const BLDCNT: usize = 0x0400_0050;
+/// `BLDALPHA`, the alpha blending coefficients.
+pub const BLDALPHA: usize = 0x0400_0052;
+/// `BLDY`, the brightness coefficient.
+pub const BLDY: usize = 0x0400_0054;
+
+// DMA.
+//
+// The registers of channel `n` are found `12 * n` bytes after channel 0's.
+
+/// `DMA0SAD`, DMA 0's source address.
+pub const DMA0SAD: usize = 0x0400_00B0;
+/// `DMA0DAD`, DMA 0's destination address.
+pub const DMA0DAD: usize = 0x0400_00B4;
+/// `DMA0CNT_L`, the number of units DMA 0 transfers.
+pub const DMA0CNT_L: usize = 0x0400_00B8;
+/// `DMA0CNT_H`, DMA 0's control register.
+pub const DMA0CNT_H: usize = 0x0400_00BA;
+
+// Timers.
+//
+// The registers of timer `n` are found `4 * n` bytes after timer 0's.
+
+/// `TM0CNT_L`, timer 0's reload value on write and counter on read.
+pub const TM0CNT_L: usize = 0x0400_0100;
+/// `TM0CNT_H`, timer 0's control register.
+pub const TM0CNT_H: usize = 0x0400_0102;
+
+// Serial communication.
+
+/// `SIODATA32`, the data sent and received in 32-bit normal mode.
+pub const SIODATA32: usize = 0x0400_0120;
+/// `SIOCNT`, the serial control register.
+pub const SIOCNT: usize = 0x0400_0128;
+/// `RCNT`, which selects the serial port's communication mode.
+pub const RCNT: usize = 0x0400_0134;
+
+// Keypad.
+
+/// `KEYINPUT`, the state of each button, with pressed buttons cleared.
+pub const KEYINPUT: usize = 0x0400_0130;
+
+// Interrupts.
+
+/// `IE`, the interrupts enabled.
+pub const IE: usize = 0x0400_0200;
+/// `IF`, the interrupts requested. Writing a set bit acknowledges that interrupt.
+pub const IF: usize = 0x0400_0202;
+/// `IME`, the master interrupt enable.
+pub const IME: usize = 0x0400_0208;
+
+// Video memory.
+
+/// The start of background palette RAM.
+pub const BG_PALETTE: usize = 0x0500_0000;
+/// The start of VRAM.
+pub const VRAM: usize = 0x0600_0000;
+
+// Cartridge general purpose I/O.
+
+/// The GPIO data register. Bit 3 drives the cartridge's rumble motor.
+pub const GPIO_DATA: usize = 0x0800_00C4;
+/// The GPIO direction register, selecting whether each pin is read or written.
+pub const GPIO_DIRECTION: usize = 0x0800_00C6;
+/// The GPIO control register, enabling reads of the GPIO registers.
+pub const GPIO_CONTROL: usize = 0x0800_00C8;
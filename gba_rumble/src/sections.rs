@@ -0,0 +1,171 @@
+//! Where this crate's code and data are placed in memory.
+//!
+//! Programs with custom linker scripts can use these section names to check that everything this
+//! crate places in RAM is copied there at startup, or to route its assets into a particular
+//! region of ROM. The names reflect the enabled features, as described in the crate's
+//! [memory placement] documentation. Code and data not listed here use the compiler's default
+//! sections, such as `.text.*` and `.rodata.*`, and are placed wherever the linker script puts
+//! those.
+//!
+//! Where something actually ended up can be checked at runtime with [`Region::of()`]:
+//!
+//! ``` rust
+//! use gba_rumble::sections::Region;
+//!
+//! let handler = (gba_rumble::game_boy_player_interrupt as *const ()).addr();
+//! assert_eq!(Region::of(handler), Some(Region::Iwram));
+//! ```
+//!
+//! [memory placement]: crate#memory-placement
+
+/// A region of the GBA's memory map that code or data can be placed in.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Region {
+    /// The 256 KiB of external work RAM, starting at `0x0200_0000`.
+    Ewram,
+    /// The 32 KiB of internal work RAM, starting at `0x0300_0000`.
+    Iwram,
+    /// The cartridge ROM, starting at `0x0800_0000`, including each of its wait state mirrors.
+    Rom,
+}
+
+impl Region {
+    /// Returns the region containing `address`, or `None` if it is not in any of them.
+    ///
+    /// Mirrors of each RAM region are included, as the hardware treats them the same.
+    pub const fn of(address: usize) -> Option<Self> {
+        match address {
+            0x0200_0000..=0x02FF_FFFF => Some(Self::Ewram),
+            0x0300_0000..=0x03FF_FFFF => Some(Self::Iwram),
+            0x0800_0000..=0x0DFF_FFFF => Some(Self::Rom),
+            _ => None,
+        }
+    }
+}
+
+/// The section containing [`game_boy_player_interrupt()`].
+///
+/// The tables the handler reads are placed in sections beginning with the same region prefix,
+/// `.iwram.gba_rumble.` or `.ewram.gba_rumble.`, as is the state it uses when the `ewram-serial`
+/// feature is enabled.
+///
+/// [`game_boy_player_interrupt()`]: crate::game_boy_player_interrupt()
+pub const SERIAL_INTERRUPT: &str = if cfg!(feature = "ewram-serial") {
+    ".ewram.gba_rumble.game_boy_player_interrupt"
+} else {
+    ".iwram.gba_rumble.game_boy_player_interrupt"
+};
+
+/// The section containing [`game_boy_player_keep_alive()`].
+///
+/// [`game_boy_player_keep_alive()`]: crate::game_boy_player_keep_alive()
+pub const KEEP_ALIVE: &str = if cfg!(feature = "ewram-serial") {
+    ".ewram.gba_rumble.game_boy_player_keep_alive"
+} else {
+    ".iwram.gba_rumble.game_boy_player_keep_alive"
+};
+
+/// The section containing the interrupt dispatcher of the [`irq`] module.
+///
+/// [`irq`]: crate::irq
+pub const IRQ_DISPATCH: &str = if cfg!(feature = "ewram-serial") {
+    ".ewram.gba_rumble.irq_dispatch"
+} else {
+    ".iwram.gba_rumble.irq_dispatch"
+};
+
+/// The prefix of the sections containing the per-frame `tick()` code of [`Sequencer`],
+/// [`Mixer`], and [`Driver`], or `None` if it is left in the default `.text` sections.
+///
+/// [`Driver`]: crate::Driver
+/// [`Mixer`]: crate::Mixer
+/// [`Sequencer`]: crate::Sequencer
+pub const TICK: Option<&str> = if cfg!(feature = "iwram-tick") {
+    Some(".iwram.gba_rumble.tick.")
+} else if cfg!(feature = "ewram-tick") {
+    Some(".ewram.gba_rumble.tick.")
+} else {
+    None
+};
+
+/// The section containing [`SPLASH_PALETTE`], [`SPLASH_MAP`], and [`SPLASH_TILES`].
+///
+/// By default, this is a `.rodata` section, so it is placed in ROM along with other constant
+/// data. With the `splash-overlay` feature enabled, it is instead a section that no standard
+/// linker script matches, so that a `*(.rodata .rodata.*)` rule cannot claim it before a rule
+/// placing it in its own region of ROM. The linker script must then place it explicitly.
+///
+/// [`SPLASH_MAP`]: crate::SPLASH_MAP
+/// [`SPLASH_PALETTE`]: crate::SPLASH_PALETTE
+/// [`SPLASH_TILES`]: crate::SPLASH_TILES
+pub const SPLASH: &str = if cfg!(feature = "splash-overlay") {
+    ".gba_rumble_overlay.splash"
+} else {
+    ".rodata.gba_rumble.splash"
+};
+
+#[cfg(test)]
+mod tests {
+    use super::Region;
+    use crate::{
+        Command, Driver, Intensity, Mixer, RumbleSpy, SPLASH_TILES, Sequencer,
+        game_boy_player_interrupt, game_boy_player_keep_alive,
+    };
+    use gba_test::test;
+
+    /// The region code and constant data are run from when not placed in RAM.
+    #[cfg(not(multiboot))]
+    const IMAGE: Region = Region::Rom;
+    #[cfg(multiboot)]
+    const IMAGE: Region = Region::Ewram;
+
+    const SERIAL: Region = if cfg!(feature = "ewram-serial") {
+        Region::Ewram
+    } else {
+        Region::Iwram
+    };
+
+    const TICK: Region = if cfg!(feature = "iwram-tick") {
+        Region::Iwram
+    } else if cfg!(feature = "ewram-tick") {
+        Region::Ewram
+    } else {
+        IMAGE
+    };
+
+    #[test]
+    fn region_of() {
+        assert_eq!(Region::of(0x0200_0000), Some(Region::Ewram));
+        assert_eq!(Region::of(0x0300_7FFF), Some(Region::Iwram));
+        assert_eq!(Region::of(0x0A00_0000), Some(Region::Rom));
+        assert_eq!(Region::of(0x0400_0000), None);
+    }
+
+    #[test]
+    fn serial_placement() {
+        assert_eq!(
+            Region::of((game_boy_player_interrupt as *const ()).addr()),
+            Some(SERIAL)
+        );
+        assert_eq!(
+            Region::of((game_boy_player_keep_alive as *const ()).addr()),
+            Some(SERIAL)
+        );
+    }
+
+    #[test]
+    fn tick_placement() {
+        let sequencer: fn(&mut Sequencer<'static>) -> Intensity = Sequencer::tick;
+        let mixer: fn(&mut Mixer<'static, 1>) -> Intensity = Mixer::tick;
+        let driver: fn(&mut Driver<RumbleSpy>, Intensity) -> Option<Command> = Driver::tick;
+
+        assert_eq!(Region::of((sequencer as *const ()).addr()), Some(TICK));
+        assert_eq!(Region::of((mixer as *const ()).addr()), Some(TICK));
+        assert_eq!(Region::of((driver as *const ()).addr()), Some(TICK));
+    }
+
+    #[test]
+    fn splash_placement() {
+        assert_eq!(Region::of(SPLASH_TILES.as_ptr().addr()), Some(IMAGE));
+    }
+}
@@ -0,0 +1,171 @@
+//! Checking that rumble reaches the motor.
+
+use crate::{Backend, Command};
+use core::{
+    fmt,
+    fmt::{Display, Formatter},
+    ops::ControlFlow,
+};
+
+/// The result of a self-test started by [`Driver::self_test()`].
+///
+/// The test pulses the motor on for [`PULSE_FRAMES`] frames, then off for as many again. The
+/// backend is asked through [`Backend::read_back()`] whether the motor is on at the end of each
+/// half, which confirms the pulse where the hardware allows it: [`Gpio`] reads back its rumble pin,
+/// and a [`GameBoyPlayer`] reports the state last acknowledged by a transfer.
+///
+/// Its [`Display`] implementation writes a short summary, suitable for showing next to a "test
+/// vibration" option.
+///
+/// [`Driver::self_test()`]: crate::Driver::self_test()
+/// [`GameBoyPlayer`]: crate::GameBoyPlayer
+/// [`Gpio`]: crate::Gpio
+/// [`PULSE_FRAMES`]: SelfTest::PULSE_FRAMES
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SelfTest {
+    /// Whether rumble was enabled using [`set_enabled()`] when the test began.
+    ///
+    /// While disabled, backends ignore the pulse entirely.
+    ///
+    /// [`set_enabled()`]: crate::set_enabled()
+    pub enabled: bool,
+    /// Whether the motor was confirmed to start, or `None` if the backend cannot tell.
+    pub started: Option<bool>,
+    /// Whether the motor was confirmed to stop again, or `None` if the backend cannot tell.
+    pub stopped: Option<bool>,
+}
+
+impl SelfTest {
+    /// The number of frames the motor is pulsed on for, and then left off for.
+    pub const PULSE_FRAMES: u8 = 15;
+
+    /// Returns whether nothing went wrong.
+    ///
+    /// This is `true` if rumble was enabled and the backend observed no failure, including when it
+    /// cannot observe its motor at all.
+    pub const fn passed(&self) -> bool {
+        self.enabled && !matches!(self.started, Some(false)) && !matches!(self.stopped, Some(false))
+    }
+
+    /// Returns whether the backend confirmed both that the motor started and that it stopped.
+    pub const fn is_confirmed(&self) -> bool {
+        matches!(self.started, Some(true)) && matches!(self.stopped, Some(true))
+    }
+}
+
+impl Display for SelfTest {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(if !self.enabled {
+            "rumble is disabled"
+        } else if self.started == Some(false) {
+            "motor did not start"
+        } else if self.stopped == Some(false) {
+            "motor did not stop"
+        } else if self.is_confirmed() {
+            "motor started and stopped"
+        } else {
+            "pulse sent"
+        })
+    }
+}
+
+/// A self-test in progress.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct SelfTestRun {
+    frame: u8,
+    enabled: bool,
+    started: Option<bool>,
+}
+
+impl SelfTestRun {
+    pub(crate) const fn new() -> Self {
+        Self {
+            frame: 0,
+            enabled: false,
+            started: None,
+        }
+    }
+
+    /// Advances the test by a frame, continuing with the command to send, or breaking with the
+    /// result once the test has finished.
+    pub(crate) fn tick<B>(&mut self, backend: &B) -> ControlFlow<SelfTest, Option<Command>>
+    where
+        B: Backend,
+    {
+        let command = match self.frame {
+            0 => {
+                self.enabled = crate::is_enabled();
+                Some(Command::Start)
+            }
+            SelfTest::PULSE_FRAMES => {
+                self.started = backend.read_back();
+                Some(Command::Stop)
+            }
+            frame if frame == SelfTest::PULSE_FRAMES * 2 => {
+                return ControlFlow::Break(SelfTest {
+                    enabled: self.enabled,
+                    started: self.started,
+                    stopped: backend.read_back().map(|on| !on),
+                });
+            }
+            _ => None,
+        };
+        self.frame += 1;
+        ControlFlow::Continue(command)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SelfTest;
+    use alloc::format;
+    use gba_test::test;
+
+    const CONFIRMED: SelfTest = SelfTest {
+        enabled: true,
+        started: Some(true),
+        stopped: Some(true),
+    };
+
+    #[test]
+    fn confirmed() {
+        assert!(CONFIRMED.passed());
+        assert!(CONFIRMED.is_confirmed());
+        assert_eq!(format!("{CONFIRMED}"), "motor started and stopped");
+    }
+
+    #[test]
+    fn unobservable() {
+        let result = SelfTest {
+            started: None,
+            stopped: None,
+            ..CONFIRMED
+        };
+
+        assert!(result.passed());
+        assert!(!result.is_confirmed());
+        assert_eq!(format!("{result}"), "pulse sent");
+    }
+
+    #[test]
+    fn did_not_start() {
+        let result = SelfTest {
+            started: Some(false),
+            ..CONFIRMED
+        };
+
+        assert!(!result.passed());
+        assert_eq!(format!("{result}"), "motor did not start");
+    }
+
+    #[test]
+    fn disabled() {
+        let result = SelfTest {
+            enabled: false,
+            ..CONFIRMED
+        };
+
+        assert!(!result.passed());
+        assert_eq!(format!("{result}"), "rumble is disabled");
+    }
+}
@@ -0,0 +1,843 @@
+//! Playback of rumble patterns.
+
+use crate::{
+    EffectParams, Intensity, Marker, Pattern, SaveState, StateError, Step,
+    state::{StateReader, StateWriter},
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum State {
+    /// Holding the current intensity for `remaining` more frames before moving to the next step.
+    Holding { remaining: u8 },
+    /// Waiting at a marker until it is advanced past.
+    Paused(Marker),
+    /// All steps have been played.
+    Finished,
+}
+
+/// The loop step currently being repeated.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct ActiveLoop {
+    index: usize,
+    remaining: u8,
+}
+
+/// A crossfade from a previous pattern into the current one.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Fade {
+    /// The intensity being faded out of.
+    from: Intensity,
+    /// Frames of the fade played so far.
+    frame: u8,
+    frames: u8,
+}
+
+impl Fade {
+    /// Advances the fade by one frame, blending towards `to`.
+    #[cfg_attr(
+        feature = "iwram-tick",
+        unsafe(link_section = ".iwram.gba_rumble.tick.sequencer_fade")
+    )]
+    #[cfg_attr(
+        all(feature = "ewram-tick", not(feature = "iwram-tick")),
+        unsafe(link_section = ".ewram.gba_rumble.tick.sequencer_fade")
+    )]
+    fn tick(&mut self, to: Intensity) -> Intensity {
+        self.frame += 1;
+        let from = self.from.get() as i32;
+        let delta = to.get() as i32 - from;
+        Intensity::new((from + delta * self.frame as i32 / self.frames as i32) as u8)
+    }
+
+    fn is_finished(&self) -> bool {
+        self.frame >= self.frames
+    }
+}
+
+/// Plays back a [`Pattern`] one frame at a time.
+///
+/// Call [`tick()`] once per frame to obtain the intensity that should be output for that frame.
+/// When the pattern reaches a [`Step::Marker`], playback pauses at the current intensity until
+/// [`advance()`] is called with that marker.
+///
+/// ``` rust
+/// use gba_rumble::{Intensity, Marker, Pattern, Sequencer, Step, pattern};
+///
+/// const RELEASE: Marker = Marker::new(0);
+/// const CHARGE: Pattern = pattern![
+///     Step::Hold { intensity: Intensity::new(64), frames: 30 },
+///     Step::Marker(RELEASE),
+///     Step::Hold { intensity: Intensity::MAX, frames: 15 },
+/// ];
+///
+/// let mut sequencer = Sequencer::new(CHARGE);
+/// // While the button is held, the sequencer holds at the marker.
+/// let intensity = sequencer.tick();
+/// // Once the button is released, the final burst plays.
+/// sequencer.advance(RELEASE);
+/// ```
+///
+/// [`advance()`]: Sequencer::advance()
+/// [`tick()`]: Sequencer::tick()
+#[derive(Clone, Debug)]
+pub struct Sequencer<'a> {
+    pattern: Pattern<'a>,
+    /// Byte offset of the next step.
+    offset: usize,
+    /// Index of the next step.
+    index: usize,
+    intensity: Intensity,
+    state: State,
+    active_loop: Option<ActiveLoop>,
+    /// The intensity returned by the most recent tick.
+    output: Intensity,
+    fade: Option<Fade>,
+    params: EffectParams,
+}
+
+impl<'a> Sequencer<'a> {
+    /// Creates a sequencer positioned at the beginning of `pattern`.
+    pub const fn new(pattern: Pattern<'a>) -> Self {
+        Self::with_params(pattern, EffectParams::new())
+    }
+
+    /// Creates a sequencer positioned at the beginning of `pattern`, which is played adjusted by
+    /// `params`.
+    pub const fn with_params(pattern: Pattern<'a>, params: EffectParams) -> Self {
+        Self {
+            pattern,
+            offset: 0,
+            index: 0,
+            intensity: Intensity::OFF,
+            state: State::Holding { remaining: 0 },
+            active_loop: None,
+            output: Intensity::OFF,
+            fade: None,
+            params,
+        }
+    }
+
+    /// Returns the pattern being played.
+    pub const fn pattern(&self) -> Pattern<'a> {
+        self.pattern
+    }
+
+    /// Returns the parameters the pattern is played with.
+    pub const fn params(&self) -> EffectParams {
+        self.params
+    }
+
+    /// Advances playback by one frame, returning the intensity for that frame.
+    ///
+    /// Once the pattern has finished, this always returns [`Intensity::OFF`].
+    ///
+    /// Because patterns cannot contain zero-frame holds or nested loops, a single tick processes at
+    /// most three steps, no matter the pattern.
+    #[cfg_attr(any(feature = "iwram-tick", feature = "ewram-tick"), inline(never))]
+    #[cfg_attr(
+        feature = "iwram-tick",
+        unsafe(link_section = ".iwram.gba_rumble.tick.sequencer")
+    )]
+    #[cfg_attr(
+        all(feature = "ewram-tick", not(feature = "iwram-tick")),
+        unsafe(link_section = ".ewram.gba_rumble.tick.sequencer")
+    )]
+    pub fn tick(&mut self) -> Intensity {
+        let mut intensity = self.step();
+        if let Some(fade) = &mut self.fade {
+            intensity = fade.tick(intensity);
+            if fade.is_finished() {
+                self.fade = None;
+            }
+        }
+        self.output = intensity;
+        intensity
+    }
+
+    /// Returns the intensity output by the most recent [`tick()`], without advancing playback.
+    ///
+    /// Before the first tick, this is [`Intensity::OFF`].
+    ///
+    /// [`tick()`]: Sequencer::tick()
+    pub const fn output(&self) -> Intensity {
+        self.output
+    }
+
+    /// Switches playback to `pattern`, crossfading into it over `frames` frames.
+    ///
+    /// Rather than cutting off the current pattern, the output ramps linearly from the most
+    /// recently output intensity to the new pattern's intensity, which plays from its beginning
+    /// during the fade. This suits smooth changes between looping patterns, such as an engine
+    /// going from idle to full throttle. A `frames` value of `0` switches immediately. The new
+    /// pattern is played as authored, without the current [`params()`].
+    ///
+    /// ``` rust
+    /// use gba_rumble::{Intensity, Sequencer, Step, pattern};
+    ///
+    /// let mut sequencer = Sequencer::new(pattern![Step::Hold {
+    ///     intensity: Intensity::new(40),
+    ///     frames: 60,
+    /// }]);
+    /// assert_eq!(sequencer.tick(), Intensity::new(40));
+    ///
+    /// sequencer.transition_to(
+    ///     pattern![Step::Hold { intensity: Intensity::new(200), frames: 60 }],
+    ///     4,
+    /// );
+    /// assert_eq!(sequencer.tick(), Intensity::new(80));
+    /// assert_eq!(sequencer.tick(), Intensity::new(120));
+    /// assert_eq!(sequencer.tick(), Intensity::new(160));
+    /// assert_eq!(sequencer.tick(), Intensity::new(200));
+    /// ```
+    ///
+    /// [`params()`]: Sequencer::params()
+    pub fn transition_to(&mut self, pattern: Pattern<'a>, frames: u8) {
+        let from = self.output;
+        *self = Self::new(pattern);
+        self.output = from;
+        if frames > 0 {
+            self.fade = Some(Fade {
+                from,
+                frame: 0,
+                frames,
+            });
+        }
+    }
+
+    /// Returns whether a crossfade started by [`transition_to()`] is in progress.
+    ///
+    /// [`transition_to()`]: Sequencer::transition_to()
+    pub fn is_transitioning(&self) -> bool {
+        self.fade.is_some()
+    }
+
+    /// Advances the pattern by one frame, returning its unfaded intensity.
+    #[cfg_attr(
+        feature = "iwram-tick",
+        unsafe(link_section = ".iwram.gba_rumble.tick.sequencer_step")
+    )]
+    #[cfg_attr(
+        all(feature = "ewram-tick", not(feature = "iwram-tick")),
+        unsafe(link_section = ".ewram.gba_rumble.tick.sequencer_step")
+    )]
+    fn step(&mut self) -> Intensity {
+        loop {
+            match self.state {
+                State::Holding { remaining: 0 } => self.next_step(),
+                State::Holding { remaining } => {
+                    self.state = State::Holding {
+                        remaining: remaining - 1,
+                    };
+                    return self.intensity;
+                }
+                State::Paused(_) => return self.intensity,
+                State::Finished => return Intensity::OFF,
+            }
+        }
+    }
+
+    /// Continues playback past `marker`.
+    ///
+    /// If playback is paused at `marker`, it resumes on the next frame. If `marker` has not been
+    /// reached yet, playback skips ahead to the step following it. This means a charge can be
+    /// released early, before the pattern has made it to the marker.
+    ///
+    /// Returns `false` if `marker` does not appear in the remainder of the pattern.
+    pub fn advance(&mut self, marker: Marker) -> bool {
+        if self.state == State::Paused(marker) {
+            self.state = State::Holding { remaining: 0 };
+            return true;
+        }
+
+        let mut offset = self.offset;
+        let mut index = self.index;
+        while let Some((step, next_offset)) = self.pattern.step_at(offset) {
+            offset = next_offset;
+            index += 1;
+            if step == Step::Marker(marker) {
+                self.offset = offset;
+                self.index = index;
+                self.state = State::Holding { remaining: 0 };
+                // Any loop that was skipped over is abandoned.
+                self.active_loop = None;
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Returns the marker playback is currently paused at, if any.
+    pub fn paused_at(&self) -> Option<Marker> {
+        match self.state {
+            State::Paused(marker) => Some(marker),
+            _ => None,
+        }
+    }
+
+    /// Returns whether every step of the pattern has been played.
+    ///
+    /// A pattern that finishes during a crossfade is not finished until the crossfade completes.
+    pub fn is_finished(&self) -> bool {
+        self.state == State::Finished && self.fade.is_none()
+    }
+
+    /// Restarts playback from the beginning of the pattern, keeping its parameters.
+    pub fn restart(&mut self) {
+        *self = Self::with_params(self.pattern, self.params);
+    }
+
+    /// Moves on to the next step of the pattern.
+    #[cfg_attr(
+        feature = "iwram-tick",
+        unsafe(link_section = ".iwram.gba_rumble.tick.sequencer_next_step")
+    )]
+    #[cfg_attr(
+        all(feature = "ewram-tick", not(feature = "iwram-tick")),
+        unsafe(link_section = ".ewram.gba_rumble.tick.sequencer_next_step")
+    )]
+    fn next_step(&mut self) {
+        let Some((step, next_offset)) = self.pattern.step_at(self.offset) else {
+            self.intensity = Intensity::OFF;
+            self.state = State::Finished;
+            return;
+        };
+        let index = self.index;
+        self.offset = next_offset;
+        self.index += 1;
+
+        match step {
+            Step::Hold { intensity, frames } => {
+                let (intensity, frames) = self.params.apply(intensity, frames);
+                self.intensity = intensity;
+                self.state = State::Holding { remaining: frames };
+            }
+            Step::Marker(marker) => self.state = State::Paused(marker),
+            Step::Loop { step, count } => {
+                let remaining = match self.active_loop {
+                    Some(active_loop) if active_loop.index == index => active_loop.remaining,
+                    _ => count,
+                };
+                if count == 0 || remaining > 0 {
+                    self.active_loop = Some(ActiveLoop {
+                        index,
+                        remaining: remaining.saturating_sub(1),
+                    });
+                    // Loop targets are validated to be earlier steps, so the offset always exists.
+                    self.offset = self.pattern.offset_of(step as usize).unwrap_or(0);
+                    self.index = step as usize;
+                } else {
+                    self.active_loop = None;
+                }
+            }
+        }
+    }
+}
+
+impl SaveState for Sequencer<'_> {
+    const STATE_LEN: usize = 22;
+
+    fn serialize_state(&self, buffer: &mut [u8]) -> Result<usize, StateError> {
+        let mut writer = StateWriter::new(buffer, Self::STATE_LEN)?;
+        writer.u32(self.offset as u32);
+        writer.u32(self.index as u32);
+        writer.u8(self.intensity.get());
+        match self.state {
+            State::Holding { remaining } => {
+                writer.u8(0);
+                writer.u8(remaining);
+            }
+            State::Paused(marker) => {
+                writer.u8(1);
+                writer.u8(marker.id());
+            }
+            State::Finished => {
+                writer.u8(2);
+                writer.u8(0);
+            }
+        }
+        match self.active_loop {
+            Some(active_loop) => {
+                writer.u8(1);
+                writer.u32(active_loop.index as u32);
+                writer.u8(active_loop.remaining);
+            }
+            None => {
+                writer.u8(0);
+                writer.u32(0);
+                writer.u8(0);
+            }
+        }
+        writer.u8(self.output.get());
+        match self.fade {
+            Some(fade) => {
+                writer.u8(1);
+                writer.u8(fade.from.get());
+                writer.u8(fade.frame);
+                writer.u8(fade.frames);
+            }
+            None => {
+                writer.u8(0);
+                writer.u8(0);
+                writer.u8(0);
+                writer.u8(0);
+            }
+        }
+        Ok(writer.finish())
+    }
+
+    fn restore_state(&mut self, buffer: &[u8]) -> Result<usize, StateError> {
+        let mut reader = StateReader::new(buffer, Self::STATE_LEN)?;
+        let offset = reader.u32()? as usize;
+        let index = reader.u32()? as usize;
+        // The position must be the start of a step of the pattern being played.
+        if self.pattern.offset_of(index) != Some(offset) {
+            return Err(StateError::Invalid);
+        }
+        let intensity = Intensity::new(reader.u8()?);
+        let state = match (reader.u8()?, reader.u8()?) {
+            (0, remaining) => State::Holding { remaining },
+            (1, id) => State::Paused(Marker::new(id)),
+            (2, _) => State::Finished,
+            _ => return Err(StateError::Invalid),
+        };
+        let active_loop = match (reader.bool()?, reader.u32()? as usize, reader.u8()?) {
+            (true, index, remaining) => {
+                let loop_step = self
+                    .pattern
+                    .offset_of(index)
+                    .and_then(|offset| self.pattern.step_at(offset));
+                if !matches!(loop_step, Some((Step::Loop { .. }, _))) {
+                    return Err(StateError::Invalid);
+                }
+                Some(ActiveLoop { index, remaining })
+            }
+            (false, ..) => None,
+        };
+        let output = Intensity::new(reader.u8()?);
+        let fade = match (reader.bool()?, reader.u8()?, reader.u8()?, reader.u8()?) {
+            (true, from, frame, frames) => {
+                if frame >= frames {
+                    return Err(StateError::Invalid);
+                }
+                Some(Fade {
+                    from: Intensity::new(from),
+                    frame,
+                    frames,
+                })
+            }
+            (false, ..) => None,
+        };
+
+        self.offset = offset;
+        self.index = index;
+        self.intensity = intensity;
+        self.state = state;
+        self.active_loop = active_loop;
+        self.output = output;
+        self.fade = fade;
+        Ok(reader.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sequencer;
+    use crate::{EffectParams, Fixed, Intensity, Marker, SaveState, StateError, Step, pattern};
+    use claims::{assert_err_eq, assert_ok_eq};
+    use gba_test::test;
+
+    const RELEASE: Marker = Marker::new(0);
+
+    #[test]
+    fn empty() {
+        let mut sequencer = Sequencer::new(pattern![]);
+
+        assert_eq!(sequencer.tick(), Intensity::OFF);
+        assert!(sequencer.is_finished());
+    }
+
+    #[test]
+    fn hold() {
+        let mut sequencer = Sequencer::new(pattern![
+            Step::Hold {
+                intensity: Intensity::new(10),
+                frames: 2
+            },
+            Step::Hold {
+                intensity: Intensity::new(20),
+                frames: 1
+            },
+        ]);
+
+        assert_eq!(sequencer.tick(), Intensity::new(10));
+        assert_eq!(sequencer.tick(), Intensity::new(10));
+        assert_eq!(sequencer.tick(), Intensity::new(20));
+        assert!(!sequencer.is_finished());
+        assert_eq!(sequencer.tick(), Intensity::OFF);
+        assert!(sequencer.is_finished());
+    }
+
+    #[test]
+    fn with_params() {
+        let mut sequencer = Sequencer::with_params(
+            pattern![Step::Hold {
+                intensity: Intensity::new(200),
+                frames: 1
+            }],
+            EffectParams::new()
+                .with_scale(Fixed::HALF)
+                .with_duration(Fixed::from_int(2)),
+        );
+
+        assert_eq!(sequencer.tick(), Intensity::new(100));
+        assert_eq!(sequencer.tick(), Intensity::new(100));
+        assert_eq!(sequencer.tick(), Intensity::OFF);
+    }
+
+    #[test]
+    fn restart_keeps_params() {
+        let params = EffectParams::new().with_scale(Fixed::HALF);
+        let mut sequencer = Sequencer::with_params(
+            pattern![Step::Hold {
+                intensity: Intensity::new(200),
+                frames: 1
+            }],
+            params,
+        );
+        sequencer.tick();
+
+        sequencer.restart();
+
+        assert_eq!(sequencer.params(), params);
+        assert_eq!(sequencer.tick(), Intensity::new(100));
+    }
+
+    #[test]
+    fn marker_pauses() {
+        let mut sequencer = Sequencer::new(pattern![
+            Step::Hold {
+                intensity: Intensity::new(10),
+                frames: 1
+            },
+            Step::Marker(RELEASE),
+            Step::Hold {
+                intensity: Intensity::MAX,
+                frames: 1
+            },
+        ]);
+
+        assert_eq!(sequencer.tick(), Intensity::new(10));
+        assert_eq!(sequencer.tick(), Intensity::new(10));
+        assert_eq!(sequencer.paused_at(), Some(RELEASE));
+        assert_eq!(sequencer.tick(), Intensity::new(10));
+        assert!(sequencer.advance(RELEASE));
+        assert_eq!(sequencer.paused_at(), None);
+        assert_eq!(sequencer.tick(), Intensity::MAX);
+        assert_eq!(sequencer.tick(), Intensity::OFF);
+    }
+
+    #[test]
+    fn advance_skips_ahead() {
+        let mut sequencer = Sequencer::new(pattern![
+            Step::Hold {
+                intensity: Intensity::new(10),
+                frames: 30
+            },
+            Step::Marker(RELEASE),
+            Step::Hold {
+                intensity: Intensity::MAX,
+                frames: 1
+            },
+        ]);
+
+        assert_eq!(sequencer.tick(), Intensity::new(10));
+        assert!(sequencer.advance(RELEASE));
+        assert_eq!(sequencer.tick(), Intensity::MAX);
+    }
+
+    #[test]
+    fn advance_unknown_marker() {
+        let mut sequencer = Sequencer::new(pattern![
+            Step::Hold {
+                intensity: Intensity::new(10),
+                frames: 1
+            },
+            Step::Marker(RELEASE),
+        ]);
+
+        assert!(!sequencer.advance(Marker::new(1)));
+        assert_eq!(sequencer.tick(), Intensity::new(10));
+    }
+
+    #[test]
+    fn advance_passed_marker() {
+        let mut sequencer = Sequencer::new(pattern![
+            Step::Marker(RELEASE),
+            Step::Hold {
+                intensity: Intensity::MAX,
+                frames: 1
+            },
+        ]);
+
+        sequencer.tick();
+        assert!(sequencer.advance(RELEASE));
+        assert!(!sequencer.advance(RELEASE));
+    }
+
+    #[test]
+    fn loop_count() {
+        let mut sequencer = Sequencer::new(pattern![
+            Step::Hold {
+                intensity: Intensity::MAX,
+                frames: 1
+            },
+            Step::Hold {
+                intensity: Intensity::OFF,
+                frames: 1
+            },
+            Step::Loop { step: 0, count: 1 },
+            Step::Hold {
+                intensity: Intensity::new(5),
+                frames: 1
+            },
+        ]);
+
+        assert_eq!(sequencer.tick(), Intensity::MAX);
+        assert_eq!(sequencer.tick(), Intensity::OFF);
+        assert_eq!(sequencer.tick(), Intensity::MAX);
+        assert_eq!(sequencer.tick(), Intensity::OFF);
+        assert_eq!(sequencer.tick(), Intensity::new(5));
+        assert_eq!(sequencer.tick(), Intensity::OFF);
+        assert!(sequencer.is_finished());
+    }
+
+    #[test]
+    fn loop_forever() {
+        let mut sequencer = Sequencer::new(pattern![
+            Step::Hold {
+                intensity: Intensity::MAX,
+                frames: 1
+            },
+            Step::Loop { step: 0, count: 0 },
+        ]);
+
+        for _ in 0..300 {
+            assert_eq!(sequencer.tick(), Intensity::MAX);
+        }
+        assert!(!sequencer.is_finished());
+    }
+
+    #[test]
+    fn restart() {
+        let mut sequencer = Sequencer::new(pattern![Step::Hold {
+            intensity: Intensity::MAX,
+            frames: 1
+        }]);
+
+        sequencer.tick();
+        sequencer.tick();
+        assert!(sequencer.is_finished());
+        sequencer.restart();
+        assert_eq!(sequencer.tick(), Intensity::MAX);
+    }
+
+    #[test]
+    fn transition_to() {
+        let mut sequencer = Sequencer::new(pattern![Step::Hold {
+            intensity: Intensity::new(100),
+            frames: 10
+        }]);
+        sequencer.tick();
+
+        sequencer.transition_to(
+            pattern![
+                Step::Hold {
+                    intensity: Intensity::new(200),
+                    frames: 2
+                },
+                Step::Hold {
+                    intensity: Intensity::new(40),
+                    frames: 10
+                },
+            ],
+            3,
+        );
+
+        assert!(sequencer.is_transitioning());
+        // Blends towards the new pattern's intensity each frame, following it as it changes.
+        assert_eq!(sequencer.tick(), Intensity::new(133));
+        assert_eq!(sequencer.tick(), Intensity::new(166));
+        assert_eq!(sequencer.tick(), Intensity::new(40));
+        assert!(!sequencer.is_transitioning());
+        assert_eq!(sequencer.tick(), Intensity::new(40));
+    }
+
+    #[test]
+    fn transition_to_decreasing() {
+        let mut sequencer = Sequencer::new(pattern![Step::Hold {
+            intensity: Intensity::MAX,
+            frames: 10
+        }]);
+        sequencer.tick();
+
+        sequencer.transition_to(
+            pattern![Step::Hold {
+                intensity: Intensity::OFF,
+                frames: 10
+            }],
+            2,
+        );
+
+        assert_eq!(sequencer.tick(), Intensity::new(128));
+        assert_eq!(sequencer.tick(), Intensity::OFF);
+    }
+
+    #[test]
+    fn transition_to_immediate() {
+        let mut sequencer = Sequencer::new(pattern![Step::Hold {
+            intensity: Intensity::new(100),
+            frames: 10
+        }]);
+        sequencer.tick();
+
+        sequencer.transition_to(
+            pattern![Step::Hold {
+                intensity: Intensity::new(200),
+                frames: 1
+            }],
+            0,
+        );
+
+        assert!(!sequencer.is_transitioning());
+        assert_eq!(sequencer.tick(), Intensity::new(200));
+    }
+
+    #[test]
+    fn transition_to_finished_pattern() {
+        let mut sequencer = Sequencer::new(pattern![Step::Hold {
+            intensity: Intensity::new(100),
+            frames: 10
+        }]);
+        sequencer.tick();
+
+        sequencer.transition_to(pattern![], 2);
+
+        assert_eq!(sequencer.tick(), Intensity::new(50));
+        assert!(!sequencer.is_finished());
+        assert_eq!(sequencer.tick(), Intensity::OFF);
+        assert!(sequencer.is_finished());
+    }
+
+    #[test]
+    fn tick_placement() {
+        let address = (Sequencer::tick as *const ()).addr();
+
+        #[cfg(feature = "iwram-tick")]
+        assert!((0x0300_0000..0x0300_8000).contains(&address));
+        #[cfg(all(feature = "ewram-tick", not(feature = "iwram-tick")))]
+        assert!((0x0200_0000..0x0204_0000).contains(&address));
+        #[cfg(all(
+            not(any(feature = "iwram-tick", feature = "ewram-tick")),
+            not(multiboot)
+        ))]
+        assert!(address >= 0x0800_0000);
+        // Multiboot programs run from EWRAM in place of ROM.
+        #[cfg(all(not(any(feature = "iwram-tick", feature = "ewram-tick")), multiboot))]
+        assert!((0x0200_0000..0x0204_0000).contains(&address));
+    }
+
+    #[test]
+    fn save_state_round_trip() {
+        let pattern = pattern![
+            Step::Hold {
+                intensity: Intensity::new(10),
+                frames: 2
+            },
+            Step::Hold {
+                intensity: Intensity::new(20),
+                frames: 1
+            },
+            Step::Loop { step: 0, count: 2 },
+            Step::Marker(RELEASE),
+        ];
+        let mut sequencer = Sequencer::new(pattern);
+        sequencer.tick();
+        sequencer.tick();
+        sequencer.tick();
+        sequencer.tick();
+        let mut buffer = [0; Sequencer::STATE_LEN];
+
+        assert_ok_eq!(sequencer.serialize_state(&mut buffer), Sequencer::STATE_LEN);
+
+        let mut restored = Sequencer::new(pattern);
+
+        assert_ok_eq!(restored.restore_state(&buffer), Sequencer::STATE_LEN);
+        for _ in 0..8 {
+            assert_eq!(restored.tick(), sequencer.tick());
+            assert_eq!(restored.paused_at(), sequencer.paused_at());
+        }
+    }
+
+    #[test]
+    fn save_state_transition() {
+        let mut sequencer = Sequencer::new(pattern![Step::Hold {
+            intensity: Intensity::new(100),
+            frames: 10
+        }]);
+        sequencer.tick();
+        let next = pattern![Step::Hold {
+            intensity: Intensity::new(200),
+            frames: 10
+        }];
+        sequencer.transition_to(next, 4);
+        sequencer.tick();
+        let mut buffer = [0; Sequencer::STATE_LEN];
+        assert_ok_eq!(sequencer.serialize_state(&mut buffer), Sequencer::STATE_LEN);
+
+        let mut restored = Sequencer::new(next);
+
+        assert_ok_eq!(restored.restore_state(&buffer), Sequencer::STATE_LEN);
+        assert!(restored.is_transitioning());
+        for _ in 0..4 {
+            assert_eq!(restored.tick(), sequencer.tick());
+        }
+    }
+
+    #[test]
+    fn restore_state_different_pattern() {
+        let mut sequencer = Sequencer::new(pattern![
+            Step::Hold {
+                intensity: Intensity::new(10),
+                frames: 1
+            },
+            Step::Hold {
+                intensity: Intensity::new(20),
+                frames: 1
+            },
+        ]);
+        sequencer.tick();
+        sequencer.tick();
+        let mut buffer = [0; Sequencer::STATE_LEN];
+        assert_ok_eq!(sequencer.serialize_state(&mut buffer), Sequencer::STATE_LEN);
+
+        let mut other = Sequencer::new(pattern![Step::Marker(RELEASE)]);
+
+        assert_err_eq!(other.restore_state(&buffer), StateError::Invalid);
+        assert_eq!(other.tick(), Intensity::OFF);
+        assert_eq!(other.paused_at(), Some(RELEASE));
+    }
+
+    #[test]
+    fn save_state_buffer_too_small() {
+        let mut sequencer = Sequencer::new(pattern![]);
+
+        assert_err_eq!(
+            sequencer.serialize_state(&mut [0; Sequencer::STATE_LEN - 1]),
+            StateError::BufferTooSmall
+        );
+        assert_err_eq!(
+            sequencer.restore_state(&[0; Sequencer::STATE_LEN - 1]),
+            StateError::BufferTooSmall
+        );
+    }
+}
@@ -0,0 +1,57 @@
+//! Low-level access to the serial session with the Game Boy Player.
+//!
+//! The Game Boy Player talks to the GBA over the same serial link it uses to relay the GameCube
+//! controller. Only the words described in [`protocol`] are known to carry meaning, but the
+//! Game Boy Player may reveal more, such as controller state polled alongside rumble requests.
+//! This module exposes every word received, so that this can be researched without patching the
+//! crate. The words are recorded by [`game_boy_player_interrupt()`] as they arrive.
+//!
+//! Nothing here is needed to perform rumble, and the meaning of the words beyond [`protocol`] is
+//! unknown.
+//!
+//! ``` rust
+//! use gba_rumble::session;
+//!
+//! let mut last = None;
+//! // Once a frame:
+//! if let Some(inbound) = session::raw_inbound()
+//!     && last != Some(inbound.sequence)
+//! {
+//!     last = Some(inbound.sequence);
+//!     // Inspect `inbound.word`.
+//! }
+//! ```
+//!
+//! [`game_boy_player_interrupt()`]: crate::game_boy_player_interrupt()
+//! [`protocol`]: crate::protocol
+
+use crate::game_boy_player::{GAME_BOY_PLAYER_INBOUND, GAME_BOY_PLAYER_INBOUND_COUNT};
+
+/// A word received from the Game Boy Player.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct RawInbound {
+    /// The word as received in `SIODATA32`.
+    pub word: u32,
+    /// The number of words received so far, including this one, wrapping on overflow.
+    ///
+    /// This changes with every word received, even when the same word is received again, so it
+    /// can be used to tell when a new word has arrived.
+    pub sequence: u32,
+}
+
+/// Returns the most recent word received from the Game Boy Player, or `None` if none has been
+/// received.
+///
+/// Only the most recent word is kept. Since the Game Boy Player typically sends one word a frame,
+/// calling this once a frame observes nearly every word, and [`RawInbound::sequence`] reveals any
+/// that were missed.
+pub fn raw_inbound() -> Option<RawInbound> {
+    loop {
+        let sequence = unsafe { (&raw const GAME_BOY_PLAYER_INBOUND_COUNT).read_volatile() };
+        let word = unsafe { (&raw const GAME_BOY_PLAYER_INBOUND).read_volatile() };
+        // A word received while reading would leave the two values mismatched, so try again.
+        if sequence == unsafe { (&raw const GAME_BOY_PLAYER_INBOUND_COUNT).read_volatile() } {
+            return (sequence != 0).then_some(RawInbound { word, sequence });
+        }
+    }
+}
@@ -0,0 +1,197 @@
+//! Player-facing rumble settings.
+
+use crate::Intensity;
+
+/// Rumble settings chosen by the player, such as in an options menu.
+///
+/// These are honored by a [`Driver`] for everything it outputs, regardless of which effects are
+/// playing. They can be persisted across reboots by implementing [`SettingsStore`] over the
+/// game's own save code.
+///
+/// [`Driver`]: crate::Driver
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Settings {
+    /// Whether rumble is enabled at all.
+    pub enabled: bool,
+    /// Scale applied to every intensity, where [`Intensity::MAX`] leaves intensities unchanged.
+    pub intensity_scale: Intensity,
+}
+
+impl Settings {
+    /// The number of bytes used by [`to_bytes()`] and [`from_bytes()`].
+    ///
+    /// [`from_bytes()`]: Settings::from_bytes()
+    /// [`to_bytes()`]: Settings::to_bytes()
+    pub const ENCODED_LEN: usize = 2;
+
+    /// Creates the default settings, with rumble enabled at full strength.
+    pub const fn new() -> Self {
+        Self {
+            enabled: true,
+            intensity_scale: Intensity::MAX,
+        }
+    }
+
+    /// Applies these settings to `intensity`.
+    ///
+    /// Scaling rounds up, so a nonzero intensity only becomes [`Intensity::OFF`] if rumble is
+    /// disabled or `intensity_scale` is [`Intensity::OFF`].
+    pub const fn apply(self, intensity: Intensity) -> Intensity {
+        if !self.enabled {
+            return Intensity::OFF;
+        }
+        let scaled = (intensity.get() as u16 * self.intensity_scale.get() as u16).div_ceil(255);
+        Intensity::new(scaled as u8)
+    }
+
+    /// Encodes these settings as bytes, for storage in save memory.
+    pub const fn to_bytes(self) -> [u8; Self::ENCODED_LEN] {
+        [self.enabled as u8, self.intensity_scale.get()]
+    }
+
+    /// Decodes settings previously encoded with [`to_bytes()`].
+    ///
+    /// Returns `None` if `bytes` do not contain valid settings. This includes erased save memory,
+    /// which reads as all `0xFF`.
+    ///
+    /// [`to_bytes()`]: Settings::to_bytes()
+    pub const fn from_bytes(bytes: [u8; Self::ENCODED_LEN]) -> Option<Self> {
+        let enabled = match bytes[0] {
+            0 => false,
+            1 => true,
+            _ => return None,
+        };
+        Some(Self {
+            enabled,
+            intensity_scale: Intensity::new(bytes[1]),
+        })
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Persistent storage for [`Settings`].
+///
+/// This crate does not access save hardware itself. Instead, games implement this trait over their
+/// own SRAM, flash, or EEPROM code, typically storing the output of [`Settings::to_bytes()`].
+///
+/// ``` rust
+/// use core::ptr;
+/// use gba_rumble::{Settings, SettingsStore};
+///
+/// struct Sram;
+///
+/// const SRAM: usize = 0x0E00_0000;
+/// const OFFSET: usize = 0x10;
+///
+/// impl SettingsStore for Sram {
+///     type Error = ();
+///
+///     fn load(&mut self) -> Result<Option<Settings>, Self::Error> {
+///         let mut bytes = [0; Settings::ENCODED_LEN];
+///         for (index, byte) in bytes.iter_mut().enumerate() {
+///             let address = ptr::with_exposed_provenance::<u8>(SRAM + OFFSET + index);
+///             *byte = unsafe { address.read_volatile() };
+///         }
+///         Ok(Settings::from_bytes(bytes))
+///     }
+///
+///     fn save(&mut self, settings: Settings) -> Result<(), Self::Error> {
+///         for (index, byte) in settings.to_bytes().into_iter().enumerate() {
+///             let address = ptr::with_exposed_provenance_mut::<u8>(SRAM + OFFSET + index);
+///             unsafe { address.write_volatile(byte) };
+///         }
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait SettingsStore {
+    /// The error returned when the save memory cannot be accessed.
+    type Error;
+
+    /// Loads the stored settings.
+    ///
+    /// Returns `Ok(None)` if no settings have been stored yet.
+    fn load(&mut self) -> Result<Option<Settings>, Self::Error>;
+
+    /// Stores `settings`, replacing any previously stored settings.
+    fn save(&mut self, settings: Settings) -> Result<(), Self::Error>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Settings;
+    use crate::Intensity;
+    use claims::{assert_none, assert_some_eq};
+    use gba_test::test;
+
+    #[test]
+    fn default_unchanged() {
+        assert_eq!(
+            Settings::default().apply(Intensity::new(42)),
+            Intensity::new(42)
+        );
+        assert_eq!(Settings::default().apply(Intensity::MAX), Intensity::MAX);
+    }
+
+    #[test]
+    fn disabled() {
+        let settings = Settings {
+            enabled: false,
+            ..Settings::new()
+        };
+
+        assert_eq!(settings.apply(Intensity::MAX), Intensity::OFF);
+    }
+
+    #[test]
+    fn scale() {
+        let settings = Settings {
+            enabled: true,
+            intensity_scale: Intensity::new(128),
+        };
+
+        assert_eq!(settings.apply(Intensity::MAX), Intensity::new(128));
+        assert_eq!(settings.apply(Intensity::new(100)), Intensity::new(51));
+        assert_eq!(settings.apply(Intensity::OFF), Intensity::OFF);
+    }
+
+    #[test]
+    fn scale_rounds_up() {
+        let settings = Settings {
+            enabled: true,
+            intensity_scale: Intensity::new(1),
+        };
+
+        assert_eq!(settings.apply(Intensity::new(1)), Intensity::new(1));
+    }
+
+    #[test]
+    fn scale_off() {
+        let settings = Settings {
+            enabled: true,
+            intensity_scale: Intensity::OFF,
+        };
+
+        assert_eq!(settings.apply(Intensity::MAX), Intensity::OFF);
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let settings = Settings {
+            enabled: false,
+            intensity_scale: Intensity::new(200),
+        };
+
+        assert_some_eq!(Settings::from_bytes(settings.to_bytes()), settings);
+    }
+
+    #[test]
+    fn bytes_erased() {
+        assert_none!(Settings::from_bytes([0xFF; Settings::ENCODED_LEN]));
+    }
+}
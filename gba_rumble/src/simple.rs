@@ -0,0 +1,186 @@
+//! A minimal interface to cartridge rumble.
+
+use crate::{Clock, Gpio};
+
+/// The simplest way to add rumble to a game.
+///
+/// `SimpleRumble` drives a cartridge's rumble motor through [`Gpio`] and nothing else: there is no
+/// detection, no interrupt handler to register, and no patterns or channels to set up. Create it
+/// once with [`init()`], turn rumble on and off with [`set()`], and call [`tick()`] once a frame.
+/// Programs that only use `SimpleRumble` link none of the crate's other machinery.
+///
+/// ``` rust
+/// use gba_rumble::SimpleRumble;
+///
+/// let mut rumble = SimpleRumble::init();
+///
+/// // When the player is hit, rumble for a quarter of a second.
+/// rumble.pulse(15);
+///
+/// // Once a frame:
+/// rumble.tick();
+/// ```
+///
+/// Games that want the Game Boy Player's rumble as well, or effects more involved than on and off,
+/// should use [`GameBoyPlayer`] and the [`Sequencer`] instead.
+///
+/// [`GameBoyPlayer`]: crate::GameBoyPlayer
+/// [`init()`]: SimpleRumble::init()
+/// [`Sequencer`]: crate::Sequencer
+/// [`set()`]: SimpleRumble::set()
+/// [`tick()`]: SimpleRumble::tick()
+#[derive(Debug)]
+pub struct SimpleRumble {
+    on: bool,
+    pulse_frames: u16,
+}
+
+impl SimpleRumble {
+    /// Sets up cartridge rumble, with the motor stopped.
+    pub fn init() -> Self {
+        Gpio.stop();
+        Self {
+            on: false,
+            pulse_frames: 0,
+        }
+    }
+
+    /// Turns rumble on or off until the next call to `set()`.
+    ///
+    /// Turning rumble off also cancels any [`pulse()`]. The change takes effect on the next
+    /// [`tick()`].
+    ///
+    /// [`pulse()`]: SimpleRumble::pulse()
+    /// [`tick()`]: SimpleRumble::tick()
+    pub fn set(&mut self, on: bool) {
+        self.on = on;
+        if !on {
+            self.pulse_frames = 0;
+        }
+    }
+
+    /// Rumbles for the given number of frames, starting on the next [`tick()`].
+    ///
+    /// A new pulse replaces any pulse already in progress.
+    ///
+    /// [`tick()`]: SimpleRumble::tick()
+    pub fn pulse(&mut self, frames: u16) {
+        self.pulse_frames = frames;
+    }
+
+    /// Returns whether the motor is rumbling this frame.
+    pub fn is_rumbling(&self) -> bool {
+        self.on || self.pulse_frames > 0
+    }
+
+    /// Updates the motor. Call this once a frame.
+    ///
+    /// Rumble respects [`set_enabled()`], so it stays off while disabled and resumes when
+    /// re-enabled.
+    ///
+    /// [`set_enabled()`]: crate::set_enabled()
+    pub fn tick(&mut self) {
+        self.advance(1);
+    }
+
+    /// Updates the motor, counting down any pulse by the number of frames elapsed on `clock`.
+    ///
+    /// This can be called in place of [`tick()`] by games whose logic does not run once a frame,
+    /// so that pulses still last as many frames as requested.
+    ///
+    /// [`tick()`]: SimpleRumble::tick()
+    pub fn tick_with<C>(&mut self, mut clock: C)
+    where
+        C: Clock,
+    {
+        self.advance(clock.elapsed_frames());
+    }
+
+    fn advance(&mut self, frames: u32) {
+        if cfg!(feature = "disabled") {
+            return;
+        }
+        if self.is_rumbling() {
+            Gpio.start();
+        } else {
+            Gpio.stop();
+        }
+        self.pulse_frames = self
+            .pulse_frames
+            .saturating_sub(frames.min(u16::MAX as u32) as u16);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SimpleRumble;
+    use crate::FrameCounter;
+    use gba_test::test;
+
+    #[test]
+    fn init_stopped() {
+        assert!(!SimpleRumble::init().is_rumbling());
+    }
+
+    #[test]
+    fn set() {
+        let mut rumble = SimpleRumble::init();
+
+        rumble.set(true);
+        rumble.tick();
+        rumble.tick();
+        assert!(rumble.is_rumbling());
+
+        rumble.set(false);
+        assert!(!rumble.is_rumbling());
+        rumble.tick();
+    }
+
+    #[test]
+    fn pulse() {
+        let mut rumble = SimpleRumble::init();
+
+        rumble.pulse(2);
+        assert!(rumble.is_rumbling());
+        rumble.tick();
+        assert!(rumble.is_rumbling());
+        rumble.tick();
+        assert!(!rumble.is_rumbling());
+    }
+
+    #[test]
+    fn pulse_tick_with() {
+        let mut rumble = SimpleRumble::init();
+        let mut clock = FrameCounter::new();
+
+        rumble.pulse(3);
+        clock.count(2);
+        rumble.tick_with(&mut clock);
+        assert!(rumble.is_rumbling());
+        clock.count(2);
+        rumble.tick_with(&mut clock);
+        assert!(!rumble.is_rumbling());
+    }
+
+    #[test]
+    fn set_off_cancels_pulse() {
+        let mut rumble = SimpleRumble::init();
+
+        rumble.pulse(10);
+        rumble.set(false);
+
+        assert!(!rumble.is_rumbling());
+    }
+
+    #[test]
+    fn set_on_outlasts_pulse() {
+        let mut rumble = SimpleRumble::init();
+
+        rumble.set(true);
+        rumble.pulse(1);
+        rumble.tick();
+        rumble.tick();
+
+        assert!(rumble.is_rumbling());
+    }
+}
@@ -0,0 +1,96 @@
+//! Stopping rumble before a soft reset.
+
+use crate::{VcountWaiter, game_boy_player, gpio};
+
+/// The most frames spent waiting for the Game Boy Player to receive a hard stop.
+const GAME_BOY_PLAYER_FRAMES: u8 = 5;
+
+/// Stops the motor on every backend and clears this crate's state, ahead of a soft reset.
+///
+/// This should be called right before resetting with `swi 0x00`, or jumping to another ROM such as
+/// a flash cartridge's menu. Otherwise, a motor left running has nothing to stop it, and keeps
+/// rumbling after the reset.
+///
+/// Cartridge rumble is stopped and the GPIO port is returned to its state at boot. If a session
+/// with the Game Boy Player is connected, a hard stop is sent, waiting up to a few frames for it to
+/// be transmitted, after which the session is ended and the serial port is disabled. Frames are
+/// waited for by polling `VCOUNT`, so this works with interrupts disabled, although the hard stop
+/// is only transmitted if serial interrupts are still being handled by
+/// [`game_boy_player_interrupt()`].
+///
+/// Any [`Driver`], [`Device`], or other value driving rumble should not be used afterward.
+///
+/// ``` rust,no_run
+/// use core::arch::asm;
+///
+/// gba_rumble::before_soft_reset();
+/// unsafe {
+///     asm!("swi #0x00", options(noreturn));
+/// }
+/// ```
+///
+/// [`Device`]: crate::Device
+/// [`Driver`]: crate::Driver
+/// [`game_boy_player_interrupt()`]: crate::game_boy_player_interrupt()
+pub fn before_soft_reset() {
+    gpio::release();
+    #[cfg(feature = "ds-rumble-pak")]
+    crate::ds_rumble_pak::reset();
+    game_boy_player::stop_before_reset(VcountWaiter, GAME_BOY_PLAYER_FRAMES);
+}
+
+/// Defines the program's panic handler, stopping rumble before calling `handler`.
+///
+/// A panic leaves the motor in whatever state it was in, so a game that crashes while rumbling
+/// keeps rumbling until the player turns off the console. This defines a `#[panic_handler]` that
+/// calls [`before_soft_reset()`] and then `handler`, which receives the [`PanicInfo`] and
+/// performs the program's usual panic behavior, such as logging a fatal error to mGBA and
+/// looping forever. As with any panic handler, it should only be used once, in the final binary.
+///
+/// The hard stop sent to a Game Boy Player is only transmitted if serial interrupts are still
+/// being handled, which is the case unless the panic occurred within an interrupt handler.
+///
+/// Requires the `panic-handler` feature.
+///
+/// ``` rust,ignore
+/// use core::panic::PanicInfo;
+///
+/// fn halt(_info: &PanicInfo) -> ! {
+///     loop {}
+/// }
+///
+/// gba_rumble::catch_panic_stop!(halt);
+/// ```
+///
+/// [`PanicInfo`]: core::panic::PanicInfo
+#[cfg(feature = "panic-handler")]
+#[macro_export]
+macro_rules! catch_panic_stop {
+    ($handler:expr $(,)?) => {
+        #[panic_handler]
+        fn __gba_rumble_panic_handler(info: &::core::panic::PanicInfo) -> ! {
+            $crate::before_soft_reset();
+            ($handler)(info)
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::before_soft_reset;
+    use crate::{GameBoyPlayer, game_boy_player, regs};
+    use core::ptr;
+    use gba_test::test;
+
+    const SIOCNT: *const u16 = ptr::with_exposed_provenance(regs::SIOCNT);
+
+    #[test]
+    fn ends_game_boy_player_session() {
+        GameBoyPlayer::new().enable_serial();
+
+        before_soft_reset();
+
+        assert!(!game_boy_player::is_connected());
+        assert_eq!(unsafe { SIOCNT.read_volatile() } & (1 << 14), 0);
+    }
+}
@@ -0,0 +1,157 @@
+//! Handles tying effects to the lifetime of the gameplay entities that emit them.
+
+use core::{
+    fmt,
+    fmt::{Debug, Formatter},
+};
+
+/// Which of the [`SourceHandle::MAX`] handles are currently held, one bit each.
+static mut SOURCES_LIVE: u32 = 0;
+/// The generation of each handle, advanced whenever a handle is dropped.
+///
+/// A [`SourceKey`] whose generation no longer matches belongs to a dropped handle.
+static mut SOURCE_GENERATIONS: [u8; SourceHandle::MAX] = [0; SourceHandle::MAX];
+
+/// A handle owned by a gameplay entity, stopping the effects it emitted when dropped.
+///
+/// Effects played on a [`Mixer`] through [`play_from()`] or [`play_effect_from()`] belong to the
+/// handle they were played from, whichever channels they are on. Once the handle is dropped, such
+/// as when the entity owning it despawns, the mixer stops every one of those effects on its next
+/// [`tick()`], including any still queued. This prevents a looping effect, such as an engine hum,
+/// from being left playing after the object emitting it is gone.
+///
+/// Up to [`MAX`] handles can be held at once. Handles are not tied to a particular mixer, so a
+/// single handle can own effects on several mixers.
+///
+/// ``` rust
+/// use gba_rumble::{Intensity, Mixer, Pattern, Preemption, SourceHandle, Step, pattern};
+///
+/// const ENGINE: Pattern = pattern![
+///     Step::Hold { intensity: Intensity::new(4), frames: 60 },
+///     Step::Loop { step: 0, count: 0 },
+/// ];
+///
+/// let mut mixer = Mixer::<2>::new();
+/// let ambient = mixer.add_channel(0, Preemption::Reject).unwrap();
+///
+/// let vehicle = SourceHandle::new().unwrap();
+/// mixer.play_from(ambient, ENGINE, &vehicle);
+/// // The vehicle is destroyed.
+/// drop(vehicle);
+/// mixer.tick();
+/// assert!(!mixer.is_playing(ambient));
+/// ```
+///
+/// [`MAX`]: SourceHandle::MAX
+/// [`Mixer`]: crate::Mixer
+/// [`play_effect_from()`]: crate::Mixer::play_effect_from()
+/// [`play_from()`]: crate::Mixer::play_from()
+/// [`tick()`]: crate::Mixer::tick()
+pub struct SourceHandle {
+    key: SourceKey,
+}
+
+impl SourceHandle {
+    /// The most handles that can be held at once.
+    pub const MAX: usize = 32;
+
+    /// Creates a handle, or returns `None` if [`MAX`] handles are already held.
+    ///
+    /// [`MAX`]: SourceHandle::MAX
+    pub fn new() -> Option<Self> {
+        let live = unsafe { (&raw const SOURCES_LIVE).read_volatile() };
+        let index = (!live).trailing_zeros() as usize;
+        if index >= Self::MAX {
+            return None;
+        }
+        unsafe {
+            (&raw mut SOURCES_LIVE).write_volatile(live | 1 << index);
+        }
+        Some(Self {
+            key: SourceKey {
+                index: index as u8,
+                generation: unsafe { (&raw const SOURCE_GENERATIONS[index]).read_volatile() },
+            },
+        })
+    }
+
+    pub(crate) const fn key(&self) -> SourceKey {
+        self.key
+    }
+}
+
+impl Drop for SourceHandle {
+    fn drop(&mut self) {
+        let index = self.key.index as usize;
+        unsafe {
+            (&raw mut SOURCE_GENERATIONS[index])
+                .write_volatile(self.key.generation.wrapping_add(1));
+            (&raw mut SOURCES_LIVE)
+                .write_volatile((&raw const SOURCES_LIVE).read_volatile() & !(1 << index));
+        }
+    }
+}
+
+impl Debug for SourceHandle {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_tuple("SourceHandle")
+            .field(&self.key.index)
+            .finish()
+    }
+}
+
+/// Identifies the [`SourceHandle`] an effect was played from, for as long as it is held.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct SourceKey {
+    index: u8,
+    generation: u8,
+}
+
+impl SourceKey {
+    /// Returns whether the handle this was taken from is still held.
+    pub(crate) fn is_live(self) -> bool {
+        let generation =
+            unsafe { (&raw const SOURCE_GENERATIONS[self.index as usize]).read_volatile() };
+        generation == self.generation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SourceHandle;
+    use claims::assert_some;
+    use gba_test::test;
+
+    #[test]
+    fn live_until_dropped() {
+        let handle = assert_some!(SourceHandle::new());
+        let key = handle.key();
+
+        assert!(key.is_live());
+        drop(handle);
+        assert!(!key.is_live());
+    }
+
+    #[test]
+    fn reused_slot_is_distinct() {
+        let handle = assert_some!(SourceHandle::new());
+        let key = handle.key();
+        drop(handle);
+
+        let handle = assert_some!(SourceHandle::new());
+
+        assert!(handle.key().is_live());
+        assert!(!key.is_live());
+    }
+
+    #[test]
+    fn exhausted() {
+        let handles: [_; SourceHandle::MAX] = core::array::from_fn(|_| SourceHandle::new());
+        assert!(handles.iter().all(Option::is_some));
+
+        assert!(SourceHandle::new().is_none());
+        drop(handles);
+        assert_some!(SourceHandle::new());
+    }
+}
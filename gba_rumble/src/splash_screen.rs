@@ -1,1095 +1,716 @@
-//! Raw data for displaying the Game Boy Player splash screen.
-
-pub(crate) const PALETTE: [u8; 128] = [
-    0xDF, 0xFF, 0x0C, 0x64, 0x0C, 0xE4, 0x2D, 0xE4, 0x4E, 0x64, 0x4E, 0xE4, 0x6E, 0xE4, 0xAF, 0x68,
-    0xB0, 0xE8, 0xD0, 0x68, 0xF0, 0x68, 0x11, 0x69, 0x11, 0xE9, 0x32, 0x6D, 0x32, 0xED, 0x73, 0xED,
-    0x93, 0x6D, 0x94, 0xED, 0xB4, 0x6D, 0xD5, 0xF1, 0xF5, 0x71, 0xF6, 0xF1, 0x16, 0x72, 0x57, 0x72,
-    0x57, 0xF6, 0x78, 0x76, 0x78, 0xF6, 0x99, 0xF6, 0xB9, 0xF6, 0xD9, 0x76, 0xDA, 0xF6, 0x1B, 0x7B,
-    0x1B, 0xFB, 0x3C, 0xFB, 0x5C, 0x7B, 0x7D, 0x7B, 0x7D, 0xFF, 0x9D, 0x7F, 0xBE, 0x7F, 0xFF, 0x7F,
-    0x2D, 0x64, 0x8E, 0x64, 0x8F, 0xE8, 0xF1, 0xE8, 0x52, 0x6D, 0x73, 0x6D, 0xB4, 0xF1, 0x16, 0xF2,
-    0x37, 0x72, 0x98, 0x76, 0xFA, 0x7A, 0xFA, 0xFA, 0x5C, 0xFB, 0xBE, 0xFF, 0xDE, 0x7F, 0xFF, 0xFF,
-    0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-];
-
-pub(crate) const MAP: [u8; 844] = [
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00,
-    0x04, 0x00, 0x05, 0x00, 0x06, 0x00, 0x07, 0x00, 0x08, 0x00, 0x09, 0x00, 0x0a, 0x00, 0x0b, 0x00,
-    0x0c, 0x00, 0x0d, 0x00, 0x0e, 0x00, 0x0f, 0x00, 0x10, 0x00, 0x11, 0x00, 0x12, 0x00, 0x13, 0x00,
-    0x14, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x16, 0x00, 0x17, 0x00, 0x18, 0x00, 0x19, 0x00,
-    0x1a, 0x00, 0x1b, 0x00, 0x1c, 0x00, 0x1d, 0x00, 0x1e, 0x00, 0x1f, 0x00, 0x20, 0x00, 0x21, 0x00,
-    0x22, 0x00, 0x23, 0x00, 0x24, 0x00, 0x25, 0x00, 0x26, 0x00, 0x27, 0x00, 0x28, 0x00, 0x29, 0x00,
-    0x2a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x2c, 0x00, 0x2d, 0x00, 0x2e, 0x00,
-    0x2f, 0x00, 0x30, 0x00, 0x31, 0x00, 0x32, 0x00, 0x33, 0x00, 0x34, 0x00, 0x35, 0x00, 0x36, 0x00,
-    0x37, 0x00, 0x38, 0x00, 0x39, 0x00, 0x3a, 0x00, 0x3b, 0x00, 0x3c, 0x00, 0x3d, 0x00, 0x3e, 0x00,
-    0x3f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x41, 0x00, 0x42, 0x00,
-    0x43, 0x00, 0x44, 0x00, 0x45, 0x00, 0x46, 0x00, 0x47, 0x00, 0x48, 0x00, 0x49, 0x00, 0x4a, 0x00,
-    0x4b, 0x00, 0x4c, 0x00, 0x4d, 0x00, 0x4e, 0x00, 0x4f, 0x00, 0x50, 0x00, 0x51, 0x00, 0x52, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x53, 0x00, 0x54, 0x00, 0x55, 0x00,
-    0x56, 0x00, 0x57, 0x00, 0x00, 0x00, 0x00, 0x00, 0x58, 0x00, 0x59, 0x00, 0x00, 0x00, 0x5a, 0x00,
-    0x5b, 0x00, 0x5c, 0x00, 0x00, 0x00, 0x5d, 0x00, 0x5e, 0x00, 0x00, 0x00, 0x5f, 0x00, 0x60, 0x00,
-    0x61, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x62, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x63, 0x00, 0x64, 0x00, 0x65, 0x00, 0x66, 0x00, 0x67, 0x00, 0x68, 0x00, 0x69, 0x00, 0x00, 0x00,
-    0x6a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6b, 0x00, 0x6c, 0x00, 0x00, 0x00, 0x6d, 0x00, 0x6e, 0x00,
-    0x6f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-];
-
-pub(crate) const TILES: [u8; 0x4000] = [
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x22, 0x0c,
-    0x37, 0x37, 0x37, 0x37, 0x27, 0x2f, 0x02, 0x01, 0x37, 0x37, 0x37, 0x00, 0x0d, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x00, 0x0a, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x0d, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x36, 0x21, 0x31, 0x37, 0x37, 0x34, 0x14, 0x09, 0x01, 0x01, 0x01,
-    0x1e, 0x2b, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x02, 0x0b, 0x13, 0x01, 0x01, 0x01, 0x2b, 0x1c, 0x27, 0x37, 0x37,
-    0x19, 0x19, 0x19, 0x1e, 0x21, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x03, 0x0e, 0x30,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x28, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x08,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x2c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x15,
-    0x19, 0x19, 0x19, 0x19, 0x12, 0x2b, 0x01, 0x1c, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x26, 0x36,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x27, 0x37, 0x37, 0x37, 0x37, 0x37, 0x33, 0x09,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x25, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x13, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x36, 0x03, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x30, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x27, 0x06, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0d, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x00, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x20, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x1a, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x13, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0d, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x29, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x27,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x33, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x12,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x05, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1f, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x33, 0x09, 0x01, 0x01, 0x01, 0x01, 0x31, 0x37,
-    0x11, 0x01, 0x01, 0x01, 0x01, 0x01, 0x10, 0x37, 0x04, 0x01, 0x01, 0x01, 0x01, 0x01, 0x09, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x28, 0x27, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x34,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x1b, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x14,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x18,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x27, 0x06, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x31, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0a, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x22, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x0f, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x26, 0x02, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x26,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x26, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x22,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x22, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x32,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x36, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x20, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x19, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x13, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x0e, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x29, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x05, 0x21, 0x21, 0x21, 0x21, 0x21, 0x21, 0x21,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x10, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x11, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x30, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x32, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x24, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x28, 0x37, 0x37, 0x37, 0x37, 0x37, 0x21, 0x21, 0x34, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x22, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x18, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x12, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x0c, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x05, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x36, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x20, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x15, 0x32,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x2d, 0x21, 0x21, 0x21, 0x1e, 0x15, 0x03, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x00, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x03, 0x19, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x0c, 0x00, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x0d, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x1b, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x09, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x00, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x36,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x25, 0x11, 0x02, 0x37, 0x37, 0x37, 0x37, 0x1a, 0x28, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x12, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x13, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x1a, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x24, 0x03, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1c, 0x11, 0x10, 0x2d, 0x11, 0x31, 0x35, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x02, 0x10, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x08, 0x2c, 0x06, 0x01, 0x01, 0x01, 0x10, 0x36, 0x37, 0x37, 0x37, 0x18, 0x02, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1c, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x36, 0x03, 0x01, 0x01, 0x01, 0x03, 0x20, 0x37, 0x37, 0x13, 0x01, 0x01, 0x01,
-    0x01, 0x04, 0x21, 0x37, 0x37, 0x02, 0x01, 0x01, 0x01, 0x01, 0x04, 0x34, 0x37, 0x0f, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x07, 0x27, 0x22, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x19, 0x37, 0x0c, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x36, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x31, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x0a, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x22, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x13, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x04, 0x27, 0x37, 0x37, 0x20, 0x01, 0x01, 0x01, 0x01, 0x31, 0x37, 0x27, 0x09,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x24, 0x0a, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x0e, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x1c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x36, 0x06, 0x01, 0x01, 0x01, 0x01, 0x01, 0x10, 0x12, 0x01, 0x01, 0x01, 0x01, 0x01, 0x05, 0x35,
-    0x02, 0x01, 0x01, 0x01, 0x01, 0x01, 0x1b, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0d, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x31, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x2a, 0x00, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x33, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1f, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x30,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0f, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c,
-    0x37, 0x1a, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x36, 0x03, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x14, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x2e, 0x05, 0x01, 0x01, 0x01, 0x01, 0x01, 0x2c, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x03, 0x26, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x16, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x23, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x04, 0x37, 0x37, 0x37,
-    0x01, 0x07, 0x21, 0x37, 0x37, 0x37, 0x37, 0x37, 0x11, 0x00, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x11, 0x09, 0x09, 0x09, 0x09,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x09, 0x09, 0x09, 0x09, 0x09, 0x0f, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x31, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x09, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x22, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x0f, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x25, 0x02, 0x01, 0x01, 0x01, 0x01, 0x08, 0x37, 0x13, 0x01, 0x01, 0x01, 0x01, 0x01, 0x31,
-    0x36, 0x03, 0x01, 0x01, 0x01, 0x01, 0x06, 0x27, 0x30, 0x01, 0x01, 0x01, 0x01, 0x01, 0x04, 0x09,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x36, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x20,
-    0x0c, 0x06, 0x01, 0x01, 0x01, 0x01, 0x01, 0x1a, 0x32, 0x0e, 0x01, 0x01, 0x01, 0x01, 0x01, 0x13,
-    0x37, 0x15, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0c, 0x37, 0x1d, 0x01, 0x01, 0x01, 0x01, 0x01, 0x29,
-    0x37, 0x25, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x09, 0x09, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x2e, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x29, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x20, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x14, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x2a, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x22, 0x01, 0x01, 0x01,
-    0x36, 0x37, 0x37, 0x37, 0x15, 0x01, 0x01, 0x01, 0x20, 0x37, 0x37, 0x37, 0x08, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0d, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x06,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x05, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x13, 0x05, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x20, 0x0d, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x30, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x27, 0x06, 0x01, 0x01, 0x01,
-    0x35, 0x37, 0x37, 0x31, 0x01, 0x01, 0x01, 0x01, 0x33, 0x37, 0x37, 0x0c, 0x01, 0x01, 0x01, 0x01,
-    0x17, 0x37, 0x22, 0x01, 0x01, 0x01, 0x01, 0x01, 0x10, 0x37, 0x0f, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x08, 0x36, 0x28, 0x01, 0x01, 0x01, 0x01, 0x03, 0x02, 0x2f, 0x01, 0x01, 0x01, 0x01, 0x01, 0x13,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x32, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x31,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x31, 0x01, 0x02, 0x01, 0x01, 0x01, 0x01, 0x01, 0x30,
-    0x2a, 0x08, 0x01, 0x01, 0x01, 0x01, 0x01, 0x30, 0x30, 0x2b, 0x01, 0x01, 0x01, 0x01, 0x01, 0x13,
-    0x36, 0x0c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x13, 0x37, 0x2c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0f,
-    0x37, 0x37, 0x00, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x22, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x1b, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x15, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x2c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x08, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x27, 0x28, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x23, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x2b, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x11, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x30, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1d, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x07, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x2c, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0e, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x14, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x31, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x31, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x13, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x0e, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x2a, 0x01, 0x01, 0x01, 0x01, 0x05,
-    0x37, 0x00, 0x02, 0x01, 0x01, 0x01, 0x01, 0x05, 0x37, 0x22, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x1c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x16, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x18, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1c, 0x01, 0x1f, 0x37, 0x37, 0x37, 0x37, 0x37, 0x26, 0x01,
-    0x35, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1d, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x22, 0x06, 0x01,
-    0x11, 0x11, 0x11, 0x11, 0x0a, 0x02, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x26, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x27, 0x37, 0x22,
-    0x01, 0x01, 0x01, 0x01, 0x2b, 0x37, 0x37, 0x12, 0x01, 0x01, 0x01, 0x01, 0x31, 0x37, 0x37, 0x29,
-    0x01, 0x01, 0x01, 0x09, 0x37, 0x37, 0x22, 0x01, 0x01, 0x01, 0x03, 0x34, 0x37, 0x37, 0x19, 0x01,
-    0x01, 0x01, 0x1c, 0x37, 0x37, 0x37, 0x2e, 0x01, 0x01, 0x01, 0x0d, 0x27, 0x37, 0x37, 0x0f, 0x01,
-    0x2c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x1b, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x15, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x0b, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x1f, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x08, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x15, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x33, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x26, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x17, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x2b,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1a, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x35,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x07, 0x37, 0x32, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x21, 0x37, 0x08,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x2f, 0x37, 0x31, 0x01, 0x01, 0x01, 0x01, 0x01, 0x2c, 0x37, 0x27,
-    0x04, 0x01, 0x01, 0x01, 0x01, 0x08, 0x37, 0x37, 0x08, 0x01, 0x01, 0x01, 0x01, 0x29, 0x37, 0x37,
-    0x06, 0x01, 0x01, 0x01, 0x01, 0x05, 0x37, 0x37, 0x02, 0x01, 0x01, 0x01, 0x01, 0x08, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x0c, 0x37, 0x30, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x33, 0x03, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x28, 0x01, 0x01, 0x06, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x30, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x36, 0x03, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x13, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x02, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x03, 0x24, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x17, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x0a, 0x27, 0x37, 0x37, 0x37, 0x01, 0x01, 0x02, 0x21, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x13, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x29, 0x00, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x32, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0f, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x08,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x2c,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x30, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x34,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x07, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x2a, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x02, 0x00, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x31, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x0a, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x15, 0x37,
-    0x0c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x11, 0x34, 0x28, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x29, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x00, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x22, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x1c, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x22, 0x19, 0x19, 0x19, 0x16, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1e, 0x01,
-    0x36, 0x37, 0x37, 0x37, 0x37, 0x37, 0x2f, 0x01, 0x03, 0x0f, 0x19, 0x31, 0x19, 0x14, 0x05, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x0f, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x16, 0x37, 0x32,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x1c, 0x37, 0x0c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x22, 0x22, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x02, 0x27, 0x0f, 0x01, 0x01, 0x01, 0x01, 0x01, 0x2a, 0x25, 0x02, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x0e, 0x15, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x2e, 0x06, 0x01, 0x01,
-    0x07, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x0e, 0x11, 0x11, 0x11, 0x01, 0x01, 0x01, 0x06, 0x27, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x30, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x03, 0x36, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x11, 0x11, 0x0f, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x28, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x09, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x10, 0x01, 0x01, 0x01, 0x01,
-    0x1a, 0x37, 0x37, 0x23, 0x01, 0x01, 0x01, 0x01, 0x13, 0x37, 0x37, 0x2f, 0x01, 0x01, 0x01, 0x01,
-    0x0c, 0x37, 0x37, 0x0a, 0x01, 0x01, 0x01, 0x01, 0x29, 0x37, 0x24, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x36, 0x17, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x20, 0x2b, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x16, 0x01, 0x01, 0x01, 0x01, 0x01, 0x28, 0x01, 0x04, 0x01, 0x01, 0x01, 0x01, 0x01, 0x2c,
-    0x01, 0x05, 0x37, 0x15, 0x01, 0x01, 0x01, 0x01, 0x01, 0x12, 0x37, 0x1e, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x32, 0x37, 0x26, 0x01, 0x01, 0x01, 0x01, 0x04, 0x27, 0x37, 0x37, 0x06, 0x01, 0x01, 0x01,
-    0x0f, 0x37, 0x37, 0x37, 0x0e, 0x01, 0x01, 0x01, 0x1d, 0x37, 0x37, 0x37, 0x2f, 0x01, 0x01, 0x01,
-    0x00, 0x37, 0x37, 0x37, 0x32, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x35, 0x01, 0x01, 0x01,
-    0x01, 0x28, 0x01, 0x01, 0x01, 0x01, 0x01, 0x34, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0c, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x32, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x08, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x30, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x03, 0x36, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x13, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x25, 0x37, 0x37, 0x37,
-    0x37, 0x0f, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0f, 0x37, 0x12, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0c,
-    0x37, 0x13, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0c, 0x37, 0x16, 0x01, 0x01, 0x01, 0x01, 0x01, 0x08,
-    0x37, 0x30, 0x01, 0x01, 0x01, 0x01, 0x01, 0x08, 0x37, 0x19, 0x01, 0x01, 0x01, 0x01, 0x01, 0x05,
-    0x37, 0x31, 0x01, 0x01, 0x01, 0x01, 0x01, 0x05, 0x37, 0x1e, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x1d, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x30, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x0f, 0x01, 0x01, 0x01, 0x01, 0x01, 0x1d, 0x37, 0x0a, 0x01, 0x01, 0x01, 0x01, 0x01, 0x24,
-    0x37, 0x03, 0x01, 0x01, 0x01, 0x01, 0x28, 0x37, 0x26, 0x01, 0x01, 0x01, 0x01, 0x01, 0x09, 0x37,
-    0x32, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0f, 0x37, 0x18, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x20, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x36, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1a, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x2c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x08, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x28, 0x01, 0x01, 0x01, 0x01, 0x0a, 0x37, 0x24, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0f, 0x37,
-    0x1d, 0x01, 0x01, 0x01, 0x01, 0x01, 0x16, 0x37, 0x30, 0x01, 0x01, 0x01, 0x01, 0x01, 0x1c, 0x37,
-    0x11, 0x01, 0x01, 0x01, 0x01, 0x01, 0x23, 0x37, 0x0a, 0x01, 0x01, 0x01, 0x01, 0x01, 0x18, 0x19,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x23, 0x30, 0x02, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x30, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x22, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x31, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x22, 0x04, 0x01, 0x19, 0x19, 0x19, 0x11, 0x0c, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x12, 0x37, 0x37, 0x0d, 0x01, 0x01, 0x01, 0x01, 0x01, 0x22, 0x37, 0x0f, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x2e, 0x37, 0x12, 0x01, 0x01, 0x01, 0x01, 0x01, 0x09, 0x37, 0x18, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x07, 0x37, 0x21, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0e, 0x37, 0x37, 0x29,
-    0x01, 0x01, 0x01, 0x01, 0x30, 0x37, 0x37, 0x16, 0x01, 0x01, 0x01, 0x03, 0x35, 0x37, 0x37, 0x35,
-    0x01, 0x01, 0x01, 0x01, 0x27, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x25, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x1d, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x0f, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x02, 0x22, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x08, 0x36, 0x37, 0x03, 0x01, 0x01, 0x01, 0x01, 0x01, 0x29, 0x1b,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x21, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x17,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x33, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x00, 0x08, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x2d, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x27, 0x2e, 0x01, 0x01, 0x01, 0x25, 0x35, 0x1b, 0x08, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x0c, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x13, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x1c, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x02, 0x36, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x0f, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x22, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x0d, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x03, 0x24, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x27, 0x02, 0x01, 0x01, 0x01, 0x01, 0x04, 0x37, 0x23, 0x01, 0x01, 0x01, 0x01, 0x01, 0x2f,
-    0x37, 0x1c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x1d, 0x37, 0x16, 0x01, 0x01, 0x01, 0x01, 0x01, 0x23,
-    0x37, 0x0f, 0x01, 0x01, 0x01, 0x01, 0x02, 0x27, 0x37, 0x0a, 0x01, 0x01, 0x01, 0x01, 0x2a, 0x37,
-    0x37, 0x03, 0x01, 0x01, 0x01, 0x01, 0x0e, 0x37, 0x26, 0x01, 0x01, 0x01, 0x01, 0x01, 0x13, 0x37,
-    0x35, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x18, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x2f, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x31, 0x03, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x26, 0x0f, 0x02, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x26, 0x2f, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x0d, 0x28, 0x01, 0x01, 0x01, 0x01, 0x06, 0x0e, 0x37, 0x37, 0x37, 0x22, 0x23, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x0c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x02, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x03, 0x16, 0x0a, 0x01, 0x01, 0x01, 0x01,
-    0x1b, 0x00, 0x25, 0x09, 0x09, 0x09, 0x09, 0x09, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x13, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x23, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x0c, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x32, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x0e, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x30, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x32, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x26, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x04, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x10, 0x09, 0x09, 0x09, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x31, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x02, 0x36,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0c, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x19, 0x37,
-    0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x26, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x29, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x2d, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x30, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x33, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x36, 0x09, 0x09, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x0c, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x32, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x19, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x09, 0x0a, 0x36, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x32, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x21, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x22, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x25, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x35, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x02, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x05, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x2b,
-    0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x2f, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x20, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x36, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x26,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1f, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x18,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x30, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x03, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x29,
-    0x09, 0x09, 0x09, 0x09, 0x09, 0x10, 0x1d, 0x27, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x17, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x0f, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x11, 0x27, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1c, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x17, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x0e, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x00, 0x0b, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x27, 0x12, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x22, 0x2d, 0x03, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x00, 0x21,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x02, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x08, 0x20,
-    0x01, 0x01, 0x01, 0x01, 0x0a, 0x1a, 0x37, 0x37, 0x1c, 0x1a, 0x21, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x1b, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x30, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x17, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x1f, 0x01, 0x01, 0x01, 0x01, 0x01, 0x19, 0x37, 0x18, 0x01, 0x01, 0x01, 0x01, 0x01, 0x1f, 0x37,
-    0x12, 0x01, 0x01, 0x01, 0x01, 0x01, 0x26, 0x37, 0x0c, 0x01, 0x01, 0x01, 0x01, 0x03, 0x37, 0x37,
-    0x0e, 0x09, 0x09, 0x09, 0x09, 0x0f, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x1b, 0x21, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x22, 0x37,
-    0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x22, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x1b, 0x21,
-    0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x2a, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x21, 0x21, 0x21, 0x21, 0x21, 0x12, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x26, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x26, 0x01, 0x01, 0x21, 0x21, 0x21, 0x21, 0x21, 0x13, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x2a,
-    0x22, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x06, 0x27, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x1f, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x32, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x32, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x1f, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x06, 0x27, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x22, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c,
-    0x01, 0x01, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x27, 0x2a, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x2f, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x26, 0x03, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x10, 0x01, 0x01, 0x04,
-    0x37, 0x37, 0x37, 0x21, 0x01, 0x01, 0x01, 0x19, 0x37, 0x37, 0x37, 0x0a, 0x01, 0x01, 0x0a, 0x37,
-    0x37, 0x37, 0x1b, 0x01, 0x01, 0x02, 0x21, 0x37, 0x37, 0x36, 0x06, 0x01, 0x01, 0x12, 0x37, 0x37,
-    0x01, 0x03, 0x26, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x10, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x20, 0x37, 0x37, 0x37, 0x37, 0x08, 0x01, 0x01, 0x0a, 0x37, 0x37, 0x37, 0x37,
-    0x33, 0x01, 0x01, 0x01, 0x1a, 0x37, 0x37, 0x37, 0x37, 0x0e, 0x01, 0x01, 0x05, 0x36, 0x37, 0x37,
-    0x37, 0x34, 0x02, 0x01, 0x01, 0x13, 0x37, 0x37, 0x37, 0x37, 0x12, 0x01, 0x01, 0x02, 0x23, 0x37,
-    0x37, 0x37, 0x1e, 0x02, 0x01, 0x01, 0x07, 0x35, 0x37, 0x37, 0x37, 0x1b, 0x02, 0x01, 0x01, 0x0a,
-    0x37, 0x37, 0x37, 0x37, 0x31, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x18, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x30, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x15,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x30, 0x01, 0x00, 0x37, 0x37, 0x37, 0x37, 0x31, 0x01, 0x01,
-    0x0b, 0x00, 0x37, 0x37, 0x1b, 0x02, 0x01, 0x01, 0x01, 0x0d, 0x27, 0x1e, 0x02, 0x01, 0x01, 0x2c,
-    0x01, 0x01, 0x0d, 0x28, 0x01, 0x01, 0x0d, 0x27, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0c, 0x00, 0x37,
-    0x13, 0x01, 0x01, 0x01, 0x0a, 0x00, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x30, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x13, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x10, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x0f, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x27, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x32, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x32, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x32, 0x01, 0x01, 0x01, 0x2e, 0x19, 0x19, 0x19, 0x32, 0x01, 0x01, 0x01, 0x32, 0x37, 0x37, 0x37,
-    0x32, 0x01, 0x01, 0x01, 0x18, 0x21, 0x21, 0x21, 0x32, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x32, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x32, 0x01, 0x01, 0x01, 0x2e, 0x19, 0x19, 0x19,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x32, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x32, 0x37,
-    0x19, 0x19, 0x19, 0x19, 0x19, 0x19, 0x25, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x21, 0x21, 0x35, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x31, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x31, 0x37, 0x37, 0x37, 0x37, 0x37, 0x19, 0x19, 0x23, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x1b, 0x21, 0x21, 0x21, 0x21, 0x21, 0x21, 0x01, 0x22, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x22, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x2c, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x07, 0x09, 0x06, 0x01, 0x01, 0x01, 0x02,
-    0x01, 0x01, 0x08, 0x22, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x06, 0x27, 0x37, 0x37, 0x37,
-    0x2e, 0x01, 0x01, 0x01, 0x1f, 0x37, 0x37, 0x37, 0x26, 0x01, 0x01, 0x01, 0x32, 0x37, 0x37, 0x37,
-    0x26, 0x01, 0x01, 0x01, 0x32, 0x37, 0x37, 0x37, 0x0a, 0x01, 0x01, 0x01, 0x21, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x0d, 0x37, 0x37, 0x37, 0x37, 0x09, 0x09, 0x15, 0x00, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x1b, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x22, 0x37,
-    0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x22, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x22, 0x37,
-    0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x25, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x17, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x32, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x32, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x34, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1f,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x15, 0x01, 0x01, 0x05, 0x36, 0x37, 0x37, 0x25, 0x28, 0x01, 0x01, 0x0e, 0x19, 0x19, 0x19,
-    0x2c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x26, 0x03, 0x01, 0x01, 0x0e, 0x37, 0x23, 0x37, 0x37, 0x2f, 0x01, 0x01, 0x01, 0x1e,
-    0x31, 0x37, 0x37, 0x27, 0x06, 0x01, 0x01, 0x2a, 0x31, 0x37, 0x37, 0x37, 0x1b, 0x01, 0x01, 0x01,
-    0x20, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x27, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x30, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x01, 0x01, 0x01, 0x30, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x30, 0x37, 0x37, 0x37,
-    0x37, 0x01, 0x01, 0x01, 0x30, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x30, 0x37, 0x37, 0x37,
-    0x37, 0x01, 0x01, 0x01, 0x32, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x32, 0x01, 0x01, 0x01, 0x32, 0x37, 0x37, 0x37, 0x32, 0x01, 0x01, 0x01, 0x32, 0x37, 0x37, 0x37,
-    0x32, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x32, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x34, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x31, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x31, 0x37,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x20, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x22, 0x37, 0x00, 0x0f, 0x01, 0x01, 0x01, 0x01, 0x22, 0x37, 0x37, 0x37, 0x17, 0x02, 0x01,
-    0x01, 0x22, 0x37, 0x37, 0x37, 0x37, 0x33, 0x05, 0x01, 0x22, 0x37, 0x37, 0x37, 0x37, 0x37, 0x24,
-    0x01, 0x25, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x2c, 0x00, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x07, 0x34, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x01, 0x01, 0x28, 0x1c, 0x37, 0x37, 0x37, 0x37, 0x09, 0x01, 0x01, 0x01, 0x2e, 0x27, 0x37, 0x37,
-    0x00, 0x01, 0x01, 0x01, 0x01, 0x1a, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-];
+//! Display of the Game Boy Player splash screen during detection.
+
+mod data;
+
+use crate::{Dma, Waiter, bios, protocol::consts, regs};
+use core::ptr;
+
+const DISPCNT: *mut u16 = ptr::with_exposed_provenance_mut(regs::DISPCNT);
+const MAP: *mut [u8; 844] = ptr::with_exposed_provenance_mut(regs::VRAM);
+const TILES: *mut [u8; 0x1C00] = ptr::with_exposed_provenance_mut(regs::VRAM + 0x8000);
+const PALETTE: *mut [u8; 128] = ptr::with_exposed_provenance_mut(regs::BG_PALETTE);
+const BACKDROP: *mut u16 = ptr::with_exposed_provenance_mut(regs::BG_PALETTE);
+const WININ: *mut u16 = ptr::with_exposed_provenance_mut(regs::WININ);
+const WINOUT: *mut u16 = ptr::with_exposed_provenance_mut(regs::WINOUT);
+const BLDCNT: *mut u16 = ptr::with_exposed_provenance_mut(regs::BLDCNT);
+const BLDALPHA: *mut u16 = ptr::with_exposed_provenance_mut(regs::BLDALPHA);
+const BLDY: *mut u16 = ptr::with_exposed_provenance_mut(regs::BLDY);
+
+/// The `DISPCNT` bit enabling BG 0.
+const BG0_ENABLE: u16 = 1 << 8;
+/// The `DISPCNT` bits enabling each background and objects.
+const LAYERS: u16 = 0b1_1111 << 8;
+/// The number of pieces the logo is split into when its upload is deferred.
+const UPLOAD_CHUNKS: u8 = 3;
+/// The size of each half of the logo's tiles, uploaded as separate chunks.
+const TILES_HALF: usize = 0xE00;
+
+/// Brightness decrease applied to the backdrop, to be combined with the logo's background.
+const BLDCNT_FADE: u16 = 0b11 << 6 | 1 << 5;
+/// The `BLDY` coefficient at which the screen is fully darkened.
+const BLDY_MAX: u16 = 16;
+
+/// The 8bpp palette of the Game Boy Player logo.
+#[cfg_attr(
+    not(feature = "splash-overlay"),
+    unsafe(link_section = ".rodata.gba_rumble.splash")
+)]
+#[cfg_attr(
+    feature = "splash-overlay",
+    unsafe(link_section = ".gba_rumble_overlay.splash")
+)]
+pub static SPLASH_PALETTE: [u8; 128] = data::PALETTE;
+/// The text background screen entries of the Game Boy Player logo, using 8bpp tiles.
+#[cfg_attr(
+    not(feature = "splash-overlay"),
+    unsafe(link_section = ".rodata.gba_rumble.splash")
+)]
+#[cfg_attr(
+    feature = "splash-overlay",
+    unsafe(link_section = ".gba_rumble_overlay.splash")
+)]
+pub static SPLASH_MAP: [u8; 844] = data::MAP;
+/// The 8bpp tiles of the Game Boy Player logo.
+///
+/// Only the 112 tiles referenced by [`SPLASH_MAP`] are included, so uploading them only occupies
+/// the first 7KB of the character base block. Several tiles use more than 15 colors, so the logo
+/// can't be stored as 4bpp without loss.
+#[cfg_attr(
+    not(feature = "splash-overlay"),
+    unsafe(link_section = ".rodata.gba_rumble.splash")
+)]
+#[cfg_attr(
+    feature = "splash-overlay",
+    unsafe(link_section = ".gba_rumble_overlay.splash")
+)]
+pub static SPLASH_TILES: [u8; 0x1C00] = data::TILES;
+
+/// Draws the Game Boy Player splash screen during detection.
+///
+/// The Game Boy Player only unlocks its extra functionality after seeing its logo on screen, so
+/// something must display the logo while [`GameBoyPlayer::detect_with()`] listens for the
+/// Game Boy Player. By default, [`BuiltinSplash`] draws the logo directly to background 0.
+/// Implementing this trait allows the logo to be drawn through whatever display management your
+/// own code or framework uses instead, while detection keeps control of the timing and input
+/// handling.
+///
+/// The logo's graphics can be obtained through [`SPLASH_PALETTE`], [`SPLASH_MAP`], and
+/// [`SPLASH_TILES`].
+///
+/// Custom renderers are used by passing them to [`DetectOptions::splash()`].
+///
+/// Detection clears forced blank in `DISPCNT` after calling [`prepare()`], so renderers may
+/// upload the logo while the screen is blanked. Renderers should not set forced blank again until
+/// [`teardown()`], or the Game Boy Player will not see the logo.
+///
+/// [`DetectOptions::splash()`]: crate::DetectOptions::splash()
+/// [`GameBoyPlayer::detect_with()`]: crate::GameBoyPlayer::detect_with()
+/// [`prepare()`]: SplashRenderer::prepare()
+/// [`teardown()`]: SplashRenderer::teardown()
+pub trait SplashRenderer {
+    /// Display the logo.
+    ///
+    /// This is called once before detection begins.
+    fn prepare(&mut self);
+
+    /// Update the display for the given frame of detection.
+    ///
+    /// This is called once per frame during detection, after waiting for v-blank, with `frame`
+    /// counting up from `0`.
+    fn render_frame(&mut self, frame: u8);
+
+    /// Remove the logo, restoring the display to its prior state.
+    ///
+    /// This is called once after detection has finished. Any further frames needed, such as to
+    /// fade the logo out, should be waited for using `waiter`.
+    fn teardown(&mut self, waiter: &mut dyn Waiter);
+}
+
+impl<R> SplashRenderer for &mut R
+where
+    R: SplashRenderer + ?Sized,
+{
+    fn prepare(&mut self) {
+        (**self).prepare();
+    }
+
+    fn render_frame(&mut self, frame: u8) {
+        (**self).render_frame(frame);
+    }
+
+    fn teardown(&mut self, waiter: &mut dyn Waiter) {
+        (**self).teardown(waiter);
+    }
+}
+
+/// A background layer that [`BuiltinSplash`] can draw the logo to.
+///
+/// See [`BuiltinSplash::layer()`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Background {
+    /// Background 0.
+    #[default]
+    Bg0 = 0,
+    /// Background 1.
+    Bg1 = 1,
+    /// Background 2.
+    Bg2 = 2,
+    /// Background 3.
+    Bg3 = 3,
+}
+
+impl Background {
+    /// Returns the background's `BGxCNT` control register.
+    fn control(self) -> *mut u16 {
+        ptr::with_exposed_provenance_mut(regs::BG0CNT + self as usize * 2)
+    }
+
+    /// Returns the background's `BGxHOFS` horizontal scroll offset register.
+    fn horizontal_offset(self) -> *mut u16 {
+        ptr::with_exposed_provenance_mut(regs::BG0HOFS + self as usize * 4)
+    }
+
+    /// Returns the background's `BGxVOFS` vertical scroll offset register.
+    fn vertical_offset(self) -> *mut u16 {
+        ptr::with_exposed_provenance_mut(regs::BG0VOFS + self as usize * 4)
+    }
+
+    /// Returns the bit selecting this background in `BLDCNT`.
+    ///
+    /// The background is enabled in `DISPCNT` by the same bit shifted left by 8.
+    fn bit(self) -> u16 {
+        1 << self as u16
+    }
+}
+
+/// The readable display registers disturbed while displaying the splash screen.
+#[derive(Debug, Default)]
+struct DisplayState {
+    dispcnt: u16,
+    /// The control register of the background the logo is drawn to.
+    bgcnt: u16,
+    winin: u16,
+    winout: u16,
+    bldcnt: u16,
+    bldalpha: u16,
+}
+
+impl DisplayState {
+    const fn new() -> Self {
+        Self {
+            dispcnt: 0,
+            bgcnt: 0,
+            winin: 0,
+            winout: 0,
+            bldcnt: 0,
+            bldalpha: 0,
+        }
+    }
+
+    fn save(background: Background) -> Self {
+        unsafe {
+            Self {
+                dispcnt: DISPCNT.read_volatile(),
+                bgcnt: background.control().read_volatile(),
+                winin: WININ.read_volatile(),
+                winout: WINOUT.read_volatile(),
+                bldcnt: BLDCNT.read_volatile(),
+                bldalpha: BLDALPHA.read_volatile(),
+            }
+        }
+    }
+
+    fn restore(&self, background: Background) {
+        unsafe {
+            DISPCNT.write_volatile(self.dispcnt);
+            background.control().write_volatile(self.bgcnt);
+            WININ.write_volatile(self.winin);
+            WINOUT.write_volatile(self.winout);
+            BLDCNT.write_volatile(self.bldcnt);
+            BLDALPHA.write_volatile(self.bldalpha);
+        }
+    }
+}
+
+/// The default [`SplashRenderer`], drawing the logo directly to a background.
+///
+/// While the logo is displayed, the display is switched to mode 0 with only background 0
+/// enabled, and any blending effects are disabled. A different background and priority can be
+/// chosen using [`layer()`], and the program's other layers can be left enabled using
+/// [`keep_layers()`]. Once detection finishes, the display control, window, and blending
+/// registers are restored, and VRAM and palette data are reset.
+///
+/// Write-only registers can't be saved, so they are left untouched wherever possible. The
+/// exceptions are the logo background's scroll offsets, which are set to `0` so the logo is
+/// positioned correctly, and `BLDY` when fading is enabled.
+///
+/// The logo can optionally be faded in and out using [`fade()`], and the color displayed behind
+/// it can be changed using [`backdrop()`]. The logo is uploaded to VRAM using DMA 3, unless
+/// another channel is selected with [`dma()`]. The upload can also be spread over the first few
+/// v-blanks of detection using [`deferred()`].
+///
+/// [`backdrop()`]: BuiltinSplash::backdrop()
+/// [`deferred()`]: BuiltinSplash::deferred()
+/// [`dma()`]: BuiltinSplash::dma()
+/// [`fade()`]: BuiltinSplash::fade()
+/// [`keep_layers()`]: BuiltinSplash::keep_layers()
+/// [`layer()`]: BuiltinSplash::layer()
+#[derive(Debug)]
+pub struct BuiltinSplash {
+    fade_frames: u8,
+    backdrop: Option<u16>,
+    dma: Option<Dma>,
+    deferred: bool,
+    background: Background,
+    priority: u8,
+    keep_layers: bool,
+
+    saved: DisplayState,
+}
+
+impl BuiltinSplash {
+    /// Creates a new built-in splash renderer.
+    pub const fn new() -> Self {
+        Self {
+            fade_frames: 0,
+            backdrop: None,
+            dma: Some(Dma::Dma3),
+            deferred: false,
+            background: Background::Bg0,
+            priority: 0,
+            keep_layers: false,
+
+            saved: DisplayState::new(),
+        }
+    }
+
+    /// Fade the logo in and out over the given number of frames.
+    ///
+    /// The logo fades in over the first `frames` frames of detection. Once detection finishes,
+    /// the logo fades out over another `frames` frames, extending the time spent displaying the
+    /// splash screen.
+    ///
+    /// Fading uses the `BLDCNT` and `BLDY` registers. `BLDCNT` is restored after the fade, but
+    /// `BLDY` is write-only and is therefore reset to `0`.
+    pub const fn fade(mut self, frames: u8) -> Self {
+        self.fade_frames = frames;
+        self
+    }
+
+    /// Display the given color behind the logo.
+    ///
+    /// `color` is a 15-bit BGR color, as stored in palette RAM. By default, the logo's own
+    /// backdrop color is used.
+    pub const fn backdrop(mut self, color: u16) -> Self {
+        self.backdrop = Some(color);
+        self
+    }
+
+    /// Upload the logo to VRAM using the given DMA channel, or by copying with the CPU if `None`.
+    ///
+    /// DMA copies the logo several times faster than the CPU, shortening the time before it is
+    /// displayed. A channel other than DMA 3 can be selected if the program uses DMA 3 for
+    /// something else during detection. The CPU is used regardless if the logo's data is not word
+    /// aligned.
+    pub const fn dma(mut self, channel: Option<Dma>) -> Self {
+        self.dma = channel;
+        self
+    }
+
+    /// Upload the logo over the first few v-blanks of detection, rather than all at once.
+    ///
+    /// By default, the whole logo is written to VRAM when detection begins, which may be in the
+    /// middle of drawing a frame. Deferring the upload instead splits it into three pieces, each
+    /// written during one of the first three v-blank periods, so VRAM is never written while the
+    /// screen is being drawn and the logo appears without tearing. Only the backdrop is displayed
+    /// until the final piece has been written.
+    ///
+    /// The logo therefore appears three frames later than it otherwise would, but these frames
+    /// are still counted towards the detection window.
+    pub const fn deferred(mut self) -> Self {
+        self.deferred = true;
+        self
+    }
+
+    /// Draw the logo to the given background, with the given priority.
+    ///
+    /// By default, the logo is drawn to background 0 with priority 0. `priority` ranges from `0`,
+    /// drawn above everything else, to `3`; only its lowest two bits are used. This is mostly
+    /// useful alongside [`keep_layers()`], to composite the logo above or below layers the
+    /// program keeps enabled. The logo must remain visible for the Game Boy Player to detect it.
+    ///
+    /// [`keep_layers()`]: BuiltinSplash::keep_layers()
+    pub const fn layer(mut self, background: Background, priority: u8) -> Self {
+        self.background = background;
+        self.priority = priority & 0b11;
+        self
+    }
+
+    /// Leave the program's enabled backgrounds and objects displayed alongside the logo.
+    ///
+    /// By default, every layer other than the logo's background is disabled while the logo is
+    /// displayed. With this option, the layers enabled in `DISPCNT` when detection begins stay
+    /// enabled, although the display is still switched to mode 0 and windows are disabled. The
+    /// logo occupies screen base block 0 and character base block 2, so the kept layers'
+    /// graphics must not overlap them, and the palette is overwritten by the logo's.
+    ///
+    /// Fading only applies to the logo's background and the backdrop.
+    pub const fn keep_layers(mut self) -> Self {
+        self.keep_layers = true;
+        self
+    }
+
+    /// Returns the `DISPCNT` value used while the logo is displayed.
+    ///
+    /// The logo's background is only enabled if `logo` is `true`.
+    fn dispcnt(&self, logo: bool) -> u16 {
+        let mut dispcnt = consts::SPLASH_DISPCNT & !BG0_ENABLE;
+        if self.keep_layers {
+            dispcnt |= self.saved.dispcnt & LAYERS & !(self.background.bit() << 8);
+        }
+        if logo {
+            dispcnt |= self.background.bit() << 8;
+        }
+        dispcnt
+    }
+
+    /// Writes the given piece of the logo to VRAM.
+    ///
+    /// `chunk` must be less than `UPLOAD_CHUNKS`. Uploading every chunk writes the entire logo.
+    fn upload_chunk(&self, chunk: u8) {
+        unsafe {
+            match chunk {
+                0 => {
+                    upload(self.dma, &SPLASH_MAP, MAP);
+                    upload(self.dma, &SPLASH_PALETTE, PALETTE);
+                    if let Some(color) = self.backdrop {
+                        BACKDROP.write_volatile(color);
+                    }
+                }
+                1 => upload(
+                    self.dma,
+                    SPLASH_TILES.first_chunk::<TILES_HALF>().unwrap(),
+                    TILES.cast(),
+                ),
+                _ => upload(
+                    self.dma,
+                    SPLASH_TILES.last_chunk::<TILES_HALF>().unwrap(),
+                    TILES.cast::<u8>().add(TILES_HALF).cast(),
+                ),
+            }
+        }
+    }
+
+    /// Returns the `BLDY` coefficient for the given step of a fade out.
+    fn fade_coefficient(&self, step: u8) -> u16 {
+        (BLDY_MAX * step as u16) / self.fade_frames as u16
+    }
+}
+
+impl SplashRenderer for BuiltinSplash {
+    fn prepare(&mut self) {
+        self.saved = DisplayState::save(self.background);
+        unsafe {
+            if self.fade_frames > 0 {
+                // Begin fully darkened, so the logo doesn't appear before it fades in.
+                BLDCNT.write_volatile(BLDCNT_FADE | self.background.bit());
+                BLDY.write_volatile(BLDY_MAX);
+            } else {
+                // Effects left enabled by the program would otherwise apply to the logo.
+                BLDCNT.write_volatile(0);
+            }
+
+            // When deferred, the logo is only enabled once the whole logo has been uploaded.
+            DISPCNT.write_volatile(self.dispcnt(!self.deferred));
+            self.background
+                .control()
+                .write_volatile(consts::SPLASH_BG0CNT | self.priority as u16);
+            self.background.horizontal_offset().write_volatile(0);
+            self.background.vertical_offset().write_volatile(0);
+        }
+
+        if !self.deferred {
+            for chunk in 0..UPLOAD_CHUNKS {
+                self.upload_chunk(chunk);
+            }
+        }
+    }
+
+    fn render_frame(&mut self, frame: u8) {
+        if self.deferred && frame < UPLOAD_CHUNKS {
+            self.upload_chunk(frame);
+            if frame == UPLOAD_CHUNKS - 1 {
+                unsafe {
+                    DISPCNT.write_volatile(self.dispcnt(true));
+                }
+            }
+        }
+        if frame < self.fade_frames {
+            unsafe {
+                BLDY.write_volatile(self.fade_coefficient(self.fade_frames - frame - 1));
+            }
+        }
+    }
+
+    fn teardown(&mut self, waiter: &mut dyn Waiter) {
+        if self.fade_frames > 0 {
+            for step in 1..=self.fade_frames {
+                waiter.wait_for_vblank();
+                unsafe {
+                    BLDY.write_volatile(self.fade_coefficient(step));
+                }
+            }
+            unsafe {
+                BLDY.write_volatile(0);
+            }
+        }
+        self.saved.restore(self.background);
+        bios::reset_vram();
+    }
+}
+
+impl Default for BuiltinSplash {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Copies `data` to `destination`, using `dma` if the data is word aligned.
+///
+/// # Safety
+/// `destination` must be word aligned and valid for writes.
+unsafe fn upload<const N: usize>(dma: Option<Dma>, data: &[u8; N], destination: *mut [u8; N]) {
+    match dma {
+        Some(dma) if N.is_multiple_of(4) && (data.as_ptr().addr()).is_multiple_of(4) => unsafe {
+            dma.copy_words(data.as_ptr().cast(), destination.cast(), (N / 4) as u16);
+        },
+        _ => unsafe { destination.write_volatile(*data) },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        BACKDROP, BLDALPHA, BLDCNT, Background, BuiltinSplash, DISPCNT, MAP, PALETTE, SPLASH_MAP,
+        SPLASH_PALETTE, SPLASH_TILES, SplashRenderer, TILES, WININ, WINOUT,
+    };
+    use crate::BiosWaiter;
+    use core::ptr;
+    use gba_test::test;
+
+    const DISPSTAT: *mut u16 = ptr::with_exposed_provenance_mut(0x0400_0004);
+    const IME: *mut bool = ptr::with_exposed_provenance_mut(0x0400_0208);
+    const IE: *mut u16 = ptr::with_exposed_provenance_mut(0x0400_0200);
+
+    #[test]
+    fn map_references_included_tiles() {
+        let tile_count = SPLASH_TILES.len() / 64;
+
+        for entry in SPLASH_MAP.chunks_exact(2) {
+            let tile = u16::from_le_bytes([entry[0], entry[1]]) & 0x03FF;
+            assert!((tile as usize) < tile_count);
+        }
+    }
+
+    const BG0CNT: *mut u16 = ptr::with_exposed_provenance_mut(0x0400_0008);
+    const BG2CNT: *mut u16 = ptr::with_exposed_provenance_mut(0x0400_000C);
+
+    #[test]
+    fn builtin_prepare() {
+        let mut splash = BuiltinSplash::new();
+
+        splash.prepare();
+
+        unsafe {
+            assert_eq!(DISPCNT.read_volatile(), 256);
+            assert_eq!(BG0CNT.read_volatile(), 0x88);
+        }
+    }
+
+    #[test]
+    fn builtin_prepare_uploads_logo() {
+        let mut splash = BuiltinSplash::new();
+
+        splash.prepare();
+
+        unsafe {
+            assert_eq!(TILES.read_volatile(), SPLASH_TILES);
+            assert_eq!(PALETTE.read_volatile(), SPLASH_PALETTE);
+        }
+    }
+
+    #[test]
+    fn builtin_prepare_uploads_logo_without_dma() {
+        let mut splash = BuiltinSplash::new().dma(None);
+        unsafe {
+            TILES.write_volatile([0; 0x1C00]);
+        }
+
+        splash.prepare();
+
+        unsafe {
+            assert_eq!(TILES.read_volatile(), SPLASH_TILES);
+            assert_eq!(PALETTE.read_volatile(), SPLASH_PALETTE);
+        }
+    }
+
+    #[test]
+    fn builtin_prepare_layer() {
+        let mut splash = BuiltinSplash::new().layer(Background::Bg2, 3);
+
+        splash.prepare();
+
+        unsafe {
+            assert_eq!(DISPCNT.read_volatile(), 0x0400);
+            assert_eq!(BG2CNT.read_volatile(), 0x8B);
+        }
+        splash.teardown(&mut BiosWaiter);
+    }
+
+    #[test]
+    fn builtin_prepare_keep_layers() {
+        let mut splash = BuiltinSplash::new().keep_layers();
+        unsafe {
+            DISPCNT.write_volatile(0x7203);
+        }
+
+        splash.prepare();
+
+        unsafe {
+            assert_eq!(DISPCNT.read_volatile(), 0x1300);
+        }
+        splash.teardown(&mut BiosWaiter);
+        unsafe {
+            assert_eq!(DISPCNT.read_volatile(), 0x7203);
+        }
+    }
+
+    #[test]
+    fn builtin_deferred_uploads_over_frames() {
+        let mut splash = BuiltinSplash::new().deferred();
+        unsafe {
+            TILES.write_volatile([0; 0x1C00]);
+        }
+
+        splash.prepare();
+
+        unsafe {
+            assert_eq!(DISPCNT.read_volatile(), 0);
+        }
+        for frame in 0..3 {
+            splash.render_frame(frame);
+        }
+        unsafe {
+            assert_eq!(DISPCNT.read_volatile(), 0x0100);
+            assert_eq!(TILES.read_volatile(), SPLASH_TILES);
+            assert_eq!(MAP.read_volatile(), SPLASH_MAP);
+            assert_eq!(PALETTE.read_volatile(), SPLASH_PALETTE);
+        }
+    }
+
+    #[test]
+    fn splash_placement() {
+        let address = SPLASH_TILES.as_ptr().addr();
+
+        #[cfg(not(multiboot))]
+        assert!(address >= 0x0800_0000);
+        // Multiboot programs are loaded into EWRAM in place of ROM.
+        #[cfg(multiboot)]
+        assert!((0x0200_0000..0x0204_0000).contains(&address));
+    }
+
+    #[test]
+    fn builtin_teardown_restores_display() {
+        let mut splash = BuiltinSplash::new();
+        unsafe {
+            DISPCNT.write_volatile(0x0403);
+            BG0CNT.write_volatile(0x1234);
+        }
+
+        splash.prepare();
+        splash.teardown(&mut BiosWaiter);
+
+        unsafe {
+            assert_eq!(DISPCNT.read_volatile(), 0x0403);
+            assert_eq!(BG0CNT.read_volatile(), 0x1234);
+        }
+    }
+
+    #[test]
+    fn builtin_fade_prepare() {
+        let mut splash = BuiltinSplash::new().fade(16);
+        unsafe {
+            BLDCNT.write_volatile(0);
+        }
+
+        splash.prepare();
+
+        unsafe {
+            assert_eq!(BLDCNT.read_volatile(), 0b11 << 6 | 1 << 5 | 1);
+        }
+    }
+
+    #[test]
+    fn builtin_fade_coefficient() {
+        let splash = BuiltinSplash::new().fade(8);
+
+        assert_eq!(splash.fade_coefficient(0), 0);
+        assert_eq!(splash.fade_coefficient(4), 8);
+        assert_eq!(splash.fade_coefficient(8), 16);
+    }
+
+    #[test]
+    fn builtin_fade_teardown_restores_blend() {
+        let mut splash = BuiltinSplash::new().fade(4);
+        unsafe {
+            DISPSTAT.write_volatile(8);
+            IE.write_volatile(1);
+            IME.write(true);
+            BLDCNT.write_volatile(0x0241);
+        }
+
+        splash.prepare();
+        splash.teardown(&mut BiosWaiter);
+
+        unsafe {
+            assert_eq!(BLDCNT.read_volatile(), 0x0241);
+        }
+    }
+
+    #[test]
+    fn builtin_disables_blend() {
+        let mut splash = BuiltinSplash::new();
+        unsafe {
+            BLDCNT.write_volatile(0x0241);
+        }
+
+        splash.prepare();
+
+        unsafe {
+            assert_eq!(BLDCNT.read_volatile(), 0);
+        }
+    }
+
+    #[test]
+    fn builtin_teardown_restores_effects() {
+        let mut splash = BuiltinSplash::new();
+        unsafe {
+            WININ.write_volatile(0x1F3F);
+            WINOUT.write_volatile(0x2A15);
+            BLDCNT.write_volatile(0x0241);
+            BLDALPHA.write_volatile(0x0A06);
+        }
+
+        splash.prepare();
+        splash.teardown(&mut BiosWaiter);
+
+        unsafe {
+            assert_eq!(WININ.read_volatile(), 0x1F3F);
+            assert_eq!(WINOUT.read_volatile(), 0x2A15);
+            assert_eq!(BLDCNT.read_volatile(), 0x0241);
+            assert_eq!(BLDALPHA.read_volatile(), 0x0A06);
+        }
+    }
+
+    #[test]
+    fn builtin_default_backdrop() {
+        let mut splash = BuiltinSplash::new();
+
+        splash.prepare();
+
+        unsafe {
+            assert_eq!(BACKDROP.read_volatile(), 0xFFDF);
+        }
+    }
+
+    #[test]
+    fn builtin_backdrop() {
+        let mut splash = BuiltinSplash::new().backdrop(0x7C00);
+
+        splash.prepare();
+
+        unsafe {
+            assert_eq!(BACKDROP.read_volatile(), 0x7C00);
+        }
+    }
+}
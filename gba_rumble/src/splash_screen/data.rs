@@ -0,0 +1,519 @@
+//! Raw data for displaying the Game Boy Player splash screen.
+
+pub(crate) const PALETTE: [u8; 128] = [
+    0xDF, 0xFF, 0x0C, 0x64, 0x0C, 0xE4, 0x2D, 0xE4, 0x4E, 0x64, 0x4E, 0xE4, 0x6E, 0xE4, 0xAF, 0x68,
+    0xB0, 0xE8, 0xD0, 0x68, 0xF0, 0x68, 0x11, 0x69, 0x11, 0xE9, 0x32, 0x6D, 0x32, 0xED, 0x73, 0xED,
+    0x93, 0x6D, 0x94, 0xED, 0xB4, 0x6D, 0xD5, 0xF1, 0xF5, 0x71, 0xF6, 0xF1, 0x16, 0x72, 0x57, 0x72,
+    0x57, 0xF6, 0x78, 0x76, 0x78, 0xF6, 0x99, 0xF6, 0xB9, 0xF6, 0xD9, 0x76, 0xDA, 0xF6, 0x1B, 0x7B,
+    0x1B, 0xFB, 0x3C, 0xFB, 0x5C, 0x7B, 0x7D, 0x7B, 0x7D, 0xFF, 0x9D, 0x7F, 0xBE, 0x7F, 0xFF, 0x7F,
+    0x2D, 0x64, 0x8E, 0x64, 0x8F, 0xE8, 0xF1, 0xE8, 0x52, 0x6D, 0x73, 0x6D, 0xB4, 0xF1, 0x16, 0xF2,
+    0x37, 0x72, 0x98, 0x76, 0xFA, 0x7A, 0xFA, 0xFA, 0x5C, 0xFB, 0xBE, 0xFF, 0xDE, 0x7F, 0xFF, 0xFF,
+    0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
+
+pub(crate) const MAP: [u8; 844] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00,
+    0x04, 0x00, 0x05, 0x00, 0x06, 0x00, 0x07, 0x00, 0x08, 0x00, 0x09, 0x00, 0x0a, 0x00, 0x0b, 0x00,
+    0x0c, 0x00, 0x0d, 0x00, 0x0e, 0x00, 0x0f, 0x00, 0x10, 0x00, 0x11, 0x00, 0x12, 0x00, 0x13, 0x00,
+    0x14, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x16, 0x00, 0x17, 0x00, 0x18, 0x00, 0x19, 0x00,
+    0x1a, 0x00, 0x1b, 0x00, 0x1c, 0x00, 0x1d, 0x00, 0x1e, 0x00, 0x1f, 0x00, 0x20, 0x00, 0x21, 0x00,
+    0x22, 0x00, 0x23, 0x00, 0x24, 0x00, 0x25, 0x00, 0x26, 0x00, 0x27, 0x00, 0x28, 0x00, 0x29, 0x00,
+    0x2a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x2c, 0x00, 0x2d, 0x00, 0x2e, 0x00,
+    0x2f, 0x00, 0x30, 0x00, 0x31, 0x00, 0x32, 0x00, 0x33, 0x00, 0x34, 0x00, 0x35, 0x00, 0x36, 0x00,
+    0x37, 0x00, 0x38, 0x00, 0x39, 0x00, 0x3a, 0x00, 0x3b, 0x00, 0x3c, 0x00, 0x3d, 0x00, 0x3e, 0x00,
+    0x3f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x41, 0x00, 0x42, 0x00,
+    0x43, 0x00, 0x44, 0x00, 0x45, 0x00, 0x46, 0x00, 0x47, 0x00, 0x48, 0x00, 0x49, 0x00, 0x4a, 0x00,
+    0x4b, 0x00, 0x4c, 0x00, 0x4d, 0x00, 0x4e, 0x00, 0x4f, 0x00, 0x50, 0x00, 0x51, 0x00, 0x52, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x53, 0x00, 0x54, 0x00, 0x55, 0x00,
+    0x56, 0x00, 0x57, 0x00, 0x00, 0x00, 0x00, 0x00, 0x58, 0x00, 0x59, 0x00, 0x00, 0x00, 0x5a, 0x00,
+    0x5b, 0x00, 0x5c, 0x00, 0x00, 0x00, 0x5d, 0x00, 0x5e, 0x00, 0x00, 0x00, 0x5f, 0x00, 0x60, 0x00,
+    0x61, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x62, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x63, 0x00, 0x64, 0x00, 0x65, 0x00, 0x66, 0x00, 0x67, 0x00, 0x68, 0x00, 0x69, 0x00, 0x00, 0x00,
+    0x6a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6b, 0x00, 0x6c, 0x00, 0x00, 0x00, 0x6d, 0x00, 0x6e, 0x00,
+    0x6f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
+
+pub(crate) const TILES: [u8; 0x1C00] = [
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x22, 0x0c,
+    0x37, 0x37, 0x37, 0x37, 0x27, 0x2f, 0x02, 0x01, 0x37, 0x37, 0x37, 0x00, 0x0d, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x00, 0x0a, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x0d, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x36, 0x21, 0x31, 0x37, 0x37, 0x34, 0x14, 0x09, 0x01, 0x01, 0x01,
+    0x1e, 0x2b, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x02, 0x0b, 0x13, 0x01, 0x01, 0x01, 0x2b, 0x1c, 0x27, 0x37, 0x37,
+    0x19, 0x19, 0x19, 0x1e, 0x21, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x03, 0x0e, 0x30,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x28, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x08,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x2c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x15,
+    0x19, 0x19, 0x19, 0x19, 0x12, 0x2b, 0x01, 0x1c, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x26, 0x36,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x27, 0x37, 0x37, 0x37, 0x37, 0x37, 0x33, 0x09,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x25, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x13, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x36, 0x03, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x30, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x27, 0x06, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0d, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x00, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x20, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x1a, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x13, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0d, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x29, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x27,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x33, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x12,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x05, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1f, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x33, 0x09, 0x01, 0x01, 0x01, 0x01, 0x31, 0x37,
+    0x11, 0x01, 0x01, 0x01, 0x01, 0x01, 0x10, 0x37, 0x04, 0x01, 0x01, 0x01, 0x01, 0x01, 0x09, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x28, 0x27, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x34,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x1b, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x14,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x18,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x27, 0x06, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x31, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0a, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x22, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x0f, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x26, 0x02, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x26,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x26, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x22,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x22, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x32,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x36, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x20, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x19, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x13, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x0e, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x29, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x05, 0x21, 0x21, 0x21, 0x21, 0x21, 0x21, 0x21,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x10, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x11, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x30, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x32, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x24, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x28, 0x37, 0x37, 0x37, 0x37, 0x37, 0x21, 0x21, 0x34, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x22, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x18, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x12, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x0c, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x05, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x36, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x20, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x15, 0x32,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x2d, 0x21, 0x21, 0x21, 0x1e, 0x15, 0x03, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x00, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x03, 0x19, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x0c, 0x00, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x0d, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x1b, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x09, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x00, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x36,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x25, 0x11, 0x02, 0x37, 0x37, 0x37, 0x37, 0x1a, 0x28, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x12, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x13, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x1a, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x24, 0x03, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1c, 0x11, 0x10, 0x2d, 0x11, 0x31, 0x35, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x02, 0x10, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x08, 0x2c, 0x06, 0x01, 0x01, 0x01, 0x10, 0x36, 0x37, 0x37, 0x37, 0x18, 0x02, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1c, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x36, 0x03, 0x01, 0x01, 0x01, 0x03, 0x20, 0x37, 0x37, 0x13, 0x01, 0x01, 0x01,
+    0x01, 0x04, 0x21, 0x37, 0x37, 0x02, 0x01, 0x01, 0x01, 0x01, 0x04, 0x34, 0x37, 0x0f, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x07, 0x27, 0x22, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x19, 0x37, 0x0c, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x36, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x31, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x0a, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x22, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x13, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x04, 0x27, 0x37, 0x37, 0x20, 0x01, 0x01, 0x01, 0x01, 0x31, 0x37, 0x27, 0x09,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x24, 0x0a, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x0e, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x1c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x36, 0x06, 0x01, 0x01, 0x01, 0x01, 0x01, 0x10, 0x12, 0x01, 0x01, 0x01, 0x01, 0x01, 0x05, 0x35,
+    0x02, 0x01, 0x01, 0x01, 0x01, 0x01, 0x1b, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0d, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x31, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x2a, 0x00, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x33, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1f, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x30,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0f, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c,
+    0x37, 0x1a, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x36, 0x03, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x14, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x2e, 0x05, 0x01, 0x01, 0x01, 0x01, 0x01, 0x2c, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x03, 0x26, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x16, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x23, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x04, 0x37, 0x37, 0x37,
+    0x01, 0x07, 0x21, 0x37, 0x37, 0x37, 0x37, 0x37, 0x11, 0x00, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x11, 0x09, 0x09, 0x09, 0x09,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x09, 0x09, 0x09, 0x09, 0x09, 0x0f, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x31, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x09, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x22, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x0f, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x25, 0x02, 0x01, 0x01, 0x01, 0x01, 0x08, 0x37, 0x13, 0x01, 0x01, 0x01, 0x01, 0x01, 0x31,
+    0x36, 0x03, 0x01, 0x01, 0x01, 0x01, 0x06, 0x27, 0x30, 0x01, 0x01, 0x01, 0x01, 0x01, 0x04, 0x09,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x36, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x20,
+    0x0c, 0x06, 0x01, 0x01, 0x01, 0x01, 0x01, 0x1a, 0x32, 0x0e, 0x01, 0x01, 0x01, 0x01, 0x01, 0x13,
+    0x37, 0x15, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0c, 0x37, 0x1d, 0x01, 0x01, 0x01, 0x01, 0x01, 0x29,
+    0x37, 0x25, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x09, 0x09, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x2e, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x29, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x20, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x14, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x2a, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x22, 0x01, 0x01, 0x01,
+    0x36, 0x37, 0x37, 0x37, 0x15, 0x01, 0x01, 0x01, 0x20, 0x37, 0x37, 0x37, 0x08, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0d, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x06,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x05, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x13, 0x05, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x20, 0x0d, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x30, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x27, 0x06, 0x01, 0x01, 0x01,
+    0x35, 0x37, 0x37, 0x31, 0x01, 0x01, 0x01, 0x01, 0x33, 0x37, 0x37, 0x0c, 0x01, 0x01, 0x01, 0x01,
+    0x17, 0x37, 0x22, 0x01, 0x01, 0x01, 0x01, 0x01, 0x10, 0x37, 0x0f, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x08, 0x36, 0x28, 0x01, 0x01, 0x01, 0x01, 0x03, 0x02, 0x2f, 0x01, 0x01, 0x01, 0x01, 0x01, 0x13,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x32, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x31,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x31, 0x01, 0x02, 0x01, 0x01, 0x01, 0x01, 0x01, 0x30,
+    0x2a, 0x08, 0x01, 0x01, 0x01, 0x01, 0x01, 0x30, 0x30, 0x2b, 0x01, 0x01, 0x01, 0x01, 0x01, 0x13,
+    0x36, 0x0c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x13, 0x37, 0x2c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0f,
+    0x37, 0x37, 0x00, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x22, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x1b, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x15, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x2c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x08, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x27, 0x28, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x23, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x2b, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x11, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x30, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1d, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x07, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x2c, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0e, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x14, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x31, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x31, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x13, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x0e, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x2a, 0x01, 0x01, 0x01, 0x01, 0x05,
+    0x37, 0x00, 0x02, 0x01, 0x01, 0x01, 0x01, 0x05, 0x37, 0x22, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x1c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x16, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x18, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1c, 0x01, 0x1f, 0x37, 0x37, 0x37, 0x37, 0x37, 0x26, 0x01,
+    0x35, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1d, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x22, 0x06, 0x01,
+    0x11, 0x11, 0x11, 0x11, 0x0a, 0x02, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x26, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x27, 0x37, 0x22,
+    0x01, 0x01, 0x01, 0x01, 0x2b, 0x37, 0x37, 0x12, 0x01, 0x01, 0x01, 0x01, 0x31, 0x37, 0x37, 0x29,
+    0x01, 0x01, 0x01, 0x09, 0x37, 0x37, 0x22, 0x01, 0x01, 0x01, 0x03, 0x34, 0x37, 0x37, 0x19, 0x01,
+    0x01, 0x01, 0x1c, 0x37, 0x37, 0x37, 0x2e, 0x01, 0x01, 0x01, 0x0d, 0x27, 0x37, 0x37, 0x0f, 0x01,
+    0x2c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x1b, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x15, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x0b, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x1f, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x08, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x15, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x33, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x26, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x17, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x2b,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1a, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x35,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x07, 0x37, 0x32, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x21, 0x37, 0x08,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x2f, 0x37, 0x31, 0x01, 0x01, 0x01, 0x01, 0x01, 0x2c, 0x37, 0x27,
+    0x04, 0x01, 0x01, 0x01, 0x01, 0x08, 0x37, 0x37, 0x08, 0x01, 0x01, 0x01, 0x01, 0x29, 0x37, 0x37,
+    0x06, 0x01, 0x01, 0x01, 0x01, 0x05, 0x37, 0x37, 0x02, 0x01, 0x01, 0x01, 0x01, 0x08, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x0c, 0x37, 0x30, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x33, 0x03, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x28, 0x01, 0x01, 0x06, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x30, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x36, 0x03, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x13, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x02, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x03, 0x24, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x17, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x0a, 0x27, 0x37, 0x37, 0x37, 0x01, 0x01, 0x02, 0x21, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x13, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x29, 0x00, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x32, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0f, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x08,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x2c,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x30, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x34,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x07, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x2a, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x02, 0x00, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x31, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x0a, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x15, 0x37,
+    0x0c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x11, 0x34, 0x28, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x29, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x00, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x22, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x1c, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x22, 0x19, 0x19, 0x19, 0x16, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1e, 0x01,
+    0x36, 0x37, 0x37, 0x37, 0x37, 0x37, 0x2f, 0x01, 0x03, 0x0f, 0x19, 0x31, 0x19, 0x14, 0x05, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x0f, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x16, 0x37, 0x32,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x1c, 0x37, 0x0c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x22, 0x22, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x02, 0x27, 0x0f, 0x01, 0x01, 0x01, 0x01, 0x01, 0x2a, 0x25, 0x02, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x0e, 0x15, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x2e, 0x06, 0x01, 0x01,
+    0x07, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x0e, 0x11, 0x11, 0x11, 0x01, 0x01, 0x01, 0x06, 0x27, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x30, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x03, 0x36, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x11, 0x11, 0x0f, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x28, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x09, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x10, 0x01, 0x01, 0x01, 0x01,
+    0x1a, 0x37, 0x37, 0x23, 0x01, 0x01, 0x01, 0x01, 0x13, 0x37, 0x37, 0x2f, 0x01, 0x01, 0x01, 0x01,
+    0x0c, 0x37, 0x37, 0x0a, 0x01, 0x01, 0x01, 0x01, 0x29, 0x37, 0x24, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x36, 0x17, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x20, 0x2b, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x16, 0x01, 0x01, 0x01, 0x01, 0x01, 0x28, 0x01, 0x04, 0x01, 0x01, 0x01, 0x01, 0x01, 0x2c,
+    0x01, 0x05, 0x37, 0x15, 0x01, 0x01, 0x01, 0x01, 0x01, 0x12, 0x37, 0x1e, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x32, 0x37, 0x26, 0x01, 0x01, 0x01, 0x01, 0x04, 0x27, 0x37, 0x37, 0x06, 0x01, 0x01, 0x01,
+    0x0f, 0x37, 0x37, 0x37, 0x0e, 0x01, 0x01, 0x01, 0x1d, 0x37, 0x37, 0x37, 0x2f, 0x01, 0x01, 0x01,
+    0x00, 0x37, 0x37, 0x37, 0x32, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x35, 0x01, 0x01, 0x01,
+    0x01, 0x28, 0x01, 0x01, 0x01, 0x01, 0x01, 0x34, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0c, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x32, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x08, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x30, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x03, 0x36, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x13, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x25, 0x37, 0x37, 0x37,
+    0x37, 0x0f, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0f, 0x37, 0x12, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0c,
+    0x37, 0x13, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0c, 0x37, 0x16, 0x01, 0x01, 0x01, 0x01, 0x01, 0x08,
+    0x37, 0x30, 0x01, 0x01, 0x01, 0x01, 0x01, 0x08, 0x37, 0x19, 0x01, 0x01, 0x01, 0x01, 0x01, 0x05,
+    0x37, 0x31, 0x01, 0x01, 0x01, 0x01, 0x01, 0x05, 0x37, 0x1e, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x1d, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x30, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x0f, 0x01, 0x01, 0x01, 0x01, 0x01, 0x1d, 0x37, 0x0a, 0x01, 0x01, 0x01, 0x01, 0x01, 0x24,
+    0x37, 0x03, 0x01, 0x01, 0x01, 0x01, 0x28, 0x37, 0x26, 0x01, 0x01, 0x01, 0x01, 0x01, 0x09, 0x37,
+    0x32, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0f, 0x37, 0x18, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x20, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x36, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1a, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x2c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x08, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x28, 0x01, 0x01, 0x01, 0x01, 0x0a, 0x37, 0x24, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0f, 0x37,
+    0x1d, 0x01, 0x01, 0x01, 0x01, 0x01, 0x16, 0x37, 0x30, 0x01, 0x01, 0x01, 0x01, 0x01, 0x1c, 0x37,
+    0x11, 0x01, 0x01, 0x01, 0x01, 0x01, 0x23, 0x37, 0x0a, 0x01, 0x01, 0x01, 0x01, 0x01, 0x18, 0x19,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x23, 0x30, 0x02, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x30, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x22, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x31, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x22, 0x04, 0x01, 0x19, 0x19, 0x19, 0x11, 0x0c, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x12, 0x37, 0x37, 0x0d, 0x01, 0x01, 0x01, 0x01, 0x01, 0x22, 0x37, 0x0f, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x2e, 0x37, 0x12, 0x01, 0x01, 0x01, 0x01, 0x01, 0x09, 0x37, 0x18, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x07, 0x37, 0x21, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0e, 0x37, 0x37, 0x29,
+    0x01, 0x01, 0x01, 0x01, 0x30, 0x37, 0x37, 0x16, 0x01, 0x01, 0x01, 0x03, 0x35, 0x37, 0x37, 0x35,
+    0x01, 0x01, 0x01, 0x01, 0x27, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x25, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x1d, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x0f, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x02, 0x22, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x08, 0x36, 0x37, 0x03, 0x01, 0x01, 0x01, 0x01, 0x01, 0x29, 0x1b,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x21, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x17,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x33, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x00, 0x08, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x2d, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x27, 0x2e, 0x01, 0x01, 0x01, 0x25, 0x35, 0x1b, 0x08, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x0c, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x13, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x1c, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x02, 0x36, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x0f, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x22, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x0d, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x03, 0x24, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x27, 0x02, 0x01, 0x01, 0x01, 0x01, 0x04, 0x37, 0x23, 0x01, 0x01, 0x01, 0x01, 0x01, 0x2f,
+    0x37, 0x1c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x1d, 0x37, 0x16, 0x01, 0x01, 0x01, 0x01, 0x01, 0x23,
+    0x37, 0x0f, 0x01, 0x01, 0x01, 0x01, 0x02, 0x27, 0x37, 0x0a, 0x01, 0x01, 0x01, 0x01, 0x2a, 0x37,
+    0x37, 0x03, 0x01, 0x01, 0x01, 0x01, 0x0e, 0x37, 0x26, 0x01, 0x01, 0x01, 0x01, 0x01, 0x13, 0x37,
+    0x35, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x18, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x2f, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x31, 0x03, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x26, 0x0f, 0x02, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x26, 0x2f, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x0d, 0x28, 0x01, 0x01, 0x01, 0x01, 0x06, 0x0e, 0x37, 0x37, 0x37, 0x22, 0x23, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x0c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x02, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x03, 0x16, 0x0a, 0x01, 0x01, 0x01, 0x01,
+    0x1b, 0x00, 0x25, 0x09, 0x09, 0x09, 0x09, 0x09, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x13, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x23, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x0c, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x32, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x0e, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x30, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x32, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x26, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x04, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x10, 0x09, 0x09, 0x09, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x31, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x02, 0x36,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0c, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x19, 0x37,
+    0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x26, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x29, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x2d, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x30, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x33, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x36, 0x09, 0x09, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x0c, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x32, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x19, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x09, 0x0a, 0x36, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x32, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x21, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x22, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x25, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x35, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x02, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x05, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x2b,
+    0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x2f, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x20, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x36, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x26,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1f, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x18,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x30, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x03, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x29,
+    0x09, 0x09, 0x09, 0x09, 0x09, 0x10, 0x1d, 0x27, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x17, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x0f, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x11, 0x27, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1c, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x17, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x0e, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x00, 0x0b, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x27, 0x12, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x22, 0x2d, 0x03, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x00, 0x21,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x02, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x08, 0x20,
+    0x01, 0x01, 0x01, 0x01, 0x0a, 0x1a, 0x37, 0x37, 0x1c, 0x1a, 0x21, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x1b, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x30, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x17, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x1f, 0x01, 0x01, 0x01, 0x01, 0x01, 0x19, 0x37, 0x18, 0x01, 0x01, 0x01, 0x01, 0x01, 0x1f, 0x37,
+    0x12, 0x01, 0x01, 0x01, 0x01, 0x01, 0x26, 0x37, 0x0c, 0x01, 0x01, 0x01, 0x01, 0x03, 0x37, 0x37,
+    0x0e, 0x09, 0x09, 0x09, 0x09, 0x0f, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x1b, 0x21, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x22, 0x37,
+    0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x22, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x1b, 0x21,
+    0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x2a, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x21, 0x21, 0x21, 0x21, 0x21, 0x12, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x26, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x26, 0x01, 0x01, 0x21, 0x21, 0x21, 0x21, 0x21, 0x13, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x2a,
+    0x22, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x06, 0x27, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x1f, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x32, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x32, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x1f, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x06, 0x27, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x22, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c,
+    0x01, 0x01, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x27, 0x2a, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x2f, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x26, 0x03, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x10, 0x01, 0x01, 0x04,
+    0x37, 0x37, 0x37, 0x21, 0x01, 0x01, 0x01, 0x19, 0x37, 0x37, 0x37, 0x0a, 0x01, 0x01, 0x0a, 0x37,
+    0x37, 0x37, 0x1b, 0x01, 0x01, 0x02, 0x21, 0x37, 0x37, 0x36, 0x06, 0x01, 0x01, 0x12, 0x37, 0x37,
+    0x01, 0x03, 0x26, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x10, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x20, 0x37, 0x37, 0x37, 0x37, 0x08, 0x01, 0x01, 0x0a, 0x37, 0x37, 0x37, 0x37,
+    0x33, 0x01, 0x01, 0x01, 0x1a, 0x37, 0x37, 0x37, 0x37, 0x0e, 0x01, 0x01, 0x05, 0x36, 0x37, 0x37,
+    0x37, 0x34, 0x02, 0x01, 0x01, 0x13, 0x37, 0x37, 0x37, 0x37, 0x12, 0x01, 0x01, 0x02, 0x23, 0x37,
+    0x37, 0x37, 0x1e, 0x02, 0x01, 0x01, 0x07, 0x35, 0x37, 0x37, 0x37, 0x1b, 0x02, 0x01, 0x01, 0x0a,
+    0x37, 0x37, 0x37, 0x37, 0x31, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x18, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x30, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x15,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x30, 0x01, 0x00, 0x37, 0x37, 0x37, 0x37, 0x31, 0x01, 0x01,
+    0x0b, 0x00, 0x37, 0x37, 0x1b, 0x02, 0x01, 0x01, 0x01, 0x0d, 0x27, 0x1e, 0x02, 0x01, 0x01, 0x2c,
+    0x01, 0x01, 0x0d, 0x28, 0x01, 0x01, 0x0d, 0x27, 0x01, 0x01, 0x01, 0x01, 0x01, 0x0c, 0x00, 0x37,
+    0x13, 0x01, 0x01, 0x01, 0x0a, 0x00, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x30, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x13, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x10, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x0f, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x27, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x32, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x32, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x32, 0x01, 0x01, 0x01, 0x2e, 0x19, 0x19, 0x19, 0x32, 0x01, 0x01, 0x01, 0x32, 0x37, 0x37, 0x37,
+    0x32, 0x01, 0x01, 0x01, 0x18, 0x21, 0x21, 0x21, 0x32, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x32, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x32, 0x01, 0x01, 0x01, 0x2e, 0x19, 0x19, 0x19,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x32, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x32, 0x37,
+    0x19, 0x19, 0x19, 0x19, 0x19, 0x19, 0x25, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x21, 0x21, 0x35, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x31, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x31, 0x37, 0x37, 0x37, 0x37, 0x37, 0x19, 0x19, 0x23, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x1b, 0x21, 0x21, 0x21, 0x21, 0x21, 0x21, 0x01, 0x22, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x22, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x2c, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x07, 0x09, 0x06, 0x01, 0x01, 0x01, 0x02,
+    0x01, 0x01, 0x08, 0x22, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x06, 0x27, 0x37, 0x37, 0x37,
+    0x2e, 0x01, 0x01, 0x01, 0x1f, 0x37, 0x37, 0x37, 0x26, 0x01, 0x01, 0x01, 0x32, 0x37, 0x37, 0x37,
+    0x26, 0x01, 0x01, 0x01, 0x32, 0x37, 0x37, 0x37, 0x0a, 0x01, 0x01, 0x01, 0x21, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x0d, 0x37, 0x37, 0x37, 0x37, 0x09, 0x09, 0x15, 0x00, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x1b, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x22, 0x37,
+    0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x22, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x22, 0x37,
+    0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x25, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x0c,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x17, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x08, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x32, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x01, 0x32, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x34, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x1f,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x15, 0x01, 0x01, 0x05, 0x36, 0x37, 0x37, 0x25, 0x28, 0x01, 0x01, 0x0e, 0x19, 0x19, 0x19,
+    0x2c, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x26, 0x03, 0x01, 0x01, 0x0e, 0x37, 0x23, 0x37, 0x37, 0x2f, 0x01, 0x01, 0x01, 0x1e,
+    0x31, 0x37, 0x37, 0x27, 0x06, 0x01, 0x01, 0x2a, 0x31, 0x37, 0x37, 0x37, 0x1b, 0x01, 0x01, 0x01,
+    0x20, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x27, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x30, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x01, 0x01, 0x01, 0x30, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x30, 0x37, 0x37, 0x37,
+    0x37, 0x01, 0x01, 0x01, 0x30, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x01, 0x30, 0x37, 0x37, 0x37,
+    0x37, 0x01, 0x01, 0x01, 0x32, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x32, 0x01, 0x01, 0x01, 0x32, 0x37, 0x37, 0x37, 0x32, 0x01, 0x01, 0x01, 0x32, 0x37, 0x37, 0x37,
+    0x32, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x32, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x34, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x31, 0x37, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x31, 0x37,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x20, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x01, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x22, 0x37, 0x00, 0x0f, 0x01, 0x01, 0x01, 0x01, 0x22, 0x37, 0x37, 0x37, 0x17, 0x02, 0x01,
+    0x01, 0x22, 0x37, 0x37, 0x37, 0x37, 0x33, 0x05, 0x01, 0x22, 0x37, 0x37, 0x37, 0x37, 0x37, 0x24,
+    0x01, 0x25, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x2c, 0x00, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x01, 0x07, 0x34, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x01, 0x01, 0x28, 0x1c, 0x37, 0x37, 0x37, 0x37, 0x09, 0x01, 0x01, 0x01, 0x2e, 0x27, 0x37, 0x37,
+    0x00, 0x01, 0x01, 0x01, 0x01, 0x1a, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+    0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+];
@@ -0,0 +1,234 @@
+//! A backend for testing rumble logic.
+
+use crate::{Backend, Capabilities, Command, TimedCommand};
+use core::{fmt::Debug, ops::RangeBounds};
+
+/// A [`Backend`] that records commands so tests can make assertions about them.
+///
+/// This allows games to verify their rumble logic in `gba_test` ROM tests without rumble hardware.
+/// Up to `N` commands are recorded, along with the frame they were sent on. Frames are counted by
+/// calls to [`tick()`], which should be made once per frame after that frame's commands have been
+/// sent.
+///
+/// ``` rust
+/// use gba_rumble::{Driver, Intensity, RumbleSpy};
+///
+/// let mut driver = Driver::<_>::new(RumbleSpy::<16>::new());
+/// for frame in 0..10 {
+///     driver.tick(if frame >= 2 && frame < 5 { Intensity::MAX } else { Intensity::OFF });
+///     driver.backend_mut().tick();
+/// }
+///
+/// driver.backend().assert_started_between(0..4);
+/// driver.backend().assert_total_on_frames(3);
+/// ```
+///
+/// [`tick()`]: RumbleSpy::tick()
+#[derive(Clone, Debug)]
+pub struct RumbleSpy<const N: usize = 64> {
+    commands: [Option<TimedCommand>; N],
+    len: usize,
+    frame: u32,
+}
+
+impl<const N: usize> RumbleSpy<N> {
+    /// Creates a spy with no recorded commands, starting at frame `0`.
+    pub const fn new() -> Self {
+        Self {
+            commands: [None; N],
+            len: 0,
+            frame: 0,
+        }
+    }
+
+    /// Advances to the next frame.
+    pub fn tick(&mut self) {
+        self.frame = self.frame.wrapping_add(1);
+    }
+
+    /// Returns the current frame.
+    pub const fn frame(&self) -> u32 {
+        self.frame
+    }
+
+    /// Returns an iterator over the recorded commands, in the order they were sent.
+    pub fn commands(&self) -> impl Iterator<Item = TimedCommand> + '_ {
+        self.commands[..self.len].iter().flatten().copied()
+    }
+
+    /// Returns whether the motor is currently on.
+    pub fn is_on(&self) -> bool {
+        self.commands()
+            .last()
+            .is_some_and(|entry| entry.command == Command::Start)
+    }
+
+    /// Returns the number of frames the motor has been on, up to the current frame.
+    pub fn on_frames(&self) -> u32 {
+        let mut total = 0;
+        let mut started = None;
+        for entry in self.commands() {
+            match (entry.command, started) {
+                (Command::Start, None) => started = Some(entry.frame),
+                (Command::Stop | Command::HardStop, Some(frame)) => {
+                    total += entry.frame.wrapping_sub(frame);
+                    started = None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(frame) = started {
+            total += self.frame.wrapping_sub(frame);
+        }
+        total
+    }
+
+    /// Asserts that the motor was started on a frame within `frames`.
+    ///
+    /// # Panics
+    /// Panics if no [`Command::Start`] was sent within `frames`.
+    #[track_caller]
+    pub fn assert_started_between<R>(&self, frames: R)
+    where
+        R: RangeBounds<u32> + Debug,
+    {
+        assert!(
+            self.commands()
+                .any(|entry| entry.command == Command::Start && frames.contains(&entry.frame)),
+            "rumble was not started between frames {frames:?}"
+        );
+    }
+
+    /// Asserts that the motor has been on for exactly `frames` frames in total.
+    ///
+    /// # Panics
+    /// Panics if [`on_frames()`] does not equal `frames`.
+    ///
+    /// [`on_frames()`]: RumbleSpy::on_frames()
+    #[track_caller]
+    pub fn assert_total_on_frames(&self, frames: u32) {
+        let on_frames = self.on_frames();
+        assert!(
+            on_frames == frames,
+            "rumble was on for {on_frames} frames, expected {frames}"
+        );
+    }
+
+    /// Asserts that the motor was never started.
+    ///
+    /// # Panics
+    /// Panics if any [`Command::Start`] was sent.
+    #[track_caller]
+    pub fn assert_never_started(&self) {
+        if let Some(entry) = self
+            .commands()
+            .find(|entry| entry.command == Command::Start)
+        {
+            panic!("rumble was started on frame {}", entry.frame);
+        }
+    }
+}
+
+impl<const N: usize> Default for RumbleSpy<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Backend for RumbleSpy<N> {
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            has_intensity: false,
+            has_brake: true,
+            latency_frames: 0,
+            shared_with_sio: false,
+        }
+    }
+
+    /// Records `command`.
+    ///
+    /// # Panics
+    /// Panics if `N` commands have already been recorded.
+    fn send(&mut self, command: Command) {
+        assert!(self.len < N, "RumbleSpy can only record {N} commands");
+        self.commands[self.len] = Some(TimedCommand {
+            frame: self.frame,
+            command,
+        });
+        self.len += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RumbleSpy;
+    use crate::{Backend, Command};
+    use gba_test::test;
+
+    fn spy(commands: &[(u32, Command)], frames: u32) -> RumbleSpy<8> {
+        let mut spy = RumbleSpy::new();
+        for frame in 0..frames {
+            for (_, command) in commands.iter().filter(|(at, _)| *at == frame) {
+                spy.send(*command);
+            }
+            spy.tick();
+        }
+        spy
+    }
+
+    #[test]
+    fn on_frames() {
+        let spy = spy(
+            &[
+                (1, Command::Start),
+                (4, Command::Stop),
+                (6, Command::Start),
+                (7, Command::HardStop),
+            ],
+            10,
+        );
+
+        assert_eq!(spy.on_frames(), 4);
+        assert!(!spy.is_on());
+        spy.assert_total_on_frames(4);
+    }
+
+    #[test]
+    fn on_frames_still_on() {
+        let spy = spy(&[(2, Command::Start), (3, Command::Start)], 5);
+
+        assert!(spy.is_on());
+        assert_eq!(spy.on_frames(), 3);
+    }
+
+    #[test]
+    fn started_between() {
+        let spy = spy(&[(3, Command::Start)], 5);
+
+        spy.assert_started_between(3..4);
+        spy.assert_started_between(..=3);
+    }
+
+    #[test]
+    #[should_panic(expected = "rumble was not started between frames 4..")]
+    fn started_between_fails() {
+        spy(&[(3, Command::Start)], 5).assert_started_between(4..);
+    }
+
+    #[test]
+    #[should_panic(expected = "rumble was on for 2 frames, expected 3")]
+    fn total_on_frames_fails() {
+        spy(&[(3, Command::Start)], 5).assert_total_on_frames(3);
+    }
+
+    #[test]
+    fn never_started() {
+        spy(&[(3, Command::Stop)], 5).assert_never_started();
+    }
+
+    #[test]
+    #[should_panic(expected = "rumble was started on frame 1")]
+    fn never_started_fails() {
+        spy(&[(1, Command::Start)], 5).assert_never_started();
+    }
+}
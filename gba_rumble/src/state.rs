@@ -0,0 +1,207 @@
+//! Saving and restoring playback state.
+
+use core::{
+    fmt,
+    fmt::{Display, Formatter},
+};
+
+/// An error encountered while serializing or restoring state with [`SaveState`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum StateError {
+    /// The buffer is shorter than [`SaveState::STATE_LEN`].
+    BufferTooSmall,
+    /// The buffer does not contain state that can be restored into this value.
+    ///
+    /// This happens if the buffer was not written by [`SaveState::serialize_state()`] on the same
+    /// type, or if it was written while playing something different, such as a different pattern.
+    Invalid,
+}
+
+impl Display for StateError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::BufferTooSmall => "buffer is too small to hold the state",
+            Self::Invalid => "buffer does not contain valid state",
+        })
+    }
+}
+
+impl core::error::Error for StateError {}
+
+/// Playback state that can be snapshotted and resumed later.
+///
+/// This allows games with their own suspend or save-state features, or emulators implementing
+/// rewind, to keep rumble consistent with the rest of the game. State is written as exactly
+/// [`STATE_LEN`] bytes, so the state of several values can be stored one after another in a single
+/// buffer.
+///
+/// Only playback progress is saved, such as the position within a pattern. Anything the game
+/// provides, such as the patterns themselves, configuration, and backends, is not. State must be
+/// restored into a value set up the same way as the one it was saved from, such as a
+/// [`Sequencer`] playing the same pattern.
+///
+/// ``` rust
+/// use gba_rumble::{Intensity, SaveState, Sequencer, Step, pattern};
+///
+/// let mut sequencer = Sequencer::new(pattern![Step::Hold {
+///     intensity: Intensity::MAX,
+///     frames: 60,
+/// }]);
+/// sequencer.tick();
+///
+/// let mut buffer = [0; Sequencer::STATE_LEN];
+/// sequencer.serialize_state(&mut buffer).unwrap();
+///
+/// // Later, after loading a save state:
+/// sequencer.restore_state(&buffer).unwrap();
+/// ```
+///
+/// [`STATE_LEN`]: SaveState::STATE_LEN
+/// [`Sequencer`]: crate::Sequencer
+pub trait SaveState {
+    /// The number of bytes used to store the state.
+    const STATE_LEN: usize;
+
+    /// Writes the current state to the start of `buffer`.
+    ///
+    /// Returns the number of bytes written, which is always [`STATE_LEN`].
+    ///
+    /// [`STATE_LEN`]: SaveState::STATE_LEN
+    fn serialize_state(&self, buffer: &mut [u8]) -> Result<usize, StateError>;
+
+    /// Restores state previously written by [`serialize_state()`] from the start of `buffer`.
+    ///
+    /// Returns the number of bytes read, which is always [`STATE_LEN`]. If an error is returned,
+    /// the current state is left unchanged.
+    ///
+    /// [`serialize_state()`]: SaveState::serialize_state()
+    /// [`STATE_LEN`]: SaveState::STATE_LEN
+    fn restore_state(&mut self, buffer: &[u8]) -> Result<usize, StateError>;
+}
+
+/// Writes state into a buffer.
+pub(crate) struct StateWriter<'a> {
+    buffer: &'a mut [u8],
+    position: usize,
+}
+
+impl<'a> StateWriter<'a> {
+    /// Creates a writer for `len` bytes at the start of `buffer`.
+    pub(crate) fn new(buffer: &'a mut [u8], len: usize) -> Result<Self, StateError> {
+        Ok(Self {
+            buffer: buffer.get_mut(..len).ok_or(StateError::BufferTooSmall)?,
+            position: 0,
+        })
+    }
+
+    pub(crate) fn u8(&mut self, value: u8) {
+        self.buffer[self.position] = value;
+        self.position += 1;
+    }
+
+    pub(crate) fn u32(&mut self, value: u32) {
+        self.buffer[self.position..self.position + 4].copy_from_slice(&value.to_le_bytes());
+        self.position += 4;
+    }
+
+    /// Fills the remaining bytes with zeros, returning the number of bytes written in total.
+    pub(crate) fn finish(self) -> usize {
+        self.buffer[self.position..].fill(0);
+        self.buffer.len()
+    }
+}
+
+/// Reads state from a buffer.
+pub(crate) struct StateReader<'a> {
+    buffer: &'a [u8],
+    position: usize,
+}
+
+impl<'a> StateReader<'a> {
+    /// Creates a reader for `len` bytes at the start of `buffer`.
+    pub(crate) fn new(buffer: &'a [u8], len: usize) -> Result<Self, StateError> {
+        Ok(Self {
+            buffer: buffer.get(..len).ok_or(StateError::BufferTooSmall)?,
+            position: 0,
+        })
+    }
+
+    pub(crate) fn u8(&mut self) -> Result<u8, StateError> {
+        let value = *self.buffer.get(self.position).ok_or(StateError::Invalid)?;
+        self.position += 1;
+        Ok(value)
+    }
+
+    pub(crate) fn u32(&mut self) -> Result<u32, StateError> {
+        let bytes = self
+            .buffer
+            .get(self.position..self.position + 4)
+            .ok_or(StateError::Invalid)?;
+        self.position += 4;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    pub(crate) fn bool(&mut self) -> Result<bool, StateError> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(StateError::Invalid),
+        }
+    }
+
+    /// Returns the number of bytes read in total.
+    pub(crate) fn finish(self) -> usize {
+        self.buffer.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StateError, StateReader, StateWriter};
+    use claims::{assert_err_eq, assert_ok, assert_ok_eq};
+    use gba_test::test;
+
+    #[test]
+    fn round_trip() {
+        let mut buffer = [0xFF; 8];
+        let mut writer = assert_ok!(StateWriter::new(&mut buffer, 7));
+        writer.u8(1);
+        writer.u32(0x1234_5678);
+
+        assert_eq!(writer.finish(), 7);
+        assert_eq!(buffer, [1, 0x78, 0x56, 0x34, 0x12, 0, 0, 0xFF]);
+
+        let mut reader = assert_ok!(StateReader::new(&buffer, 7));
+
+        assert_ok_eq!(reader.bool(), true);
+        assert_ok_eq!(reader.u32(), 0x1234_5678);
+        assert_ok_eq!(reader.u8(), 0);
+        assert_eq!(reader.finish(), 7);
+    }
+
+    #[test]
+    fn buffer_too_small() {
+        assert_err_eq!(
+            StateWriter::new(&mut [0; 3], 4).map(|_| ()),
+            StateError::BufferTooSmall
+        );
+        assert_err_eq!(
+            StateReader::new(&[0; 3], 4).map(|_| ()),
+            StateError::BufferTooSmall
+        );
+    }
+
+    #[test]
+    fn invalid_bool() {
+        let mut reader = assert_ok!(StateReader::new(&[2], 1));
+
+        assert_err_eq!(reader.bool(), StateError::Invalid);
+    }
+
+    #[test]
+    fn read_past_end() {
+        let mut reader = assert_ok!(StateReader::new(&[0; 2], 2));
+
+        assert_err_eq!(reader.u32(), StateError::Invalid);
+    }
+}
@@ -0,0 +1,59 @@
+//! Hardware timers.
+
+use crate::regs;
+use core::ptr;
+
+/// One of the GBA's four hardware timers.
+///
+/// This is used to select which timer drives [`GameBoyPlayer::enable_timer_updates()`].
+///
+/// [`GameBoyPlayer::enable_timer_updates()`]: crate::GameBoyPlayer::enable_timer_updates()
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Timer {
+    /// Timer 0, controlled by `TM0CNT`.
+    Timer0,
+    /// Timer 1, controlled by `TM1CNT`.
+    Timer1,
+    /// Timer 2, controlled by `TM2CNT`.
+    Timer2,
+    /// Timer 3, controlled by `TM3CNT`.
+    Timer3,
+}
+
+impl Timer {
+    /// Returns the timer's `TMxCNT_L` register, holding the reload value on write and the counter
+    /// on read.
+    pub(crate) fn counter(self) -> *mut u16 {
+        ptr::with_exposed_provenance_mut(regs::TM0CNT_L + self as usize * 4)
+    }
+
+    /// Returns the timer's `TMxCNT_H` control register.
+    pub(crate) fn control(self) -> *mut u16 {
+        ptr::with_exposed_provenance_mut(regs::TM0CNT_H + self as usize * 4)
+    }
+
+    /// Returns the bit of `IE` and `IF` used by this timer's interrupt.
+    pub const fn interrupt_bit(self) -> u16 {
+        1 << (3 + self as u16)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Timer;
+    use gba_test::test;
+
+    #[test]
+    fn registers() {
+        assert_eq!(Timer::Timer0.counter().addr(), 0x0400_0100);
+        assert_eq!(Timer::Timer0.control().addr(), 0x0400_0102);
+        assert_eq!(Timer::Timer3.counter().addr(), 0x0400_010C);
+        assert_eq!(Timer::Timer3.control().addr(), 0x0400_010E);
+    }
+
+    #[test]
+    fn interrupt_bit() {
+        assert_eq!(Timer::Timer0.interrupt_bit(), 1 << 3);
+        assert_eq!(Timer::Timer3.interrupt_bit(), 1 << 6);
+    }
+}
@@ -0,0 +1,156 @@
+//! Waiting for v-blank.
+
+use crate::{bios, regs};
+use core::{
+    fmt,
+    fmt::{Debug, Formatter},
+    ptr,
+};
+
+const VCOUNT: *const u16 = ptr::with_exposed_provenance(regs::VCOUNT);
+const IME: *const u16 = ptr::with_exposed_provenance(regs::IME);
+
+/// Waits for the start of the next v-blank.
+///
+/// Detection waits once per frame while listening for the Game Boy Player. By default, this is
+/// done with [`BiosWaiter`], which issues the BIOS's `VBlankIntrWait` call. Runtimes that manage
+/// interrupt acknowledgement themselves can supply their own implementation through
+/// [`DetectOptions::waiter()`], such as one wrapping `agb`'s `VBlank` using a [`WaitFn`].
+///
+/// [`DetectOptions::waiter()`]: crate::DetectOptions::waiter()
+pub trait Waiter {
+    /// Block until the next v-blank begins.
+    fn wait_for_vblank(&mut self);
+}
+
+impl<W> Waiter for &mut W
+where
+    W: Waiter + ?Sized,
+{
+    fn wait_for_vblank(&mut self) {
+        (**self).wait_for_vblank();
+    }
+}
+
+/// Waits using the BIOS `VBlankIntrWait` call.
+///
+/// This requires v-blank interrupts to be enabled, and the interrupt handler to acknowledge them
+/// in the BIOS interrupt flags, or waiting will hang forever.
+///
+/// Boot code often runs with interrupts disabled in `IME` until the engine is initialized, in
+/// which case no interrupt could ever end the wait. If `IME` is found disabled, this waits by
+/// polling `VCOUNT` instead, as [`VcountWaiter`] does, so that detection still works before
+/// interrupts are set up.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct BiosWaiter;
+
+impl Waiter for BiosWaiter {
+    fn wait_for_vblank(&mut self) {
+        if unsafe { IME.read_volatile() } & 1 == 0 {
+            VcountWaiter.wait_for_vblank();
+        } else {
+            bios::wait_for_vblank();
+        }
+    }
+}
+
+/// Waits by polling `VCOUNT`.
+///
+/// This does not rely on interrupts at all, but keeps the CPU busy while waiting.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct VcountWaiter;
+
+impl Waiter for VcountWaiter {
+    fn wait_for_vblank(&mut self) {
+        // Finish any v-blank already in progress, then wait for the next one to begin.
+        while unsafe { VCOUNT.read_volatile() } >= 160 {}
+        while unsafe { VCOUNT.read_volatile() } < 160 {}
+    }
+}
+
+/// A [`Waiter`] that calls a closure.
+///
+/// ``` rust,ignore
+/// use gba_rumble::{DetectOptions, GameBoyPlayer, GbpConfig, WaitFn};
+///
+/// let vblank = agb::interrupt::VBlank::get();
+/// let game_boy_player = GameBoyPlayer::detect_with(GbpConfig::new().detection(
+///     DetectOptions::new().waiter(WaitFn::new(|| vblank.wait_for_vblank())),
+/// ));
+/// ```
+#[derive(Clone, Copy)]
+pub struct WaitFn<F> {
+    f: F,
+}
+
+impl<F> WaitFn<F>
+where
+    F: FnMut(),
+{
+    /// Creates a waiter that calls `f` to wait for v-blank.
+    pub const fn new(f: F) -> Self {
+        Self { f }
+    }
+}
+
+impl<F> Waiter for WaitFn<F>
+where
+    F: FnMut(),
+{
+    fn wait_for_vblank(&mut self) {
+        (self.f)();
+    }
+}
+
+impl<F> Debug for WaitFn<F> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.debug_struct("WaitFn").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BiosWaiter, IME, VCOUNT, VcountWaiter, WaitFn, Waiter};
+    use alloc::format;
+    use gba_test::test;
+
+    #[test]
+    fn vcount_waiter() {
+        VcountWaiter.wait_for_vblank();
+
+        assert_eq!(unsafe { VCOUNT.read_volatile() }, 160);
+    }
+
+    #[test]
+    fn bios_waiter_without_ime() {
+        let ime = IME.cast_mut();
+        let previous = unsafe { ime.read_volatile() };
+        unsafe {
+            ime.write_volatile(0);
+        }
+
+        BiosWaiter.wait_for_vblank();
+
+        let vcount = unsafe { VCOUNT.read_volatile() };
+        unsafe {
+            ime.write_volatile(previous);
+        }
+        assert_eq!(vcount, 160);
+    }
+
+    #[test]
+    fn wait_fn() {
+        let mut count = 0;
+        let mut waiter = WaitFn::new(|| count += 1);
+
+        waiter.wait_for_vblank();
+        waiter.wait_for_vblank();
+
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn wait_fn_debug() {
+        assert_eq!(format!("{:?}", WaitFn::new(|| {})), "WaitFn { .. }");
+    }
+}
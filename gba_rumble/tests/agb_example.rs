@@ -0,0 +1,140 @@
+//! Integration tests following the `agb` example.
+//!
+//! Programs using the [`agb`](https://crates.io/crates/agb) crate register a separate closure for
+//! each interrupt source through `add_interrupt_handler()`. These tests register the serial
+//! interrupt handler through an [`IrqRegistrarFn`] into a table of the same shape, as
+//! [`GameBoyPlayer::boot()`] would with `agb`, and wait for each frame with `VBlankIntrWait`.
+//!
+//! These tests are only built with the `integration-tests` feature enabled.
+
+#![no_std]
+#![no_main]
+#![feature(custom_test_frameworks)]
+#![test_runner(gba_test::runner)]
+#![reexport_test_harness_main = "test_harness"]
+
+mod simulator;
+
+use gba_rumble::{
+    BiosWaiter, Driver, GameBoyPlayer, Gpio, IrqRegistrar, IrqRegistrarFn, Sequencer, Waiter,
+    irq::Interrupt,
+    protocol::consts::{RUMBLE_REQUEST, RUMBLE_START, RUMBLE_STOP},
+    regs,
+};
+use gba_test::test;
+
+const DISPSTAT: *mut u16 = regs::DISPSTAT as *mut u16;
+const IE: *mut u16 = regs::IE as *mut u16;
+const IME: *mut u16 = regs::IME as *mut u16;
+
+#[unsafe(no_mangle)]
+pub fn main() {
+    let _ = mgba_log::init();
+    test_harness()
+}
+
+/// The handlers registered for each interrupt source, as `agb` keeps them.
+struct Handlers {
+    serial: Option<fn()>,
+}
+
+impl Handlers {
+    /// Registers handlers the way the `agb` example does, enabling each interrupt as it is added.
+    fn registrar(&mut self) -> impl IrqRegistrar + '_ {
+        IrqRegistrarFn::new(|handler| {
+            self.serial = Some(handler);
+            unsafe {
+                IE.write_volatile(IE.read_volatile() | Interrupt::Serial.bit());
+            }
+        })
+    }
+
+    /// Delivers a serial interrupt the way `agb` does, calling the registered handler.
+    fn serial_interrupt(&self) -> impl FnMut() + '_ {
+        || {
+            if let Some(handler) = self.serial {
+                handler();
+            }
+        }
+    }
+}
+
+/// Enables the vblank interrupt, which `agb` enables on startup.
+fn enable_vblank() {
+    unsafe {
+        DISPSTAT.write_volatile(DISPSTAT.read_volatile() | (1 << 3));
+        IE.write_volatile(Interrupt::VBlank.bit());
+        IME.write_volatile(1);
+    }
+}
+
+#[test]
+#[cfg_attr(
+    not(game_boy_player),
+    ignore = "This test should be run on a Game Boy Player (or emulator with Game Boy Player functionality). Pass `--cfg game_boy_player` to enable."
+)]
+fn boot_game_boy_player() {
+    enable_vblank();
+    BiosWaiter.wait_for_vblank();
+    let mut handlers = Handlers { serial: None };
+
+    let game_boy_player =
+        GameBoyPlayer::boot(handlers.registrar()).expect("Game Boy Player detected");
+
+    assert!(handlers.serial.is_some());
+    assert_eq!(game_boy_player.check_serial(), Ok(()));
+    simulator::disconnect();
+}
+
+#[test]
+#[cfg_attr(
+    game_boy_player,
+    ignore = "This test should be run on a console that is not a Game Boy Player (or emulator with Game Boy Player functionality disabled). Omit `--cfg game_boy_player` to enable."
+)]
+fn fall_back_to_gpio() {
+    enable_vblank();
+    BiosWaiter.wait_for_vblank();
+    let mut handlers = Handlers { serial: None };
+
+    assert!(GameBoyPlayer::boot(handlers.registrar()).is_none());
+    assert!(handlers.serial.is_none());
+    let gpio = Gpio;
+    gpio.start();
+    BiosWaiter.wait_for_vblank();
+    gpio.stop();
+}
+
+#[test]
+fn play_effect() {
+    enable_vblank();
+    let mut handlers = Handlers { serial: None };
+    let game_boy_player = simulator::game_boy_player();
+    handlers
+        .registrar()
+        .register_serial(gba_rumble::game_boy_player_interrupt);
+    game_boy_player.enable_serial();
+    let mut serial_interrupt = handlers.serial_interrupt();
+    simulator::handshake(&mut serial_interrupt);
+    let mut driver = Driver::<_>::new(game_boy_player);
+    let mut sequencer = Sequencer::new(simulator::EFFECT);
+
+    let mut responses = [0; 5];
+    for response in &mut responses {
+        BiosWaiter.wait_for_vblank();
+        driver.tick(sequencer.tick());
+        driver.backend().update();
+        *response = simulator::exchange(&mut serial_interrupt, RUMBLE_REQUEST);
+    }
+
+    simulator::disconnect();
+    assert_eq!(
+        responses,
+        [
+            RUMBLE_START,
+            RUMBLE_START,
+            RUMBLE_START,
+            RUMBLE_STOP,
+            RUMBLE_STOP
+        ]
+    );
+}
@@ -0,0 +1,117 @@
+//! Integration tests following the `gba` example.
+//!
+//! Programs using the [`gba`](https://crates.io/crates/gba) crate handle interrupts in a single
+//! function assigned to `RUST_IRQ_HANDLER`, which the runtime calls with the bits of every pending
+//! interrupt. These tests route serial interrupts through a function of the same shape, as the
+//! example does, and wait for each frame with `VBlankIntrWait`.
+//!
+//! These tests are only built with the `integration-tests` feature enabled.
+
+#![no_std]
+#![no_main]
+#![feature(custom_test_frameworks)]
+#![test_runner(gba_test::runner)]
+#![reexport_test_harness_main = "test_harness"]
+
+mod simulator;
+
+use gba_rumble::{
+    BiosWaiter, Driver, GameBoyPlayer, Gpio, Sequencer, Waiter,
+    irq::Interrupt,
+    protocol::consts::{RUMBLE_REQUEST, RUMBLE_START, RUMBLE_STOP},
+    regs,
+};
+use gba_test::test;
+
+const DISPSTAT: *mut u16 = regs::DISPSTAT as *mut u16;
+const IE: *mut u16 = regs::IE as *mut u16;
+const IME: *mut u16 = regs::IME as *mut u16;
+
+#[unsafe(no_mangle)]
+pub fn main() {
+    let _ = mgba_log::init();
+    test_harness()
+}
+
+/// The interrupt handler, as written in the example.
+extern "C" fn irq_handler(bits: u16) {
+    if bits & Interrupt::Serial.bit() != 0 {
+        gba_rumble::game_boy_player_interrupt();
+    }
+}
+
+/// Enables the interrupts the example enables.
+fn enable_interrupts() {
+    unsafe {
+        DISPSTAT.write_volatile(DISPSTAT.read_volatile() | (1 << 3));
+        IE.write_volatile(Interrupt::VBlank.bit() | Interrupt::Serial.bit());
+        IME.write_volatile(1);
+    }
+}
+
+/// Delivers a serial interrupt the way the `gba` runtime does.
+fn serial_interrupt() {
+    irq_handler(Interrupt::Serial.bit());
+}
+
+#[test]
+#[cfg_attr(
+    not(game_boy_player),
+    ignore = "This test should be run on a Game Boy Player (or emulator with Game Boy Player functionality). Pass `--cfg game_boy_player` to enable."
+)]
+fn detect_game_boy_player() {
+    enable_interrupts();
+    BiosWaiter.wait_for_vblank();
+
+    let game_boy_player = GameBoyPlayer::detect().expect("Game Boy Player detected");
+    game_boy_player.enable_serial();
+
+    assert_eq!(game_boy_player.check_serial(), Ok(()));
+    simulator::disconnect();
+}
+
+#[test]
+#[cfg_attr(
+    game_boy_player,
+    ignore = "This test should be run on a console that is not a Game Boy Player (or emulator with Game Boy Player functionality disabled). Omit `--cfg game_boy_player` to enable."
+)]
+fn fall_back_to_gpio() {
+    enable_interrupts();
+    BiosWaiter.wait_for_vblank();
+
+    assert!(GameBoyPlayer::detect().is_none());
+    let gpio = Gpio;
+    gpio.start();
+    BiosWaiter.wait_for_vblank();
+    gpio.stop();
+}
+
+#[test]
+fn play_effect() {
+    enable_interrupts();
+    let game_boy_player = simulator::game_boy_player();
+    game_boy_player.enable_serial();
+    simulator::handshake(&mut serial_interrupt);
+    let mut driver = Driver::<_>::new(game_boy_player);
+    let mut sequencer = Sequencer::new(simulator::EFFECT);
+
+    let mut responses = [0; 5];
+    for response in &mut responses {
+        BiosWaiter.wait_for_vblank();
+        driver.tick(sequencer.tick());
+        driver.backend().update();
+        *response = simulator::exchange(&mut serial_interrupt, RUMBLE_REQUEST);
+    }
+
+    simulator::disconnect();
+    assert_eq!(
+        responses,
+        [
+            RUMBLE_START,
+            RUMBLE_START,
+            RUMBLE_START,
+            RUMBLE_STOP,
+            RUMBLE_STOP
+        ]
+    );
+}
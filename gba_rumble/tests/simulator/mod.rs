@@ -0,0 +1,62 @@
+//! An offline Game Boy Player, driving the serial interrupt handler through a session.
+//!
+//! Nothing is connected to the serial port while these tests run, so the values the Game Boy
+//! Player would send are written to `SIODATA32` directly, and the handler is called through the
+//! interrupt model under test. The responses the handler prepares are read back from `SIODATA32`.
+
+use gba_rumble::{
+    GameBoyPlayer, Intensity, Pattern, Step, pattern,
+    protocol::consts::{HANDSHAKE_RECEIVED, HANDSHAKE_SENT, MAGIC_RECEIVED, MAGIC_SENT},
+    regs,
+};
+
+const SIODATA: *mut u32 = regs::SIODATA32 as *mut u32;
+const SIOCNT: *mut u16 = regs::SIOCNT as *mut u16;
+const IE: *mut u16 = regs::IE as *mut u16;
+
+/// The raw session of a Game Boy Player at the start of the handshake, with rumble stopped.
+const SESSION_START: u32 = 0x4700_0000;
+
+/// The effect played by each test: three frames of rumble.
+pub const EFFECT: Pattern = pattern![Step::Hold {
+    intensity: Intensity::MAX,
+    frames: 3
+}];
+
+/// Returns a Game Boy Player at the start of a fresh session.
+pub fn game_boy_player() -> GameBoyPlayer {
+    // SAFETY: No real Game Boy Player is communicated with; every transfer is simulated.
+    unsafe { GameBoyPlayer::from_raw(SESSION_START) }.expect("valid raw session")
+}
+
+/// Passes `input` to the serial interrupt handler through `interrupt`, as if it were received
+/// from the Game Boy Player, returning the response the handler prepared.
+pub fn exchange(interrupt: &mut impl FnMut(), input: u32) -> u32 {
+    unsafe {
+        SIODATA.write_volatile(input);
+    }
+    interrupt();
+    unsafe {
+        // Keep the transfer armed by the handler from starting.
+        SIOCNT.write_volatile(SIOCNT.read_volatile() & !(1 << 7));
+        SIODATA.read_volatile()
+    }
+}
+
+/// Completes the handshake through `interrupt`, checking every response along the way.
+pub fn handshake(interrupt: &mut impl FnMut()) {
+    for (input, response) in HANDSHAKE_RECEIVED.into_iter().zip(HANDSHAKE_SENT) {
+        assert_eq!(exchange(interrupt, input), response);
+    }
+    for (input, response) in MAGIC_RECEIVED.into_iter().zip(MAGIC_SENT) {
+        assert_eq!(exchange(interrupt, input), response);
+    }
+}
+
+/// Disables the serial port and its interrupt, so that later tests start from a clean state.
+pub fn disconnect() {
+    unsafe {
+        SIOCNT.write_volatile(0);
+        IE.write_volatile(IE.read_volatile() & !(1 << 7));
+    }
+}
@@ -0,0 +1,115 @@
+//! Tests of `gba_rumble`'s non-volatile core, run under [Miri](https://github.com/rust-lang/miri).
+//!
+//! The crate's own tests only run on a GBA, where Miri cannot follow them. The code below touches
+//! no hardware registers, only pattern bytecode, playback state, and the Game Boy Player's
+//! protocol state, so it can instead be interpreted for `armv5te-unknown-linux-gnueabi`, an ARM
+//! target with `std` that the crate also builds for:
+//!
+//! ``` sh
+//! cargo +nightly miri test --target armv5te-unknown-linux-gnueabi
+//! ```
+//!
+//! Miri then checks these paths for undefined behavior, including the crate's accesses to its
+//! statics and its pointer provenance.
+
+#[cfg(test)]
+mod tests {
+    use gba_rumble::{
+        GameBoyPlayer, Intensity, Mixer, Pattern, Preemption, SaveState, Sequencer, Settings,
+        SourceHandle, Step, ValidationRules, compressed_pattern, pattern,
+    };
+
+    const PULSE: Pattern = pattern![
+        Step::Hold {
+            intensity: Intensity::MAX,
+            frames: 2,
+        },
+        Step::Hold {
+            intensity: Intensity::OFF,
+            frames: 1,
+        },
+        Step::Loop { step: 0, count: 1 },
+    ];
+
+    const COMPRESSED: Pattern = compressed_pattern![
+        Step::Hold {
+            intensity: Intensity::MAX,
+            frames: 2,
+        },
+        Step::Hold {
+            intensity: Intensity::OFF,
+            frames: 1,
+        },
+        Step::Loop { step: 0, count: 1 },
+    ];
+
+    #[test]
+    fn pattern_encodings() {
+        assert!(COMPRESSED.size_in_bytes() < PULSE.size_in_bytes());
+        assert!(PULSE.steps().eq(COMPRESSED.steps()));
+        assert_eq!(
+            Pattern::validate(PULSE.as_bytes(), ValidationRules::new()).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn pattern_header() {
+        let mut bytes = Pattern::header(PULSE.as_bytes()).to_vec();
+        bytes.extend_from_slice(PULSE.as_bytes());
+
+        let pattern = Pattern::from_bytes(&bytes).unwrap();
+        assert!(pattern.steps().eq(PULSE.steps()));
+
+        *bytes.last_mut().unwrap() ^= 1;
+        assert!(Pattern::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn sequencer_state_round_trip() {
+        let mut sequencer = Sequencer::new(PULSE);
+        sequencer.tick();
+        sequencer.tick();
+        let mut buffer = [0; Sequencer::STATE_LEN];
+        sequencer.serialize_state(&mut buffer).unwrap();
+
+        let mut restored = Sequencer::new(PULSE);
+        restored.restore_state(&buffer).unwrap();
+
+        for _ in 0..6 {
+            assert_eq!(restored.tick(), sequencer.tick());
+        }
+        assert!(restored.is_finished());
+    }
+
+    #[test]
+    fn mixer_source_dropped() {
+        let mut mixer = Mixer::<2>::new();
+        let channel = mixer.add_channel(0, Preemption::Reject).unwrap();
+        let source = SourceHandle::new().unwrap();
+
+        mixer.play_from(channel, PULSE, &source);
+        assert_eq!(mixer.tick(), Intensity::MAX);
+        drop(source);
+        assert_eq!(mixer.tick(), Intensity::OFF);
+        assert!(!mixer.is_playing(channel));
+    }
+
+    #[test]
+    fn settings_round_trip() {
+        let settings = Settings::new();
+
+        assert_eq!(Settings::from_bytes(settings.to_bytes()), Some(settings));
+    }
+
+    #[test]
+    fn game_boy_player_raw_round_trip() {
+        let game_boy_player = unsafe { GameBoyPlayer::from_raw(0x4700_0000) }.unwrap();
+        game_boy_player.start();
+        let raw = game_boy_player.into_raw();
+
+        let restored = unsafe { GameBoyPlayer::from_raw(raw) }.unwrap();
+        assert_eq!(restored.into_raw(), raw);
+        assert!(!format!("{}", restored.debug_state_machine()).is_empty());
+    }
+}
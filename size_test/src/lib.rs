@@ -0,0 +1,204 @@
+//! Size regression tests for programs using only cartridge rumble.
+//!
+//! Programs that only use [`Gpio`] shouldn't pay for the Game Boy Player. This crate is linked
+//! using `gpio_only.ld`, which collects the Game Boy Player splash screen data and anything
+//! `gba_rumble` places in IWRAM (the serial interrupt handler) into their own sections. The linker
+//! script asserts that both are empty, failing the build otherwise, and the tests below check the
+//! same at runtime.
+//!
+//! The Game Boy Player's serial code can only be reached through a detected `GameBoyPlayer`, and
+//! detection references the splash screen, so an empty splash section also means no serial code
+//! was linked.
+//!
+//! The GBA has no FPU, so the linker script also asserts that none of the compiler's software
+//! floating-point routines are linked. The tests below exercise the effects subsystem, so that all
+//! of its code is included in this check.
+//!
+//! With the `disabled` feature, which enables `gba_rumble`'s `disabled` feature, the tests instead
+//! call into every part of the crate a game typically uses, and check that the code and read-only
+//! data compiled into `gba_rumble` stay within a small budget, proving rumble was compiled out.
+//!
+//! [`Gpio`]: gba_rumble::Gpio
+
+#![no_std]
+#![cfg_attr(test, no_main)]
+#![cfg_attr(test, feature(custom_test_frameworks))]
+#![cfg_attr(test, test_runner(gba_test::runner))]
+#![cfg_attr(test, reexport_test_harness_main = "test_harness")]
+
+#[cfg(test)]
+#[unsafe(no_mangle)]
+pub fn main() {
+    let _ = mgba_log::init();
+    test_harness()
+}
+
+#[cfg(all(test, not(feature = "disabled")))]
+mod tests {
+    use gba_rumble::{
+        Backend, Command, Driver, EffectFn, Fixed, Gpio, Intensity, Mixer, Pattern, Preemption,
+        ResponseCurve, Sequencer, Settings, SimpleRumble, Step, pattern,
+    };
+    use gba_test::test;
+
+    unsafe extern "C" {
+        static __gba_rumble_splash_start: u8;
+        static __gba_rumble_splash_end: u8;
+        static __gba_rumble_iwram_start: u8;
+        static __gba_rumble_iwram_end: u8;
+    }
+
+    #[test]
+    fn gpio() {
+        let mut gpio = Gpio;
+
+        gpio.start();
+        gpio.stop();
+        gpio.send(Command::Start);
+        gpio.send(Command::HardStop);
+    }
+
+    #[test]
+    fn simple() {
+        let mut rumble = SimpleRumble::init();
+
+        rumble.pulse(1);
+        rumble.tick();
+        rumble.set(true);
+        rumble.tick();
+    }
+
+    #[test]
+    fn no_splash_data() {
+        let start = &raw const __gba_rumble_splash_start;
+        let end = &raw const __gba_rumble_splash_end;
+
+        assert_eq!(end as usize - start as usize, 0);
+    }
+
+    #[test]
+    fn no_iwram_code() {
+        let start = &raw const __gba_rumble_iwram_start;
+        let end = &raw const __gba_rumble_iwram_end;
+
+        assert_eq!(end as usize - start as usize, 0);
+    }
+
+    #[test]
+    fn effects() {
+        const PULSE: Pattern = pattern![
+            Step::Hold {
+                intensity: Intensity::MAX,
+                frames: 2
+            },
+            Step::Hold {
+                intensity: Intensity::new(64),
+                frames: 2
+            },
+            Step::Loop { step: 0, count: 2 },
+        ];
+        let mut engine = EffectFn::new(|frame| {
+            Intensity::from_distance(frame as u16, 8).scale(Fixed::from_ratio(3, 4))
+        });
+        let mut mixer = Mixer::<2>::new();
+        let pattern = mixer.add_channel(0, Preemption::Queue).unwrap();
+        let effect = mixer.add_channel(1, Preemption::Replace).unwrap();
+        let mut driver = Driver::<_, 8>::new(Gpio);
+        driver.set_settings(Settings {
+            enabled: true,
+            intensity_scale: Intensity::new(200),
+        });
+        driver.set_response_curve(ResponseCurve::SQUARE_ROOT);
+
+        mixer.play(pattern, PULSE);
+        mixer.play_effect(effect, &mut engine);
+        for _ in 0..20 {
+            driver.tick(mixer.tick());
+        }
+        driver.tick(Intensity::from_impulse(0));
+
+        let mut sequencer = Sequencer::new(PULSE);
+        assert_eq!(sequencer.tick(), Intensity::MAX);
+        assert!(
+            driver
+                .log()
+                .iter()
+                .any(|entry| entry.command == Command::Stop)
+        );
+    }
+}
+
+#[cfg(all(test, feature = "disabled"))]
+mod disabled {
+    use gba_rumble::{
+        Driver, GameBoyPlayer, Gpio, Haptics, Intensity, IrqRegistrar, Mixer, Pattern, Preemption,
+        SimpleRumble, Step, presets,
+    };
+    use gba_test::test;
+
+    /// The most bytes of code and read-only data `gba_rumble` may contribute when disabled.
+    const BUDGET: usize = 1024;
+
+    const EFFECTS: [Pattern; 2] = [presets::MENU_TICK, presets::CONFIRM];
+
+    unsafe extern "C" {
+        static __gba_rumble_text_start: u8;
+        static __gba_rumble_text_end: u8;
+        static __gba_rumble_rodata_start: u8;
+        static __gba_rumble_rodata_end: u8;
+    }
+
+    struct Registrar;
+
+    impl IrqRegistrar for Registrar {
+        fn register_serial(&mut self, _handler: fn()) {
+            panic!("no serial handler should be registered while disabled");
+        }
+    }
+
+    #[test]
+    fn nothing_detected() {
+        assert!(GameBoyPlayer::detect().is_none());
+        assert!(GameBoyPlayer::boot(Registrar).is_none());
+        assert!(!gba_rumble::is_enabled());
+    }
+
+    #[test]
+    fn updates_do_nothing() {
+        let mut device = gba_rumble::detect_all();
+        device.play(presets::ERROR);
+        device.update();
+        assert!(!device.is_rumbling());
+
+        let mut haptics = Haptics::detect(&EFFECTS);
+        haptics.tick();
+
+        let mut mixer = Mixer::<1>::new();
+        let channel = mixer.add_channel(0, Preemption::Replace).unwrap();
+        mixer.play(
+            channel,
+            gba_rumble::pattern![Step::Hold {
+                intensity: Intensity::MAX,
+                frames: 4,
+            }],
+        );
+        let mut driver = Driver::<_>::new(Gpio);
+        assert_eq!(driver.tick(mixer.tick()), None);
+
+        let mut rumble = SimpleRumble::init();
+        rumble.set(true);
+        rumble.tick();
+        Gpio.start();
+        Gpio.stop();
+    }
+
+    #[test]
+    fn compiled_out() {
+        let text =
+            (&raw const __gba_rumble_text_end).addr() - (&raw const __gba_rumble_text_start).addr();
+        let rodata = (&raw const __gba_rumble_rodata_end).addr()
+            - (&raw const __gba_rumble_rodata_start).addr();
+
+        assert!(text + rodata <= BUDGET, "{text} + {rodata} bytes linked");
+    }
+}